@@ -4,12 +4,18 @@ use strum_macros::EnumIter;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, EnumIter)]
 pub enum Action {
+    ApplySavedFilter1,
+    ApplySavedFilter2,
+    ApplySavedFilter3,
+    ArchiveCard,
     ChangeCardStatusToActive,
     ChangeCardStatusToCompleted,
     ChangeCardStatusToStale,
     ClearAllToasts,
+    CycleTimelineZoom,
     Delete,
     DeleteBoard,
+    DismissReminder,
     Down,
     Accept,
     GoToMainMenu,
@@ -30,8 +36,13 @@ pub enum Action {
     ResetUI,
     Right,
     SaveState,
+    SnoozeReminder,
+    SortCardTable,
     StopUserInput,
     TakeUserInput,
+    ToggleCalendarLayout,
+    ToggleCardSelection,
+    ToggleCardTimer,
     ToggleCommandPalette,
     Undo,
     Up,
@@ -46,12 +57,18 @@ impl Action {
 impl Display for Action {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
+            Action::ApplySavedFilter1 => "Apply saved filter 1",
+            Action::ApplySavedFilter2 => "Apply saved filter 2",
+            Action::ApplySavedFilter3 => "Apply saved filter 3",
+            Action::ArchiveCard => "Archive card",
             Action::ChangeCardStatusToActive => "Change card status to active",
             Action::ChangeCardStatusToCompleted => "Change card status to completed",
             Action::ChangeCardStatusToStale => "Change card status to stale",
             Action::ClearAllToasts => "Clear all toasts",
+            Action::CycleTimelineZoom => "Change the timeline zoom between days, weeks and months",
             Action::Delete => "Delete focused element",
             Action::DeleteBoard => "Delete Board",
+            Action::DismissReminder => "Dismiss the latest due date reminder",
             Action::Down => "Go down",
             Action::Accept => "Accept",
             Action::GoToMainMenu => "Go to main menu",
@@ -72,8 +89,15 @@ impl Display for Action {
             Action::ResetUI => "Reset UI",
             Action::Right => "Go right",
             Action::SaveState => "Save Kanban state",
+            Action::SnoozeReminder => "Snooze the latest due date reminder",
+            Action::SortCardTable => "Sort the card table by the selected column",
             Action::StopUserInput => "Stop input mode",
             Action::TakeUserInput => "Enter input mode",
+            Action::ToggleCalendarLayout => "Switch the calendar between month and week",
+            Action::ToggleCardSelection => {
+                "Select or unselect the card under the cursor in the card table"
+            }
+            Action::ToggleCardTimer => "Start or stop timer on current card",
             Action::ToggleCommandPalette => "Open command palette",
             Action::Undo => "Undo",
             Action::Up => "Go up",
@@ -0,0 +1,119 @@
+use super::{
+    kanban::{parse_timestamp, Boards, Card, CardPriority, CardStatus},
+    reminders::get_due_moment,
+};
+use crate::constants::{AGENDA_RECENT_DAYS, AGENDA_UPCOMING_DAYS};
+use chrono::{DateTime, Duration, Local, NaiveDateTime};
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AgendaSection {
+    Overdue,
+    DueToday,
+    DueThisWeek,
+    HighPriority,
+    RecentlyModified,
+}
+
+impl AgendaSection {
+    pub fn all() -> Vec<Self> {
+        vec![
+            AgendaSection::Overdue,
+            AgendaSection::DueToday,
+            AgendaSection::DueThisWeek,
+            AgendaSection::HighPriority,
+            AgendaSection::RecentlyModified,
+        ]
+    }
+}
+
+impl Display for AgendaSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AgendaSection::Overdue => write!(f, "Overdue"),
+            AgendaSection::DueToday => write!(f, "Due Today"),
+            AgendaSection::DueThisWeek => write!(f, "Due This Week"),
+            AgendaSection::HighPriority => write!(f, "High Priority"),
+            AgendaSection::RecentlyModified => write!(f, "Recently Modified"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AgendaEntry {
+    pub section: AgendaSection,
+    pub board_id: (u64, u64),
+    pub board_name: String,
+    pub card: Card,
+}
+
+/// every card is listed once, in the first section it fits, sections come in the order of
+/// AgendaSection::all; due dates are sorted soonest first and modified cards newest first
+pub fn collect_agenda_entries(boards: &Boards, now: DateTime<Local>) -> Vec<AgendaEntry> {
+    let mut entries = vec![];
+    for board in boards.get_boards() {
+        for card in board.cards.get_all_cards() {
+            if let Some(section) = get_agenda_section(card, now) {
+                entries.push(AgendaEntry {
+                    section,
+                    board_id: board.id,
+                    board_name: board.name.clone(),
+                    card: card.clone(),
+                });
+            }
+        }
+    }
+    // stable sort, cards that compare equal keep the board order
+    entries.sort_by_cached_key(|entry| {
+        let due_moment = get_due_moment(&entry.card.due_date).map(|(due_moment, _)| due_moment);
+        let modified = match entry.section {
+            AgendaSection::RecentlyModified => parse_timestamp(&entry.card.date_modified)
+                .map(|date_modified| -date_modified.timestamp())
+                .unwrap_or(0),
+            _ => 0,
+        };
+        (entry.section, modified, due_moment.is_none(), due_moment)
+    });
+    entries
+}
+
+fn get_agenda_section(card: &Card, now: DateTime<Local>) -> Option<AgendaSection> {
+    let now_naive = now.naive_local();
+    if card.card_status != CardStatus::Complete {
+        if let Some(section) = get_due_section(&card.due_date, now_naive) {
+            return Some(section);
+        }
+        if card.priority == CardPriority::High && card.card_status == CardStatus::Active {
+            return Some(AgendaSection::HighPriority);
+        }
+    }
+    let recently_modified = parse_timestamp(&card.date_modified).is_some_and(|date_modified| {
+        now.signed_duration_since(date_modified) <= Duration::days(AGENDA_RECENT_DAYS)
+    });
+    if recently_modified {
+        Some(AgendaSection::RecentlyModified)
+    } else {
+        None
+    }
+}
+
+/// anything due within the next seven days counts as this week
+fn get_due_section(due_date: &str, now: NaiveDateTime) -> Option<AgendaSection> {
+    let (due_moment, has_time) = get_due_moment(due_date)?;
+    if now >= due_moment {
+        return Some(AgendaSection::Overdue);
+    }
+    let due_day = if has_time {
+        due_moment.date()
+    } else {
+        due_moment.date() - Duration::days(1)
+    };
+    let today = now.date();
+    if due_day == today {
+        Some(AgendaSection::DueToday)
+    } else if due_day < today + Duration::days(AGENDA_UPCOMING_DAYS) {
+        Some(AgendaSection::DueThisWeek)
+    } else {
+        None
+    }
+}
@@ -1,21 +1,44 @@
 use super::{
     actions::Action,
+    agenda::AgendaEntry,
     date_format_converter, handle_exit,
-    kanban::{Board, Boards, Card, CardPriority, CardStatus, Cards},
-    state::{AppStatus, Focus, UiMode},
-    App, AppReturn, DateFormat, MainMenuItem, PopupMode,
+    kanban::{
+        format_duration, parse_due_date, Board, BoardTemplate, Boards, Card, CardActivityKind,
+        CardAttachment, CardComment, CardPriority, CardSortMode, CardStatus, CardTemplate, Cards,
+        CustomFieldDefinition, RecurrenceRule, SwimlaneGrouping, TimeReportRange,
+        TIME_REPORT_DATE_FORMAT,
+    },
+    query::CardQuery,
+    reminders::ReminderLog,
+    search::{SearchField, SearchResult},
+    sprint::{Sprint, SPRINT_DATE_FORMAT},
+    state::{AppStatus, Focus, KeyBindingEnum, UiMode},
+    stats::CardStatistics,
+    App, AppReturn, CardAttachmentEntry, CardTableColumn, CardTableRow, DateFormat, Label,
+    MainMenuItem, PopupMode, SavedFilter, TimelineEntry, TimelineHandle, Workspace,
+    WorkspaceSwitcherEntry,
 };
 use crate::{
     app::{state::KeyBindings, ActionHistory, AppConfig, ConfigEnum, PathCheckState},
     constants::{
-        DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, LOGIN_FORM_DEFAULT_STATE,
-        MOUSE_OUT_OF_BOUNDS_COORDINATES, NEW_BOARD_FORM_DEFAULT_STATE, NEW_CARD_FORM_DEFAULT_STATE,
-        RESET_PASSWORD_FORM_DEFAULT_STATE, SIGNUP_FORM_DEFAULT_STATE,
+        DEFAULT_TOAST_DURATION, DEFAULT_UI_MODE, FIELD_NOT_SET, IO_EVENT_WAIT_TIME,
+        LOGIN_FORM_DEFAULT_STATE, MAX_TOASTS_TO_DISPLAY, MOUSE_OUT_OF_BOUNDS_COORDINATES,
+        NEW_BOARD_FORM_DEFAULT_STATE, NEW_CARD_FORM_DEFAULT_STATE, REMINDER_CHECK_INTERVAL,
+        REMINDER_SNOOZE_MINUTES, REMINDER_TOAST_DURATION, RESET_PASSWORD_FORM_DEFAULT_STATE,
+        SIGNUP_FORM_DEFAULT_STATE,
     },
     inputs::{key::Key, mouse::Mouse},
     io::{
-        data_handler::{get_config, save_theme, write_config},
-        io_handler::refresh_visible_boards_and_cards,
+        data_handler::{
+            export_statistics_to_csv, export_time_report_to_csv, get_config,
+            get_local_save_snapshots, get_reminder_log, get_saved_board_templates,
+            get_saved_card_templates, save_board_template, save_reminder_log, save_theme,
+            write_config,
+        },
+        io_handler::{
+            get_latest_workspace_boards, make_file_system_safe_name,
+            refresh_visible_boards_and_cards,
+        },
         IoEvent,
     },
     ui::{
@@ -23,15 +46,23 @@ use crate::{
         widgets::{CommandPaletteWidget, ToastType, ToastWidget},
         TextColorOptions, TextModifierOptions, Theme,
     },
+    util::open_with_system_opener,
 };
-use chrono::Utc;
+use chrono::{Datelike, Local, Utc};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info, warn};
 use ratatui::{
     style::Color,
     widgets::{Block, Borders, ListState},
 };
-use std::{fs, path::Path, str::FromStr, time::Duration};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
 pub fn go_right(app: &mut App) {
     let current_visible_boards: LinkedHashMap<(u64, u64), Vec<(u64, u64)>> =
@@ -515,6 +546,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
             Focus::CardName => app.state.app_form_states.new_card[0] = "".to_string(),
             Focus::CardDescription => app.state.app_form_states.new_card[1] = "".to_string(),
             Focus::CardDueDate => app.state.app_form_states.new_card[2] = "".to_string(),
+            Focus::CardEstimate => app.state.app_form_states.new_card[3] = "".to_string(),
             Focus::EmailIDField => {
                 if app.state.ui_mode == UiMode::Login {
                     app.state.app_form_states.login.0[0] = "".to_string()
@@ -549,6 +581,11 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
             match app.state.popup_mode.unwrap() {
                 PopupMode::CommandPalette => {
                     app.state.popup_mode = None;
+                    if app.state.card_dependency_picker_target.is_some() {
+                        app.state.card_dependency_picker_target = None;
+                        app.state.popup_mode = Some(PopupMode::ViewCard);
+                        app.state.set_focus(Focus::CardBlockedBy);
+                    }
                     if app.widgets.command_palette.already_in_user_input_mode {
                         app.widgets.command_palette.already_in_user_input_mode = false;
                         if app.widgets.command_palette.last_focus.is_some() {
@@ -609,6 +646,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
     } else if app.config.keybindings.toggle_command_palette.contains(&key) {
         app.widgets.command_palette.already_in_user_input_mode = true;
         app.widgets.command_palette.last_focus = Some(app.state.focus);
+        sync_search_index(app);
         app.state.popup_mode = Some(PopupMode::CommandPalette);
     } else {
         if app.config.keybindings.toggle_command_palette.contains(&key) {
@@ -656,8 +694,11 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                             return CommandPaletteWidget::handle_command(app).await
                         }
                         Focus::CommandPaletteCard => {
-                            handle_command_palette_card_selection(app);
-                            app.state.popup_mode = None;
+                            if app.state.card_dependency_picker_target.is_some() {
+                                handle_card_dependency_selection(app);
+                            } else {
+                                handle_command_palette_card_selection(app);
+                            }
                             app.clear_user_input_state();
                             app.state.current_cursor_position = None;
                             app.state.app_status = AppStatus::Initialized;
@@ -751,7 +792,10 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                 return AppReturn::Continue;
                             }
                             Focus::CardComments => {
-                                card_being_edited.1.comments.push(String::new());
+                                card_being_edited.1.comments.push(CardComment::new(
+                                    &app.state.user_login_data.activity_author(),
+                                    "",
+                                ));
                                 app.state.current_cursor_position = Some(0);
                                 app.state
                                     .app_list_states
@@ -759,7 +803,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                     .select(Some(card_being_edited.1.comments.len() - 1));
                                 return AppReturn::Continue;
                             }
-                            Focus::CardDueDate => {
+                            Focus::CardDueDate | Focus::CardEstimate => {
                                 return AppReturn::Continue;
                             }
                             Focus::CardStatus => {
@@ -770,6 +814,26 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                 app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
                                 return AppReturn::Continue;
                             }
+                            Focus::CardBlockedBy => {
+                                open_card_dependency_picker(app);
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardCustomFields => {
+                                open_card_custom_fields_editor(app);
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardRecurrence => {
+                                open_card_recurrence_prompt(app);
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardActivityLog => {
+                                open_card_activity_log(app);
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardAttachments => {
+                                open_card_attachments(app);
+                                return AppReturn::Continue;
+                            }
                             Focus::SubmitButton => {
                                 return handle_edit_card_submit(app);
                             }
@@ -823,6 +887,13 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                             &mut card_being_edited.1.due_date,
                                         );
                                 }
+                                Focus::CardEstimate => {
+                                    card_being_edited.1.estimate = card_being_edited
+                                        .1
+                                        .estimate
+                                        .filter(|estimate| *estimate >= 10)
+                                        .map(|estimate| estimate / 10);
+                                }
                                 Focus::CardTags => {
                                     if app
                                         .state
@@ -928,6 +999,17 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                         }
                                         let selected_comment_index =
                                             selected_comment_index.unwrap();
+                                        if !card_being_edited.1.comments[selected_comment_index]
+                                            .is_editable_by(
+                                                &app.state.user_login_data.activity_author(),
+                                            )
+                                        {
+                                            app.send_warning_toast(
+                                                "You can only edit or delete your own comments",
+                                                None,
+                                            );
+                                            return AppReturn::Continue;
+                                        }
                                         let current_cursor_position =
                                             if app.state.current_cursor_position.is_some() {
                                                 app.state.current_cursor_position.unwrap()
@@ -941,6 +1023,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                                 .comments
                                                 .get_mut(selected_comment_index)
                                                 .unwrap()
+                                                .body
                                                 .remove(current_cursor_position - 1);
                                             app.state.current_cursor_position =
                                                 Some(current_cursor_position - 1);
@@ -1031,8 +1114,11 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                             .card_view_comment_list
                                             .selected()
                                             .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get_mut(selected_comment);
+                                        let comment = card_being_edited
+                                            .1
+                                            .comments
+                                            .get_mut(selected_comment)
+                                            .map(|comment| &mut comment.body);
                                         if comment.is_some() {
                                             let comment = comment.unwrap();
                                             if app.state.current_cursor_position.is_none() {
@@ -1134,8 +1220,11 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                             .card_view_comment_list
                                             .selected()
                                             .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get_mut(selected_comment);
+                                        let comment = card_being_edited
+                                            .1
+                                            .comments
+                                            .get_mut(selected_comment)
+                                            .map(|comment| &mut comment.body);
                                         if comment.is_some() {
                                             let comment = comment.unwrap();
                                             if app.state.current_cursor_position.is_none() {
@@ -1318,7 +1407,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                             card_being_edited.1.comments.get(selected_comment);
                                         if comment.is_some() {
                                             app.state.current_cursor_position =
-                                                Some(comment.unwrap().len());
+                                                Some(comment.unwrap().body.len());
                                         }
                                     } else {
                                         app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
@@ -1543,6 +1632,22 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                             .card_view_comment_list
                                             .selected()
                                             .unwrap();
+                                        if card_being_edited
+                                            .1
+                                            .comments
+                                            .get(selected_comment_index)
+                                            .is_some_and(|comment| {
+                                                !comment.is_editable_by(
+                                                    &app.state.user_login_data.activity_author(),
+                                                )
+                                            })
+                                        {
+                                            app.send_warning_toast(
+                                                "You can only edit or delete your own comments",
+                                                None,
+                                            );
+                                            return AppReturn::Continue;
+                                        }
                                         card_being_edited.1.comments.remove(selected_comment_index);
                                         if selected_comment_index
                                             < card_being_edited.1.comments.len()
@@ -1620,6 +1725,48 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     }
                     return AppReturn::Continue;
                 }
+                PopupMode::CardAttachments if key == Key::Enter => {
+                    handle_card_attachment_submit(app);
+                    return AppReturn::Continue;
+                }
+                PopupMode::EditCardCustomFields | PopupMode::CustomFieldDefinitionPrompt
+                    if key == Key::Enter =>
+                {
+                    if app.state.popup_mode == Some(PopupMode::EditCardCustomFields) {
+                        handle_custom_field_value_submit(app);
+                    } else {
+                        handle_custom_field_definition_submit(app);
+                    }
+                    return AppReturn::Continue;
+                }
+                PopupMode::CardRecurrencePrompt if key == Key::Enter => {
+                    handle_card_recurrence_submit(app);
+                    return AppReturn::Continue;
+                }
+                PopupMode::SwimlaneGroupingPrompt if key == Key::Enter => {
+                    handle_swimlane_grouping_submit(app);
+                    return AppReturn::Continue;
+                }
+                PopupMode::FilterByQueryPrompt if key == Key::Enter => {
+                    handle_filter_by_query_submit(app);
+                    return AppReturn::Continue;
+                }
+                PopupMode::SaveFilterPrompt if key == Key::Enter => {
+                    handle_save_filter_submit(app);
+                    return AppReturn::Continue;
+                }
+                PopupMode::SprintPrompt if key == Key::Enter => {
+                    handle_sprint_prompt_submit(app);
+                    return AppReturn::Continue;
+                }
+                PopupMode::TimeReportRangePrompt if key == Key::Enter => {
+                    handle_time_report_range_submit(app);
+                    return AppReturn::Continue;
+                }
+                PopupMode::SaveBoardTemplatePrompt if key == Key::Enter => {
+                    handle_save_board_template_submit(app);
+                    return AppReturn::Continue;
+                }
                 PopupMode::CardPrioritySelector => {
                     match key {
                         Key::Up => {
@@ -1715,6 +1862,26 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                             handle_next_focus(app);
                         }
                         current_key = "".to_string();
+                    } else if app.state.ui_mode == UiMode::ArchiveBrowser {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.set_focus(Focus::Body);
+                        current_key = "".to_string();
+                    } else if app.state.ui_mode == UiMode::WorkspaceSwitcher {
+                        app.state.app_status = AppStatus::Initialized;
+                        app.state.set_focus(Focus::Body);
+                        if app
+                            .state
+                            .app_list_states
+                            .workspace_list
+                            .selected()
+                            .is_none()
+                        {
+                            app.workspace_list_next();
+                        }
+                        current_key = "".to_string();
+                    } else if app.state.ui_mode == UiMode::LabelManager {
+                        handle_label_manager_submit(app);
+                        return AppReturn::Continue;
                     } else {
                         current_key = "\n".to_string();
                     }
@@ -1788,6 +1955,12 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                 &mut app.state.app_form_states.new_card[2],
                             );
                         }
+                        Focus::CardEstimate => {
+                            app.state.current_cursor_position = handle_cursor_pos_for_backspace(
+                                app.state.current_cursor_position,
+                                &mut app.state.app_form_states.new_card[3],
+                            );
+                        }
                         _ => {
                             app.state.current_cursor_position = handle_cursor_pos_for_backspace(
                                 app.state.current_cursor_position,
@@ -1924,6 +2097,12 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                 &app.state.app_form_states.new_card[2],
                             );
                         }
+                        Focus::CardEstimate => {
+                            app.state.current_cursor_position = move_cursor_left(
+                                app.state.current_cursor_position,
+                                &app.state.app_form_states.new_card[3],
+                            );
+                        }
                         _ => {
                             app.state.current_cursor_position = move_cursor_left(
                                 app.state.current_cursor_position,
@@ -2060,6 +2239,12 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                 &app.state.app_form_states.new_card[2],
                             );
                         }
+                        Focus::CardEstimate => {
+                            app.state.current_cursor_position = move_cursor_right(
+                                app.state.current_cursor_position,
+                                &app.state.app_form_states.new_card[3],
+                            );
+                        }
                         _ => {
                             app.state.current_cursor_position = move_cursor_right(
                                 app.state.current_cursor_position,
@@ -2165,7 +2350,7 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                     },
                     UiMode::NewCard => match app.state.focus {
-                        Focus::CardName | Focus::CardDueDate => {
+                        Focus::CardName | Focus::CardDueDate | Focus::CardEstimate => {
                             app.state.current_cursor_position = Some(0);
                         }
                         Focus::CardDescription => {
@@ -2244,6 +2429,10 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                             app.state.current_cursor_position =
                                 Some(app.state.app_form_states.new_card[2].len());
                         }
+                        Focus::CardEstimate => {
+                            app.state.current_cursor_position =
+                                Some(app.state.app_form_states.new_card[3].len());
+                        }
                         _ => {
                             app.state.current_cursor_position =
                                 Some(app.state.current_user_input.len());
@@ -2358,6 +2547,18 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                         current_key,
                                     );
                             }
+                            Focus::CardEstimate => {
+                                // estimates are whole numbers, anything but digits is ignored
+                                for digit in current_key.chars().filter_map(|c| c.to_digit(10)) {
+                                    let estimate = card_being_edited.1.estimate.unwrap_or(0);
+                                    card_being_edited.1.estimate = Some(
+                                        estimate
+                                            .checked_mul(10)
+                                            .and_then(|estimate| estimate.checked_add(digit))
+                                            .unwrap_or(estimate),
+                                    );
+                                }
+                            }
                             Focus::CardTags => {
                                 let mut current_cursor_position =
                                     app.state.current_cursor_position.unwrap_or(0);
@@ -2410,6 +2611,16 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                                         card_being_edited.1.comments.get_mut(selected_comment);
                                     if comment.is_some() {
                                         let comment = comment.as_mut().unwrap();
+                                        if !comment.is_editable_by(
+                                            &app.state.user_login_data.activity_author(),
+                                        ) {
+                                            app.send_warning_toast(
+                                                "You can only edit or delete your own comments",
+                                                None,
+                                            );
+                                            return AppReturn::Continue;
+                                        }
+                                        let comment = &mut comment.body;
                                         if current_cursor_position > comment.len() {
                                             current_cursor_position = comment.len();
                                         }
@@ -2429,6 +2640,16 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                     PopupMode::CommandPalette
                     | PopupMode::CustomRGBPromptFG
                     | PopupMode::CustomRGBPromptBG
+                    | PopupMode::EditCardCustomFields
+                    | PopupMode::CardAttachments
+                    | PopupMode::CustomFieldDefinitionPrompt
+                    | PopupMode::CardRecurrencePrompt
+                    | PopupMode::SwimlaneGroupingPrompt
+                    | PopupMode::FilterByQueryPrompt
+                    | PopupMode::SaveFilterPrompt
+                    | PopupMode::SprintPrompt
+                    | PopupMode::TimeReportRangePrompt
+                    | PopupMode::SaveBoardTemplatePrompt
                     | PopupMode::EditGeneralConfig => {
                         app.state.current_cursor_position = handle_cursor_pos_for_insert_string(
                             app.state.current_cursor_position,
@@ -2491,6 +2712,13 @@ pub async fn handle_user_input_mode(app: &mut App<'_>, key: Key) -> AppReturn {
                             current_key,
                         );
                     }
+                    Focus::CardEstimate => {
+                        app.state.current_cursor_position = handle_cursor_pos_for_insert_string(
+                            app.state.current_cursor_position,
+                            &mut app.state.app_form_states.new_card[3],
+                            current_key,
+                        );
+                    }
                     _ => {
                         app.state.current_cursor_position = handle_cursor_pos_for_insert_string(
                             app.state.current_cursor_position,
@@ -2631,11 +2859,23 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
         match action {
             Action::Quit => handle_exit(app).await,
             Action::NextFocus => {
-                handle_next_focus(app);
+                if app.state.ui_mode == UiMode::Calendar && app.state.popup_mode.is_none() {
+                    app.calendar_card_next();
+                } else if app.state.ui_mode == UiMode::Timeline && app.state.popup_mode.is_none() {
+                    app.timeline_next();
+                } else {
+                    handle_next_focus(app);
+                }
                 AppReturn::Continue
             }
             Action::PrvFocus => {
-                handle_prv_focus(app);
+                if app.state.ui_mode == UiMode::Calendar && app.state.popup_mode.is_none() {
+                    app.calendar_card_prv();
+                } else if app.state.ui_mode == UiMode::Timeline && app.state.popup_mode.is_none() {
+                    app.timeline_prv();
+                } else {
+                    handle_prv_focus(app);
+                }
                 AppReturn::Continue
             }
             Action::ResetUI => {
@@ -2648,7 +2888,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         app.state.set_focus(available_focus_targets[0]);
                     }
                 }
-                let default_theme = app.config.default_theme.clone();
+                let default_theme = app.config.get_theme_name();
                 for theme in app.all_themes.iter_mut() {
                     if theme.name == default_theme {
                         app.current_theme = theme.clone();
@@ -2717,6 +2957,26 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                         PopupMode::ChangeDateFormatPopup => app.change_date_format_popup_prv(),
                         PopupMode::FilterByTag => app.filter_by_tag_popup_prv(),
+                        PopupMode::TimeReport => {
+                            app.state.time_report_range = app.state.time_report_range.prv();
+                        }
+                        PopupMode::CardActivityLog => app.card_activity_list_prv(),
+                        PopupMode::SelectBoardTemplate => app.board_template_list_prv(),
+                        PopupMode::SelectCardSortMode => app.card_sort_mode_list_prv(),
+                        PopupMode::SelectSavedFilter => app.saved_filter_list_prv(),
+                        PopupMode::SelectCardTemplate | PopupMode::SelectDefaultCardTemplate => {
+                            app.card_template_list_prv()
+                        }
+                        PopupMode::EditCardCustomFields
+                            if app.state.focus == Focus::CustomFieldSelector =>
+                        {
+                            app.custom_field_selector_prv();
+                        }
+                        PopupMode::CardAttachments
+                            if app.state.focus == Focus::CardAttachmentList =>
+                        {
+                            app.card_attachment_list_prv();
+                        }
                         PopupMode::ViewCard => {
                             if app.state.focus == Focus::CardDescription {
                                 if app.state.card_description_text_buffer.is_none() {
@@ -2816,6 +3076,33 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     UiMode::EditKeybindings => {
                         app.edit_keybindings_prv();
                     }
+                    UiMode::ArchiveBrowser => {
+                        app.archived_card_list_prv();
+                    }
+                    UiMode::Calendar => {
+                        app.calendar_move_days(-7);
+                    }
+                    UiMode::Timeline => {
+                        app.timeline_prv();
+                    }
+                    UiMode::CardTable => {
+                        app.card_table_prv();
+                    }
+                    UiMode::Statistics => {
+                        app.statistics_table_prv();
+                    }
+                    UiMode::Sprints => {
+                        app.sprint_list_prv();
+                    }
+                    UiMode::Agenda => {
+                        app.agenda_prv();
+                    }
+                    UiMode::WorkspaceSwitcher => {
+                        app.workspace_list_prv();
+                    }
+                    UiMode::LabelManager => {
+                        app.label_list_prv();
+                    }
                     UiMode::CreateTheme => {
                         if app.state.focus == Focus::ThemeEditor {
                             app.select_create_theme_prv();
@@ -2841,7 +3128,11 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         if app.state.focus == Focus::Body
                             && UiMode::view_modes().contains(&app.state.ui_mode)
                         {
-                            go_up(app);
+                            if app.state.swimlane_grouping.is_some() {
+                                go_vertical_in_swimlanes(app, false);
+                            } else {
+                                go_up(app);
+                            }
                         } else if app.state.focus == Focus::Help {
                             app.help_prv();
                         } else if app.state.focus == Focus::Log {
@@ -2871,6 +3162,26 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         }
                         PopupMode::ChangeDateFormatPopup => app.change_date_format_popup_next(),
                         PopupMode::FilterByTag => app.filter_by_tag_popup_next(),
+                        PopupMode::TimeReport => {
+                            app.state.time_report_range = app.state.time_report_range.next();
+                        }
+                        PopupMode::CardActivityLog => app.card_activity_list_next(),
+                        PopupMode::SelectBoardTemplate => app.board_template_list_next(),
+                        PopupMode::SelectCardSortMode => app.card_sort_mode_list_next(),
+                        PopupMode::SelectSavedFilter => app.saved_filter_list_next(),
+                        PopupMode::SelectCardTemplate | PopupMode::SelectDefaultCardTemplate => {
+                            app.card_template_list_next()
+                        }
+                        PopupMode::EditCardCustomFields
+                            if app.state.focus == Focus::CustomFieldSelector =>
+                        {
+                            app.custom_field_selector_next();
+                        }
+                        PopupMode::CardAttachments
+                            if app.state.focus == Focus::CardAttachmentList =>
+                        {
+                            app.card_attachment_list_next();
+                        }
                         PopupMode::ViewCard => {
                             if app.state.focus == Focus::CardDescription {
                                 if app.state.card_description_text_buffer.is_none() {
@@ -2970,6 +3281,33 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     UiMode::EditKeybindings => {
                         app.edit_keybindings_next();
                     }
+                    UiMode::ArchiveBrowser => {
+                        app.archived_card_list_next();
+                    }
+                    UiMode::Calendar => {
+                        app.calendar_move_days(7);
+                    }
+                    UiMode::Timeline => {
+                        app.timeline_next();
+                    }
+                    UiMode::CardTable => {
+                        app.card_table_next();
+                    }
+                    UiMode::Statistics => {
+                        app.statistics_table_next();
+                    }
+                    UiMode::Sprints => {
+                        app.sprint_list_next();
+                    }
+                    UiMode::Agenda => {
+                        app.agenda_next();
+                    }
+                    UiMode::WorkspaceSwitcher => {
+                        app.workspace_list_next();
+                    }
+                    UiMode::LabelManager => {
+                        app.label_list_next();
+                    }
                     UiMode::CreateTheme => {
                         if app.state.focus == Focus::ThemeEditor {
                             app.select_create_theme_next();
@@ -2993,7 +3331,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     }
                     _ => {
                         if app.state.focus == Focus::Body {
-                            go_down(app);
+                            if app.state.swimlane_grouping.is_some()
+                                && UiMode::view_modes().contains(&app.state.ui_mode)
+                            {
+                                go_vertical_in_swimlanes(app, true);
+                            } else {
+                                go_down(app);
+                            }
                         } else if app.state.focus == Focus::Help {
                             app.help_next();
                         } else if app.state.focus == Focus::Log {
@@ -3005,11 +3349,25 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
             }
             Action::Right => {
                 reset_mouse(app);
-                if app.state.focus == Focus::Body
+                if app.state.ui_mode == UiMode::Calendar && app.state.popup_mode.is_none() {
+                    app.calendar_move_days(1);
+                } else if app.state.ui_mode == UiMode::Timeline && app.state.popup_mode.is_none() {
+                    let pan_columns = (app.state.timeline.visible_columns / 4).max(1);
+                    app.timeline_pan(pan_columns);
+                } else if app.state.ui_mode == UiMode::CardTable && app.state.popup_mode.is_none() {
+                    app.state.card_table.selected_column =
+                        app.state.card_table.selected_column.next();
+                } else if app.state.ui_mode == UiMode::Agenda && app.state.popup_mode.is_none() {
+                    snooze_agenda_card(app, 1);
+                } else if app.state.focus == Focus::Body
                     && UiMode::view_modes().contains(&app.state.ui_mode)
                     && app.state.popup_mode.is_none()
                 {
+                    let current_swimlane = get_current_swimlane(app);
                     go_right(app);
+                    if let Some(current_swimlane) = current_swimlane {
+                        select_first_card_in_swimlane(app, current_swimlane);
+                    }
                 } else if app.state.popup_mode.is_some()
                     && (app.state.popup_mode.unwrap() == PopupMode::ConfirmDiscardCardChanges
                         || app.state.popup_mode.unwrap() == PopupMode::SaveThemePrompt)
@@ -3024,11 +3382,25 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
             }
             Action::Left => {
                 reset_mouse(app);
-                if app.state.focus == Focus::Body
+                if app.state.ui_mode == UiMode::Calendar && app.state.popup_mode.is_none() {
+                    app.calendar_move_days(-1);
+                } else if app.state.ui_mode == UiMode::Timeline && app.state.popup_mode.is_none() {
+                    let pan_columns = (app.state.timeline.visible_columns / 4).max(1);
+                    app.timeline_pan(-pan_columns);
+                } else if app.state.ui_mode == UiMode::CardTable && app.state.popup_mode.is_none() {
+                    app.state.card_table.selected_column =
+                        app.state.card_table.selected_column.prv();
+                } else if app.state.ui_mode == UiMode::Agenda && app.state.popup_mode.is_none() {
+                    snooze_agenda_card(app, -1);
+                } else if app.state.focus == Focus::Body
                     && UiMode::view_modes().contains(&app.state.ui_mode)
                     && app.state.popup_mode.is_none()
                 {
+                    let current_swimlane = get_current_swimlane(app);
                     go_left(app);
+                    if let Some(current_swimlane) = current_swimlane {
+                        select_first_card_in_swimlane(app, current_swimlane);
+                    }
                 } else if app.state.popup_mode.is_some()
                     && (app.state.popup_mode.unwrap() == PopupMode::ConfirmDiscardCardChanges
                         || app.state.popup_mode.unwrap() == PopupMode::SaveThemePrompt)
@@ -3043,16 +3415,41 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
             }
             Action::TakeUserInput => {
                 match app.state.ui_mode {
+                    UiMode::NewCard if app.state.focus == Focus::CardTemplate => {
+                        open_card_template_selector(app);
+                    }
                     UiMode::NewBoard | UiMode::NewCard => {
                         app.state.app_status = AppStatus::UserInput;
                         info!("Taking user input");
                     }
+                    UiMode::ArchiveBrowser | UiMode::WorkspaceSwitcher | UiMode::LabelManager => {
+                        app.state.set_focus(Focus::TextInput);
+                        app.state.app_status = AppStatus::UserInput;
+                        info!("Taking user input");
+                    }
                     _ => {
                         if app.state.popup_mode.is_some() {
                             match app.state.popup_mode.unwrap() {
                                 PopupMode::EditGeneralConfig
                                 | PopupMode::CustomRGBPromptFG
-                                | PopupMode::CustomRGBPromptBG => {
+                                | PopupMode::CustomRGBPromptBG
+                                | PopupMode::CustomFieldDefinitionPrompt
+                                | PopupMode::CardRecurrencePrompt
+                                | PopupMode::SwimlaneGroupingPrompt
+                                | PopupMode::FilterByQueryPrompt
+                                | PopupMode::SaveFilterPrompt
+                                | PopupMode::SprintPrompt
+                                | PopupMode::TimeReportRangePrompt
+                                | PopupMode::SaveBoardTemplatePrompt => {
+                                    app.state.app_status = AppStatus::UserInput;
+                                    info!("Taking user input");
+                                }
+                                PopupMode::TimeReport => {
+                                    open_time_report_range_prompt(app);
+                                }
+                                PopupMode::EditCardCustomFields | PopupMode::CardAttachments
+                                    if app.state.focus == Focus::TextInput =>
+                                {
                                     app.state.app_status = AppStatus::UserInput;
                                     info!("Taking user input");
                                 }
@@ -3140,9 +3537,30 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 app.state.popup_mode = Some(PopupMode::CardStatusSelector);
                                 return AppReturn::Continue;
                             }
+                            Focus::CardBlockedBy => {
+                                open_card_dependency_picker(app);
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardCustomFields => {
+                                open_card_custom_fields_editor(app);
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardRecurrence => {
+                                open_card_recurrence_prompt(app);
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardActivityLog => {
+                                open_card_activity_log(app);
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardAttachments => {
+                                open_card_attachments(app);
+                                return AppReturn::Continue;
+                            }
                             Focus::CardName
                             | Focus::CardDescription
                             | Focus::CardDueDate
+                            | Focus::CardEstimate
                             | Focus::CardTags
                             | Focus::CardComments => return handle_edit_new_card(app),
                             Focus::SubmitButton => {
@@ -3185,6 +3603,116 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                             handle_filter_by_tag(app);
                             return AppReturn::Continue;
                         }
+                        PopupMode::TimeReport => {
+                            handle_export_time_report(app);
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::CardActivityLog => {
+                            close_card_activity_log(app);
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::CardAttachments => {
+                            match app.state.focus {
+                                Focus::CardAttachmentList => handle_card_attachment_selection(app),
+                                Focus::TextInput => {
+                                    app.state.app_status = AppStatus::UserInput;
+                                }
+                                Focus::SubmitButton => handle_card_attachment_submit(app),
+                                _ => {}
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::EditCardCustomFields => {
+                            match app.state.focus {
+                                Focus::CustomFieldSelector => load_selected_custom_field_value(app),
+                                Focus::TextInput => {
+                                    app.state.app_status = AppStatus::UserInput;
+                                }
+                                Focus::SubmitButton => handle_custom_field_value_submit(app),
+                                _ => {}
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::CustomFieldDefinitionPrompt => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                            } else if app.state.focus == Focus::SubmitButton {
+                                handle_custom_field_definition_submit(app);
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::CardRecurrencePrompt => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                            } else if app.state.focus == Focus::SubmitButton {
+                                handle_card_recurrence_submit(app);
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::SwimlaneGroupingPrompt => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                            } else if app.state.focus == Focus::SubmitButton {
+                                handle_swimlane_grouping_submit(app);
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::FilterByQueryPrompt => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                            } else if app.state.focus == Focus::SubmitButton {
+                                handle_filter_by_query_submit(app);
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::SaveBoardTemplatePrompt => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                            } else if app.state.focus == Focus::SubmitButton {
+                                handle_save_board_template_submit(app);
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::SelectBoardTemplate => {
+                            handle_create_boards_from_template(app);
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::SelectCardTemplate | PopupMode::SelectDefaultCardTemplate => {
+                            handle_card_template_selection(app);
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::SelectCardSortMode => {
+                            handle_card_sort_mode_selection(app);
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::SelectSavedFilter => {
+                            handle_saved_filter_selection(app);
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::SaveFilterPrompt => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                            } else if app.state.focus == Focus::SubmitButton {
+                                handle_save_filter_submit(app);
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::SprintPrompt => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                            } else if app.state.focus == Focus::SubmitButton {
+                                handle_sprint_prompt_submit(app);
+                            }
+                            return AppReturn::Continue;
+                        }
+                        PopupMode::TimeReportRangePrompt => {
+                            if app.state.focus == Focus::TextInput {
+                                app.state.app_status = AppStatus::UserInput;
+                            } else if app.state.focus == Focus::SubmitButton {
+                                handle_time_report_range_submit(app);
+                            }
+                            return AppReturn::Continue;
+                        }
                     }
                     app.state.popup_mode = None;
                     return AppReturn::Continue;
@@ -3216,6 +3744,54 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         handle_edit_keybindings_action(app);
                         AppReturn::Continue
                     }
+                    UiMode::ArchiveBrowser => {
+                        if app.state.focus == Focus::TextInput {
+                            app.state.app_status = AppStatus::UserInput;
+                        } else {
+                            handle_restore_archived_card(app);
+                        }
+                        AppReturn::Continue
+                    }
+                    UiMode::Calendar => {
+                        open_calendar_card(app);
+                        AppReturn::Continue
+                    }
+                    UiMode::Timeline => {
+                        open_timeline_card(app);
+                        AppReturn::Continue
+                    }
+                    UiMode::CardTable => {
+                        handle_card_table_accept(app);
+                        AppReturn::Continue
+                    }
+                    UiMode::Statistics => {
+                        handle_export_statistics(app);
+                        AppReturn::Continue
+                    }
+                    UiMode::Sprints => {
+                        cycle_sprint_chart_mode(app);
+                        AppReturn::Continue
+                    }
+                    UiMode::Agenda => {
+                        open_agenda_card(app);
+                        AppReturn::Continue
+                    }
+                    UiMode::WorkspaceSwitcher => {
+                        if app.state.focus == Focus::TextInput {
+                            app.state.app_status = AppStatus::UserInput;
+                        } else {
+                            handle_workspace_switcher_selection(app).await;
+                        }
+                        AppReturn::Continue
+                    }
+                    UiMode::LabelManager => {
+                        if app.state.focus == Focus::TextInput {
+                            app.state.app_status = AppStatus::UserInput;
+                        } else {
+                            handle_label_manager_selection(app);
+                        }
+                        AppReturn::Continue
+                    }
                     UiMode::CreateTheme => {
                         handle_create_theme_action(app);
                         AppReturn::Continue
@@ -3403,13 +3979,17 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                         app.send_warning_toast("No board available to add card to", None);
                         return AppReturn::Continue;
                     }
-                    reset_new_card_form(app);
+                    prepare_new_card_form(app);
                     app.set_ui_mode(UiMode::NewCard);
                     app.state.prev_focus = Some(app.state.focus);
                 }
                 AppReturn::Continue
             }
             Action::Delete => match app.state.ui_mode {
+                _ if app.state.popup_mode == Some(PopupMode::SelectSavedFilter) => {
+                    handle_saved_filter_delete(app);
+                    AppReturn::Continue
+                }
                 UiMode::LoadLocalSave => {
                     app.dispatch(IoEvent::DeleteLocalSave).await;
                     tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
@@ -3424,6 +4004,10 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     app.dispatch(IoEvent::LoadCloudPreview).await;
                     AppReturn::Continue
                 }
+                UiMode::LabelManager => {
+                    handle_label_manager_delete(app);
+                    AppReturn::Continue
+                }
                 _ => {
                     if !UiMode::view_modes().contains(&app.state.ui_mode) {
                         return AppReturn::Continue;
@@ -3465,8 +4049,15 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                             app.state.current_card_id = None;
                                         }
                                         warn!("Deleted card {}", card_name);
+                                        let deleted_card_board_id = current_board.id;
+                                        let removed_links =
+                                            app.boards.remove_blocked_by_links(current_card_id);
                                         app.action_history_manager.new_action(
-                                            ActionHistory::DeleteCard(card, current_board.id),
+                                            ActionHistory::DeleteCard(
+                                                card,
+                                                deleted_card_board_id,
+                                                removed_links,
+                                            ),
                                         );
                                         app.send_warning_toast(
                                             &format!("Deleted card {}", card_name),
@@ -3492,6 +4083,14 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                             app.boards.get_board_index(current_board_id).unwrap();
                                         let board_name = board.name.clone();
                                         app.boards.remove_board_with_id(current_board_id);
+                                        let removed_links = board
+                                            .cards
+                                            .get_all_card_ids()
+                                            .into_iter()
+                                            .flat_map(|card_id| {
+                                                app.boards.remove_blocked_by_links(card_id)
+                                            })
+                                            .collect::<Vec<_>>();
                                         if board_index > 0 && !app.boards.is_empty() {
                                             app.state.current_board_id = Some(
                                                 app.boards
@@ -3503,8 +4102,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                             app.state.current_board_id = None;
                                         }
                                         warn!("Deleted board {}", board_name);
-                                        app.action_history_manager
-                                            .new_action(ActionHistory::DeleteBoard(board));
+                                        app.action_history_manager.new_action(
+                                            ActionHistory::DeleteBoard(board, removed_links),
+                                        );
                                         app.send_warning_toast(
                                             &format!("Deleted board {}", board_name),
                                             None,
@@ -3533,6 +4133,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                     app.boards.get_board_index(current_board_id).unwrap();
                                 let board_name = board.name.clone();
                                 app.boards.remove_board_with_id(current_board_id);
+                                let removed_links = board
+                                    .cards
+                                    .get_all_card_ids()
+                                    .into_iter()
+                                    .flat_map(|card_id| app.boards.remove_blocked_by_links(card_id))
+                                    .collect::<Vec<_>>();
                                 if board_index > 0 {
                                     app.state.current_board_id = Some(
                                         app.boards
@@ -3550,7 +4156,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 app.visible_boards_and_cards.remove(&current_board_id);
                                 warn!("Deleted board: {}", board_name);
                                 app.action_history_manager
-                                    .new_action(ActionHistory::DeleteBoard(board));
+                                    .new_action(ActionHistory::DeleteBoard(board, removed_links));
                                 app.send_warning_toast(
                                     &format!("Deleted board: {}", board_name),
                                     None,
@@ -3563,6 +4169,14 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 }
             }
             Action::ChangeCardStatusToCompleted => {
+                if app.state.ui_mode == UiMode::CardTable && app.state.popup_mode.is_none() {
+                    set_card_table_status(app, CardStatus::Complete);
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Agenda && app.state.popup_mode.is_none() {
+                    set_agenda_card_status(app, CardStatus::Complete);
+                    return AppReturn::Continue;
+                }
                 if !UiMode::view_modes().contains(&app.state.ui_mode)
                     || app.state.focus != Focus::Body
                 {
@@ -3571,6 +4185,14 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 handle_change_card_status(app, Some(CardStatus::Complete))
             }
             Action::ChangeCardStatusToActive => {
+                if app.state.ui_mode == UiMode::CardTable && app.state.popup_mode.is_none() {
+                    set_card_table_status(app, CardStatus::Active);
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Agenda && app.state.popup_mode.is_none() {
+                    set_agenda_card_status(app, CardStatus::Active);
+                    return AppReturn::Continue;
+                }
                 if !UiMode::view_modes().contains(&app.state.ui_mode)
                     || app.state.focus != Focus::Body
                 {
@@ -3579,6 +4201,14 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 handle_change_card_status(app, Some(CardStatus::Active))
             }
             Action::ChangeCardStatusToStale => {
+                if app.state.ui_mode == UiMode::CardTable && app.state.popup_mode.is_none() {
+                    set_card_table_status(app, CardStatus::Stale);
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Agenda && app.state.popup_mode.is_none() {
+                    set_agenda_card_status(app, CardStatus::Stale);
+                    return AppReturn::Continue;
+                }
                 if !UiMode::view_modes().contains(&app.state.ui_mode)
                     || app.state.focus != Focus::Body
                 {
@@ -3586,20 +4216,71 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 };
                 handle_change_card_status(app, Some(CardStatus::Stale))
             }
-            Action::GoToMainMenu => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => {
-                        reset_new_board_form(app);
-                    }
-                    UiMode::NewCard => {
-                        reset_new_card_form(app);
-                    }
-                    UiMode::Login => {
-                        reset_login_form(app);
-                    }
-                    UiMode::SignUp => {
-                        reset_signup_form(app);
-                    }
+            Action::ToggleCalendarLayout => {
+                if app.state.ui_mode != UiMode::Calendar || app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
+                }
+                app.state.calendar.layout = app.state.calendar.layout.toggle();
+                app.state.calendar.mouse_target = None;
+                AppReturn::Continue
+            }
+            Action::CycleTimelineZoom => {
+                if app.state.ui_mode != UiMode::Timeline || app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
+                }
+                cycle_timeline_zoom(app);
+                AppReturn::Continue
+            }
+            Action::ToggleCardSelection => {
+                if app.state.ui_mode != UiMode::CardTable || app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
+                }
+                toggle_card_table_selection(app);
+                app.card_table_next();
+                AppReturn::Continue
+            }
+            Action::SortCardTable => {
+                if app.state.ui_mode != UiMode::CardTable || app.state.popup_mode.is_some() {
+                    return AppReturn::Continue;
+                }
+                sort_card_table(app, app.state.card_table.selected_column);
+                AppReturn::Continue
+            }
+            Action::SnoozeReminder => handle_snooze_reminder(app),
+            Action::DismissReminder => handle_dismiss_reminder(app),
+            Action::ApplySavedFilter1 => handle_apply_saved_filter_action(app, 0),
+            Action::ApplySavedFilter2 => handle_apply_saved_filter_action(app, 1),
+            Action::ApplySavedFilter3 => handle_apply_saved_filter_action(app, 2),
+            Action::ToggleCardTimer => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                handle_toggle_card_timer(app)
+            }
+            Action::ArchiveCard => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.focus != Focus::Body
+                {
+                    return AppReturn::Continue;
+                };
+                handle_archive_card(app)
+            }
+            Action::GoToMainMenu => {
+                match app.state.ui_mode {
+                    UiMode::NewBoard => {
+                        reset_new_board_form(app);
+                    }
+                    UiMode::NewCard => {
+                        reset_new_card_form(app);
+                    }
+                    UiMode::Login => {
+                        reset_login_form(app);
+                    }
+                    UiMode::SignUp => {
+                        reset_signup_form(app);
+                    }
                     UiMode::ResetPassword => {
                         reset_reset_password_form(app);
                     }
@@ -3615,6 +4296,21 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 AppReturn::Continue
             }
             Action::MoveCardUp => {
+                if app.state.ui_mode == UiMode::CardTable && app.state.popup_mode.is_none() {
+                    // shift and an arrow extends the selection like in a spreadsheet
+                    if let Some(row) = get_current_card_table_row(app) {
+                        app.state.card_table.selected_cards.insert(row.card.id);
+                    }
+                    app.card_table_prv();
+                    if let Some(row) = get_current_card_table_row(app) {
+                        app.state.card_table.selected_cards.insert(row.card.id);
+                    }
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Calendar && app.state.popup_mode.is_none() {
+                    reschedule_calendar_card(app, -7);
+                    return AppReturn::Continue;
+                }
                 if !UiMode::view_modes().contains(&app.state.ui_mode) {
                     return AppReturn::Continue;
                 }
@@ -3622,6 +4318,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else {
+                        if let Some(current_board_id) = app.state.current_board_id {
+                            switch_board_to_manual_sort(app, current_board_id);
+                        }
                         let boards: &mut Boards = if app.filtered_boards.is_empty() {
                             &mut app.boards
                         } else {
@@ -3712,6 +4411,21 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 AppReturn::Continue
             }
             Action::MoveCardDown => {
+                if app.state.ui_mode == UiMode::CardTable && app.state.popup_mode.is_none() {
+                    // shift and an arrow extends the selection like in a spreadsheet
+                    if let Some(row) = get_current_card_table_row(app) {
+                        app.state.card_table.selected_cards.insert(row.card.id);
+                    }
+                    app.card_table_next();
+                    if let Some(row) = get_current_card_table_row(app) {
+                        app.state.card_table.selected_cards.insert(row.card.id);
+                    }
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Calendar && app.state.popup_mode.is_none() {
+                    reschedule_calendar_card(app, 7);
+                    return AppReturn::Continue;
+                }
                 if !UiMode::view_modes().contains(&app.state.ui_mode) {
                     return AppReturn::Continue;
                 }
@@ -3719,6 +4433,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     if app.state.current_card_id.is_none() {
                         return AppReturn::Continue;
                     } else {
+                        if let Some(current_board_id) = app.state.current_board_id {
+                            switch_board_to_manual_sort(app, current_board_id);
+                        }
                         let boards: &mut Boards = if app.filtered_boards.is_empty() {
                             &mut app.boards
                         } else {
@@ -3812,6 +4529,18 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 AppReturn::Continue
             }
             Action::MoveCardRight => {
+                if app.state.ui_mode == UiMode::Calendar && app.state.popup_mode.is_none() {
+                    reschedule_calendar_card(app, 1);
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Timeline && app.state.popup_mode.is_none() {
+                    move_selected_timeline_due_date(app, 1);
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Agenda && app.state.popup_mode.is_none() {
+                    move_agenda_card(app, true);
+                    return AppReturn::Continue;
+                }
                 if !UiMode::view_modes().contains(&app.state.ui_mode) {
                     return AppReturn::Continue;
                 }
@@ -3844,16 +4573,24 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                     .cards
                                     .get_card_index(current_card_id)
                                     .unwrap();
-                                let card = moved_from_board
+                                let mut card = moved_from_board
                                     .cards
                                     .remove_card_with_id(current_card_id)
                                     .unwrap();
                                 let card_id = card.id;
                                 let card_name = card.name.clone();
+                                let moved_from_board_name = moved_from_board.name.clone();
                                 let moved_from_board_cards = moved_from_board.cards.clone();
                                 let moved_to_board = boards
                                     .get_mut_board_with_index(moved_to_board_index)
                                     .unwrap();
+                                card.log_activity(
+                                    &app.state.user_login_data.activity_author(),
+                                    CardActivityKind::Moved {
+                                        from_board: moved_from_board_name,
+                                        to_board: moved_to_board.name.clone(),
+                                    },
+                                );
                                 moved_to_board.cards.add_card(card.clone());
                                 if moved_to_board.cards.len()
                                     <= app.config.no_of_cards_to_show as usize
@@ -3908,6 +4645,9 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
 
                                 info!("{}", info_msg);
                                 app.send_info_toast(info_msg, None);
+                                if let Some(moved_to_board_id) = app.state.current_board_id {
+                                    warn_if_over_wip_limit(app, moved_to_board_id);
+                                }
                             }
                         } else {
                             error!("Cannot move card right as it is the last board");
@@ -3921,6 +4661,18 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                 AppReturn::Continue
             }
             Action::MoveCardLeft => {
+                if app.state.ui_mode == UiMode::Calendar && app.state.popup_mode.is_none() {
+                    reschedule_calendar_card(app, -1);
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Timeline && app.state.popup_mode.is_none() {
+                    move_selected_timeline_due_date(app, -1);
+                    return AppReturn::Continue;
+                }
+                if app.state.ui_mode == UiMode::Agenda && app.state.popup_mode.is_none() {
+                    move_agenda_card(app, false);
+                    return AppReturn::Continue;
+                }
                 if !UiMode::view_modes().contains(&app.state.ui_mode) {
                     return AppReturn::Continue;
                 }
@@ -3951,11 +4703,12 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                     .unwrap();
                                 let card_index =
                                     moved_from_board.cards.get_card_index(current_card).unwrap();
-                                let card = moved_from_board
+                                let mut card = moved_from_board
                                     .cards
                                     .remove_card_with_id(current_card)
                                     .unwrap();
                                 let moved_from_board_id = moved_from_board.id;
+                                let moved_from_board_name = moved_from_board.name.clone();
                                 let moved_from_board_cards = moved_from_board.cards.clone();
                                 let moved_to_board = boards
                                     .get_mut_board_with_index(moved_to_board_index)
@@ -3963,6 +4716,13 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                                 let moved_to_board_id = moved_to_board.id;
                                 let card_id = card.id;
                                 let card_name = card.name.clone();
+                                card.log_activity(
+                                    &app.state.user_login_data.activity_author(),
+                                    CardActivityKind::Moved {
+                                        from_board: moved_from_board_name,
+                                        to_board: moved_to_board.name.clone(),
+                                    },
+                                );
                                 moved_to_board.cards.add_card(card.clone());
                                 if moved_to_board.cards.len()
                                     <= app.config.no_of_cards_to_show as usize
@@ -4017,6 +4777,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
 
                                 info!("{}", info_msg);
                                 app.send_info_toast(info_msg, None);
+                                warn_if_over_wip_limit(app, moved_to_board_id);
                             }
                         } else {
                             error!("Cannot move card left as it is the first board");
@@ -4036,6 +4797,7 @@ pub async fn handle_general_actions(app: &mut App<'_>, key: Key) -> AppReturn {
                     match app.state.popup_mode.unwrap() {
                         PopupMode::CommandPalette => {
                             app.state.popup_mode = None;
+                            app.state.card_dependency_picker_target = None;
                             app.clear_user_input_state();
                             app.state.current_cursor_position = None;
                             app.state.app_status = AppStatus::Initialized;
@@ -4115,14 +4877,18 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
         Mouse::Drag(x, y) => {
             app.state.current_mouse_coordinates = (x, y);
             let current_ui_mode = app.state.ui_mode;
-            let is_invalid_state = !UiMode::view_modes().contains(&current_ui_mode)
-                || app.state.hovered_card.is_none()
-                || app.state.hovered_board.is_none();
-            if is_invalid_state {
-                return AppReturn::Continue;
-            }
-            if !app.state.card_drag_mode {
-                app.state.card_drag_mode = true;
+            if current_ui_mode == UiMode::Timeline && app.state.popup_mode.is_none() {
+                start_timeline_drag(app);
+            } else {
+                let is_invalid_state = !UiMode::view_modes().contains(&current_ui_mode)
+                    || app.state.hovered_card.is_none()
+                    || app.state.hovered_board.is_none();
+                if is_invalid_state {
+                    return AppReturn::Continue;
+                }
+                if !app.state.card_drag_mode {
+                    app.state.card_drag_mode = true;
+                }
             }
         }
         Mouse::LeftPress => left_button_pressed = true,
@@ -4142,11 +4908,33 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     left_button_pressed = false;
                     right_button_pressed = false;
                     middle_button_pressed = false;
-                    if app.state.hovered_card.is_some() && app.state.hovered_board.is_some() {
+                    if app.state.ui_mode == UiMode::Timeline {
+                        finish_timeline_drag(app);
+                    }
+                    let dropped_on_other_board = app
+                        .state
+                        .hovered_card
+                        .zip(app.state.hovered_board)
+                        .filter(|((dragged_from_board_id, _), hovered_board_id)| {
+                            dragged_from_board_id != hovered_board_id
+                        })
+                        .map(|(_, hovered_board_id)| hovered_board_id);
+                    if app.state.hovered_card.is_some()
+                        && app.state.hovered_board.is_some()
+                        && app.state.swimlane_grouping.is_some()
+                    {
+                        move_dragged_card_to_swimlane(app);
+                        reset_card_drag_mode(app);
+                        refresh_visible_boards_and_cards(app);
+                    } else if app.state.hovered_card.is_some() && app.state.hovered_board.is_some()
+                    {
                         move_dragged_card(app);
                         reset_card_drag_mode(app);
                         refresh_visible_boards_and_cards(app);
                     }
+                    if let Some(board_id) = dropped_on_other_board {
+                        warn_if_over_wip_limit(app, board_id);
+                    }
                     reset_card_drag_mode(app);
                 }
             }
@@ -4177,6 +4965,7 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
             match app.state.popup_mode.unwrap() {
                 PopupMode::CommandPalette => {
                     app.state.popup_mode = None;
+                    app.state.card_dependency_picker_target = None;
                     app.clear_user_input_state();
                     app.state.current_cursor_position = None;
                     app.state.app_status = AppStatus::Initialized;
@@ -4226,8 +5015,11 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                             return CommandPaletteWidget::handle_command(app).await;
                         }
                         Focus::CommandPaletteCard => {
-                            handle_command_palette_card_selection(app);
-                            app.state.popup_mode = None;
+                            if app.state.card_dependency_picker_target.is_some() {
+                                handle_card_dependency_selection(app);
+                            } else {
+                                handle_command_palette_card_selection(app);
+                            }
                             app.clear_user_input_state();
                             app.state.current_cursor_position = None;
                             app.state.app_status = AppStatus::Initialized;
@@ -4341,6 +5133,217 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     }
                 }
             }
+            PopupMode::CardAttachments => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::CardAttachmentList) => handle_card_attachment_selection(app),
+                        Some(Focus::TextInput) => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Some(Focus::SubmitButton) => handle_card_attachment_submit(app),
+                        Some(Focus::CloseButton) => close_card_attachments(app),
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.card_attachment_list_prv();
+                } else if mouse_scroll_down {
+                    app.card_attachment_list_next();
+                }
+            }
+            PopupMode::EditCardCustomFields => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::CustomFieldSelector) => load_selected_custom_field_value(app),
+                        Some(Focus::TextInput) => {
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Some(Focus::SubmitButton) => handle_custom_field_value_submit(app),
+                        Some(Focus::CloseButton) => close_card_custom_fields_editor(app),
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.custom_field_selector_prv();
+                } else if mouse_scroll_down {
+                    app.custom_field_selector_next();
+                }
+            }
+            PopupMode::CustomFieldDefinitionPrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_custom_field_definition_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.clear_user_input_state();
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::FilterByQueryPrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_filter_by_query_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.clear_user_input_state();
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::SwimlaneGroupingPrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_swimlane_grouping_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.clear_user_input_state();
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::CardRecurrencePrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_card_recurrence_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        close_card_recurrence_prompt(app);
+                    }
+                }
+            }
+            PopupMode::SaveBoardTemplatePrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_save_board_template_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.clear_user_input_state();
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::SelectCardTemplate | PopupMode::SelectDefaultCardTemplate => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CardTemplateSelector) {
+                        handle_card_template_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                } else if mouse_scroll_up {
+                    app.card_template_list_prv();
+                } else if mouse_scroll_down {
+                    app.card_template_list_next();
+                }
+            }
+            PopupMode::SelectSavedFilter => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SavedFilterSelector) {
+                        handle_saved_filter_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                } else if mouse_scroll_up {
+                    app.saved_filter_list_prv();
+                } else if mouse_scroll_down {
+                    app.saved_filter_list_next();
+                }
+            }
+            PopupMode::SaveFilterPrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_save_filter_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.clear_user_input_state();
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::SprintPrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_sprint_prompt_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.sprints.closing_sprint = None;
+                        app.clear_user_input_state();
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::TimeReportRangePrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_time_report_range_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::TextInput) {
+                        app.state.app_status = AppStatus::UserInput;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        close_time_report_range_prompt(app);
+                    }
+                }
+            }
+            PopupMode::SelectCardSortMode => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CardSortModeSelector) {
+                        handle_card_sort_mode_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                } else if mouse_scroll_up {
+                    app.card_sort_mode_list_prv();
+                } else if mouse_scroll_down {
+                    app.card_sort_mode_list_next();
+                }
+            }
+            PopupMode::SelectBoardTemplate => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::BoardTemplateSelector) {
+                        handle_create_boards_from_template(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                } else if mouse_scroll_up {
+                    app.board_template_list_prv();
+                } else if mouse_scroll_down {
+                    app.board_template_list_next();
+                }
+            }
+            PopupMode::CardActivityLog => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        close_card_activity_log(app);
+                    }
+                } else if mouse_scroll_up {
+                    app.card_activity_list_prv();
+                } else if mouse_scroll_down {
+                    app.card_activity_list_next();
+                }
+            }
+            PopupMode::TimeReport => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        handle_export_time_report(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                } else if mouse_scroll_up {
+                    app.state.time_report_range = app.state.time_report_range.prv();
+                } else if mouse_scroll_down {
+                    app.state.time_report_range = app.state.time_report_range.next();
+                }
+            }
             PopupMode::ChangeTheme => {
                 if left_button_pressed {
                     if app.state.mouse_focus == Some(Focus::ThemeSelector) {
@@ -4349,7 +5352,7 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
                         let config_theme = {
                             let all_themes = Theme::all_default_themes();
-                            let default_theme = app.config.default_theme.clone();
+                            let default_theme = app.config.get_theme_name();
                             all_themes.iter().find(|t| t.name == default_theme).cloned()
                         };
                         if config_theme.is_some() {
@@ -4432,7 +5435,8 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                         | Focus::CardDescription
                         | Focus::CardTags
                         | Focus::CardComments
-                        | Focus::CardDueDate => return handle_edit_new_card(app),
+                        | Focus::CardDueDate
+                        | Focus::CardEstimate => return handle_edit_new_card(app),
                         Focus::CardPriority => {
                             if app.state.card_being_edited.is_none() {
                                 handle_edit_new_card(app);
@@ -4447,6 +5451,26 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                             app.state.popup_mode = Some(PopupMode::CardStatusSelector);
                             return AppReturn::Continue;
                         }
+                        Focus::CardBlockedBy => {
+                            open_card_dependency_picker(app);
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardCustomFields => {
+                            open_card_custom_fields_editor(app);
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardRecurrence => {
+                            open_card_recurrence_prompt(app);
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardActivityLog => {
+                            open_card_activity_log(app);
+                            return AppReturn::Continue;
+                        }
+                        Focus::CardAttachments => {
+                            open_card_attachments(app);
+                            return AppReturn::Continue;
+                        }
                         Focus::SubmitButton => return handle_edit_card_submit(app),
                         _ => {}
                     }
@@ -4609,13 +5633,158 @@ pub async fn handle_mouse_action(app: &mut App<'_>, mouse_action: Mouse) -> AppR
                     }
                 }
             }
-            UiMode::Login => {
-                if left_button_pressed && app.state.mouse_focus.is_some() {
-                    handle_login_action(app).await
+            UiMode::ArchiveBrowser => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::TextInput) => {
+                            app.state.set_focus(Focus::TextInput);
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Some(Focus::Body) => handle_restore_archived_card(app),
+                        Some(Focus::CloseButton) => {
+                            return handle_go_to_previous_ui_mode(app).await
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.archived_card_list_prv();
+                } else if mouse_scroll_down {
+                    app.archived_card_list_next();
                 }
             }
-            UiMode::SignUp => {
-                if left_button_pressed && app.state.mouse_focus.is_some() {
+            UiMode::Calendar => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::Body) => handle_calendar_mouse_click(app),
+                        Some(Focus::CloseButton) => {
+                            return handle_go_to_previous_ui_mode(app).await
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.calendar_move_days(-7);
+                } else if mouse_scroll_down {
+                    app.calendar_move_days(7);
+                }
+            }
+            UiMode::Timeline => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::Body) => handle_timeline_mouse_click(app),
+                        Some(Focus::CloseButton) => {
+                            return handle_go_to_previous_ui_mode(app).await
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.timeline_prv();
+                } else if mouse_scroll_down {
+                    app.timeline_next();
+                } else if mouse_scroll_left {
+                    app.timeline_pan(-1);
+                } else if mouse_scroll_right {
+                    app.timeline_pan(1);
+                }
+            }
+            UiMode::CardTable => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::Body) => handle_card_table_mouse_click(app),
+                        Some(Focus::CloseButton) => {
+                            return handle_go_to_previous_ui_mode(app).await
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.card_table_prv();
+                } else if mouse_scroll_down {
+                    app.card_table_next();
+                }
+            }
+            UiMode::Statistics => {
+                if left_button_pressed && app.state.mouse_focus == Some(Focus::CloseButton) {
+                    return handle_go_to_previous_ui_mode(app).await;
+                } else if mouse_scroll_up {
+                    app.statistics_table_prv();
+                } else if mouse_scroll_down {
+                    app.statistics_table_next();
+                }
+            }
+            UiMode::Sprints => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::CloseButton) => {
+                            return handle_go_to_previous_ui_mode(app).await;
+                        }
+                        Some(Focus::Body) => cycle_sprint_chart_mode(app),
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.sprint_list_prv();
+                } else if mouse_scroll_down {
+                    app.sprint_list_next();
+                }
+            }
+            UiMode::Agenda => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::Body) => handle_agenda_mouse_click(app),
+                        Some(Focus::CloseButton) => {
+                            return handle_go_to_previous_ui_mode(app).await
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.agenda_prv();
+                } else if mouse_scroll_down {
+                    app.agenda_next();
+                }
+            }
+            UiMode::WorkspaceSwitcher => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::TextInput) => {
+                            app.state.set_focus(Focus::TextInput);
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Some(Focus::Body) => handle_workspace_switcher_selection(app).await,
+                        Some(Focus::CloseButton) => {
+                            return handle_go_to_previous_ui_mode(app).await
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.workspace_list_prv();
+                } else if mouse_scroll_down {
+                    app.workspace_list_next();
+                }
+            }
+            UiMode::LabelManager => {
+                if left_button_pressed {
+                    match app.state.mouse_focus {
+                        Some(Focus::TextInput) => {
+                            app.state.set_focus(Focus::TextInput);
+                            app.state.app_status = AppStatus::UserInput;
+                        }
+                        Some(Focus::Body) => handle_label_manager_selection(app),
+                        Some(Focus::CloseButton) => {
+                            return handle_go_to_previous_ui_mode(app).await
+                        }
+                        _ => {}
+                    }
+                } else if mouse_scroll_up {
+                    app.label_list_prv();
+                } else if mouse_scroll_down {
+                    app.label_list_next();
+                }
+            }
+            UiMode::Login => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    handle_login_action(app).await
+                }
+            }
+            UiMode::SignUp => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
                     handle_signup_action(app).await
                 }
             }
@@ -4736,10 +5905,14 @@ async fn handle_left_click_for_ui_mode_mouse_action(app: &mut App<'_>) -> Option
         | Focus::NewBoardDescription
         | Focus::CardName
         | Focus::CardDescription
-        | Focus::CardDueDate => {
+        | Focus::CardDueDate
+        | Focus::CardEstimate => {
             app.state.app_status = AppStatus::UserInput;
             info!("Taking user input");
         }
+        Focus::CardTemplate => {
+            open_card_template_selector(app);
+        }
         Focus::LoadSave => {
             if app.state.app_list_states.load_save.selected().is_some() {
                 app.dispatch(IoEvent::LoadLocalPreview).await;
@@ -4827,6 +6000,7 @@ fn move_dragged_card(app: &mut App<'_>) {
             .unwrap()
             .name
             .clone();
+        switch_board_to_manual_sort(app, hovered_board_id);
         // swap cards
         app.boards.get_mut_boards().iter_mut().for_each(|board| {
             if board.id == hovered_board_id {
@@ -4875,11 +6049,18 @@ fn move_dragged_card(app: &mut App<'_>) {
             return;
         }
         let dragged_card_index = dragged_card_index.unwrap();
-        let dragged_card = dragged_card_board
+        let mut dragged_card = dragged_card_board
             .cards
             .get_card_with_index(dragged_card_index)
             .unwrap()
             .clone();
+        dragged_card.log_activity(
+            &app.state.user_login_data.activity_author(),
+            CardActivityKind::Moved {
+                from_board: dragged_card_board.name.clone(),
+                to_board: hovered_board.name.clone(),
+            },
+        );
         let dragged_card_name = dragged_card.name.clone();
         if hovered_card_id.is_none() {
             // check if hovered board is empty
@@ -5015,10 +6196,6 @@ fn move_dragged_card(app: &mut App<'_>) {
             }
         } else {
             let hovered_card_index = hovered_card_index.unwrap();
-            let dragged_card = dragged_card_board
-                .cards
-                .get_card_with_index(dragged_card_index)
-                .unwrap();
             // remove dragged card from current board
             app.boards.get_mut_boards().iter_mut().for_each(|board| {
                 if board.id == dragged_card_board_id {
@@ -5051,6 +6228,23 @@ fn move_dragged_card(app: &mut App<'_>) {
     }
 }
 
+fn warn_if_over_wip_limit(app: &mut App, board_id: (u64, u64)) {
+    let over_limit_board = app
+        .boards
+        .get_board_with_id(board_id)
+        .filter(|board| board.is_over_wip_limit());
+    if let Some(board) = over_limit_board {
+        let warning_msg = format!(
+            "Board \"{}\" is over its WIP limit ({}/{})",
+            board.name,
+            board.wip_count(),
+            board.wip_limit.unwrap_or(0)
+        );
+        warn!("{}", warning_msg);
+        app.send_warning_toast(&warning_msg, None);
+    }
+}
+
 pub fn reset_card_drag_mode(app: &mut App) {
     app.state.card_drag_mode = false;
     app.state.hovered_board = None;
@@ -5133,7 +6327,9 @@ fn handle_config_menu_action(app: &mut App) -> AppReturn {
             | ConfigEnum::DisableAnimations
             | ConfigEnum::AutoLogin
             | ConfigEnum::ShowLineNumbers
-            | ConfigEnum::EnableMouseSupport => {
+            | ConfigEnum::EnableMouseSupport
+            | ConfigEnum::EnableReminders
+            | ConfigEnum::ReminderAlert => {
                 AppConfig::edit_config(
                     app,
                     config_enum,
@@ -5177,6 +6373,7 @@ async fn handle_main_menu_action(app: &mut App<'_>) -> AppReturn {
                 app.state.prev_ui_mode = Some(UiMode::MainMenu);
                 app.state.ui_mode = app.config.default_view;
             }
+            MainMenuItem::Workspaces => open_workspace_switcher(app),
             MainMenuItem::Help => {
                 app.state.prev_ui_mode = Some(UiMode::MainMenu);
                 app.state.ui_mode = UiMode::HelpMenu;
@@ -5199,7 +6396,7 @@ async fn handle_main_menu_action(app: &mut App<'_>) -> AppReturn {
 }
 
 fn handle_default_view_selection(app: &mut App) {
-    let all_ui_modes = UiMode::view_modes_as_string();
+    let all_ui_modes = UiMode::default_view_modes_as_string();
     let current_selected_mode = app
         .state
         .app_list_states
@@ -5373,10 +6570,43 @@ pub async fn handle_go_to_previous_ui_mode(app: &mut App<'_>) -> AppReturn {
                 app.state.all_available_tags = None;
                 app.state.app_list_states.filter_by_tag_list.select(None);
             }
+            PopupMode::EditCardCustomFields => {
+                close_card_custom_fields_editor(app);
+                return AppReturn::Continue;
+            }
+            PopupMode::CardAttachments => {
+                close_card_attachments(app);
+                return AppReturn::Continue;
+            }
+            PopupMode::CustomFieldDefinitionPrompt
+            | PopupMode::SwimlaneGroupingPrompt
+            | PopupMode::FilterByQueryPrompt
+            | PopupMode::SaveFilterPrompt
+            | PopupMode::SaveBoardTemplatePrompt => {
+                app.clear_user_input_state();
+                app.state.current_cursor_position = None;
+            }
+            PopupMode::SprintPrompt => {
+                app.state.sprints.closing_sprint = None;
+                app.clear_user_input_state();
+                app.state.current_cursor_position = None;
+            }
+            PopupMode::CardRecurrencePrompt => {
+                close_card_recurrence_prompt(app);
+                return AppReturn::Continue;
+            }
+            PopupMode::TimeReportRangePrompt => {
+                close_time_report_range_prompt(app);
+                return AppReturn::Continue;
+            }
+            PopupMode::CardActivityLog => {
+                close_card_activity_log(app);
+                return AppReturn::Continue;
+            }
             PopupMode::ChangeTheme => {
                 let config_theme = {
                     let all_themes = Theme::all_default_themes();
-                    let default_theme = app.config.default_theme.clone();
+                    let default_theme = app.config.get_theme_name();
                     all_themes.iter().find(|t| t.name == default_theme).cloned()
                 };
                 if config_theme.is_some() {
@@ -5407,6 +6637,54 @@ pub async fn handle_go_to_previous_ui_mode(app: &mut App<'_>) -> AppReturn {
             AppReturn::Continue
         }
         UiMode::MainMenu => handle_exit(app).await,
+        UiMode::ArchiveBrowser => {
+            app.clear_user_input_state();
+            app.state.current_cursor_position = None;
+            app.state.app_list_states.archived_card_list.select(None);
+            app.state.ui_mode = app
+                .state
+                .prev_ui_mode
+                .filter(|prev_ui_mode| *prev_ui_mode != UiMode::ArchiveBrowser)
+                .unwrap_or(app.config.default_view);
+            AppReturn::Continue
+        }
+        UiMode::LabelManager => {
+            close_label_manager(app);
+            AppReturn::Continue
+        }
+        UiMode::Calendar => {
+            close_calendar(app);
+            AppReturn::Continue
+        }
+        UiMode::Timeline => {
+            close_timeline(app);
+            AppReturn::Continue
+        }
+        UiMode::CardTable => {
+            close_card_table(app);
+            AppReturn::Continue
+        }
+        UiMode::Statistics => {
+            close_statistics(app);
+            AppReturn::Continue
+        }
+        UiMode::Sprints => {
+            close_sprints(app);
+            AppReturn::Continue
+        }
+        UiMode::Agenda => {
+            close_agenda(app);
+            AppReturn::Continue
+        }
+        UiMode::WorkspaceSwitcher => {
+            let prev_ui_mode = app.state.prev_ui_mode;
+            close_workspace_switcher(app);
+            if prev_ui_mode == Some(UiMode::MainMenu) {
+                app.state.ui_mode = UiMode::MainMenu;
+                app.state.set_focus(Focus::MainMenu);
+            }
+            AppReturn::Continue
+        }
         UiMode::EditKeybindings => {
             app.state.ui_mode = UiMode::ConfigMenu;
             if app.state.app_table_states.config.selected().is_none() {
@@ -5491,6 +6769,105 @@ pub async fn handle_go_to_previous_ui_mode(app: &mut App<'_>) -> AppReturn {
     }
 }
 
+fn handle_export_time_report(app: &mut App) {
+    match export_time_report_to_csv(&app.boards, app.state.time_report_range, &app.config) {
+        Ok(file_path) => {
+            info!("Exported time report to {}", file_path);
+            app.send_info_toast(&format!("Exported time report to {}", file_path), None);
+        }
+        Err(err) => {
+            error!("Could not export time report: {}", err);
+            app.send_error_toast(&format!("Could not export time report: {}", err), None);
+        }
+    }
+}
+
+/// the prompt is prefilled with the current custom range, or the current month up to today
+fn open_time_report_range_prompt(app: &mut App) {
+    let prompt_text = app.state.time_report_range.to_spec().unwrap_or_else(|| {
+        let today = Local::now().date_naive();
+        format!(
+            "{} | {}",
+            today
+                .with_day(1)
+                .unwrap_or(today)
+                .format(TIME_REPORT_DATE_FORMAT),
+            today.format(TIME_REPORT_DATE_FORMAT)
+        )
+    });
+    app.state.current_user_input = prompt_text;
+    app.state.current_cursor_position = Some(app.state.current_user_input.len());
+    app.state.popup_mode = Some(PopupMode::TimeReportRangePrompt);
+    app.state.set_focus(Focus::TextInput);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+fn close_time_report_range_prompt(app: &mut App) {
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.popup_mode = Some(PopupMode::TimeReport);
+    app.state.set_focus(Focus::NoFocus);
+}
+
+fn handle_time_report_range_submit(app: &mut App) {
+    match TimeReportRange::from_spec(&app.state.current_user_input) {
+        Ok(range) => {
+            app.state.time_report_range = range;
+            close_time_report_range_prompt(app);
+        }
+        Err(err) => {
+            app.send_error_toast(&err, None);
+        }
+    }
+}
+
+pub fn handle_toggle_card_timer(app: &mut App) -> AppReturn {
+    let current_card_id = if let Some(current_card_id) = app.state.current_card_id {
+        current_card_id
+    } else {
+        app.send_error_toast("No card selected to track time on", None);
+        return AppReturn::Continue;
+    };
+    let previous_card_name = app
+        .boards
+        .get_card_with_running_timer()
+        .filter(|card| card.id != current_card_id)
+        .map(|card| card.name.clone());
+    match app.boards.toggle_card_timer(current_card_id) {
+        Some(started) => {
+            // filtered boards hold copies of the cards, keep their timers in sync
+            if !app.filtered_boards.is_empty() {
+                app.filtered_boards.toggle_card_timer(current_card_id);
+            }
+            let card = app.boards.get_card_with_id(current_card_id).unwrap();
+            let card_name = card.name.clone();
+            let total_time = format_duration(card.total_time_tracked());
+            if started {
+                if let Some(previous_card_name) = previous_card_name {
+                    app.send_info_toast(
+                        &format!("Stopped timer on card {}", previous_card_name),
+                        None,
+                    );
+                }
+                app.send_info_toast(&format!("Started timer on card {}", card_name), None);
+            } else {
+                app.send_info_toast(
+                    &format!(
+                        "Stopped timer on card {}, total time tracked {}",
+                        card_name, total_time
+                    ),
+                    None,
+                );
+            }
+        }
+        None => {
+            app.send_error_toast("Could not find the current card", None);
+        }
+    }
+    AppReturn::Continue
+}
+
 fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppReturn {
     let selected_status = if let Some(status) = status {
         status
@@ -5511,6 +6888,40 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
         all_statuses[current_index].clone()
     };
 
+    if selected_status == CardStatus::Complete {
+        let card_to_complete = if let Some((_, card)) = &app.state.card_being_edited {
+            Some(card.clone())
+        } else {
+            app.state
+                .current_card_id
+                .and_then(|card_id| app.boards.get_card_with_id(card_id).cloned())
+        };
+        if let Some(card_to_complete) = card_to_complete {
+            let blocker_names = app
+                .boards
+                .get_incomplete_blockers(&card_to_complete)
+                .iter()
+                .map(|blocker| blocker.name.clone())
+                .collect::<Vec<String>>();
+            if !blocker_names.is_empty() {
+                let error_msg = format!(
+                    "Cannot complete card {}, it is blocked by: {}",
+                    card_to_complete.name,
+                    blocker_names.join(", ")
+                );
+                error!("{}", error_msg);
+                app.send_error_toast(&error_msg, None);
+                if app.state.card_being_edited.is_some() {
+                    app.state.popup_mode = Some(PopupMode::ViewCard);
+                    app.state.set_focus(Focus::CardStatus);
+                } else {
+                    app.state.popup_mode = None;
+                }
+                return AppReturn::Continue;
+            }
+        }
+    }
+
     if app.state.card_being_edited.is_some() {
         let card_being_edited = app.state.card_being_edited.clone().unwrap();
         let card_coordinates = card_being_edited.0;
@@ -5527,6 +6938,7 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
         return AppReturn::Continue;
     } else if let Some(current_board_id) = app.state.current_board_id {
         let mut card_found = String::new();
+        let mut completed_recurring_card = None;
         let boards: &mut Boards = if app.filtered_boards.is_empty() {
             &mut app.boards
         } else {
@@ -5538,6 +6950,12 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
                     current_board.cards.get_mut_card_with_id(current_card_id)
                 {
                     let temp_old_card = current_card.clone();
+                    if temp_old_card.card_status != CardStatus::Complete
+                        && selected_status == CardStatus::Complete
+                        && temp_old_card.recurrence.is_some()
+                    {
+                        completed_recurring_card = Some(current_card_id);
+                    }
                     current_card.card_status = selected_status.clone();
                     if current_card.card_status == CardStatus::Complete {
                         current_card.date_completed = Utc::now().to_string();
@@ -5545,10 +6963,14 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
                         current_card.date_completed = FIELD_NOT_SET.to_string();
                     }
                     current_card.date_modified = Utc::now().to_string();
+                    current_card.log_changes_from(
+                        &temp_old_card,
+                        &app.state.user_login_data.activity_author(),
+                    );
                     app.action_history_manager
                         .new_action(ActionHistory::EditCard(
-                            temp_old_card,
-                            current_card.clone(),
+                            Box::new(temp_old_card),
+                            Box::new(current_card.clone()),
                             current_board_id,
                         ));
                     info!(
@@ -5568,6 +6990,9 @@ fn handle_change_card_status(app: &mut App, status: Option<CardStatus>) -> AppRe
                 ),
                 None,
             );
+            if let Some(completed_card_id) = completed_recurring_card {
+                handle_recurring_card_completion(app, current_board_id, completed_card_id);
+            }
         } else {
             app.send_error_toast("Error Could not find current card", None);
         }
@@ -5607,7 +7032,12 @@ fn handle_change_card_priority(app: &mut App) -> AppReturn {
                 if let Some(current_card) =
                     current_board.cards.get_mut_card_with_id(current_card_id)
                 {
+                    let temp_old_card = current_card.clone();
                     current_card.priority = selected_priority;
+                    current_card.log_changes_from(
+                        &temp_old_card,
+                        &app.state.user_login_data.activity_author(),
+                    );
                     app.state.popup_mode = None;
                     return AppReturn::Continue;
                 }
@@ -5765,12 +7195,18 @@ fn handle_new_board_action(app: &mut App) {
         app.state.app_status = AppStatus::UserInput;
     }
     if !app.filtered_boards.is_empty() {
+        app.state.filter_query = None;
+        app.state.active_saved_filter = None;
         app.state.filter_tags = None;
         app.send_warning_toast("Filter Reset", None);
     }
 }
 
 fn handle_new_card_action(app: &mut App) -> AppReturn {
+    if app.state.focus == Focus::CardTemplate {
+        open_card_template_selector(app);
+        return AppReturn::Continue;
+    }
     if app.state.focus == Focus::SubmitButton {
         let new_card_name = app.state.app_form_states.new_card[0].clone();
         let new_card_name = new_card_name.trim();
@@ -5830,21 +7266,49 @@ fn handle_new_card_action(app: &mut App) -> AppReturn {
         } else {
             FIELD_NOT_SET.to_string()
         };
+        let new_card_estimate = app.state.app_form_states.new_card[3].clone();
+        let new_card_estimate = new_card_estimate.trim();
+        let parsed_estimate = if new_card_estimate.is_empty() {
+            None
+        } else if let Ok(estimate) = new_card_estimate.parse::<u32>() {
+            Some(estimate)
+        } else {
+            app.send_warning_toast(
+                &format!(
+                    "Invalid estimate '{}'. Estimates must be whole numbers. Estimate has been reset and other changes have been saved.",
+                    new_card_estimate
+                ),
+                None,
+            );
+            warn!("Invalid estimate '{}'. Estimates must be whole numbers. Estimate has been reset and other changes have been saved.",
+            new_card_estimate);
+            None
+        };
         if !new_card_name.is_empty() && !same_name_exists {
-            let new_card = Card::new(
+            let (new_card_priority, new_card_tags) = match &app.state.new_card_template {
+                Some(card_template) => (card_template.priority.clone(), card_template.tags.clone()),
+                None => (CardPriority::Low, vec![]),
+            };
+            let mut new_card = Card::new(
                 new_card_name,
                 new_card_description,
                 &parsed_date,
-                CardPriority::Low,
-                vec![],
+                new_card_priority,
+                new_card_tags,
                 vec![],
             );
+            new_card.estimate = parsed_estimate;
+            new_card.log_activity(
+                &app.state.user_login_data.activity_author(),
+                CardActivityKind::Created,
+            );
             let current_board = app.boards.get_mut_board_with_id(current_board_id);
             if let Some(current_board) = current_board {
                 current_board.cards.add_card(new_card.clone());
                 app.state.current_card_id = Some(new_card.id);
                 app.action_history_manager
                     .new_action(ActionHistory::CreateCard(new_card, current_board.id));
+                warn_if_over_wip_limit(app, current_board_id);
             } else {
                 debug!("Current board not found");
                 app.send_error_toast("Something went wrong", None);
@@ -5874,6 +7338,8 @@ fn handle_new_card_action(app: &mut App) -> AppReturn {
         app.state.app_status = AppStatus::UserInput;
     }
     if !app.filtered_boards.is_empty() {
+        app.state.filter_query = None;
+        app.state.active_saved_filter = None;
         app.state.filter_tags = None;
         app.state.all_available_tags = None;
         app.state.app_list_states.filter_by_tag_list.select(None);
@@ -6956,25 +8422,36 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
     };
     edited_card.due_date = parsed_date;
     edited_card.date_modified = Utc::now().to_string();
+    if let Some(text_buffer) = &app.state.card_description_text_buffer {
+        edited_card.description = text_buffer.lines().join("\n");
+    }
+    // comments left empty are dropped, edited ones are stamped so the card view can mark them
+    edited_card
+        .comments
+        .retain(|comment| !comment.body.trim().is_empty());
+    for comment in edited_card.comments.iter_mut() {
+        if card
+            .comments
+            .iter()
+            .any(|original| original.id == comment.id && original.body != comment.body)
+        {
+            comment.date_edited = Some(Utc::now().to_string());
+        }
+    }
+    edited_card.log_changes_from(card, &app.state.user_login_data.activity_author());
+    let completed_recurring_card = card.card_status != CardStatus::Complete
+        && edited_card.card_status == CardStatus::Complete
+        && edited_card.recurrence.is_some();
+    let board_id = board.id;
+    let card_id = card.id;
     app.action_history_manager
         .new_action(ActionHistory::EditCard(
-            card.clone(),
-            edited_card.clone(),
+            Box::new(card.clone()),
+            Box::new(edited_card.clone()),
             board.id,
         ));
     *card = edited_card;
 
-    if app.state.card_description_text_buffer.is_some() {
-        let description = app
-            .state
-            .card_description_text_buffer
-            .as_ref()
-            .unwrap()
-            .lines()
-            .join("\n");
-        card.description = description;
-    }
-
     let card_name = card.name.clone();
     app.state.card_being_edited = None;
     reset_text_buffer(app);
@@ -6997,6 +8474,9 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
         );
     }
     app.send_info_toast(&format!("Changes to Card '{}' saved", card_name), None);
+    if completed_recurring_card {
+        handle_recurring_card_completion(app, board_id, card_id);
+    }
     app.state.set_focus(Focus::CardName);
     app.state.app_status = AppStatus::Initialized;
     let calculated_tags = CommandPaletteWidget::calculate_tags(app);
@@ -7011,7 +8491,20 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
     AppReturn::Continue
 }
 
+/// boards cannot change while the command palette is open, so the index is synced once when it opens
+fn sync_search_index(app: &mut App) {
+    let reindexed_cards = app.search_index.sync(&app.boards);
+    if reindexed_cards > 0 {
+        debug!(
+            "Re-indexed {} of {} cards for search",
+            reindexed_cards,
+            app.search_index.len()
+        );
+    }
+}
+
 fn open_command_palette(app: &mut App) {
+    sync_search_index(app);
     app.state.popup_mode = Some(PopupMode::CommandPalette);
     app.state.set_focus(Focus::CommandPaletteCommand);
     app.clear_user_input_state();
@@ -7057,12 +8550,25 @@ fn handle_filter_by_tag(app: &mut App) {
                 app.state.filter_tags = Some(filter_tags);
             }
         }
-        Focus::SubmitButton => filter_boards(app),
+        Focus::SubmitButton => {
+            app.state.filter_query = None;
+            app.state.active_saved_filter = None;
+            filter_boards(app)
+        }
         _ => {}
     }
 }
 
 fn filter_boards(app: &mut App) {
+    if let Some(filter_query) = app.state.filter_query.clone() {
+        match CardQuery::parse(&filter_query) {
+            Ok(card_query) => {
+                apply_card_query(app, &card_query);
+            }
+            Err(err) => app.send_error_toast(&format!("Invalid filter query: {}", err), None),
+        }
+        return;
+    }
     if app.state.filter_tags.is_none() {
         app.send_warning_toast("No tags selected to filter", None);
         app.state.popup_mode = None;
@@ -7077,6 +8583,7 @@ fn filter_boards(app: &mut App) {
         let mut filtered_cards = Vec::new();
         for card in board.cards.get_all_cards() {
             let mut card_tags = card.tags.clone();
+            card_tags.extend(card.custom_field_tags());
             card_tags.retain(|tag| filter_tags.contains(&tag.to_lowercase()));
             if !card_tags.is_empty() {
                 filtered_cards.push(card.clone());
@@ -7088,6 +8595,11 @@ fn filter_boards(app: &mut App) {
                 name: board.name.clone(),
                 description: board.description.clone(),
                 cards: Cards::from(filtered_cards),
+                custom_fields: board.custom_fields.clone(),
+                archived_cards: board.archived_cards.clone(),
+                wip_limit: board.wip_limit,
+                default_card_template: board.default_card_template.clone(),
+                sort_mode: board.sort_mode,
             });
         }
     }
@@ -7104,47 +8616,2366 @@ fn filter_boards(app: &mut App) {
     app.state.app_list_states.filter_by_tag_list.select(None);
 }
 
-fn handle_command_palette_card_selection(app: &mut App) {
-    reset_mouse(app);
+/// keeps the cards that match the query, returns the number of matching cards
+fn apply_card_query(app: &mut App, card_query: &CardQuery) -> usize {
+    let all_boards = app.boards.clone();
+    let mut filtered_boards = Vec::new();
+    let mut matching_cards = 0;
+    for board in all_boards.get_boards() {
+        let filtered_cards = board
+            .cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card_query.matches(card, board))
+            .cloned()
+            .collect::<Vec<Card>>();
+        matching_cards += filtered_cards.len();
+        if !filtered_cards.is_empty() {
+            filtered_boards.push(Board {
+                id: board.id,
+                name: board.name.clone(),
+                description: board.description.clone(),
+                cards: Cards::from(filtered_cards),
+                custom_fields: board.custom_fields.clone(),
+                archived_cards: board.archived_cards.clone(),
+                wip_limit: board.wip_limit,
+                default_card_template: board.default_card_template.clone(),
+                sort_mode: board.sort_mode,
+            });
+        }
+    }
+    app.state.current_board_id = None;
+    app.state.current_card_id = None;
+    app.filtered_boards = Boards::from(filtered_boards);
     refresh_visible_boards_and_cards(app);
-    let card_details_index = app
-        .state
-        .app_list_states
-        .command_palette_card_search
-        .selected();
-    if card_details_index.is_none() {
-        return;
+    matching_cards
+}
+
+fn handle_filter_by_query_submit(app: &mut App) {
+    let filter_query = app.state.current_user_input.trim().to_string();
+    if filter_query.is_empty() {
+        app.state.filter_query = None;
+        app.state.active_saved_filter = None;
+        app.filtered_boards.reset();
+        refresh_visible_boards_and_cards(app);
+        app.send_info_toast("Query filter cleared", None);
+    } else {
+        let card_query = match CardQuery::parse(&filter_query) {
+            Ok(card_query) => card_query,
+            Err(err) => {
+                // the prompt already shows the error next to the input
+                app.send_error_toast(&format!("Invalid filter query: {}", err), None);
+                return;
+            }
+        };
+        let matching_cards = apply_card_query(app, &card_query);
+        if matching_cards == 0 {
+            app.state.filter_query = None;
+            app.state.active_saved_filter = None;
+            app.send_warning_toast("No cards match the query", None);
+        } else {
+            app.state.filter_query = Some(filter_query);
+            app.state.active_saved_filter = None;
+            app.state.filter_tags = None;
+            app.send_info_toast(
+                &format!("Showing {} cards matching the query", matching_cards),
+                None,
+            );
+        }
     }
-    let card_details_index = card_details_index.unwrap();
-    let all_card_details = app.widgets.command_palette.card_search_results.clone();
-    if all_card_details.is_none() {
-        debug!("No card details found to select");
+    app.state.popup_mode = None;
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.set_focus(Focus::Body);
+}
+
+fn handle_apply_saved_filter_action(app: &mut App, index: usize) -> AppReturn {
+    let filterable_ui_mode =
+        UiMode::view_modes().contains(&app.state.ui_mode) || app.state.ui_mode == UiMode::CardTable;
+    if filterable_ui_mode && app.state.popup_mode.is_none() {
+        apply_saved_filter(app, index);
+    }
+    AppReturn::Continue
+}
+
+pub fn open_saved_filter_selector(app: &mut App) {
+    if app.config.saved_filters.is_empty() {
+        app.send_warning_toast(
+            "No saved filters, use \"Save Current Filter\" to add one",
+            None,
+        );
+        return;
+    }
+    let selected_index = app
+        .state
+        .active_saved_filter
+        .as_ref()
+        .and_then(|active_saved_filter| {
+            app.config
+                .saved_filters
+                .iter()
+                .position(|saved_filter| saved_filter.name == *active_saved_filter)
+        })
+        .unwrap_or(0);
+    app.state
+        .app_list_states
+        .saved_filter_list
+        .select(Some(selected_index));
+    app.state.popup_mode = Some(PopupMode::SelectSavedFilter);
+    app.state.set_focus(Focus::SavedFilterSelector);
+}
+
+fn handle_saved_filter_selection(app: &mut App) {
+    app.state.popup_mode = None;
+    match app.state.app_list_states.saved_filter_list.selected() {
+        Some(index) => apply_saved_filter(app, index),
+        None => app.send_warning_toast("No saved filter selected", None),
+    }
+}
+
+fn clear_saved_filter(app: &mut App) {
+    app.state.active_saved_filter = None;
+    app.state.filter_query = None;
+    app.filtered_boards.reset();
+    refresh_visible_boards_and_cards(app);
+}
+
+/// applying the saved filter that is already active clears it again
+pub fn apply_saved_filter(app: &mut App, index: usize) {
+    let saved_filter = match app.config.saved_filters.get(index) {
+        Some(saved_filter) => saved_filter.clone(),
+        None => {
+            app.send_warning_toast(&format!("No saved filter number {}", index + 1), None);
+            return;
+        }
+    };
+    if app.state.active_saved_filter.as_ref() == Some(&saved_filter.name) {
+        clear_saved_filter(app);
+        app.send_info_toast(&format!("Saved filter {} cleared", saved_filter.name), None);
+        return;
+    }
+    let card_query = match CardQuery::parse(&saved_filter.query) {
+        Ok(card_query) => card_query,
+        Err(err) => {
+            app.send_error_toast(
+                &format!("Saved filter {} is invalid: {}", saved_filter.name, err),
+                None,
+            );
+            return;
+        }
+    };
+    let matching_cards = apply_card_query(app, &card_query);
+    if matching_cards == 0 {
+        app.state.filter_query = None;
+        app.state.active_saved_filter = None;
+        app.send_warning_toast(
+            &format!("No cards match saved filter {}", saved_filter.name),
+            None,
+        );
+    } else {
+        app.state.filter_query = Some(saved_filter.query);
+        app.state.filter_tags = None;
+        app.send_info_toast(
+            &format!(
+                "Showing {} cards for saved filter {}",
+                matching_cards, saved_filter.name
+            ),
+            None,
+        );
+        app.state.active_saved_filter = Some(saved_filter.name);
+    }
+}
+
+fn handle_saved_filter_delete(app: &mut App) {
+    let selected_index = match app.state.app_list_states.saved_filter_list.selected() {
+        Some(index) if index < app.config.saved_filters.len() => index,
+        _ => {
+            app.send_warning_toast("No saved filter selected", None);
+            return;
+        }
+    };
+    let saved_filter = app.config.saved_filters.remove(selected_index);
+    if let Err(err) = write_config(&app.config) {
+        error!("Cannot save saved filters: {}", err);
+        app.send_error_toast("Cannot save saved filters", None);
+        app.config
+            .saved_filters
+            .insert(selected_index, saved_filter);
+        return;
+    }
+    if app.state.active_saved_filter.as_ref() == Some(&saved_filter.name) {
+        clear_saved_filter(app);
+    }
+    let saved_filters_len = app.config.saved_filters.len();
+    if saved_filters_len == 0 {
+        app.state.app_list_states.saved_filter_list.select(None);
+        app.state.popup_mode = None;
+    } else {
+        app.state
+            .app_list_states
+            .saved_filter_list
+            .select(Some(selected_index.min(saved_filters_len - 1)));
+    }
+    app.send_info_toast(&format!("Deleted saved filter {}", saved_filter.name), None);
+}
+
+/// the current filter as a query, a tag filter becomes tags joined with OR
+pub fn get_current_filter_query(app: &App) -> Option<String> {
+    if let Some(filter_query) = &app.state.filter_query {
+        return Some(filter_query.clone());
+    }
+    let filter_tags = app.state.filter_tags.as_ref()?;
+    if filter_tags.is_empty() || app.filtered_boards.is_empty() {
+        return None;
+    }
+    Some(
+        filter_tags
+            .iter()
+            .map(|tag| {
+                if tag.contains(char::is_whitespace) {
+                    format!("tag:\"{}\"", tag)
+                } else {
+                    format!("tag:{}", tag)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" OR "),
+    )
+}
+
+pub fn open_save_filter_prompt(app: &mut App) -> bool {
+    if get_current_filter_query(app).is_none() {
+        app.send_warning_toast("No filter applied to save", None);
+        return false;
+    }
+    app.state.current_user_input = app.state.active_saved_filter.clone().unwrap_or_default();
+    app.state.current_cursor_position = Some(app.state.current_user_input.len());
+    app.state.popup_mode = Some(PopupMode::SaveFilterPrompt);
+    app.state.set_focus(Focus::TextInput);
+    app.state.app_status = AppStatus::UserInput;
+    true
+}
+
+/// saving under an existing name replaces that filter's query
+fn handle_save_filter_submit(app: &mut App) {
+    let filter_name = app.state.current_user_input.trim().to_string();
+    if filter_name.is_empty() {
+        app.send_error_toast("Filter name cannot be empty", None);
+        return;
+    }
+    let filter_query = match get_current_filter_query(app) {
+        Some(filter_query) => filter_query,
+        None => {
+            app.send_warning_toast("No filter applied to save", None);
+            return;
+        }
+    };
+    let previous_saved_filters = app.config.saved_filters.clone();
+    let replaced = match app
+        .config
+        .saved_filters
+        .iter_mut()
+        .find(|saved_filter| saved_filter.name.eq_ignore_ascii_case(&filter_name))
+    {
+        Some(saved_filter) => {
+            saved_filter.query = filter_query.clone();
+            true
+        }
+        None => {
+            app.config
+                .saved_filters
+                .push(SavedFilter::new(&filter_name, &filter_query));
+            false
+        }
+    };
+    if let Err(err) = write_config(&app.config) {
+        error!("Cannot save saved filters: {}", err);
+        app.send_error_toast("Cannot save saved filters", None);
+        app.config.saved_filters = previous_saved_filters;
+        return;
+    }
+    let saved_filter_name = app
+        .config
+        .get_saved_filter(&filter_name)
+        .map(|saved_filter| saved_filter.name.clone())
+        .unwrap_or(filter_name);
+    app.state.filter_query = Some(filter_query);
+    app.state.filter_tags = None;
+    app.state.active_saved_filter = Some(saved_filter_name.clone());
+    app.state.popup_mode = None;
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.set_focus(Focus::Body);
+    let info_msg = if replaced {
+        format!("Updated saved filter {}", saved_filter_name)
+    } else {
+        format!("Saved filter {}", saved_filter_name)
+    };
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+}
+
+pub fn filter_unestimated_cards(app: &mut App) {
+    let all_boards = app.boards.clone();
+    app.state.current_board_id = None;
+    app.state.current_card_id = None;
+    let mut filtered_boards = Vec::new();
+    for board in all_boards.get_boards() {
+        let filtered_cards = board
+            .cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card.estimate.is_none() && card.card_status != CardStatus::Complete)
+            .cloned()
+            .collect::<Vec<Card>>();
+        if !filtered_cards.is_empty() {
+            filtered_boards.push(Board {
+                id: board.id,
+                name: board.name.clone(),
+                description: board.description.clone(),
+                cards: Cards::from(filtered_cards),
+                custom_fields: board.custom_fields.clone(),
+                archived_cards: board.archived_cards.clone(),
+                wip_limit: board.wip_limit,
+                default_card_template: board.default_card_template.clone(),
+                sort_mode: board.sort_mode,
+            });
+        }
+    }
+    if filtered_boards.is_empty() {
+        app.send_info_toast("All open cards have an estimate", None);
+        return;
+    }
+    app.filtered_boards = Boards::from(filtered_boards);
+    refresh_visible_boards_and_cards(app);
+    app.send_info_toast("Showing open cards without an estimate", None);
+}
+
+/// same as picking Estimate in the sort mode selector, the board stays sorted until its sort mode changes
+pub fn sort_current_board_by_estimate(app: &mut App) {
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        app.send_error_toast("No board selected to sort", None);
+        return;
+    };
+    set_board_sort_mode(app, current_board_id, CardSortMode::Estimate);
+    refresh_visible_boards_and_cards(app);
+    if let Some(current_board) = app.boards.get_board_with_id(current_board_id) {
+        let info_msg = format!(
+            "Board {} is now sorted by {}",
+            current_board.name,
+            CardSortMode::Estimate
+        );
+        info!("{}", info_msg);
+        app.send_info_toast(&info_msg, None);
+    }
+}
+
+pub fn open_card_sort_mode_selector(app: &mut App) {
+    let current_sort_mode = if let Some(board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+    {
+        board.sort_mode
+    } else {
+        app.send_error_toast("No board selected to sort", None);
+        return;
+    };
+    let selected_index = CardSortMode::all()
+        .iter()
+        .position(|sort_mode| *sort_mode == current_sort_mode);
+    app.state
+        .app_list_states
+        .card_sort_mode_list
+        .select(selected_index);
+    app.state.popup_mode = Some(PopupMode::SelectCardSortMode);
+    app.state.set_focus(Focus::CardSortModeSelector);
+}
+
+fn handle_card_sort_mode_selection(app: &mut App) {
+    app.state.popup_mode = None;
+    let selected_sort_mode = app
+        .state
+        .app_list_states
+        .card_sort_mode_list
+        .selected()
+        .and_then(|index| CardSortMode::all().get(index).copied());
+    let (current_board_id, selected_sort_mode) =
+        match (app.state.current_board_id, selected_sort_mode) {
+            (Some(current_board_id), Some(selected_sort_mode)) => {
+                (current_board_id, selected_sort_mode)
+            }
+            _ => {
+                app.send_error_toast("No board selected to sort", None);
+                return;
+            }
+        };
+    set_board_sort_mode(app, current_board_id, selected_sort_mode);
+    refresh_visible_boards_and_cards(app);
+    if let Some(current_board) = app.boards.get_board_with_id(current_board_id) {
+        let info_msg = if selected_sort_mode == CardSortMode::Manual {
+            format!("Board {} is now ordered manually", current_board.name)
+        } else {
+            format!(
+                "Board {} is now sorted by {}",
+                current_board.name, selected_sort_mode
+            )
+        };
+        info!("{}", info_msg);
+        app.send_info_toast(&info_msg, None);
+    }
+}
+
+fn set_board_sort_mode(app: &mut App, board_id: (u64, u64), sort_mode: CardSortMode) {
+    if let Some(board) = app.boards.get_mut_board_with_id(board_id) {
+        board.sort_mode = sort_mode;
+    }
+    if let Some(board) = app.filtered_boards.get_mut_board_with_id(board_id) {
+        board.sort_mode = sort_mode;
+    }
+}
+
+/// moving a card by hand in a sorted board switches it back to manual order, the sorted order is kept as the starting point
+fn switch_board_to_manual_sort(app: &mut App, board_id: (u64, u64)) {
+    let board = match app.boards.get_board_with_id(board_id) {
+        Some(board) if board.sort_mode != CardSortMode::Manual => board,
+        _ => return,
+    };
+    let info_msg = format!(
+        "Board {} was sorted by {}, it is now ordered manually",
+        board.name, board.sort_mode
+    );
+    set_board_sort_mode(app, board_id, CardSortMode::Manual);
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+}
+
+fn handle_command_palette_card_selection(app: &mut App) {
+    reset_mouse(app);
+    refresh_visible_boards_and_cards(app);
+    app.state.popup_mode = None;
+    let card_details_index = app
+        .state
+        .app_list_states
+        .command_palette_card_search
+        .selected();
+    if card_details_index.is_none() {
+        return;
+    }
+    let card_details_index = card_details_index.unwrap();
+    let all_card_details = app.widgets.command_palette.card_search_results.clone();
+    if all_card_details.is_none() {
+        debug!("No card details found to select");
+        return;
+    }
+    let all_card_details = all_card_details.unwrap();
+    if card_details_index >= all_card_details.len() {
+        debug!("Selected index is out of bounds");
+        return;
+    }
+    let search_result = all_card_details[card_details_index].clone();
+    jump_to_card(app, search_result.card_id);
+    open_card_at_search_match(app, &search_result);
+}
+
+/// opens the card on the field that matched, description matches scroll to the matching line
+fn open_card_at_search_match(app: &mut App, search_result: &SearchResult) {
+    if app.state.current_card_id != Some(search_result.card_id) {
+        debug!("Searched card is not visible, not opening it");
+        return;
+    }
+    let card = match app.boards.get_card_with_id(search_result.card_id) {
+        Some(card) => card.clone(),
+        None => return,
+    };
+    reset_text_buffer(app);
+    let focus = match search_result.field {
+        SearchField::Name => Focus::CardName,
+        SearchField::Description => {
+            let mut text_buffer =
+                TextBox::from(card.description.split('\n').collect::<Vec<&str>>());
+            let (match_row, match_column) = search_result.match_position;
+            text_buffer.move_cursor(CursorMove::Jump(match_row as u16, match_column as u16));
+            app.state.card_description_text_buffer = Some(text_buffer);
+            Focus::CardDescription
+        }
+        SearchField::Tag(tag_index) => {
+            app.state
+                .app_list_states
+                .card_view_tag_list
+                .select(Some(tag_index));
+            Focus::CardTags
+        }
+        SearchField::Comment(comment_index) => {
+            app.state
+                .app_list_states
+                .card_view_comment_list
+                .select(Some(comment_index));
+            Focus::CardComments
+        }
+        SearchField::CustomFields => Focus::CardCustomFields,
+    };
+    app.state.popup_mode = Some(PopupMode::ViewCard);
+    app.state.set_focus(focus);
+}
+
+/// moves the selection to the given card, scrolling boards and cards into view
+pub fn jump_to_card(app: &mut App, card_id: (u64, u64)) {
+    let mut number_of_times_to_go_right = 0;
+    let mut number_of_times_to_go_down = 0;
+    for (board_index, board) in app.boards.get_boards().iter().enumerate() {
+        for (card_index, card) in board.cards.get_all_cards().iter().enumerate() {
+            if card.id == card_id {
+                number_of_times_to_go_right = board_index;
+                number_of_times_to_go_down = card_index;
+                break;
+            }
+        }
+    }
+    for _ in 0..number_of_times_to_go_right {
+        go_right(app);
+    }
+    for _ in 0..number_of_times_to_go_down {
+        go_down(app);
+    }
+    app.state.set_focus(Focus::Body);
+}
+
+fn open_card_dependency_picker(app: &mut App) {
+    let target_card_id = if let Some((_, card)) = &app.state.card_being_edited {
+        Some(card.id)
+    } else {
+        app.state.current_card_id
+    };
+    if target_card_id.is_none() {
+        debug!("No card selected to pick blockers for");
+        return;
+    }
+    app.state.card_dependency_picker_target = target_card_id;
+    sync_search_index(app);
+    app.state.popup_mode = Some(PopupMode::CommandPalette);
+    app.state.set_focus(Focus::CommandPaletteCard);
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::UserInput;
+    app.send_info_toast(
+        "Search for a card and select it to add or remove it as a blocker",
+        None,
+    );
+}
+
+fn open_card_custom_fields_editor(app: &mut App) {
+    let has_custom_fields = app
+        .get_custom_field_board()
+        .map(|board| !board.custom_fields.is_empty())
+        .unwrap_or(false);
+    if !has_custom_fields {
+        app.send_warning_toast(
+            "No custom fields defined for this board, use 'Add Custom Field' from the command palette",
+            None,
+        );
+        return;
+    }
+    app.state.popup_mode = Some(PopupMode::EditCardCustomFields);
+    app.state.set_focus(Focus::CustomFieldSelector);
+    app.state
+        .app_list_states
+        .custom_field_selector
+        .select(Some(0));
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+}
+
+fn close_card_custom_fields_editor(app: &mut App) {
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.app_list_states.custom_field_selector.select(None);
+    app.state.popup_mode = Some(PopupMode::ViewCard);
+    app.state.set_focus(Focus::CardCustomFields);
+}
+
+fn get_selected_custom_field(app: &App) -> Option<CustomFieldDefinition> {
+    let selected_index = app.state.app_list_states.custom_field_selector.selected()?;
+    app.get_custom_field_board()?
+        .custom_fields
+        .get(selected_index)
+        .cloned()
+}
+
+fn load_selected_custom_field_value(app: &mut App) {
+    let custom_field = if let Some(custom_field) = get_selected_custom_field(app) {
+        custom_field
+    } else {
+        debug!("No custom field selected");
+        return;
+    };
+    let current_value = if let Some((_, card)) = &app.state.card_being_edited {
+        card.custom_fields.get(&custom_field.name).cloned()
+    } else {
+        app.state
+            .current_card_id
+            .and_then(|card_id| app.boards.get_card_with_id(card_id))
+            .and_then(|card| card.custom_fields.get(&custom_field.name).cloned())
+    };
+    app.state.current_user_input = current_value.unwrap_or_default();
+    app.state.current_cursor_position = Some(app.state.current_user_input.len());
+    app.state.set_focus(Focus::TextInput);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+fn handle_custom_field_value_submit(app: &mut App) {
+    let custom_field = if let Some(custom_field) = get_selected_custom_field(app) {
+        custom_field
+    } else {
+        app.send_error_toast("No custom field selected", None);
+        return;
+    };
+    let value = match custom_field
+        .field_type
+        .validate(&app.state.current_user_input, app.config.date_format)
+    {
+        Ok(value) => value,
+        Err(err) => {
+            app.send_error_toast(&err, None);
+            return;
+        }
+    };
+    let set_value = |custom_fields: &mut BTreeMap<String, String>| {
+        if value.is_empty() {
+            custom_fields.remove(&custom_field.name);
+        } else {
+            custom_fields.insert(custom_field.name.clone(), value.clone());
+        }
+    };
+    if let Some((_, card)) = app.state.card_being_edited.as_mut() {
+        set_value(&mut card.custom_fields);
+    } else if let (Some(current_board_id), Some(current_card_id)) =
+        (app.state.current_board_id, app.state.current_card_id)
+    {
+        if edit_card_with(app, current_board_id, current_card_id, |card| {
+            set_value(&mut card.custom_fields)
+        })
+        .is_none()
+        {
+            app.send_error_toast("Error Could not find current card", None);
+            return;
+        }
+    }
+    if value.is_empty() {
+        app.send_info_toast(&format!("Cleared {}", custom_field.name), None);
+    } else {
+        app.send_info_toast(&format!("Set {} to {}", custom_field.name, value), None);
+    }
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.set_focus(Focus::CustomFieldSelector);
+}
+
+fn handle_custom_field_definition_submit(app: &mut App) {
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else {
+        app.send_error_toast("No board selected to add a custom field to", None);
+        return;
+    };
+    let spec = app.state.current_user_input.trim().to_string();
+    let current_board =
+        if let Some(current_board) = app.boards.get_mut_board_with_id(current_board_id) {
+            current_board
+        } else {
+            app.send_error_toast("Could not find the current board", None);
+            return;
+        };
+    let old_board = current_board.clone();
+    let toast_msg = if let Some(name) = spec.strip_prefix('-') {
+        let name = name.trim();
+        let field_count = current_board.custom_fields.len();
+        current_board
+            .custom_fields
+            .retain(|custom_field| !custom_field.name.eq_ignore_ascii_case(name));
+        if current_board.custom_fields.len() == field_count {
+            let error_msg = format!("No custom field named {} on this board", name);
+            app.send_error_toast(&error_msg, None);
+            return;
+        }
+        let archived_cards = current_board
+            .archived_cards
+            .iter_mut()
+            .map(|archived_card| &mut archived_card.card);
+        for card in current_board
+            .cards
+            .get_mut_all_cards()
+            .iter_mut()
+            .chain(archived_cards)
+        {
+            card.custom_fields
+                .retain(|field_name, _| !field_name.eq_ignore_ascii_case(name));
+        }
+        format!("Removed custom field {}", name)
+    } else {
+        let custom_field = match CustomFieldDefinition::from_spec(&spec) {
+            Ok(custom_field) => custom_field,
+            Err(err) => {
+                app.send_error_toast(&err, None);
+                return;
+            }
+        };
+        let existing_field = current_board
+            .custom_fields
+            .iter_mut()
+            .find(|existing_field| existing_field.name.eq_ignore_ascii_case(&custom_field.name));
+        if let Some(existing_field) = existing_field {
+            existing_field.field_type = custom_field.field_type.clone();
+            format!("Updated custom field {}", custom_field.name)
+        } else {
+            let toast_msg = format!("Added custom field {}", custom_field.name);
+            current_board.custom_fields.push(custom_field);
+            toast_msg
+        }
+    };
+    app.action_history_manager
+        .new_action(ActionHistory::EditBoard(
+            Box::new(old_board),
+            Box::new(current_board.clone()),
+        ));
+    if app.filtered_boards.is_empty() {
+        refresh_visible_boards_and_cards(app);
+    } else {
+        filter_boards(app);
+    }
+    info!("{}", toast_msg);
+    app.send_info_toast(&toast_msg, None);
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.popup_mode = None;
+}
+
+fn open_card_recurrence_prompt(app: &mut App) {
+    let current_recurrence = if let Some((_, card)) = &app.state.card_being_edited {
+        card.recurrence.clone()
+    } else {
+        app.state
+            .current_card_id
+            .and_then(|card_id| app.boards.get_card_with_id(card_id))
+            .and_then(|card| card.recurrence.clone())
+    };
+    app.state.current_user_input = current_recurrence
+        .map(|recurrence| recurrence.to_spec())
+        .unwrap_or_default();
+    app.state.current_cursor_position = Some(app.state.current_user_input.len());
+    app.state.popup_mode = Some(PopupMode::CardRecurrencePrompt);
+    app.state.set_focus(Focus::TextInput);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+fn close_card_recurrence_prompt(app: &mut App) {
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.popup_mode = Some(PopupMode::ViewCard);
+    app.state.set_focus(Focus::CardRecurrence);
+}
+
+fn open_card_activity_log(app: &mut App) {
+    app.state.app_list_states.card_activity_list.select(None);
+    app.state.popup_mode = Some(PopupMode::CardActivityLog);
+    app.state.set_focus(Focus::NoFocus);
+}
+
+fn close_card_activity_log(app: &mut App) {
+    app.state.popup_mode = Some(PopupMode::ViewCard);
+    app.state.set_focus(Focus::CardActivityLog);
+}
+
+fn open_card_attachments(app: &mut App) {
+    app.state.popup_mode = Some(PopupMode::CardAttachments);
+    app.state.set_focus(Focus::CardAttachmentList);
+    let has_entries = !app.get_card_attachment_entries().is_empty();
+    app.state
+        .app_list_states
+        .card_attachment_list
+        .select(if has_entries { Some(0) } else { None });
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+}
+
+fn close_card_attachments(app: &mut App) {
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.app_list_states.card_attachment_list.select(None);
+    app.state.popup_mode = Some(PopupMode::ViewCard);
+    app.state.set_focus(Focus::CardAttachments);
+}
+
+/// opens an attachment, or attaches a url that was found in the description
+fn handle_card_attachment_selection(app: &mut App) {
+    let selected_entry = app
+        .state
+        .app_list_states
+        .card_attachment_list
+        .selected()
+        .and_then(|index| app.get_card_attachment_entries().get(index).cloned());
+    match selected_entry {
+        Some(CardAttachmentEntry::Attachment(attachment)) => {
+            if attachment.is_missing_file() {
+                warn!("Attachment file {} does not exist", attachment.target);
+                app.send_warning_toast(&format!("File {} does not exist", attachment.target), None);
+                return;
+            }
+            let target = attachment
+                .file_path()
+                .map(|file_path| file_path.to_string_lossy().to_string())
+                .unwrap_or(attachment.target.clone());
+            match open_with_system_opener(&target) {
+                Ok(()) => {
+                    info!("Opened attachment {}", target);
+                    app.send_info_toast(&format!("Opened {}", attachment.title), None);
+                }
+                Err(err) => {
+                    error!("{}", err);
+                    app.send_error_toast(&err, None);
+                }
+            }
+        }
+        Some(CardAttachmentEntry::DetectedUrl(url)) => match CardAttachment::from_spec(&url) {
+            Ok(attachment) => update_viewed_card_attachments(app, |attachments| {
+                attachments.push(attachment);
+                format!("Attached {}", url)
+            }),
+            Err(err) => app.send_error_toast(&err, None),
+        },
+        None => debug!("No attachment selected"),
+    }
+}
+
+/// "<title> | <url or path>" adds an attachment, "-<number>" removes the attachment at that position
+fn handle_card_attachment_submit(app: &mut App) {
+    let spec = app.state.current_user_input.trim().to_string();
+    if let Some(attachment_number) = spec.strip_prefix('-') {
+        let attachment_count = app
+            .get_viewed_card()
+            .map(|card| card.attachments.len())
+            .unwrap_or(0);
+        let attachment_index = match attachment_number.trim().parse::<usize>() {
+            Ok(attachment_number)
+                if attachment_number > 0 && attachment_number <= attachment_count =>
+            {
+                attachment_number - 1
+            }
+            _ => {
+                app.send_error_toast(
+                    &format!(
+                        "Enter -<number> with a number between 1 and {} to remove an attachment",
+                        attachment_count
+                    ),
+                    None,
+                );
+                return;
+            }
+        };
+        update_viewed_card_attachments(app, |attachments| {
+            let removed_attachment = attachments.remove(attachment_index);
+            format!("Removed attachment {}", removed_attachment.title)
+        });
+    } else {
+        let attachment = match CardAttachment::from_spec(&spec) {
+            Ok(attachment) => attachment,
+            Err(err) => {
+                app.send_error_toast(&err, None);
+                return;
+            }
+        };
+        if attachment.is_missing_file() {
+            warn!("Attachment file {} does not exist", attachment.target);
+            app.send_warning_toast(
+                &format!(
+                    "File {} does not exist, it has been attached anyway",
+                    attachment.target
+                ),
+                None,
+            );
+        }
+        update_viewed_card_attachments(app, |attachments| {
+            let info_msg = format!("Attached {}", attachment.title);
+            attachments.push(attachment);
+            info_msg
+        });
+    }
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.set_focus(Focus::CardAttachmentList);
+}
+
+fn update_viewed_card_attachments<F>(app: &mut App, update: F)
+where
+    F: FnOnce(&mut Vec<CardAttachment>) -> String,
+{
+    let info_msg = if let Some((_, card)) = app.state.card_being_edited.as_mut() {
+        update(&mut card.attachments)
+    } else if let (Some(current_board_id), Some(current_card_id)) =
+        (app.state.current_board_id, app.state.current_card_id)
+    {
+        let mut info_msg = String::new();
+        if edit_card_with(app, current_board_id, current_card_id, |card| {
+            info_msg = update(&mut card.attachments)
+        })
+        .is_none()
+        {
+            app.send_error_toast("Error Could not find current card", None);
+            return;
+        }
+        info_msg
+    } else {
+        app.send_error_toast("Error Could not find current card", None);
+        return;
+    };
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+}
+
+fn handle_card_recurrence_submit(app: &mut App) {
+    let recurrence = match RecurrenceRule::from_spec(&app.state.current_user_input) {
+        Ok(recurrence) => recurrence,
+        Err(err) => {
+            app.send_error_toast(&err, None);
+            return;
+        }
+    };
+    if let Some((_, card)) = app.state.card_being_edited.as_mut() {
+        card.recurrence = recurrence.clone();
+    } else if let (Some(current_board_id), Some(current_card_id)) =
+        (app.state.current_board_id, app.state.current_card_id)
+    {
+        if edit_card_with(app, current_board_id, current_card_id, |card| {
+            card.recurrence = recurrence.clone()
+        })
+        .is_none()
+        {
+            app.send_error_toast("Error Could not find current card", None);
+            return;
+        }
+    }
+    if let Some(recurrence) = recurrence {
+        app.send_info_toast(&format!("Card now repeats {}", recurrence), None);
+    } else {
+        app.send_info_toast("Card recurrence cleared", None);
+    }
+    close_card_recurrence_prompt(app);
+}
+
+/// spawns the next instance of a recurring card after it has been completed, the rule moving
+/// off the completed card is recorded so undo puts it back
+fn handle_recurring_card_completion(app: &mut App, board_id: (u64, u64), card_id: (u64, u64)) {
+    let date_format = app.config.date_format;
+    let board = if let Some(board) = app.boards.get_mut_board_with_id(board_id) {
+        board
+    } else {
+        return;
+    };
+    let card = if let Some(card) = board.cards.get_mut_card_with_id(card_id) {
+        card
+    } else {
+        return;
+    };
+    let old_card = card.clone();
+    let next_card = card.spawn_next_occurrence(date_format);
+    let updated_card = card.clone();
+    if let Some(next_card) = next_card {
+        board.cards.add_card(next_card.clone());
+        app.action_history_manager
+            .new_action(ActionHistory::EditCard(
+                Box::new(old_card),
+                Box::new(updated_card),
+                board_id,
+            ));
+        let toast_msg = format!(
+            "Next occurrence of {} created, due {}",
+            next_card.name, next_card.due_date
+        );
+        info!("{}", toast_msg);
+        app.send_info_toast(&toast_msg, None);
+        app.action_history_manager
+            .new_action(ActionHistory::CreateCard(next_card, board_id));
+        refresh_visible_boards_and_cards(app);
+    }
+}
+
+/// runs time based card updates (recurring cards, auto archive) at most once a minute
+pub fn handle_scheduled_card_updates(app: &mut App) {
+    if app
+        .state
+        .last_scheduled_card_update
+        .map(|last_update| last_update.elapsed() < Duration::from_secs(60))
+        .unwrap_or(false)
+    {
+        return;
+    }
+    app.state.last_scheduled_card_update = Some(Instant::now());
+    handle_due_recurring_cards(app);
+    if app.config.auto_archive_days > 0 {
+        handle_auto_archive(app);
+    }
+}
+
+/// runs in the background, so the spawns are kept out of the undo history
+fn handle_due_recurring_cards(app: &mut App) {
+    let spawned_cards = app.boards.spawn_due_recurring_cards(app.config.date_format);
+    if spawned_cards.is_empty() {
+        return;
+    }
+    for (_, card) in spawned_cards {
+        info!(
+            "Next occurrence of {} created, due {}",
+            card.name, card.due_date
+        );
+    }
+    app.send_info_toast(
+        "Created the next occurrence of recurring cards that came due",
+        None,
+    );
+    if app.filtered_boards.is_empty() {
+        refresh_visible_boards_and_cards(app);
+    } else {
+        filter_boards(app);
+    }
+}
+
+fn handle_auto_archive(app: &mut App) {
+    let archived_cards = app
+        .boards
+        .archive_completed_cards_older_than(app.config.auto_archive_days);
+    if archived_cards.is_empty() {
+        return;
+    }
+    let archived_count = archived_cards.len();
+    for (board_id, archived_card) in archived_cards {
+        if app.state.current_card_id == Some(archived_card.card.id) {
+            app.state.current_card_id = None;
+        }
+        app.action_history_manager
+            .new_action(ActionHistory::ArchiveCard(
+                Box::new(archived_card),
+                board_id,
+            ));
+    }
+    info!("Auto archived {} completed cards", archived_count);
+    app.send_info_toast(
+        &format!("Auto archived {} completed cards", archived_count),
+        None,
+    );
+    if app.filtered_boards.is_empty() {
+        refresh_visible_boards_and_cards(app);
+    } else {
+        filter_boards(app);
+    }
+}
+
+pub fn handle_archive_card(app: &mut App) -> AppReturn {
+    let (current_board_id, current_card_id) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(current_board_id), Some(current_card_id)) => (current_board_id, current_card_id),
+            _ => {
+                app.send_error_toast("No card selected to archive", None);
+                return AppReturn::Continue;
+            }
+        };
+    let current_board =
+        if let Some(current_board) = app.boards.get_mut_board_with_id(current_board_id) {
+            current_board
+        } else {
+            app.send_error_toast("Could not find the current board", None);
+            return AppReturn::Continue;
+        };
+    let card_index = current_board
+        .cards
+        .get_card_index(current_card_id)
+        .unwrap_or(0);
+    if let Some(archived_card) = current_board.archive_card(current_card_id) {
+        app.state.current_card_id = if card_index > 0 {
+            current_board
+                .cards
+                .get_card_with_index(card_index - 1)
+                .map(|card| card.id)
+        } else {
+            current_board.cards.get_first_card_id()
+        };
+        let card_name = archived_card.card.name.clone();
+        info!("Archived card {}", card_name);
+        app.action_history_manager
+            .new_action(ActionHistory::ArchiveCard(
+                Box::new(archived_card),
+                current_board_id,
+            ));
+        app.send_info_toast(&format!("Archived card {}", card_name), None);
+        if app.filtered_boards.is_empty() {
+            refresh_visible_boards_and_cards(app);
+        } else {
+            filter_boards(app);
+        }
+    } else {
+        app.send_error_toast("Could not find the current card", None);
+    }
+    AppReturn::Continue
+}
+
+pub fn open_calendar(app: &mut App) {
+    if app.state.ui_mode != UiMode::Calendar {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
+    }
+    app.state.ui_mode = UiMode::Calendar;
+    app.state.calendar.selected_date = Local::now().date_naive();
+    app.state.calendar.selected_card_index = 0;
+    app.state.set_focus(Focus::Body);
+}
+
+fn close_calendar(app: &mut App) {
+    app.state.calendar.mouse_target = None;
+    app.state.ui_mode = app
+        .state
+        .prev_ui_mode
+        .filter(|prev_ui_mode| *prev_ui_mode != UiMode::Calendar)
+        .unwrap_or(app.config.default_view);
+    app.state.set_focus(Focus::Body);
+}
+
+fn open_calendar_card(app: &mut App) {
+    let selected_date = app.state.calendar.selected_date;
+    let day_cards = app.get_calendar_day_cards(selected_date);
+    match day_cards.get(app.state.calendar.selected_card_index) {
+        Some((board_id, card)) => {
+            app.state.current_board_id = Some(*board_id);
+            app.state.current_card_id = Some(card.id);
+            reset_text_buffer(app);
+            app.state.popup_mode = Some(PopupMode::ViewCard);
+            app.state.set_focus(Focus::CardName);
+        }
+        None => app.send_warning_toast("No card due on this day", None),
+    }
+}
+
+/// edits a card in both the boards and the filtered boards, logging the change and recording it for undo
+fn edit_card_with(
+    app: &mut App,
+    board_id: (u64, u64),
+    card_id: (u64, u64),
+    edit: impl FnOnce(&mut Card),
+) -> Option<Card> {
+    let old_card = app
+        .boards
+        .get_board_with_id(board_id)
+        .and_then(|board| board.cards.get_card_with_id(card_id))?
+        .clone();
+    let mut new_card = old_card.clone();
+    edit(&mut new_card);
+    new_card.date_modified = Utc::now().to_string();
+    new_card.log_changes_from(&old_card, &app.state.user_login_data.activity_author());
+    if let Some(card) = app
+        .boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+    {
+        *card = new_card.clone();
+    }
+    if let Some(card) = app
+        .filtered_boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(card_id))
+    {
+        *card = new_card.clone();
+    }
+    app.action_history_manager
+        .new_action(ActionHistory::EditCard(
+            Box::new(old_card),
+            Box::new(new_card.clone()),
+            board_id,
+        ));
+    Some(new_card)
+}
+
+/// moves the due date of a card by a number of days, keeping its time and date format
+fn shift_card_due_date(
+    app: &mut App,
+    board_id: (u64, u64),
+    card: &Card,
+    days: i64,
+) -> Option<Card> {
+    let (due_date, date_format) = parse_due_date(&card.due_date)?;
+    let new_due_date = due_date + chrono::Duration::days(days);
+    edit_card_with(app, board_id, card.id, |card| {
+        card.due_date = new_due_date
+            .format(date_format.to_parser_string())
+            .to_string();
+    })
+}
+
+fn reschedule_calendar_card(app: &mut App, days: i64) {
+    let selected_date = app.state.calendar.selected_date;
+    let day_cards = app.get_calendar_day_cards(selected_date);
+    let (board_id, card) = match day_cards.get(app.state.calendar.selected_card_index) {
+        Some(day_card) => day_card.clone(),
+        None => {
+            app.send_warning_toast("No card selected to reschedule", None);
+            return;
+        }
+    };
+    let new_card = match shift_card_due_date(app, board_id, &card, days) {
+        Some(new_card) => new_card,
+        None => {
+            app.send_error_toast("Could not find the card to reschedule", None);
+            return;
+        }
+    };
+    let new_date = selected_date + chrono::Duration::days(days);
+    app.state.calendar.selected_date = new_date;
+    app.state.calendar.selected_card_index = app
+        .get_calendar_day_cards(new_date)
+        .iter()
+        .position(|(_, day_card)| day_card.id == card.id)
+        .unwrap_or(0);
+    let info_msg = format!("Moved '{}' to {}", new_card.name, new_card.due_date);
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+}
+
+/// clicking a day selects it, clicking the selected card opens it
+fn handle_calendar_mouse_click(app: &mut App) {
+    let (date, card_index) = match app.state.calendar.mouse_target {
+        Some(mouse_target) => mouse_target,
+        None => return,
+    };
+    let calendar = &mut app.state.calendar;
+    if date == calendar.selected_date && card_index == Some(calendar.selected_card_index) {
+        open_calendar_card(app);
+        return;
+    }
+    calendar.selected_date = date;
+    calendar.selected_card_index = card_index.unwrap_or(0);
+}
+
+pub fn open_timeline(app: &mut App) {
+    if app.state.ui_mode != UiMode::Timeline {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
+    }
+    app.state.ui_mode = UiMode::Timeline;
+    let timeline = &mut app.state.timeline;
+    timeline.selected_entry = 0;
+    timeline.scroll_offset = 0;
+    timeline.dragging = None;
+    // today starts a quarter of the way in so there is room for what is coming up
+    timeline.origin = timeline.zoom.shift(
+        timeline.zoom.align(Local::now().date_naive()),
+        -(timeline.visible_columns / 4),
+    );
+    app.state.set_focus(Focus::Body);
+}
+
+fn close_timeline(app: &mut App) {
+    app.state.timeline.mouse_target = None;
+    app.state.timeline.dragging = None;
+    app.state.ui_mode = app
+        .state
+        .prev_ui_mode
+        .filter(|prev_ui_mode| *prev_ui_mode != UiMode::Timeline)
+        .unwrap_or(app.config.default_view);
+    app.state.set_focus(Focus::Body);
+}
+
+fn open_timeline_card(app: &mut App) {
+    let entries = app.get_timeline_entries();
+    match entries.get(app.state.timeline.selected_entry) {
+        Some(entry) => {
+            app.state.current_board_id = Some(entry.board_id);
+            app.state.current_card_id = Some(entry.card.id);
+            reset_text_buffer(app);
+            app.state.popup_mode = Some(PopupMode::ViewCard);
+            app.state.set_focus(Focus::CardName);
+        }
+        None => app.send_warning_toast("No card with a due date to open", None),
+    }
+}
+
+fn cycle_timeline_zoom(app: &mut App) {
+    let timeline = &mut app.state.timeline;
+    let old_zoom = timeline.zoom;
+    let new_zoom = old_zoom.next();
+    // keep the date in the middle of the screen where it was
+    let center_date = old_zoom.shift(timeline.origin, timeline.visible_columns / 2);
+    let new_visible_columns = (timeline.visible_columns * old_zoom.column_width() as i64
+        / new_zoom.column_width() as i64)
+        .max(1);
+    timeline.zoom = new_zoom;
+    timeline.origin = new_zoom.shift(new_zoom.align(center_date), -(new_visible_columns / 2));
+    app.send_info_toast(&format!("Timeline zoom: {}", new_zoom), None);
+}
+
+/// moves the start or the due date of a card by whole timeline columns
+fn move_timeline_bar_end(
+    app: &mut App,
+    entry: &TimelineEntry,
+    handle: TimelineHandle,
+    columns: i64,
+) {
+    let zoom = app.state.timeline.zoom;
+    let date_format = entry.date_format.to_parser_string();
+    let edited_card = match handle {
+        TimelineHandle::End => {
+            let new_due_date = zoom.shift_date_time(entry.due, columns);
+            edit_card_with(app, entry.board_id, entry.card.id, |card| {
+                card.due_date = new_due_date.format(date_format).to_string();
+            })
+        }
+        TimelineHandle::Start => {
+            let new_start_date = zoom.shift_date_time(entry.start, columns).min(entry.due);
+            edit_card_with(app, entry.board_id, entry.card.id, |card| {
+                card.start_date = Some(new_start_date.format(date_format).to_string());
+            })
+        }
+    };
+    let new_card = match edited_card {
+        Some(new_card) => new_card,
+        None => {
+            app.send_error_toast("Could not find the card to reschedule", None);
+            return;
+        }
+    };
+    if let Some(entry_index) = app
+        .get_timeline_entries()
+        .iter()
+        .position(|timeline_entry| timeline_entry.card.id == new_card.id)
+    {
+        app.state.timeline.selected_entry = entry_index;
+    }
+    let info_msg = match handle {
+        TimelineHandle::End => format!(
+            "Moved the due date of '{}' to {}",
+            new_card.name, new_card.due_date
+        ),
+        TimelineHandle::Start => format!(
+            "Moved the start of '{}' to {}",
+            new_card.name,
+            new_card.start_date.clone().unwrap_or_default()
+        ),
+    };
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+}
+
+fn move_selected_timeline_due_date(app: &mut App, columns: i64) {
+    let entries = app.get_timeline_entries();
+    match entries.get(app.state.timeline.selected_entry) {
+        Some(entry) => move_timeline_bar_end(app, entry, TimelineHandle::End, columns),
+        None => app.send_warning_toast("No card selected to reschedule", None),
+    }
+}
+
+/// clicking a bar selects it, clicking the selected bar opens the card
+fn handle_timeline_mouse_click(app: &mut App) {
+    let entry_index = match app.state.timeline.mouse_target {
+        Some((entry_index, _)) => entry_index,
+        None => return,
+    };
+    if entry_index == app.state.timeline.selected_entry {
+        open_timeline_card(app);
+    } else {
+        app.state.timeline.selected_entry = entry_index;
+    }
+}
+
+fn start_timeline_drag(app: &mut App) {
+    if app.state.timeline.dragging.is_some() {
+        return;
+    }
+    if let Some((entry_index, Some(handle))) = app.state.timeline.mouse_target {
+        if let Some(entry) = app.get_timeline_entries().get(entry_index) {
+            app.state.timeline.selected_entry = entry_index;
+            app.state.timeline.dragging = Some((entry.card.id, handle));
+        }
+    }
+}
+
+/// drops a dragged bar end on the column under the mouse
+fn finish_timeline_drag(app: &mut App) {
+    let (card_id, handle) = match app.state.timeline.dragging.take() {
+        Some(dragging) => dragging,
+        None => return,
+    };
+    let mouse_column = match app.state.timeline.mouse_column {
+        Some(mouse_column) => mouse_column,
+        None => return,
+    };
+    let entry = match app
+        .get_timeline_entries()
+        .into_iter()
+        .find(|entry| entry.card.id == card_id)
+    {
+        Some(entry) => entry,
+        None => return,
+    };
+    let timeline = &app.state.timeline;
+    let handle_date = match handle {
+        TimelineHandle::Start => entry.start.date(),
+        TimelineHandle::End => entry.due.date(),
+    };
+    let columns = mouse_column - timeline.zoom.column_of(timeline.origin, handle_date);
+    if columns != 0 {
+        move_timeline_bar_end(app, &entry, handle, columns);
+    }
+}
+
+pub fn open_card_table(app: &mut App) {
+    if app.state.ui_mode != UiMode::CardTable {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
+    }
+    app.state.ui_mode = UiMode::CardTable;
+    let first_row = if app.get_card_table_rows().is_empty() {
+        None
+    } else {
+        Some(0)
+    };
+    app.state.app_table_states.card_table.select(first_row);
+    app.state.set_focus(Focus::Body);
+}
+
+fn close_card_table(app: &mut App) {
+    if !app.state.card_table.selected_cards.is_empty() {
+        app.state.card_table.selected_cards.clear();
+        app.send_info_toast("Selection cleared", None);
+        return;
+    }
+    app.state.ui_mode = app
+        .state
+        .prev_ui_mode
+        .filter(|prev_ui_mode| *prev_ui_mode != UiMode::CardTable)
+        .unwrap_or(app.config.default_view);
+    app.state.set_focus(Focus::Body);
+}
+
+fn get_current_card_table_row(app: &App) -> Option<CardTableRow> {
+    let selected_row = app.state.app_table_states.card_table.selected()?;
+    app.get_card_table_rows().into_iter().nth(selected_row)
+}
+
+/// the selected cards, or the card under the cursor when nothing is selected
+fn get_card_table_targets(app: &App) -> Vec<CardTableRow> {
+    if app.state.card_table.selected_cards.is_empty() {
+        get_current_card_table_row(app).into_iter().collect()
+    } else {
+        app.get_card_table_rows()
+            .into_iter()
+            .filter(|row| app.state.card_table.selected_cards.contains(&row.card.id))
+            .collect()
+    }
+}
+
+fn open_card_table_card(app: &mut App) {
+    match get_current_card_table_row(app) {
+        Some(row) => {
+            app.state.current_board_id = Some(row.board_id);
+            app.state.current_card_id = Some(row.card.id);
+            reset_text_buffer(app);
+            app.state.popup_mode = Some(PopupMode::ViewCard);
+            app.state.set_focus(Focus::CardName);
+        }
+        None => app.send_warning_toast("No card to open", None),
+    }
+}
+
+fn set_card_table_status(app: &mut App, status: CardStatus) {
+    let targets = get_card_table_targets(app)
+        .into_iter()
+        .map(|row| (row.board_id, row.card))
+        .collect::<Vec<((u64, u64), Card)>>();
+    set_status_of_cards(app, targets, status);
+}
+
+/// completing a card is refused while it has incomplete blockers, recurring cards get their next occurrence
+fn set_status_of_cards(app: &mut App, targets: Vec<((u64, u64), Card)>, status: CardStatus) {
+    if targets.is_empty() {
+        app.send_warning_toast("No card selected", None);
+        return;
+    }
+    let mut changed_cards = 0;
+    for (board_id, card) in targets {
+        if status == CardStatus::Complete {
+            let blocker_names = app
+                .boards
+                .get_incomplete_blockers(&card)
+                .iter()
+                .map(|blocker| blocker.name.clone())
+                .collect::<Vec<String>>();
+            if !blocker_names.is_empty() {
+                let error_msg = format!(
+                    "Cannot complete card {}, it is blocked by: {}",
+                    card.name,
+                    blocker_names.join(", ")
+                );
+                error!("{}", error_msg);
+                app.send_error_toast(&error_msg, None);
+                continue;
+            }
+        }
+        let completes_recurring_card = card.card_status != CardStatus::Complete
+            && status == CardStatus::Complete
+            && card.recurrence.is_some();
+        let edited_card = edit_card_with(app, board_id, card.id, |card| {
+            card.card_status = status.clone();
+            card.date_completed = if status == CardStatus::Complete {
+                Utc::now().to_string()
+            } else {
+                FIELD_NOT_SET.to_string()
+            };
+        });
+        if edited_card.is_some() {
+            changed_cards += 1;
+            if completes_recurring_card {
+                handle_recurring_card_completion(app, board_id, card.id);
+            }
+        }
+    }
+    if changed_cards > 0 {
+        let info_msg = format!("Changed status to {} for {} cards", status, changed_cards);
+        info!("{}", info_msg);
+        app.send_info_toast(&info_msg, None);
+        refresh_visible_boards_and_cards(app);
+    }
+}
+
+fn set_card_table_priority(app: &mut App, priority: CardPriority) {
+    let targets = get_card_table_targets(app);
+    if targets.is_empty() {
+        app.send_warning_toast("No card selected", None);
+        return;
+    }
+    let mut changed_cards = 0;
+    for row in targets {
+        if edit_card_with(app, row.board_id, row.card.id, |card| {
+            card.priority = priority.clone();
+        })
+        .is_some()
+        {
+            changed_cards += 1;
+        }
+    }
+    let info_msg = format!(
+        "Changed priority to {} for {} cards",
+        priority, changed_cards
+    );
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    refresh_visible_boards_and_cards(app);
+}
+
+/// status and priority cells are edited in place, any other column opens the card
+fn handle_card_table_accept(app: &mut App) {
+    let current_row = match get_current_card_table_row(app) {
+        Some(current_row) => current_row,
+        None => return,
+    };
+    match app.state.card_table.selected_column {
+        CardTableColumn::Status => {
+            let next_status = match current_row.card.card_status {
+                CardStatus::Active => CardStatus::Complete,
+                CardStatus::Complete => CardStatus::Stale,
+                CardStatus::Stale => CardStatus::Active,
+            };
+            set_card_table_status(app, next_status);
+        }
+        CardTableColumn::Priority => {
+            let next_priority = match current_row.card.priority {
+                CardPriority::Low => CardPriority::Medium,
+                CardPriority::Medium => CardPriority::High,
+                CardPriority::High => CardPriority::Low,
+            };
+            set_card_table_priority(app, next_priority);
+        }
+        _ => open_card_table_card(app),
+    }
+}
+
+fn toggle_card_table_selection(app: &mut App) {
+    if let Some(row) = get_current_card_table_row(app) {
+        let selected_cards = &mut app.state.card_table.selected_cards;
+        if !selected_cards.remove(&row.card.id) {
+            selected_cards.insert(row.card.id);
+        }
+    }
+}
+
+/// sorts by the selected column, sorting by the same column again flips the order
+fn sort_card_table(app: &mut App, column: CardTableColumn) {
+    let current_card_id = get_current_card_table_row(app).map(|row| row.card.id);
+    let card_table = &mut app.state.card_table;
+    if card_table.sort_column == column {
+        card_table.sort_descending = !card_table.sort_descending;
+    } else {
+        card_table.sort_column = column;
+        card_table.sort_descending = false;
+    }
+    card_table.selected_column = column;
+    if let Some(current_card_id) = current_card_id {
+        let current_row = app
+            .get_card_table_rows()
+            .iter()
+            .position(|row| row.card.id == current_card_id);
+        app.state.app_table_states.card_table.select(current_row);
+    }
+}
+
+/// clicking a header sorts by it, clicking a row selects it and clicking it again opens the card
+fn handle_card_table_mouse_click(app: &mut App) {
+    if let Some(column) = app.state.card_table.mouse_header {
+        sort_card_table(app, column);
+        return;
+    }
+    if let Some(mouse_row) = app.state.card_table.mouse_row {
+        if app.state.app_table_states.card_table.selected() == Some(mouse_row) {
+            open_card_table_card(app);
+        } else {
+            app.state
+                .app_table_states
+                .card_table
+                .select(Some(mouse_row));
+        }
+    }
+}
+
+pub fn open_sprints(app: &mut App) {
+    if app.state.ui_mode != UiMode::Sprints {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
+    }
+    app.state.sprints.snapshots = get_local_save_snapshots(&app.config);
+    let sprints_len = app.config.get_sprints().len();
+    let selected_sprint = match app.state.app_list_states.sprint_list.selected() {
+        Some(selected_sprint) if selected_sprint < sprints_len => Some(selected_sprint),
+        _ => app
+            .config
+            .get_open_sprint_index()
+            .or(sprints_len.checked_sub(1)),
+    };
+    app.state
+        .app_list_states
+        .sprint_list
+        .select(selected_sprint);
+    app.refresh_sprint_progress();
+    app.state.ui_mode = UiMode::Sprints;
+    app.state.set_focus(Focus::Body);
+}
+
+fn close_sprints(app: &mut App) {
+    app.state.sprints.snapshots.clear();
+    app.state.sprints.progress.clear();
+    app.state.ui_mode = app
+        .state
+        .prev_ui_mode
+        .filter(|prev_ui_mode| *prev_ui_mode != UiMode::Sprints)
+        .unwrap_or(app.config.default_view);
+    app.state.set_focus(Focus::Body);
+}
+
+fn cycle_sprint_chart_mode(app: &mut App) {
+    app.state.sprints.chart_mode = app.state.sprints.chart_mode.next();
+    app.refresh_sprint_progress();
+}
+
+fn open_sprint_prompt(app: &mut App, prompt_text: String) {
+    app.state.current_user_input = prompt_text;
+    app.state.current_cursor_position = Some(app.state.current_user_input.len());
+    app.state.popup_mode = Some(PopupMode::SprintPrompt);
+    app.state.set_focus(Focus::TextInput);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+/// the prompt is prefilled with the sprint after the latest one, or a two week sprint starting today
+pub fn open_create_sprint_prompt(app: &mut App) {
+    let prompt_text = app
+        .config
+        .get_sprints()
+        .last()
+        .map(|sprint| sprint.next_spec())
+        .filter(|next_spec| !next_spec.is_empty())
+        .unwrap_or_else(|| {
+            format!(
+                "Sprint 1 | {}",
+                Local::now().date_naive().format(SPRINT_DATE_FORMAT)
+            )
+        });
+    app.state.sprints.closing_sprint = None;
+    open_sprint_prompt(app, prompt_text);
+}
+
+/// closes the sprint selected in the sprint view, or the open sprint anywhere else
+pub fn open_close_sprint_prompt(app: &mut App) -> bool {
+    let selected_sprint = if app.state.ui_mode == UiMode::Sprints {
+        app.state
+            .app_list_states
+            .sprint_list
+            .selected()
+            .filter(|index| {
+                app.config
+                    .get_sprints()
+                    .get(*index)
+                    .is_some_and(|sprint| !sprint.closed)
+            })
+    } else {
+        app.config.get_open_sprint_index()
+    };
+    let sprint_index = match selected_sprint {
+        Some(sprint_index) => sprint_index,
+        None => {
+            app.send_warning_toast("No open sprint to close", None);
+            return false;
+        }
+    };
+    let next_spec = app.config.get_sprints()[sprint_index].next_spec();
+    app.state.sprints.closing_sprint = Some(sprint_index);
+    open_sprint_prompt(app, next_spec);
+    true
+}
+
+/// creates a sprint, or when closing one carries its unfinished cards over to the sprint entered
+fn handle_sprint_prompt_submit(app: &mut App) {
+    let spec = app.state.current_user_input.trim().to_string();
+    let closing_sprint = app.state.sprints.closing_sprint;
+    let next_sprint = if spec.is_empty() && closing_sprint.is_some() {
+        None
+    } else {
+        match Sprint::from_spec(&spec) {
+            Ok(sprint) => Some(sprint),
+            Err(err) => {
+                app.send_error_toast(&err, None);
+                return;
+            }
+        }
+    };
+    let closing = closing_sprint.and_then(|sprint_index| {
+        app.config
+            .get_sprints()
+            .get(sprint_index)
+            .map(|sprint| (sprint_index, sprint.clone()))
+    });
+    let previous_workspaces = app.config.workspaces.clone();
+    let mut carried_over = 0;
+    let toast_msg = {
+        let boards = &app.boards;
+        let workspace = match app.config.get_current_workspace_mut() {
+            Some(workspace) => workspace,
+            None => {
+                app.send_error_toast("Could not find the current workspace", None);
+                return;
+            }
+        };
+        let mut next_sprint = next_sprint;
+        let toast_msg = match (&closing, &mut next_sprint) {
+            (Some((sprint_index, sprint)), next_sprint) => {
+                // the scope is frozen so retagging cards later does not rewrite the sprint's history
+                let closed_sprint = &mut workspace.sprints[*sprint_index];
+                closed_sprint.card_ids = sprint.scope_card_ids(boards);
+                closed_sprint.closed = true;
+                match next_sprint {
+                    Some(next_sprint) => {
+                        for card_id in sprint.unfinished_card_ids(boards) {
+                            if !next_sprint.card_ids.contains(&card_id) {
+                                next_sprint.card_ids.push(card_id);
+                                carried_over += 1;
+                            }
+                        }
+                        format!(
+                            "Closed sprint {}, {} unfinished cards carried over to {}",
+                            sprint.name, carried_over, next_sprint.name
+                        )
+                    }
+                    None => format!("Closed sprint {}", sprint.name),
+                }
+            }
+            (None, Some(next_sprint)) => format!("Created sprint {}", next_sprint.name),
+            (None, None) => return,
+        };
+        workspace.sprints.extend(next_sprint);
+        toast_msg
+    };
+    if let Err(err) = write_config(&app.config) {
+        error!("Cannot save sprints: {}", err);
+        app.send_error_toast("Cannot save sprints", None);
+        app.config.workspaces = previous_workspaces;
+        return;
+    }
+    app.state.sprints.closing_sprint = None;
+    app.state.popup_mode = None;
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    if app.state.ui_mode == UiMode::Sprints {
+        let sprints_len = app.config.get_sprints().len();
+        app.state
+            .app_list_states
+            .sprint_list
+            .select(sprints_len.checked_sub(1));
+        app.refresh_sprint_progress();
+    }
+    app.state.set_focus(Focus::Body);
+    info!("{}", toast_msg);
+    app.send_info_toast(&toast_msg, None);
+}
+
+/// adds the current card to the open sprint, or takes it out if it was added by hand
+pub fn toggle_current_card_in_sprint(app: &mut App) {
+    let current_card_id = match app.state.current_card_id {
+        Some(current_card_id) => current_card_id,
+        None => {
+            app.send_error_toast("No card selected to add to the sprint", None);
+            return;
+        }
+    };
+    let card_name = match app.boards.get_card_with_id(current_card_id) {
+        Some(card) => card.name.clone(),
+        None => {
+            app.send_error_toast("Could not find the current card", None);
+            return;
+        }
+    };
+    let sprint_index = match app.config.get_open_sprint_index() {
+        Some(sprint_index) => sprint_index,
+        None => {
+            app.send_warning_toast("No open sprint, create one from the command palette", None);
+            return;
+        }
+    };
+    let previous_workspaces = app.config.workspaces.clone();
+    let toast_msg = match app.config.get_current_workspace_mut() {
+        Some(workspace) => {
+            let sprint = &mut workspace.sprints[sprint_index];
+            match sprint
+                .card_ids
+                .iter()
+                .position(|card_id| *card_id == current_card_id)
+            {
+                Some(position) => {
+                    sprint.card_ids.remove(position);
+                    format!("Removed {} from sprint {}", card_name, sprint.name)
+                }
+                None => {
+                    sprint.card_ids.push(current_card_id);
+                    format!("Added {} to sprint {}", card_name, sprint.name)
+                }
+            }
+        }
+        None => {
+            app.send_error_toast("Could not find the current workspace", None);
+            return;
+        }
+    };
+    if let Err(err) = write_config(&app.config) {
+        error!("Cannot save sprints: {}", err);
+        app.send_error_toast("Cannot save sprints", None);
+        app.config.workspaces = previous_workspaces;
+        return;
+    }
+    info!("{}", toast_msg);
+    app.send_info_toast(&toast_msg, None);
+}
+
+/// runs from the widget manager loop, reminders are looked for every REMINDER_CHECK_INTERVAL seconds
+pub fn check_due_reminders(app: &mut App) {
+    if !app.config.enable_reminders
+        || app.state.reminders.last_check.is_some_and(|last_check| {
+            last_check.elapsed() < Duration::from_secs(REMINDER_CHECK_INTERVAL)
+        })
+    {
         return;
     }
-    let all_card_details = all_card_details.unwrap();
-    if card_details_index >= all_card_details.len() {
-        debug!("Selected index is out of bounds");
+    app.state.reminders.last_check = Some(Instant::now());
+    let reminder_log = app.state.reminders.log.get_or_insert_with(get_reminder_log);
+    let (reminders, changed) = reminder_log.collect_due_reminders(
+        app.boards
+            .get_boards()
+            .iter()
+            .flat_map(|board| board.cards.get_all_cards()),
+        app.config.reminder_hours,
+        MAX_TOASTS_TO_DISPLAY,
+        Local::now(),
+    );
+    if changed {
+        if let Err(err) = save_reminder_log(reminder_log) {
+            error!("Cannot save reminder log: {}", err);
+        }
+    }
+    if reminders.is_empty() {
         return;
     }
-    let card_id = all_card_details[card_details_index].1;
-    let mut number_of_times_to_go_right = 0;
-    let mut number_of_times_to_go_down = 0;
-    for (board_index, board) in app.boards.get_boards().iter().enumerate() {
-        for (card_index, card) in board.cards.get_all_cards().iter().enumerate() {
-            if card.id == card_id {
-                number_of_times_to_go_right = board_index;
-                number_of_times_to_go_down = card_index;
-                break;
+    let snooze_key = app
+        .get_first_keybinding(KeyBindingEnum::SnoozeReminder)
+        .unwrap_or("".to_string());
+    let dismiss_key = app
+        .get_first_keybinding(KeyBindingEnum::DismissReminder)
+        .unwrap_or("".to_string());
+    for reminder in &reminders {
+        info!("Reminder: {}", reminder.message);
+        app.send_warning_toast(
+            &format!(
+                "{}. Press {} to snooze or {} to dismiss",
+                reminder.message, snooze_key, dismiss_key
+            ),
+            Some(Duration::from_secs(REMINDER_TOAST_DURATION)),
+        );
+        if let Some(escape_sequence) = app.config.reminder_alert.escape_sequence(&reminder.message)
+        {
+            let mut stdout = io::stdout();
+            if let Err(err) = stdout
+                .write_all(escape_sequence.as_bytes())
+                .and_then(|_| stdout.flush())
+            {
+                debug!("Could not send reminder alert: {}", err);
             }
         }
     }
-    for _ in 0..number_of_times_to_go_right {
-        go_right(app);
+    app.state.reminders.recent_cards = reminders.iter().map(|reminder| reminder.card_id).collect();
+}
+
+/// the card being viewed or selected in the agenda, otherwise the card of the latest reminder
+fn get_reminder_target(app: &mut App) -> Option<(u64, u64)> {
+    let selected_card = if app.state.popup_mode == Some(PopupMode::ViewCard) {
+        app.state.current_card_id
+    } else if app.state.ui_mode == UiMode::Agenda && app.state.popup_mode.is_none() {
+        get_current_agenda_entry(app).map(|entry| entry.card.id)
+    } else {
+        None
+    };
+    match selected_card {
+        Some(card_id) => {
+            app.state
+                .reminders
+                .recent_cards
+                .retain(|recent_card| *recent_card != card_id);
+            Some(card_id)
+        }
+        None => app.state.reminders.recent_cards.pop(),
     }
-    for _ in 0..number_of_times_to_go_down {
-        go_down(app);
+}
+
+fn handle_snooze_reminder(app: &mut App) -> AppReturn {
+    let card_id = match get_reminder_target(app) {
+        Some(card_id) => card_id,
+        None => {
+            app.send_warning_toast("No reminder to snooze", None);
+            return AppReturn::Continue;
+        }
+    };
+    let snoozed_until = Local::now() + chrono::Duration::minutes(REMINDER_SNOOZE_MINUTES);
+    let card_name = app
+        .boards
+        .get_card_with_id(card_id)
+        .map(|card| card.name.clone())
+        .unwrap_or_default();
+    let snoozed = update_reminder_log(app, |reminder_log| {
+        reminder_log.snooze(card_id, snoozed_until.timestamp())
+    });
+    if !snoozed {
+        app.send_warning_toast(&format!("No reminder to snooze for {}", card_name), None);
+        return AppReturn::Continue;
+    }
+    app.send_info_toast(
+        &format!(
+            "Snoozed reminder for {} until {}",
+            card_name,
+            snoozed_until.format("%H:%M")
+        ),
+        None,
+    );
+    AppReturn::Continue
+}
+
+fn handle_dismiss_reminder(app: &mut App) -> AppReturn {
+    let card_id = match get_reminder_target(app) {
+        Some(card_id) => card_id,
+        None => {
+            app.send_warning_toast("No reminder to dismiss", None);
+            return AppReturn::Continue;
+        }
+    };
+    let card_name = app
+        .boards
+        .get_card_with_id(card_id)
+        .map(|card| card.name.clone())
+        .unwrap_or_default();
+    if !update_reminder_log(app, |reminder_log| reminder_log.dismiss(card_id)) {
+        app.send_warning_toast(&format!("No reminder to dismiss for {}", card_name), None);
+        return AppReturn::Continue;
+    }
+    app.send_info_toast(
+        &format!(
+            "No more reminders for {} until its due date changes",
+            card_name
+        ),
+        None,
+    );
+    AppReturn::Continue
+}
+
+/// returns whether the update changed the log
+fn update_reminder_log(app: &mut App, update: impl FnOnce(&mut ReminderLog) -> bool) -> bool {
+    let reminder_log = app.state.reminders.log.get_or_insert_with(get_reminder_log);
+    if !update(reminder_log) {
+        return false;
+    }
+    if let Err(err) = save_reminder_log(reminder_log) {
+        error!("Cannot save reminder log: {}", err);
+        app.send_error_toast("Cannot save reminder log", None);
+    }
+    true
+}
+
+pub fn open_agenda(app: &mut App) {
+    if app.state.ui_mode != UiMode::Agenda {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
+    }
+    app.state.app_list_states.agenda_list.select(None);
+    app.state.ui_mode = UiMode::Agenda;
+    app.state.set_focus(Focus::Body);
+}
+
+/// the agenda can be the default view, so going back from it falls back to a board view
+fn close_agenda(app: &mut App) {
+    app.state.ui_mode = app
+        .state
+        .prev_ui_mode
+        .filter(|prev_ui_mode| *prev_ui_mode != UiMode::Agenda)
+        .or(Some(app.config.default_view).filter(|default_view| *default_view != UiMode::Agenda))
+        .unwrap_or(DEFAULT_UI_MODE);
+    app.state.set_focus(Focus::Body);
+}
+
+fn get_current_agenda_entry(app: &App) -> Option<AgendaEntry> {
+    let selected_entry = app.state.app_list_states.agenda_list.selected()?;
+    app.get_agenda_entries().into_iter().nth(selected_entry)
+}
+
+/// keeps the cursor on a card after an edit moved it to another section
+fn select_agenda_card(app: &mut App, card_id: (u64, u64)) {
+    if let Some(entry_index) = app
+        .get_agenda_entries()
+        .iter()
+        .position(|entry| entry.card.id == card_id)
+    {
+        app.state
+            .app_list_states
+            .agenda_list
+            .select(Some(entry_index));
+    }
+}
+
+fn open_agenda_card(app: &mut App) {
+    match get_current_agenda_entry(app) {
+        Some(entry) => {
+            app.state.current_board_id = Some(entry.board_id);
+            app.state.current_card_id = Some(entry.card.id);
+            reset_text_buffer(app);
+            app.state.popup_mode = Some(PopupMode::ViewCard);
+            app.state.set_focus(Focus::CardName);
+        }
+        None => app.send_warning_toast("No card to open", None),
+    }
+}
+
+fn set_agenda_card_status(app: &mut App, status: CardStatus) {
+    let entry = match get_current_agenda_entry(app) {
+        Some(entry) => entry,
+        None => {
+            app.send_warning_toast("No card selected", None);
+            return;
+        }
+    };
+    set_status_of_cards(app, vec![(entry.board_id, entry.card.clone())], status);
+    select_agenda_card(app, entry.card.id);
+}
+
+/// pushes the due date of the selected card back or pulls it in by a number of days
+fn snooze_agenda_card(app: &mut App, days: i64) {
+    let entry = match get_current_agenda_entry(app) {
+        Some(entry) => entry,
+        None => {
+            app.send_warning_toast("No card selected", None);
+            return;
+        }
+    };
+    if parse_due_date(&entry.card.due_date).is_none() {
+        app.send_warning_toast(
+            &format!("{} has no due date to snooze", entry.card.name),
+            None,
+        );
+        return;
+    }
+    match shift_card_due_date(app, entry.board_id, &entry.card, days) {
+        Some(new_card) => {
+            let info_msg = format!("Moved '{}' to {}", new_card.name, new_card.due_date);
+            info!("{}", info_msg);
+            app.send_info_toast(&info_msg, None);
+            refresh_visible_boards_and_cards(app);
+            select_agenda_card(app, new_card.id);
+        }
+        None => app.send_error_toast("Could not find the card to snooze", None),
+    }
+}
+
+/// moves the selected card to the board before or after the one it is on
+fn move_agenda_card(app: &mut App, move_right: bool) {
+    let entry = match get_current_agenda_entry(app) {
+        Some(entry) => entry,
+        None => {
+            app.send_warning_toast("No card selected", None);
+            return;
+        }
+    };
+    let target_board = app
+        .boards
+        .get_board_index(entry.board_id)
+        .and_then(|board_index| {
+            if move_right {
+                board_index.checked_add(1)
+            } else {
+                board_index.checked_sub(1)
+            }
+        })
+        .and_then(|board_index| app.boards.get_board_with_index(board_index))
+        .map(|board| (board.id, board.name.clone()));
+    let (target_board_id, target_board_name) = match target_board {
+        Some(target_board) => target_board,
+        None => {
+            let error_msg = if move_right {
+                "Cannot move card right as it is the last board"
+            } else {
+                "Cannot move card left as it is the first board"
+            };
+            error!("{}", error_msg);
+            app.send_error_toast(error_msg, None);
+            return;
+        }
+    };
+    if move_card_to_board(app, entry.board_id, entry.card.id, target_board_id).is_none() {
+        app.send_error_toast("Could not find the card to move", None);
+        return;
+    }
+    let info_msg = format!(
+        "Moved card {} to board \"{}\"",
+        entry.card.name, target_board_name
+    );
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+    warn_if_over_wip_limit(app, target_board_id);
+    if !app.filtered_boards.is_empty() {
+        filter_boards(app);
+    }
+    refresh_visible_boards_and_cards(app);
+    select_agenda_card(app, entry.card.id);
+}
+
+/// clicking an entry selects it, clicking the selected entry opens the card
+fn handle_agenda_mouse_click(app: &mut App) {
+    if let Some(mouse_entry) = app.state.agenda.mouse_entry {
+        if app.state.app_list_states.agenda_list.selected() == Some(mouse_entry) {
+            open_agenda_card(app);
+        } else {
+            app.state
+                .app_list_states
+                .agenda_list
+                .select(Some(mouse_entry));
+        }
+    }
+}
+
+pub fn open_statistics(app: &mut App) {
+    if app.state.ui_mode != UiMode::Statistics {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
+    }
+    let snapshots = get_local_save_snapshots(&app.config);
+    app.state.statistics = Some(CardStatistics::new(&app.boards, &snapshots));
+    app.state.app_table_states.statistics.select(None);
+    app.state.ui_mode = UiMode::Statistics;
+    app.state.set_focus(Focus::Body);
+}
+
+fn close_statistics(app: &mut App) {
+    app.state.statistics = None;
+    app.state.ui_mode = app
+        .state
+        .prev_ui_mode
+        .filter(|prev_ui_mode| *prev_ui_mode != UiMode::Statistics)
+        .unwrap_or(app.config.default_view);
+    app.state.set_focus(Focus::Body);
+}
+
+fn handle_export_statistics(app: &mut App) {
+    let statistics = match &app.state.statistics {
+        Some(statistics) => statistics,
+        None => return,
+    };
+    match export_statistics_to_csv(statistics, &app.config) {
+        Ok(file_path) => {
+            info!("Exported statistics to {}", file_path);
+            app.send_info_toast(&format!("Exported statistics to {}", file_path), None);
+        }
+        Err(err) => {
+            error!("Could not export statistics: {}", err);
+            app.send_error_toast(&format!("Could not export statistics: {}", err), None);
+        }
+    }
+}
+
+pub fn open_archive_browser(app: &mut App) {
+    if app.state.ui_mode != UiMode::ArchiveBrowser {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
     }
+    app.state.ui_mode = UiMode::ArchiveBrowser;
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
     app.state.set_focus(Focus::Body);
+    let has_archived_cards = !app.get_archived_cards_matching_search().is_empty();
+    app.state
+        .app_list_states
+        .archived_card_list
+        .select(if has_archived_cards { Some(0) } else { None });
+}
+
+fn handle_restore_archived_card(app: &mut App) {
+    let archived_cards = app.get_archived_cards_matching_search();
+    let selected_index = app.state.app_list_states.archived_card_list.selected();
+    let (board_id, board_name, archived_card) =
+        if let Some(selected) = selected_index.and_then(|index| archived_cards.get(index)) {
+            selected.clone()
+        } else {
+            app.send_warning_toast("No archived card selected to restore", None);
+            return;
+        };
+    let restored_card = app
+        .boards
+        .get_mut_board_with_id(board_id)
+        .and_then(|board| board.restore_archived_card(archived_card.card.id));
+    if let Some(restored_card) = restored_card {
+        let card_name = restored_card.card.name.clone();
+        info!("Restored card {} to board {}", card_name, board_name);
+        app.action_history_manager
+            .new_action(ActionHistory::RestoreArchivedCard(
+                Box::new(restored_card),
+                board_id,
+            ));
+        app.send_info_toast(
+            &format!("Restored card {} to board {}", card_name, board_name),
+            None,
+        );
+        let remaining_cards = archived_cards.len() - 1;
+        if remaining_cards == 0 {
+            app.state.app_list_states.archived_card_list.select(None);
+        } else if selected_index.unwrap_or(0) >= remaining_cards {
+            app.state
+                .app_list_states
+                .archived_card_list
+                .select(Some(remaining_cards - 1));
+        }
+        if app.filtered_boards.is_empty() {
+            refresh_visible_boards_and_cards(app);
+        } else {
+            filter_boards(app);
+        }
+    } else {
+        app.send_error_toast(
+            &format!("Could not restore card {}", archived_card.card.name),
+            None,
+        );
+    }
+}
+
+fn handle_card_dependency_selection(app: &mut App) {
+    let target_card_id = app.state.card_dependency_picker_target.take();
+    app.state.popup_mode = Some(PopupMode::ViewCard);
+    app.state.set_focus(Focus::CardBlockedBy);
+    if target_card_id.is_none() {
+        debug!("No target card for dependency selection");
+        return;
+    }
+    let target_card_id = target_card_id.unwrap();
+    let card_details_index = app
+        .state
+        .app_list_states
+        .command_palette_card_search
+        .selected();
+    if card_details_index.is_none() {
+        return;
+    }
+    let card_details_index = card_details_index.unwrap();
+    let all_card_details = app.widgets.command_palette.card_search_results.clone();
+    if all_card_details.is_none() {
+        debug!("No card details found to select");
+        return;
+    }
+    let all_card_details = all_card_details.unwrap();
+    if card_details_index >= all_card_details.len() {
+        debug!("Selected index is out of bounds");
+        return;
+    }
+    let blocker_id = all_card_details[card_details_index].card_id;
+    if blocker_id == target_card_id {
+        app.send_error_toast("A card cannot be blocked by itself", None);
+        return;
+    }
+    let blocker_name = if let Some(blocker) = app.boards.get_card_with_id(blocker_id) {
+        blocker.name.clone()
+    } else {
+        app.send_error_toast("Could not find the selected card", None);
+        return;
+    };
+    let blocker_already_added = if let Some((_, card)) = &app.state.card_being_edited {
+        card.blocked_by.contains(&blocker_id)
+    } else if let Some(card) = app.boards.get_card_with_id(target_card_id) {
+        card.blocked_by.contains(&blocker_id)
+    } else {
+        false
+    };
+    if !blocker_already_added && app.boards.card_depends_on(blocker_id, target_card_id) {
+        app.send_error_toast(
+            &format!(
+                "Cannot add {} as a blocker, it would create a circular dependency",
+                blocker_name
+            ),
+            None,
+        );
+        return;
+    }
+    let toggle_blocker = |blocked_by: &mut Vec<(u64, u64)>| {
+        if blocker_already_added {
+            blocked_by.retain(|id| *id != blocker_id);
+        } else {
+            blocked_by.push(blocker_id);
+        }
+    };
+    if let Some((_, card)) = app.state.card_being_edited.as_mut() {
+        toggle_blocker(&mut card.blocked_by);
+    } else if let Some(current_board_id) = app.state.current_board_id {
+        if edit_card_with(app, current_board_id, target_card_id, |card| {
+            toggle_blocker(&mut card.blocked_by)
+        })
+        .is_none()
+        {
+            app.send_error_toast("Error Could not find current card", None);
+            return;
+        }
+    }
+    if blocker_already_added {
+        info!("Removed blocker {}", blocker_name);
+        app.send_info_toast(&format!("Removed blocker {}", blocker_name), None);
+    } else {
+        info!("Added blocker {}", blocker_name);
+        app.send_info_toast(&format!("Added blocker {}", blocker_name), None);
+    }
 }
 
 fn handle_command_palette_board_selection(app: &mut App) {
@@ -7223,12 +11054,165 @@ fn reset_new_board_form(app: &mut App) {
         .collect();
 }
 
-fn reset_new_card_form(app: &mut App) {
-    app.state.app_form_states.new_card = NEW_CARD_FORM_DEFAULT_STATE
-        .iter()
-        .map(|s| s.to_string())
-        .collect();
-    reset_text_buffer(app);
+fn reset_new_card_form(app: &mut App) {
+    app.state.app_form_states.new_card = NEW_CARD_FORM_DEFAULT_STATE
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    app.state.new_card_template = None;
+    reset_text_buffer(app);
+}
+
+/// resets the new card form and fills it in from the current board's default card template
+pub fn prepare_new_card_form(app: &mut App) {
+    reset_new_card_form(app);
+    load_card_templates(app);
+    let board_default_template = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+        .and_then(|board| board.default_card_template.clone())
+        .and_then(|template_name| {
+            app.card_templates
+                .iter()
+                .find(|template| template.name == template_name)
+                .cloned()
+        });
+    if board_default_template.is_some() {
+        apply_card_template(app, board_default_template);
+    }
+}
+
+fn load_card_templates(app: &mut App) {
+    let mut card_templates = CardTemplate::all_default_templates();
+    card_templates.extend(get_saved_card_templates());
+    app.card_templates = card_templates;
+}
+
+/// index in the card template list, the first entry is the blank card or no default
+fn get_card_template_list_index(app: &App, template_name: Option<&String>) -> usize {
+    template_name
+        .and_then(|template_name| {
+            app.card_templates
+                .iter()
+                .position(|template| &template.name == template_name)
+        })
+        .map(|index| index + 1)
+        .unwrap_or(0)
+}
+
+pub fn open_card_template_selector(app: &mut App) {
+    load_card_templates(app);
+    let selected_template_name = app
+        .state
+        .new_card_template
+        .as_ref()
+        .map(|template| template.name.clone());
+    let selected_index = get_card_template_list_index(app, selected_template_name.as_ref());
+    app.state
+        .app_list_states
+        .card_template_list
+        .select(Some(selected_index));
+    app.state.app_status = AppStatus::Initialized;
+    app.state.popup_mode = Some(PopupMode::SelectCardTemplate);
+    app.state.set_focus(Focus::CardTemplateSelector);
+}
+
+pub fn open_default_card_template_selector(app: &mut App) {
+    let board_default_template = if let Some(board) = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+    {
+        board.default_card_template.clone()
+    } else {
+        app.send_error_toast("No board selected to set a default card template for", None);
+        return;
+    };
+    load_card_templates(app);
+    let selected_index = get_card_template_list_index(app, board_default_template.as_ref());
+    app.state
+        .app_list_states
+        .card_template_list
+        .select(Some(selected_index));
+    app.state.popup_mode = Some(PopupMode::SelectDefaultCardTemplate);
+    app.state.set_focus(Focus::CardTemplateSelector);
+}
+
+fn handle_card_template_selection(app: &mut App) {
+    let selected_template = app
+        .state
+        .app_list_states
+        .card_template_list
+        .selected()
+        .filter(|index| *index > 0)
+        .and_then(|index| app.card_templates.get(index - 1))
+        .cloned();
+    if app.state.popup_mode == Some(PopupMode::SelectDefaultCardTemplate) {
+        let current_board = app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.get_mut_board_with_id(board_id));
+        if let Some(current_board) = current_board {
+            current_board.default_card_template = selected_template
+                .as_ref()
+                .map(|template| template.name.clone());
+            let info_msg = match &selected_template {
+                Some(template) => format!(
+                    "New cards in \"{}\" now start from the \"{}\" template",
+                    current_board.name, template.name
+                ),
+                None => format!(
+                    "Removed the default card template of \"{}\"",
+                    current_board.name
+                ),
+            };
+            info!("{}", info_msg);
+            app.send_info_toast(&info_msg, None);
+        } else {
+            app.send_error_toast("No board selected to set a default card template for", None);
+        }
+    } else {
+        apply_card_template(app, selected_template);
+        app.state.set_focus(Focus::CardName);
+    }
+    app.state.popup_mode = None;
+}
+
+/// swaps the name prefix and description skeleton of the previous template for the new one,
+/// text the user has already typed is kept
+fn apply_card_template(app: &mut App, card_template: Option<CardTemplate>) {
+    let previous_template = app.state.new_card_template.take();
+    let previous_prefix = previous_template
+        .as_ref()
+        .map(|template| template.name_prefix.clone())
+        .unwrap_or_default();
+    let previous_description = previous_template
+        .as_ref()
+        .map(|template| template.description_with_checklist())
+        .unwrap_or_default();
+    let card_name = app.state.app_form_states.new_card[0].clone();
+    let card_name = card_name
+        .strip_prefix(&previous_prefix)
+        .unwrap_or(&card_name);
+    let new_prefix = card_template
+        .as_ref()
+        .map(|template| template.name_prefix.clone())
+        .unwrap_or_default();
+    app.state.app_form_states.new_card[0] = format!("{}{}", new_prefix, card_name);
+    let current_description = if let Some(text_buffer) = &app.state.card_description_text_buffer {
+        text_buffer.lines().join("\n")
+    } else {
+        app.state.app_form_states.new_card[1].clone()
+    };
+    if current_description.trim().is_empty() || current_description == previous_description {
+        app.state.app_form_states.new_card[1] = card_template
+            .as_ref()
+            .map(|template| template.description_with_checklist())
+            .unwrap_or_default();
+        reset_text_buffer(app);
+    }
+    app.state.new_card_template = card_template;
 }
 
 fn reset_login_form(app: &mut App) {
@@ -7458,3 +11442,633 @@ pub fn reset_preview_boards(app: &mut App) {
     app.state.preview_file_name = None;
     app.state.preview_visible_boards_and_cards = LinkedHashMap::new();
 }
+
+fn handle_swimlane_grouping_submit(app: &mut App) {
+    match SwimlaneGrouping::from_spec(&app.state.current_user_input) {
+        Ok(swimlane_grouping) => {
+            let toast_msg = if let Some(swimlane_grouping) = &swimlane_grouping {
+                format!("Showing swimlanes by {}", swimlane_grouping)
+            } else {
+                "Swimlanes turned off".to_string()
+            };
+            app.state.swimlane_grouping = swimlane_grouping;
+            app.state.current_swimlane = None;
+            app.state.popup_mode = None;
+            app.clear_user_input_state();
+            app.state.current_cursor_position = None;
+            app.state.app_status = AppStatus::Initialized;
+            app.state.set_focus(Focus::Body);
+            info!("{}", toast_msg);
+            app.send_info_toast(&toast_msg, None);
+        }
+        Err(err) => app.send_error_toast(&err, None),
+    }
+}
+
+pub fn open_board_template_selector(app: &mut App) {
+    let mut board_templates = BoardTemplate::all_default_templates();
+    board_templates.extend(get_saved_board_templates());
+    app.board_templates = board_templates;
+    app.state
+        .app_list_states
+        .board_template_list
+        .select(Some(0));
+    app.state.popup_mode = Some(PopupMode::SelectBoardTemplate);
+    app.state.set_focus(Focus::BoardTemplateSelector);
+}
+
+/// adds the boards of the selected template after the existing boards
+fn handle_create_boards_from_template(app: &mut App) {
+    let selected_template = app
+        .state
+        .app_list_states
+        .board_template_list
+        .selected()
+        .and_then(|index| app.board_templates.get(index))
+        .cloned();
+    let template = if let Some(template) = selected_template {
+        template
+    } else {
+        app.send_error_toast("No template selected", None);
+        return;
+    };
+    let new_boards = template.to_boards();
+    let first_board_id = new_boards.first().map(|board| board.id);
+    for board in new_boards {
+        app.boards.add_board(board.clone());
+        app.action_history_manager
+            .new_action(ActionHistory::CreateBoard(board));
+    }
+    app.state.popup_mode = None;
+    app.state.set_focus(Focus::Body);
+    refresh_visible_boards_and_cards(app);
+    if let Some(first_board_id) = first_board_id {
+        app.state.current_board_id = Some(first_board_id);
+        app.state.current_card_id = app
+            .visible_boards_and_cards
+            .get(&first_board_id)
+            .and_then(|card_ids| card_ids.first().copied());
+    }
+    let info_msg = format!(
+        "Created {} boards from template \"{}\"",
+        template.boards.len(),
+        template.name
+    );
+    info!("{}", info_msg);
+    app.send_info_toast(&info_msg, None);
+}
+
+pub fn open_save_board_template_prompt(app: &mut App) {
+    app.clear_user_input_state();
+    app.state.current_cursor_position = Some(0);
+    app.state.popup_mode = Some(PopupMode::SaveBoardTemplatePrompt);
+    app.state.set_focus(Focus::TextInput);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+fn handle_save_board_template_submit(app: &mut App) {
+    let template_name = app.state.current_user_input.trim().to_string();
+    if template_name.is_empty() {
+        app.send_error_toast("Template name cannot be empty", None);
+        return;
+    }
+    if app.boards.is_empty() {
+        app.send_error_toast("There are no boards to save as a template", None);
+        return;
+    }
+    let template = BoardTemplate::from_boards(&template_name, &app.boards);
+    match save_board_template(&template) {
+        Ok(template_path) => {
+            app.state.popup_mode = None;
+            app.clear_user_input_state();
+            app.state.current_cursor_position = None;
+            app.state.app_status = AppStatus::Initialized;
+            app.state.set_focus(Focus::Body);
+            info!("Saved board template to {}", template_path);
+            app.send_info_toast(&format!("Saved board template \"{}\"", template_name), None);
+        }
+        Err(err) => {
+            error!("Could not save board template: {}", err);
+            app.send_error_toast(&format!("Could not save board template: {}", err), None);
+        }
+    }
+}
+
+/// the lane of the selected card, or the lane the selection was last in when the cell is empty
+fn get_current_swimlane(app: &App) -> Option<String> {
+    let swimlane_grouping = app.state.swimlane_grouping.as_ref()?;
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    let current_card = app
+        .state
+        .current_card_id
+        .and_then(|card_id| boards.get_card_with_id(card_id));
+    if let Some(current_card) = current_card {
+        return Some(swimlane_grouping.lane_for(current_card));
+    }
+    app.state
+        .current_swimlane
+        .clone()
+        .or_else(|| swimlane_grouping.lanes(boards).first().cloned())
+}
+
+fn get_cards_in_swimlane(app: &App, board_id: (u64, u64), swimlane: &str) -> Vec<(u64, u64)> {
+    let swimlane_grouping = if let Some(swimlane_grouping) = &app.state.swimlane_grouping {
+        swimlane_grouping
+    } else {
+        return vec![];
+    };
+    let boards = if app.filtered_boards.is_empty() {
+        &app.boards
+    } else {
+        &app.filtered_boards
+    };
+    boards
+        .get_board_with_id(board_id)
+        .map(|board| {
+            board
+                .cards
+                .get_all_cards()
+                .iter()
+                .filter(|card| swimlane_grouping.lane_for(card) == swimlane)
+                .map(|card| card.id)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn select_first_card_in_swimlane(app: &mut App, swimlane: String) {
+    if let Some(current_board_id) = app.state.current_board_id {
+        app.state.current_card_id = get_cards_in_swimlane(app, current_board_id, &swimlane)
+            .first()
+            .copied();
+    }
+    app.state.current_swimlane = Some(swimlane);
+}
+
+/// moves through the cards of the current board column, crossing into the next or previous lane at the edges
+fn go_vertical_in_swimlanes(app: &mut App, go_down: bool) {
+    let swimlane_grouping = if let Some(swimlane_grouping) = app.state.swimlane_grouping.clone() {
+        swimlane_grouping
+    } else {
+        return;
+    };
+    let current_board_id = if let Some(current_board_id) = app.state.current_board_id {
+        current_board_id
+    } else if let Some(first_board_id) = app.visible_boards_and_cards.keys().next() {
+        *first_board_id
+    } else {
+        return;
+    };
+    app.state.current_board_id = Some(current_board_id);
+    let lanes = if app.filtered_boards.is_empty() {
+        swimlane_grouping.lanes(&app.boards)
+    } else {
+        swimlane_grouping.lanes(&app.filtered_boards)
+    };
+    let current_swimlane = get_current_swimlane(app).unwrap_or(FIELD_NOT_SET.to_string());
+    let lane_cards = get_cards_in_swimlane(app, current_board_id, &current_swimlane);
+    let card_index = app.state.current_card_id.and_then(|card_id| {
+        lane_cards
+            .iter()
+            .position(|lane_card| *lane_card == card_id)
+    });
+    let next_card_in_lane = match (card_index, go_down) {
+        (Some(card_index), true) => lane_cards.get(card_index + 1),
+        (Some(card_index), false) if card_index > 0 => lane_cards.get(card_index - 1),
+        _ => None,
+    };
+    if let Some(next_card_in_lane) = next_card_in_lane {
+        app.state.current_card_id = Some(*next_card_in_lane);
+        return;
+    }
+    let lane_index = lanes
+        .iter()
+        .position(|lane| *lane == current_swimlane)
+        .unwrap_or(0);
+    let next_lane = if go_down {
+        lanes.get(lane_index + 1)
+    } else if lane_index > 0 {
+        lanes.get(lane_index - 1)
+    } else {
+        None
+    };
+    let next_lane = if let Some(next_lane) = next_lane {
+        next_lane.clone()
+    } else if go_down {
+        app.send_error_toast("Cannot go down: Already at the last swimlane", None);
+        return;
+    } else {
+        app.send_error_toast("Cannot go up: Already at the first swimlane", None);
+        return;
+    };
+    let next_lane_cards = get_cards_in_swimlane(app, current_board_id, &next_lane);
+    app.state.current_card_id = if go_down {
+        next_lane_cards.first().copied()
+    } else {
+        next_lane_cards.last().copied()
+    };
+    app.state.current_swimlane = Some(next_lane);
+}
+
+/// moves a card to the end of another board, logging the move and recording it for undo
+fn move_card_to_board(
+    app: &mut App,
+    from_board_id: (u64, u64),
+    card_id: (u64, u64),
+    to_board_id: (u64, u64),
+) -> Option<Card> {
+    let from_board = app.boards.get_board_with_id(from_board_id)?;
+    let from_board_name = from_board.name.clone();
+    let card_index = from_board.cards.get_card_index(card_id)?;
+    let to_board_name = app.boards.get_board_with_id(to_board_id)?.name.clone();
+    let mut card = app
+        .boards
+        .get_mut_board_with_id(from_board_id)
+        .and_then(|board| board.cards.remove_card_with_id(card_id))?;
+    card.log_activity(
+        &app.state.user_login_data.activity_author(),
+        CardActivityKind::Moved {
+            from_board: from_board_name,
+            to_board: to_board_name,
+        },
+    );
+    let to_board = app.boards.get_mut_board_with_id(to_board_id).unwrap();
+    to_board.cards.add_card(card.clone());
+    app.action_history_manager
+        .new_action(ActionHistory::MoveCardBetweenBoards(
+            card.clone(),
+            from_board_id,
+            to_board_id,
+            card_index,
+            to_board.cards.len() - 1,
+        ));
+    Some(card)
+}
+
+/// dropping a card in a swimlane moves it to the hovered board and sets its grouping field to the lane
+fn move_dragged_card_to_swimlane(app: &mut App) {
+    let (dragged_card_board_id, dragged_card_id) = app.state.hovered_card.unwrap();
+    let hovered_board_id = app.state.hovered_board.unwrap();
+    let (swimlane_grouping, hovered_swimlane) = match (
+        app.state.swimlane_grouping.clone(),
+        app.state.hovered_swimlane.clone(),
+    ) {
+        (Some(swimlane_grouping), Some(hovered_swimlane)) => (swimlane_grouping, hovered_swimlane),
+        _ => {
+            debug!("No swimlane hovered to drop the card in");
+            return;
+        }
+    };
+    let author = app.state.user_login_data.activity_author();
+    if dragged_card_board_id != hovered_board_id
+        && move_card_to_board(
+            app,
+            dragged_card_board_id,
+            dragged_card_id,
+            hovered_board_id,
+        )
+        .is_none()
+    {
+        debug!("Could not move the dragged card to the hovered board");
+        return;
+    }
+    let card = app
+        .boards
+        .get_mut_board_with_id(hovered_board_id)
+        .and_then(|board| board.cards.get_mut_card_with_id(dragged_card_id));
+    if let Some(card) = card {
+        let previous_card = card.clone();
+        swimlane_grouping.assign(card, &hovered_swimlane);
+        if *card != previous_card {
+            card.date_modified = Utc::now().to_string();
+            card.log_changes_from(&previous_card, &author);
+            app.action_history_manager
+                .new_action(ActionHistory::EditCard(
+                    Box::new(previous_card),
+                    Box::new(card.clone()),
+                    hovered_board_id,
+                ));
+        }
+        let info_msg = format!(
+            "Moved card {} to the {} swimlane",
+            card.name, hovered_swimlane
+        );
+        info!("{}", info_msg);
+        app.send_info_toast(&info_msg, None);
+    }
+    app.state.current_board_id = Some(hovered_board_id);
+    app.state.current_card_id = Some(dragged_card_id);
+    app.state.current_swimlane = Some(hovered_swimlane);
+    if !app.filtered_boards.is_empty() {
+        filter_boards(app);
+    }
+}
+
+pub fn open_workspace_switcher(app: &mut App) {
+    if app.state.ui_mode != UiMode::WorkspaceSwitcher {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
+    }
+    app.state.ui_mode = UiMode::WorkspaceSwitcher;
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.set_focus(Focus::Body);
+    let current_workspace = app.config.current_workspace.clone();
+    app.workspace_boards = app
+        .config
+        .workspaces
+        .iter()
+        .filter(|workspace| workspace.name != current_workspace)
+        .map(|workspace| {
+            (
+                workspace.name.clone(),
+                get_latest_workspace_boards(&app.config, &workspace.name),
+            )
+        })
+        .collect();
+    let current_workspace_index = app
+        .get_workspace_switcher_entries()
+        .iter()
+        .position(|entry| *entry == WorkspaceSwitcherEntry::Workspace(current_workspace.clone()));
+    app.state
+        .app_list_states
+        .workspace_list
+        .select(current_workspace_index);
+}
+
+fn close_workspace_switcher(app: &mut App) {
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.app_list_states.workspace_list.select(None);
+    app.workspace_boards.clear();
+    app.state.ui_mode = app
+        .state
+        .prev_ui_mode
+        .filter(|prev_ui_mode| UiMode::view_modes().contains(prev_ui_mode))
+        .unwrap_or(app.config.default_view);
+    app.state.set_focus(Focus::Body);
+}
+
+async fn handle_workspace_switcher_selection(app: &mut App<'_>) {
+    let entries = app.get_workspace_switcher_entries();
+    let selected_entry = app
+        .state
+        .app_list_states
+        .workspace_list
+        .selected()
+        .and_then(|index| entries.get(index))
+        .cloned();
+    let (workspace_name, card_to_select) = match selected_entry {
+        Some(WorkspaceSwitcherEntry::Workspace(workspace_name)) => (workspace_name, None),
+        Some(WorkspaceSwitcherEntry::NewWorkspace(workspace_name)) => {
+            let safe_name = make_file_system_safe_name(&workspace_name);
+            if safe_name.is_empty() {
+                app.send_error_toast("Workspace names need at least one letter or number", None);
+                return;
+            }
+            if app
+                .config
+                .workspaces
+                .iter()
+                .any(|workspace| make_file_system_safe_name(&workspace.name) == safe_name)
+            {
+                app.send_error_toast(
+                    &format!(
+                        "Workspace {} would share its save folder with an existing workspace",
+                        workspace_name
+                    ),
+                    None,
+                );
+                return;
+            }
+            app.config.workspaces.push(Workspace::new(&workspace_name));
+            if let Err(err) = write_config(&app.config) {
+                error!("Cannot save workspace {}: {}", workspace_name, err);
+                app.send_error_toast(&format!("Cannot save workspace {}", workspace_name), None);
+                app.config.workspaces.pop();
+                return;
+            }
+            info!("Created workspace {}", workspace_name);
+            app.send_info_toast(&format!("Created workspace {}", workspace_name), None);
+            (workspace_name, None)
+        }
+        Some(WorkspaceSwitcherEntry::Card {
+            workspace, card_id, ..
+        }) => (workspace, Some(card_id)),
+        None => {
+            app.send_warning_toast("No workspace selected", None);
+            return;
+        }
+    };
+    close_workspace_switcher(app);
+    app.dispatch(IoEvent::SwitchWorkspace(workspace_name, card_to_select))
+        .await;
+}
+
+pub fn toggle_workspace_theme_override(app: &mut App) {
+    let current_theme_name = app.current_theme.name.clone();
+    let default_theme_name = app.config.default_theme.clone();
+    let current_workspace = app.config.current_workspace.clone();
+    let workspace = if let Some(workspace) = app
+        .config
+        .workspaces
+        .iter_mut()
+        .find(|workspace| workspace.name == current_workspace)
+    {
+        workspace
+    } else {
+        app.send_error_toast("Could not find the current workspace", None);
+        return;
+    };
+    let toast_msg = if workspace.theme_override.take().is_some() {
+        if let Some(theme) = app.all_themes.iter().find(|t| t.name == default_theme_name) {
+            app.current_theme = theme.clone();
+        }
+        format!(
+            "Workspace {} now uses the default theme {}",
+            current_workspace, default_theme_name
+        )
+    } else {
+        workspace.theme_override = Some(current_theme_name.clone());
+        format!(
+            "Workspace {} will always use the theme {}",
+            current_workspace, current_theme_name
+        )
+    };
+    if let Err(err) = write_config(&app.config) {
+        error!("Cannot save workspace theme: {}", err);
+        app.send_error_toast("Cannot save workspace theme", None);
+        return;
+    }
+    info!("{}", toast_msg);
+    app.send_info_toast(&toast_msg, None);
+}
+
+pub fn open_label_manager(app: &mut App) {
+    if app.state.ui_mode != UiMode::LabelManager {
+        app.state.prev_ui_mode = Some(app.state.ui_mode);
+    }
+    app.state.ui_mode = UiMode::LabelManager;
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.label_being_edited = None;
+    app.state.set_focus(Focus::Body);
+    if app.get_label_manager_entries().is_empty() {
+        app.state.app_list_states.label_list.select(None);
+    } else {
+        app.state.app_list_states.label_list.select(Some(0));
+    }
+}
+
+fn close_label_manager(app: &mut App) {
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.label_being_edited = None;
+    app.state.app_list_states.label_list.select(None);
+    app.state.ui_mode = app
+        .state
+        .prev_ui_mode
+        .filter(|prev_ui_mode| UiMode::view_modes().contains(prev_ui_mode))
+        .unwrap_or(app.config.default_view);
+    app.state.set_focus(Focus::Body);
+}
+
+/// loads the selected label into the text input so it can be edited
+fn handle_label_manager_selection(app: &mut App) {
+    let entries = app.get_label_manager_entries();
+    let selected_entry = app
+        .state
+        .app_list_states
+        .label_list
+        .selected()
+        .and_then(|index| entries.get(index));
+    if let Some(entry) = selected_entry {
+        let label = entry
+            .label
+            .clone()
+            .unwrap_or_else(|| Label::new(&entry.name));
+        app.state.label_being_edited = Some(entry.name.clone());
+        app.state.current_user_input = label.to_spec();
+        app.state.current_cursor_position = Some(app.state.current_user_input.len());
+    } else {
+        app.state.label_being_edited = None;
+    }
+    app.state.set_focus(Focus::TextInput);
+    app.state.app_status = AppStatus::UserInput;
+}
+
+/// saves the label from the text input, renaming a label moves its tag on every card
+/// and renaming onto an existing label merges the two
+fn handle_label_manager_submit(app: &mut App) {
+    let new_label = match Label::from_spec(&app.state.current_user_input) {
+        Ok(label) => label,
+        Err(err) => {
+            app.send_error_toast(&err, None);
+            return;
+        }
+    };
+    let label_being_edited = app.state.label_being_edited.take();
+    let toast_msg = match label_being_edited {
+        Some(old_name) if old_name != new_label.name => {
+            let merged = !old_name.eq_ignore_ascii_case(&new_label.name)
+                && (app.config.get_label(&new_label.name).is_some()
+                    || app.boards.tag_usage_count(&new_label.name) > 0);
+            let changed_cards = app.boards.rename_tag(&old_name, &new_label.name);
+            app.config
+                .labels
+                .retain(|label| !label.name.eq_ignore_ascii_case(&old_name));
+            if merged {
+                format!(
+                    "Merged label {} into {} on {} cards",
+                    old_name, new_label.name, changed_cards
+                )
+            } else {
+                format!(
+                    "Renamed label {} to {} on {} cards",
+                    old_name, new_label.name, changed_cards
+                )
+            }
+        }
+        Some(_) => format!("Updated label {}", new_label.name),
+        None => format!("Saved label {}", new_label.name),
+    };
+    let new_label_name = new_label.name.clone();
+    app.config
+        .labels
+        .retain(|label| !label.name.eq_ignore_ascii_case(&new_label.name));
+    app.config.labels.push(new_label);
+    app.config
+        .labels
+        .sort_by_key(|label| label.name.to_lowercase());
+    if let Err(err) = write_config(&app.config) {
+        error!("Cannot save labels: {}", err);
+        app.send_error_toast("Cannot save labels", None);
+    } else {
+        info!("{}", toast_msg);
+        app.send_info_toast(&toast_msg, None);
+    }
+    if !app.filtered_boards.is_empty() {
+        filter_boards(app);
+    }
+    app.clear_user_input_state();
+    app.state.current_cursor_position = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.set_focus(Focus::Body);
+    let new_label_index = app
+        .get_label_manager_entries()
+        .iter()
+        .position(|entry| entry.name == new_label_name);
+    app.state.app_list_states.label_list.select(new_label_index);
+}
+
+/// only labels that are not used on any card can be deleted, used ones can be renamed or merged instead
+fn handle_label_manager_delete(app: &mut App) {
+    let entries = app.get_label_manager_entries();
+    let selected_entry = app
+        .state
+        .app_list_states
+        .label_list
+        .selected()
+        .and_then(|index| entries.get(index));
+    let entry = match selected_entry {
+        Some(entry) => entry,
+        None => {
+            app.send_warning_toast("No label selected", None);
+            return;
+        }
+    };
+    if entry.usage > 0 {
+        app.send_warning_toast(
+            &format!(
+                "Label {} is used on {} cards, rename it to merge it into another label instead",
+                entry.name, entry.usage
+            ),
+            None,
+        );
+        return;
+    }
+    let label_name = entry.name.clone();
+    app.config
+        .labels
+        .retain(|label| !label.name.eq_ignore_ascii_case(&label_name));
+    if let Err(err) = write_config(&app.config) {
+        error!("Cannot save labels: {}", err);
+        app.send_error_toast("Cannot save labels", None);
+        return;
+    }
+    let entries_len = app.get_label_manager_entries().len();
+    if entries_len == 0 {
+        app.state.app_list_states.label_list.select(None);
+    } else if let Some(selected_index) = app.state.app_list_states.label_list.selected() {
+        app.state
+            .app_list_states
+            .label_list
+            .select(Some(selected_index.min(entries_len - 1)));
+    }
+    app.send_info_toast(&format!("Deleted label {}", label_name), None);
+}
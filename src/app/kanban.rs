@@ -1,17 +1,37 @@
+use super::{date_format_converter, date_format_finder, DateFormat};
 use crate::constants::{FIELD_NA, FIELD_NOT_SET};
-use chrono::Utc;
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday,
+};
 use log::debug;
-use serde::{Deserialize, Serialize};
+use regex::Regex;
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
-use std::fmt;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    path::{Path, PathBuf},
+};
 use uuid::Uuid;
 
+/// (blocked_card_id, blocker_id)
+pub type BlockedByLink = ((u64, u64), (u64, u64));
+
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct Board {
+    #[serde(default)]
+    pub archived_cards: Vec<ArchivedCard>,
     pub cards: Cards,
+    #[serde(default)]
+    pub custom_fields: Vec<CustomFieldDefinition>,
     pub description: String,
     pub id: (u64, u64),
     pub name: String,
+    pub wip_limit: Option<u16>,
+    pub default_card_template: Option<String>,
+    #[serde(default)]
+    pub sort_mode: CardSortMode,
 }
 
 impl Board {
@@ -21,9 +41,27 @@ impl Board {
             name: name.to_owned(),
             description: description.to_owned(),
             cards: Cards::default(),
+            archived_cards: Vec::new(),
+            custom_fields: Vec::new(),
+            wip_limit: None,
+            default_card_template: None,
+            sort_mode: CardSortMode::default(),
         }
     }
 
+    /// cards that are not completed count against the wip limit
+    pub fn wip_count(&self) -> usize {
+        self.cards
+            .get_all_cards()
+            .iter()
+            .filter(|card| card.card_status != CardStatus::Complete)
+            .count()
+    }
+
+    pub fn is_over_wip_limit(&self) -> bool {
+        matches!(self.wip_limit, Some(wip_limit) if self.wip_count() > wip_limit as usize)
+    }
+
     pub fn from_json(value: &Value) -> Result<Self, String> {
         let id = match value["id"].as_array() {
             Some(id) => {
@@ -79,23 +117,92 @@ impl Board {
                 }
             }
         };
+        // custom_fields was added later, older saves will not have it
+        let custom_fields = match value["custom_fields"].as_array() {
+            Some(custom_fields) => custom_fields
+                .iter()
+                .filter_map(|custom_field| serde_json::from_value(custom_field.clone()).ok())
+                .collect(),
+            None => Vec::new(),
+        };
+        // archived_cards was added later, older saves will not have it
+        let archived_cards = match value["archived_cards"].as_array() {
+            Some(archived_cards) => archived_cards
+                .iter()
+                .map(ArchivedCard::from_json)
+                .collect::<Result<Vec<ArchivedCard>, String>>()?,
+            None => Vec::new(),
+        };
+        // wip_limit was added later, older saves will not have it
+        let wip_limit = value["wip_limit"]
+            .as_u64()
+            .map(|wip_limit| wip_limit.min(u16::MAX as u64) as u16);
+        // default_card_template was added later, older saves will not have it
+        let default_card_template = value["default_card_template"]
+            .as_str()
+            .map(|template_name| template_name.to_string());
+        // sort_mode was added later, older saves will not have it
+        let sort_mode = value
+            .get("sort_mode")
+            .and_then(|sort_mode| serde_json::from_value(sort_mode.clone()).ok())
+            .unwrap_or_default();
 
         Ok(Self {
             id,
             name: name.to_string(),
             description: description.to_string(),
+            archived_cards,
             cards,
+            custom_fields,
+            wip_limit,
+            default_card_template,
+            sort_mode,
         })
     }
+
+    /// moves a card out of the visible cards into the archive, remembering its position
+    pub fn archive_card(&mut self, card_id: (u64, u64)) -> Option<ArchivedCard> {
+        let position = self
+            .cards
+            .get_all_cards()
+            .iter()
+            .position(|card| card.id == card_id)?;
+        let card = self.cards.remove_card_with_id(card_id)?;
+        let archived_card = ArchivedCard {
+            card,
+            date_archived: Utc::now().to_string(),
+            position,
+        };
+        self.archived_cards.push(archived_card.clone());
+        Some(archived_card)
+    }
+
+    /// moves an archived card back to its original position, or the end of the board if it has shrunk
+    pub fn restore_archived_card(&mut self, card_id: (u64, u64)) -> Option<ArchivedCard> {
+        let archive_index = self
+            .archived_cards
+            .iter()
+            .position(|archived_card| archived_card.card.id == card_id)?;
+        let archived_card = self.archived_cards.remove(archive_index);
+        let position = archived_card.position.min(self.cards.len());
+        self.cards
+            .add_card_at_index(position, archived_card.card.clone());
+        Some(archived_card)
+    }
 }
 
 impl Default for Board {
     fn default() -> Self {
         Self {
+            archived_cards: Vec::new(),
             cards: Cards::default(),
+            custom_fields: Vec::new(),
             description: String::from("Default Board Description"),
             id: get_id(),
             name: String::from("Default Board"),
+            wip_limit: None,
+            default_card_template: None,
+            sort_mode: CardSortMode::default(),
         }
     }
 }
@@ -148,6 +255,217 @@ impl Boards {
     pub fn reset(&mut self) {
         self.boards.clear();
     }
+    pub fn get_card_with_id(&self, card_id: (u64, u64)) -> Option<&Card> {
+        self.boards
+            .iter()
+            .find_map(|b| b.cards.get_card_with_id(card_id))
+    }
+    pub fn get_incomplete_blockers(&self, card: &Card) -> Vec<&Card> {
+        card.blocked_by
+            .iter()
+            .filter_map(|blocker_id| self.get_card_with_id(*blocker_id))
+            .filter(|blocker| blocker.card_status != CardStatus::Complete)
+            .collect()
+    }
+    pub fn is_card_blocked(&self, card: &Card) -> bool {
+        !self.get_incomplete_blockers(card).is_empty()
+    }
+    /// checks if card_id depends on dependency_id directly or through other blockers
+    pub fn card_depends_on(&self, card_id: (u64, u64), dependency_id: (u64, u64)) -> bool {
+        let mut visited: Vec<(u64, u64)> = vec![];
+        let mut to_visit = vec![card_id];
+        while let Some(current_id) = to_visit.pop() {
+            if visited.contains(&current_id) {
+                continue;
+            }
+            visited.push(current_id);
+            if let Some(current_card) = self.get_card_with_id(current_id) {
+                for blocker_id in &current_card.blocked_by {
+                    if *blocker_id == dependency_id {
+                        return true;
+                    }
+                    to_visit.push(*blocker_id);
+                }
+            }
+        }
+        false
+    }
+    /// returns the removed links so they can be restored on undo
+    pub fn remove_blocked_by_links(&mut self, card_id: (u64, u64)) -> Vec<BlockedByLink> {
+        let mut removed_links = vec![];
+        for board in self.boards.iter_mut() {
+            for card in board.cards.get_mut_all_cards().iter_mut() {
+                if card.blocked_by.contains(&card_id) {
+                    card.blocked_by.retain(|blocker_id| *blocker_id != card_id);
+                    removed_links.push((card.id, card_id));
+                }
+            }
+        }
+        removed_links
+    }
+    /// cards that no longer exist are skipped, links that are already there are not duplicated
+    pub fn restore_blocked_by_links(&mut self, links: &[BlockedByLink]) {
+        for (blocked_card_id, blocker_id) in links {
+            let blocked_card = self
+                .boards
+                .iter_mut()
+                .find_map(|b| b.cards.get_mut_card_with_id(*blocked_card_id));
+            if let Some(card) = blocked_card {
+                if !card.blocked_by.contains(blocker_id) {
+                    card.blocked_by.push(*blocker_id);
+                }
+            }
+        }
+    }
+    pub fn get_card_with_running_timer(&self) -> Option<&Card> {
+        self.boards.iter().find_map(|b| {
+            b.cards
+                .get_all_cards()
+                .iter()
+                .find(|card| card.running_time_entry().is_some())
+        })
+    }
+    /// stops the timer if card_id is running, otherwise starts it and stops any other running timer
+    /// returns Some(true) if the timer was started and Some(false) if it was stopped
+    pub fn toggle_card_timer(&mut self, card_id: (u64, u64)) -> Option<bool> {
+        let was_running = self
+            .get_card_with_id(card_id)?
+            .running_time_entry()
+            .is_some();
+        for board in self.boards.iter_mut() {
+            for card in board.cards.get_mut_all_cards().iter_mut() {
+                if card.id == card_id && !was_running {
+                    card.start_timer();
+                } else {
+                    card.stop_timer();
+                }
+            }
+        }
+        Some(!was_running)
+    }
+    /// number of cards, archived cards included, that carry the tag
+    pub fn tag_usage_count(&self, tag: &str) -> usize {
+        self.boards
+            .iter()
+            .flat_map(|board| {
+                board
+                    .cards
+                    .get_all_cards()
+                    .iter()
+                    .chain(board.archived_cards.iter().map(|archived| &archived.card))
+            })
+            .filter(|card| {
+                card.tags
+                    .iter()
+                    .any(|card_tag| card_tag.eq_ignore_ascii_case(tag))
+            })
+            .count()
+    }
+    /// renames the tag on every card, archived cards included, cards that already have the
+    /// new tag end up with it once, returns the number of cards changed
+    pub fn rename_tag(&mut self, old_tag: &str, new_tag: &str) -> usize {
+        let mut changed_cards = 0;
+        for board in self.boards.iter_mut() {
+            let archived_cards = board
+                .archived_cards
+                .iter_mut()
+                .map(|archived| &mut archived.card);
+            for card in board
+                .cards
+                .get_mut_all_cards()
+                .iter_mut()
+                .chain(archived_cards)
+            {
+                if !card
+                    .tags
+                    .iter()
+                    .any(|tag| tag.eq_ignore_ascii_case(old_tag))
+                {
+                    continue;
+                }
+                let mut new_tags: Vec<String> = vec![];
+                for tag in card.tags.iter() {
+                    let tag = if tag.eq_ignore_ascii_case(old_tag) {
+                        new_tag.to_string()
+                    } else {
+                        tag.clone()
+                    };
+                    if !new_tags
+                        .iter()
+                        .any(|existing| existing.eq_ignore_ascii_case(&tag))
+                    {
+                        new_tags.push(tag);
+                    }
+                }
+                if new_tags != card.tags {
+                    card.tags = new_tags;
+                    card.date_modified = Utc::now().to_string();
+                    changed_cards += 1;
+                }
+            }
+        }
+        changed_cards
+    }
+    /// archives completed cards whose completion date is more than days ago,
+    /// returns the board id and the archived card for each archived card
+    pub fn archive_completed_cards_older_than(
+        &mut self,
+        days: u16,
+    ) -> Vec<((u64, u64), ArchivedCard)> {
+        let cutoff = Utc::now() - Duration::days(days as i64);
+        let mut archived_cards = vec![];
+        for board in self.boards.iter_mut() {
+            let card_ids = board
+                .cards
+                .get_all_cards()
+                .iter()
+                .filter(|card| card.card_status == CardStatus::Complete)
+                .filter(|card| {
+                    parse_timestamp(&card.date_completed)
+                        .map(|date_completed| date_completed < cutoff)
+                        .unwrap_or(false)
+                })
+                .map(|card| card.id)
+                .collect::<Vec<(u64, u64)>>();
+            for card_id in card_ids {
+                if let Some(archived_card) = board.archive_card(card_id) {
+                    archived_cards.push((board.id, archived_card));
+                }
+            }
+        }
+        archived_cards
+    }
+    /// spawns the next instance of every active recurring card that has come due,
+    /// returns the board id and the newly created card for each spawned instance
+    pub fn spawn_due_recurring_cards(
+        &mut self,
+        date_format: DateFormat,
+    ) -> Vec<((u64, u64), Card)> {
+        let now = Local::now().naive_local();
+        let mut spawned_cards = vec![];
+        for board in self.boards.iter_mut() {
+            let mut new_cards = vec![];
+            for card in board.cards.get_mut_all_cards().iter_mut() {
+                if card.card_status != CardStatus::Active || card.recurrence.is_none() {
+                    continue;
+                }
+                let is_due = parse_due_date(&card.due_date)
+                    .map(|(due_date, _)| due_date <= now)
+                    .unwrap_or(false);
+                if !is_due {
+                    continue;
+                }
+                if let Some(next_card) = card.spawn_next_occurrence(date_format) {
+                    new_cards.push(next_card);
+                }
+            }
+            for new_card in new_cards {
+                spawned_cards.push((board.id, new_card.clone()));
+                board.cards.add_card(new_card);
+            }
+        }
+        spawned_cards
+    }
 }
 
 impl From<Vec<Board>> for Boards {
@@ -204,17 +522,32 @@ impl CardPriority {
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct Card {
+    #[serde(default)]
+    pub activity_log: Vec<CardActivity>,
+    #[serde(default)]
+    pub attachments: Vec<CardAttachment>,
+    #[serde(default)]
+    pub blocked_by: Vec<(u64, u64)>,
     pub card_status: CardStatus,
-    pub comments: Vec<String>,
+    #[serde(deserialize_with = "deserialize_card_comments")]
+    pub comments: Vec<CardComment>,
+    #[serde(default)]
+    pub custom_fields: BTreeMap<String, String>,
     pub date_completed: String,
     pub date_created: String,
     pub date_modified: String,
     pub description: String,
     pub due_date: String,
+    pub estimate: Option<u32>,
     pub id: (u64, u64),
     pub name: String,
     pub priority: CardPriority,
+    pub recurrence: Option<RecurrenceRule>,
+    /// explicit start for the timeline, in the same format as the due date
+    pub start_date: Option<String>,
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub time_entries: Vec<TimeEntry>,
 }
 
 impl Card {
@@ -224,7 +557,7 @@ impl Card {
         due_date: &str,
         priority: CardPriority,
         tags: Vec<String>,
-        comments: Vec<String>,
+        comments: Vec<CardComment>,
     ) -> Self {
         let name = if name.is_empty() { FIELD_NOT_SET } else { name };
         let description = if description.is_empty() {
@@ -253,14 +586,22 @@ impl Card {
             id: get_id(),
             name: name.to_string(),
             description: description.to_string(),
+            activity_log: Vec::new(),
+            attachments: Vec::new(),
+            blocked_by: Vec::new(),
+            custom_fields: BTreeMap::new(),
             date_created: Utc::now().to_string(),
             date_modified: Utc::now().to_string(),
             due_date: due_date.to_string(),
             date_completed: FIELD_NA.to_string(),
+            estimate: None,
             priority,
             card_status: CardStatus::Active,
+            recurrence: None,
+            start_date: None,
             tags,
             comments,
+            time_entries: Vec::new(),
         }
     }
 
@@ -340,48 +681,1466 @@ impl Card {
                 .collect(),
             None => return Err("card tags is invalid for card".to_string()),
         };
-        let comments = match value["comments"].as_array() {
+        let mut comments: Vec<CardComment> = match value["comments"].as_array() {
             Some(comments) => comments
                 .iter()
-                .map(|c| c.as_str().unwrap().to_string())
+                .filter_map(|comment| CardComment::from_json(comment, date_created))
                 .collect(),
             None => return Err("card comments is invalid for card".to_string()),
         };
+        comments.sort_by_key(|comment| parse_timestamp(&comment.date_created));
+        // blocked_by was added later, older saves will not have it
+        let blocked_by = match value["blocked_by"].as_array() {
+            Some(blocked_by) => blocked_by
+                .iter()
+                .filter_map(|blocker_id| {
+                    let blocker_id = blocker_id.as_array()?;
+                    Some((blocker_id.first()?.as_u64()?, blocker_id.get(1)?.as_u64()?))
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        let time_entries = match value["time_entries"].as_array() {
+            Some(time_entries) => time_entries
+                .iter()
+                .filter_map(|time_entry| {
+                    Some(TimeEntry {
+                        start: time_entry["start"].as_i64()?,
+                        end: time_entry["end"].as_i64(),
+                    })
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        let estimate = value["estimate"].as_u64().map(|estimate| estimate as u32);
+        let custom_fields = match value["custom_fields"].as_object() {
+            Some(custom_fields) => custom_fields
+                .iter()
+                .filter_map(|(name, value)| Some((name.clone(), value.as_str()?.to_string())))
+                .collect(),
+            None => BTreeMap::new(),
+        };
+        // recurrence was added later, older saves will not have it
+        let recurrence = serde_json::from_value(value["recurrence"].clone()).unwrap_or(None);
+        // start_date was added later, older saves will not have it
+        let start_date = value["start_date"]
+            .as_str()
+            .map(|start_date| start_date.to_string());
+        // activity_log was added later, older saves will not have it
+        let activity_log = match value["activity_log"].as_array() {
+            Some(activity_log) => activity_log
+                .iter()
+                .filter_map(|activity| serde_json::from_value(activity.clone()).ok())
+                .collect(),
+            None => Vec::new(),
+        };
+        // attachments were added later, older saves will not have them
+        let attachments = match value["attachments"].as_array() {
+            Some(attachments) => attachments
+                .iter()
+                .filter_map(|attachment| serde_json::from_value(attachment.clone()).ok())
+                .collect(),
+            None => Vec::new(),
+        };
 
         Ok(Self {
             id,
             name: name.to_string(),
             description: description.to_string(),
+            activity_log,
+            attachments,
             date_created: date_created.to_string(),
             date_modified: date_modified.to_string(),
             due_date: due_date.to_string(),
             date_completed: date_completed.to_string(),
+            estimate,
             priority,
             card_status,
             tags,
             comments,
+            blocked_by,
+            custom_fields,
+            recurrence,
+            start_date,
+            time_entries,
         })
     }
+
+    pub fn log_activity(&mut self, author: &str, kind: CardActivityKind) {
+        self.activity_log.push(CardActivity {
+            author: author.to_string(),
+            kind,
+            timestamp: Utc::now().to_string(),
+        });
+    }
+
+    /// logs everything that differs from the previous version of this card
+    pub fn log_changes_from(&mut self, previous: &Card, author: &str) {
+        let mut changes = vec![];
+        if previous.card_status != self.card_status {
+            changes.push(CardActivityKind::StatusChanged {
+                from: previous.card_status.clone(),
+                to: self.card_status.clone(),
+            });
+        }
+        if previous.priority != self.priority {
+            changes.push(CardActivityKind::PriorityChanged {
+                from: previous.priority.clone(),
+                to: self.priority.clone(),
+            });
+        }
+        let mut edited_fields = vec![
+            ("Name", previous.name.clone(), self.name.clone()),
+            (
+                "Description",
+                previous.description.clone(),
+                self.description.clone(),
+            ),
+            ("Due Date", previous.due_date.clone(), self.due_date.clone()),
+            (
+                "Start Date",
+                previous
+                    .start_date
+                    .clone()
+                    .unwrap_or(FIELD_NOT_SET.to_string()),
+                self.start_date.clone().unwrap_or(FIELD_NOT_SET.to_string()),
+            ),
+            ("Tags", previous.tags.join(", "), self.tags.join(", ")),
+            // only ids are stored, so the number of blocking cards is logged
+            (
+                "Blocked By",
+                previous.blocked_by.len().to_string(),
+                self.blocked_by.len().to_string(),
+            ),
+            (
+                "Attachments",
+                previous
+                    .attachments
+                    .iter()
+                    .map(|attachment| attachment.title.clone())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                self.attachments
+                    .iter()
+                    .map(|attachment| attachment.title.clone())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ),
+            (
+                "Estimate",
+                previous
+                    .estimate
+                    .map_or(FIELD_NOT_SET.to_string(), |estimate| estimate.to_string()),
+                self.estimate
+                    .map_or(FIELD_NOT_SET.to_string(), |estimate| estimate.to_string()),
+            ),
+            (
+                "Repeats",
+                previous
+                    .recurrence
+                    .as_ref()
+                    .map_or(FIELD_NOT_SET.to_string(), |recurrence| {
+                        recurrence.to_string()
+                    }),
+                self.recurrence
+                    .as_ref()
+                    .map_or(FIELD_NOT_SET.to_string(), |recurrence| {
+                        recurrence.to_string()
+                    }),
+            ),
+        ];
+        let custom_field_names: BTreeSet<&String> = previous
+            .custom_fields
+            .keys()
+            .chain(self.custom_fields.keys())
+            .collect();
+        for name in custom_field_names {
+            edited_fields.push((
+                name.as_str(),
+                previous
+                    .custom_fields
+                    .get(name)
+                    .cloned()
+                    .unwrap_or_default(),
+                self.custom_fields.get(name).cloned().unwrap_or_default(),
+            ));
+        }
+        for (field, from, to) in edited_fields {
+            if from != to {
+                changes.push(CardActivityKind::FieldEdited {
+                    field: field.to_string(),
+                    from,
+                    to,
+                });
+            }
+        }
+        for comment in self.comments.iter() {
+            match previous
+                .comments
+                .iter()
+                .find(|previous_comment| previous_comment.id == comment.id)
+            {
+                Some(previous_comment) if previous_comment.body != comment.body => {
+                    changes.push(CardActivityKind::CommentEdited(comment.body.clone()));
+                }
+                Some(_) => {}
+                None => changes.push(CardActivityKind::CommentAdded(comment.body.clone())),
+            }
+        }
+        for previous_comment in previous.comments.iter() {
+            if !self
+                .comments
+                .iter()
+                .any(|comment| comment.id == previous_comment.id)
+            {
+                changes.push(CardActivityKind::CommentDeleted(
+                    previous_comment.body.clone(),
+                ));
+            }
+        }
+        for change in changes {
+            self.log_activity(author, change);
+        }
+    }
+
+    /// custom field values as lowercase name:value pairs, used for filtering alongside tags
+    pub fn custom_field_tags(&self) -> Vec<String> {
+        self.custom_fields
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(name, value)| format!("{}:{}", name, value).to_lowercase())
+            .collect()
+    }
+
+    pub fn running_time_entry(&self) -> Option<&TimeEntry> {
+        self.time_entries.iter().find(|entry| entry.end.is_none())
+    }
+
+    pub fn start_timer(&mut self) {
+        if self.running_time_entry().is_none() {
+            self.time_entries.push(TimeEntry {
+                start: Utc::now().timestamp(),
+                end: None,
+            });
+        }
+    }
+
+    pub fn stop_timer(&mut self) {
+        let now = Utc::now().timestamp();
+        for entry in self.time_entries.iter_mut() {
+            if entry.end.is_none() {
+                entry.end = Some(now);
+            }
+        }
+    }
+
+    /// takes the recurrence off this card and returns the next instance with an advanced due date,
+    /// the due date is advanced until it is in the future so a late completion does not spawn a backlog
+    pub fn spawn_next_occurrence(&mut self, date_format: DateFormat) -> Option<Card> {
+        let recurrence = self.recurrence.take()?;
+        let now = Local::now().naive_local();
+        let (mut next_due_date, due_date_format) =
+            parse_due_date(&self.due_date).unwrap_or((now, date_format));
+        loop {
+            let advanced_due_date = recurrence.next_date(next_due_date);
+            // a rule that does not move the date forward would never reach the future
+            if advanced_due_date <= next_due_date {
+                break;
+            }
+            next_due_date = advanced_due_date;
+            if next_due_date > now {
+                break;
+            }
+        }
+        let mut next_card = Card::new(
+            &self.name,
+            &self.description,
+            &next_due_date
+                .format(due_date_format.to_parser_string())
+                .to_string(),
+            self.priority.clone(),
+            self.tags.clone(),
+            vec![],
+        );
+        next_card.custom_fields = self.custom_fields.clone();
+        next_card.estimate = self.estimate;
+        next_card.recurrence = Some(recurrence);
+        next_card.log_activity("Recurring schedule", CardActivityKind::Created);
+        Some(next_card)
+    }
+
+    /// total tracked seconds, a running timer counts up to now
+    pub fn total_time_tracked(&self) -> i64 {
+        self.time_entries
+            .iter()
+            .map(|entry| entry.duration_within(i64::MIN, i64::MAX))
+            .sum()
+    }
 }
 
 impl Default for Card {
     fn default() -> Self {
         Self {
+            activity_log: Vec::new(),
+            attachments: Vec::new(),
+            blocked_by: Vec::new(),
             card_status: CardStatus::Active,
             comments: Vec::new(),
+            custom_fields: BTreeMap::new(),
             date_completed: FIELD_NOT_SET.to_string(),
             date_created: Utc::now().to_string(),
             date_modified: Utc::now().to_string(),
             description: String::from("Default Card Description"),
             due_date: FIELD_NOT_SET.to_string(),
+            estimate: None,
             id: get_id(),
             name: String::from("Default Card"),
             priority: CardPriority::Low,
+            recurrence: None,
+            start_date: None,
             tags: Vec::new(),
+            time_entries: Vec::new(),
+        }
+    }
+}
+
+/// a link or a local file referenced from a card
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CardAttachment {
+    pub title: String,
+    pub target: String,
+}
+
+impl CardAttachment {
+    /// parses "<title> | <url or path>" or just "<url or path>", the title then defaults to the
+    /// file name or the url
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let (title, target) = match spec.split_once('|') {
+            Some((title, target)) => (title.trim(), target.trim()),
+            None => ("", spec.trim()),
+        };
+        if target.is_empty() {
+            return Err(
+                "Enter a url or a file path, optionally prefixed with a title and |".to_string(),
+            );
         }
+        let title = if title.is_empty() {
+            Self::default_title(target)
+        } else {
+            title.to_string()
+        };
+        Ok(Self {
+            title,
+            target: target.to_string(),
+        })
+    }
+
+    fn default_title(target: &str) -> String {
+        if Self::is_url_target(target) {
+            return target.to_string();
+        }
+        Path::new(target)
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or(target.to_string())
+    }
+
+    fn is_url_target(target: &str) -> bool {
+        let target = target.to_lowercase();
+        target.starts_with("http://") || target.starts_with("https://")
+    }
+
+    pub fn is_url(&self) -> bool {
+        Self::is_url_target(&self.target)
+    }
+
+    /// the target with a leading ~ expanded to the home directory
+    pub fn file_path(&self) -> Option<PathBuf> {
+        if self.is_url() {
+            return None;
+        }
+        match self.target.strip_prefix("~/") {
+            Some(relative_path) => home::home_dir().map(|home_dir| home_dir.join(relative_path)),
+            None => Some(PathBuf::from(&self.target)),
+        }
+    }
+
+    pub fn is_missing_file(&self) -> bool {
+        matches!(self.file_path(), Some(file_path) if !file_path.exists())
     }
 }
 
+impl fmt::Display for CardAttachment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.title == self.target {
+            write!(f, "{}", self.target)
+        } else {
+            write!(f, "{} ({})", self.title, self.target)
+        }
+    }
+}
+
+/// urls in the text that are not already attached
+pub fn detect_attachment_urls(text: &str, attachments: &[CardAttachment]) -> Vec<String> {
+    let url_regex = Regex::new(r"https?://[^\s<>()\[\]]+").unwrap();
+    let mut urls: Vec<String> = Vec::new();
+    for url_match in url_regex.find_iter(text) {
+        let url = url_match
+            .as_str()
+            .trim_end_matches(['.', ',', ';', ':', '!', '?', '"', '\''])
+            .to_string();
+        let already_attached = attachments
+            .iter()
+            .any(|attachment| attachment.target == url);
+        if !already_attached && !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    urls
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    Date,
+    SingleSelect(Vec<String>),
+    Url,
+}
+
+impl fmt::Display for CustomFieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CustomFieldType::Text => write!(f, "Text"),
+            CustomFieldType::Number => write!(f, "Number"),
+            CustomFieldType::Date => write!(f, "Date"),
+            CustomFieldType::SingleSelect(options) => {
+                write!(f, "Single Select ({})", options.join(", "))
+            }
+            CustomFieldType::Url => write!(f, "URL"),
+        }
+    }
+}
+
+impl CustomFieldType {
+    /// checks a value against the field type and returns it in the form it should be stored in
+    pub fn validate(&self, value: &str, date_format: DateFormat) -> Result<String, String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(String::new());
+        }
+        match self {
+            CustomFieldType::Text => Ok(value.to_string()),
+            CustomFieldType::Number => match value.parse::<f64>() {
+                Ok(number) if number.is_finite() => Ok(value.to_string()),
+                _ => Err(format!("'{}' is not a number", value)),
+            },
+            CustomFieldType::Date => date_format_converter(value, date_format)
+                .map_err(|_| format!("'{}' is not a valid date", value)),
+            CustomFieldType::SingleSelect(options) => options
+                .iter()
+                .find(|option| option.eq_ignore_ascii_case(value))
+                .cloned()
+                .ok_or_else(|| {
+                    format!(
+                        "'{}' is not one of the options: {}",
+                        value,
+                        options.join(", ")
+                    )
+                }),
+            CustomFieldType::Url => {
+                if (value.starts_with("http://") || value.starts_with("https://"))
+                    && !value.contains(char::is_whitespace)
+                {
+                    Ok(value.to_string())
+                } else {
+                    Err(format!("'{}' is not a valid URL", value))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomFieldDefinition {
+    pub field_type: CustomFieldType,
+    pub name: String,
+}
+
+impl CustomFieldDefinition {
+    /// parses a definition in the form name:type or name:select:option1,option2
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut parts = spec.splitn(3, ':').map(|part| part.trim());
+        let name = parts.next().unwrap_or_default();
+        if name.is_empty() {
+            return Err("Custom field name cannot be empty".to_string());
+        }
+        let field_type = match parts.next().unwrap_or_default().to_lowercase().as_str() {
+            "text" => CustomFieldType::Text,
+            "number" => CustomFieldType::Number,
+            "date" => CustomFieldType::Date,
+            "url" => CustomFieldType::Url,
+            "select" => {
+                let options = parts
+                    .next()
+                    .unwrap_or_default()
+                    .split(',')
+                    .map(|option| option.trim().to_string())
+                    .filter(|option| !option.is_empty())
+                    .collect::<Vec<String>>();
+                if options.is_empty() {
+                    return Err("A select field needs at least one option".to_string());
+                }
+                CustomFieldType::SingleSelect(options)
+            }
+            field_type => {
+                return Err(format!(
+                    "Unknown custom field type '{}', use text, number, date, select or url",
+                    field_type
+                ))
+            }
+        };
+        Ok(Self {
+            field_type,
+            name: name.to_string(),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum RecurrenceRule {
+    Daily,
+    /// weekdays as days from monday, 0 is monday and 6 is sunday
+    Weekly(Vec<u32>),
+    Monthly,
+    #[serde(deserialize_with = "deserialize_recurrence_interval")]
+    EveryNDays(u32),
+}
+
+/// saves are not validated by from_spec, an interval of 0 would never move the due date
+fn deserialize_recurrence_interval<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let days = u32::deserialize(deserializer)?;
+    if days == 0 {
+        return Err(serde::de::Error::custom(
+            "recurrence interval must be above 0 days",
+        ));
+    }
+    Ok(days)
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecurrenceRule::Daily => write!(f, "Daily"),
+            RecurrenceRule::Weekly(weekdays) => {
+                let weekdays = weekdays
+                    .iter()
+                    .filter_map(|weekday| Weekday::try_from(*weekday as u8).ok())
+                    .map(|weekday| weekday.to_string())
+                    .collect::<Vec<String>>();
+                if weekdays.is_empty() {
+                    write!(f, "Weekly")
+                } else {
+                    write!(f, "Weekly on {}", weekdays.join(", "))
+                }
+            }
+            RecurrenceRule::Monthly => write!(f, "Monthly"),
+            RecurrenceRule::EveryNDays(days) => write!(f, "Every {} days", days),
+        }
+    }
+}
+
+impl RecurrenceRule {
+    /// parses "daily", "weekly", "weekly:mon,thu", "monthly" or "every:N", an empty spec or "none" clears the rule
+    pub fn from_spec(spec: &str) -> Result<Option<Self>, String> {
+        let spec = spec.trim().to_lowercase();
+        let (kind, options) = match spec.split_once(':') {
+            Some((kind, options)) => (kind.trim(), options.trim()),
+            None => (spec.as_str(), ""),
+        };
+        match kind {
+            "" | "none" => Ok(None),
+            "daily" => Ok(Some(RecurrenceRule::Daily)),
+            "monthly" => Ok(Some(RecurrenceRule::Monthly)),
+            "weekly" => {
+                let mut weekdays = vec![];
+                for weekday in options.split(',').map(str::trim).filter(|w| !w.is_empty()) {
+                    match weekday.parse::<Weekday>() {
+                        Ok(weekday) => weekdays.push(weekday.num_days_from_monday()),
+                        Err(_) => return Err(format!("Invalid weekday {}", weekday)),
+                    }
+                }
+                weekdays.sort();
+                weekdays.dedup();
+                Ok(Some(RecurrenceRule::Weekly(weekdays)))
+            }
+            "every" => match options.trim_end_matches("days").trim().parse::<u32>() {
+                Ok(days) if days > 0 => Ok(Some(RecurrenceRule::EveryNDays(days))),
+                _ => Err("Recurrence interval must be a whole number of days above 0".to_string()),
+            },
+            _ => Err(format!(
+                "Invalid recurrence {}, use daily, weekly:mon,thu, monthly or every:N",
+                spec
+            )),
+        }
+    }
+
+    /// the inverse of from_spec, used to prefill the recurrence prompt
+    pub fn to_spec(&self) -> String {
+        match self {
+            RecurrenceRule::Daily => "daily".to_string(),
+            RecurrenceRule::Weekly(weekdays) => {
+                let weekdays = weekdays
+                    .iter()
+                    .filter_map(|weekday| Weekday::try_from(*weekday as u8).ok())
+                    .map(|weekday| weekday.to_string().to_lowercase())
+                    .collect::<Vec<String>>();
+                if weekdays.is_empty() {
+                    "weekly".to_string()
+                } else {
+                    format!("weekly:{}", weekdays.join(","))
+                }
+            }
+            RecurrenceRule::Monthly => "monthly".to_string(),
+            RecurrenceRule::EveryNDays(days) => format!("every:{}", days),
+        }
+    }
+
+    /// the first occurrence strictly after date
+    pub fn next_date(&self, date: NaiveDateTime) -> NaiveDateTime {
+        match self {
+            RecurrenceRule::Daily => date + Duration::days(1),
+            RecurrenceRule::Weekly(weekdays) => {
+                if weekdays.is_empty() {
+                    return date + Duration::days(7);
+                }
+                (1..=7)
+                    .map(|offset| date + Duration::days(offset))
+                    .find(|next_date| {
+                        weekdays.contains(&next_date.weekday().num_days_from_monday())
+                    })
+                    .unwrap_or(date + Duration::days(7))
+            }
+            RecurrenceRule::Monthly => {
+                let (year, month) = if date.month() == 12 {
+                    (date.year() + 1, 1)
+                } else {
+                    (date.year(), date.month() + 1)
+                };
+                // clamp to the last day of shorter months
+                let day = (28..=date.day())
+                    .rev()
+                    .find(|day| NaiveDate::from_ymd_opt(year, month, *day).is_some())
+                    .unwrap_or(date.day());
+                NaiveDate::from_ymd_opt(year, month, day)
+                    .map(|next_date| next_date.and_time(date.time()))
+                    .unwrap_or(date + Duration::days(30))
+            }
+            RecurrenceRule::EveryNDays(days) => date + Duration::days(*days as i64),
+        }
+    }
+}
+
+/// order of the cards in a board, every mode other than manual keeps the board sorted
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CardSortMode {
+    #[default]
+    Manual,
+    Priority,
+    DueDate,
+    DateCreated,
+    DateModified,
+    Name,
+    Estimate,
+}
+
+impl fmt::Display for CardSortMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardSortMode::Manual => write!(f, "Manual"),
+            CardSortMode::Priority => write!(f, "Priority"),
+            CardSortMode::DueDate => write!(f, "Due Date"),
+            CardSortMode::DateCreated => write!(f, "Date Created"),
+            CardSortMode::DateModified => write!(f, "Date Modified"),
+            CardSortMode::Name => write!(f, "Name"),
+            CardSortMode::Estimate => write!(f, "Estimate"),
+        }
+    }
+}
+
+impl CardSortMode {
+    pub fn all() -> Vec<CardSortMode> {
+        vec![
+            CardSortMode::Manual,
+            CardSortMode::Priority,
+            CardSortMode::DueDate,
+            CardSortMode::DateCreated,
+            CardSortMode::DateModified,
+            CardSortMode::Name,
+            CardSortMode::Estimate,
+        ]
+    }
+}
+
+/// how the board view splits cards into horizontal swimlanes
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum SwimlaneGrouping {
+    Priority,
+    /// lanes come from tags with this prefix, "epic:" groups "epic:login" into the "login" lane
+    TagPrefix(String),
+    CustomField(String),
+}
+
+impl fmt::Display for SwimlaneGrouping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SwimlaneGrouping::Priority => write!(f, "Priority"),
+            SwimlaneGrouping::TagPrefix(prefix) => write!(f, "Tag {}", prefix),
+            SwimlaneGrouping::CustomField(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl SwimlaneGrouping {
+    /// parses "priority", "tag:<prefix>", "field:<name>" or "assignee", an empty spec or "none" turns swimlanes off
+    pub fn from_spec(spec: &str) -> Result<Option<Self>, String> {
+        let spec = spec.trim();
+        let (kind, option) = match spec.split_once(':') {
+            Some((kind, option)) => (kind.trim().to_lowercase(), option.trim()),
+            None => (spec.to_lowercase(), ""),
+        };
+        match kind.as_str() {
+            "" | "none" => Ok(None),
+            "priority" => Ok(Some(SwimlaneGrouping::Priority)),
+            "assignee" => Ok(Some(SwimlaneGrouping::CustomField("Assignee".to_string()))),
+            "tag" if !option.is_empty() => {
+                Ok(Some(SwimlaneGrouping::TagPrefix(option.to_lowercase())))
+            }
+            "field" if !option.is_empty() => {
+                Ok(Some(SwimlaneGrouping::CustomField(option.to_string())))
+            }
+            _ => Err(format!(
+                "Invalid swimlane grouping {}, use priority, tag:<prefix>, field:<name> or assignee",
+                spec
+            )),
+        }
+    }
+
+    pub fn to_spec(&self) -> String {
+        match self {
+            SwimlaneGrouping::Priority => "priority".to_string(),
+            SwimlaneGrouping::TagPrefix(prefix) => format!("tag:{}", prefix),
+            SwimlaneGrouping::CustomField(name) => format!("field:{}", name),
+        }
+    }
+
+    /// the lane a card belongs to, cards without a value go in the FIELD_NOT_SET lane
+    pub fn lane_for(&self, card: &Card) -> String {
+        let lane = match self {
+            SwimlaneGrouping::Priority => Some(card.priority.to_string()),
+            SwimlaneGrouping::TagPrefix(prefix) => card.tags.iter().find_map(|tag| {
+                let tag = tag.to_lowercase();
+                tag.strip_prefix(prefix.as_str())
+                    .filter(|lane| !lane.is_empty())
+                    .map(str::to_string)
+            }),
+            SwimlaneGrouping::CustomField(name) => card
+                .custom_fields
+                .iter()
+                .find(|(field_name, value)| {
+                    field_name.eq_ignore_ascii_case(name) && !value.is_empty()
+                })
+                .map(|(_, value)| value.clone()),
+        };
+        lane.unwrap_or(FIELD_NOT_SET.to_string())
+    }
+
+    /// every lane that has cards, priorities always show all three lanes
+    pub fn lanes(&self, boards: &Boards) -> Vec<String> {
+        let mut lanes = match self {
+            SwimlaneGrouping::Priority => vec![
+                CardPriority::High.to_string(),
+                CardPriority::Medium.to_string(),
+                CardPriority::Low.to_string(),
+            ],
+            _ => vec![],
+        };
+        let mut found_lanes = BTreeSet::new();
+        for board in boards.get_boards() {
+            for card in board.cards.get_all_cards() {
+                found_lanes.insert(self.lane_for(card));
+            }
+        }
+        let has_unset_lane = found_lanes.remove(FIELD_NOT_SET);
+        for lane in found_lanes {
+            if !lanes.contains(&lane) {
+                lanes.push(lane);
+            }
+        }
+        if has_unset_lane {
+            lanes.push(FIELD_NOT_SET.to_string());
+        }
+        lanes
+    }
+
+    /// updates the grouping field of a card so it lands in the given lane
+    pub fn assign(&self, card: &mut Card, lane: &str) {
+        match self {
+            SwimlaneGrouping::Priority => {
+                if let Some(priority) = CardPriority::all()
+                    .into_iter()
+                    .find(|priority| priority.to_string() == lane)
+                {
+                    card.priority = priority;
+                }
+            }
+            SwimlaneGrouping::TagPrefix(prefix) => {
+                card.tags
+                    .retain(|tag| !tag.to_lowercase().starts_with(prefix.as_str()));
+                if lane != FIELD_NOT_SET {
+                    card.tags.push(format!("{}{}", prefix, lane));
+                }
+            }
+            SwimlaneGrouping::CustomField(name) => {
+                card.custom_fields
+                    .retain(|field_name, _| !field_name.eq_ignore_ascii_case(name));
+                if lane != FIELD_NOT_SET {
+                    card.custom_fields.insert(name.clone(), lane.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CardComment {
+    /// empty for comments from older saves, those can be edited by anyone
+    pub author: String,
+    pub body: String,
+    pub date_created: String,
+    pub date_edited: Option<String>,
+    pub id: (u64, u64),
+}
+
+impl CardComment {
+    pub fn new(author: &str, body: &str) -> Self {
+        Self {
+            author: author.to_string(),
+            body: body.to_string(),
+            date_created: Utc::now().to_string(),
+            date_edited: None,
+            id: get_id(),
+        }
+    }
+
+    /// older saves stored comments as plain strings, those are given the card creation date
+    fn from_json(value: &Value, card_date_created: &str) -> Option<Self> {
+        if let Some(body) = value.as_str() {
+            return Some(Self {
+                author: String::new(),
+                body: body.to_string(),
+                date_created: card_date_created.to_string(),
+                date_edited: None,
+                id: get_id(),
+            });
+        }
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    pub fn is_editable_by(&self, author: &str) -> bool {
+        self.author.is_empty() || self.author == author
+    }
+}
+
+/// cloud saves are decoded with serde, older ones stored comments as plain strings which are
+/// kept without a creation date since the card is not available here
+fn deserialize_card_comments<'de, D>(deserializer: D) -> Result<Vec<CardComment>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredComment {
+        Plain(String),
+        Structured(CardComment),
+    }
+    let stored_comments = Vec::<StoredComment>::deserialize(deserializer)?;
+    Ok(stored_comments
+        .into_iter()
+        .map(|stored_comment| match stored_comment {
+            StoredComment::Plain(body) => CardComment {
+                author: String::new(),
+                body,
+                date_created: FIELD_NOT_SET.to_string(),
+                date_edited: None,
+                id: get_id(),
+            },
+            StoredComment::Structured(comment) => comment,
+        })
+        .collect())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CardActivity {
+    pub author: String,
+    pub kind: CardActivityKind,
+    pub timestamp: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum CardActivityKind {
+    Created,
+    Moved {
+        from_board: String,
+        to_board: String,
+    },
+    StatusChanged {
+        from: CardStatus,
+        to: CardStatus,
+    },
+    PriorityChanged {
+        from: CardPriority,
+        to: CardPriority,
+    },
+    FieldEdited {
+        field: String,
+        from: String,
+        to: String,
+    },
+    CommentAdded(String),
+    CommentEdited(String),
+    CommentDeleted(String),
+}
+
+impl fmt::Display for CardActivityKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardActivityKind::Created => write!(f, "Created the card"),
+            CardActivityKind::Moved {
+                from_board,
+                to_board,
+            } => write!(f, "Moved from board {} to {}", from_board, to_board),
+            CardActivityKind::StatusChanged { from, to } => {
+                write!(f, "Changed status from {} to {}", from, to)
+            }
+            CardActivityKind::PriorityChanged { from, to } => {
+                write!(f, "Changed priority from {} to {}", from, to)
+            }
+            CardActivityKind::FieldEdited { field, from, to } => {
+                write!(f, "Changed {} from '{}' to '{}'", field, from, to)
+            }
+            CardActivityKind::CommentAdded(comment) => write!(f, "Commented '{}'", comment),
+            CardActivityKind::CommentEdited(comment) => {
+                write!(f, "Edited a comment to '{}'", comment)
+            }
+            CardActivityKind::CommentDeleted(comment) => {
+                write!(f, "Deleted the comment '{}'", comment)
+            }
+        }
+    }
+}
+
+/// a reusable set of boards, stored next to the themes in the config directory
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BoardTemplate {
+    pub name: String,
+    pub boards: Vec<BoardTemplateBoard>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BoardTemplateBoard {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub wip_limit: Option<u16>,
+    #[serde(default)]
+    pub seed_cards: Vec<BoardTemplateCard>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BoardTemplateCard {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl BoardTemplateBoard {
+    fn new(name: &str, description: &str, wip_limit: Option<u16>, seed_cards: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            wip_limit,
+            seed_cards: seed_cards
+                .iter()
+                .map(|card_name| BoardTemplateCard {
+                    name: card_name.to_string(),
+                    description: String::new(),
+                    tags: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl BoardTemplate {
+    pub fn all_default_templates() -> Vec<BoardTemplate> {
+        vec![
+            BoardTemplate {
+                name: "Basic Kanban".to_string(),
+                boards: vec![
+                    BoardTemplateBoard::new(
+                        "Backlog",
+                        "Ideas and work that is not planned yet",
+                        None,
+                        &[],
+                    ),
+                    BoardTemplateBoard::new(
+                        "Todo",
+                        "Planned work that is ready to start",
+                        None,
+                        &[],
+                    ),
+                    BoardTemplateBoard::new("Doing", "Work in progress", Some(3), &[]),
+                    BoardTemplateBoard::new("Review", "Work waiting for review", Some(2), &[]),
+                    BoardTemplateBoard::new("Done", "Finished work", None, &[]),
+                ],
+            },
+            BoardTemplate {
+                name: "Scrum Sprint".to_string(),
+                boards: vec![
+                    BoardTemplateBoard::new(
+                        "Product Backlog",
+                        "Prioritised work for future sprints",
+                        None,
+                        &[],
+                    ),
+                    BoardTemplateBoard::new(
+                        "Sprint Backlog",
+                        "Work committed to for this sprint",
+                        None,
+                        &["Sprint planning", "Sprint review", "Sprint retrospective"],
+                    ),
+                    BoardTemplateBoard::new(
+                        "In Progress",
+                        "Work being done this sprint",
+                        Some(4),
+                        &[],
+                    ),
+                    BoardTemplateBoard::new("Testing", "Work being verified", Some(3), &[]),
+                    BoardTemplateBoard::new(
+                        "Done",
+                        "Work that meets the definition of done",
+                        None,
+                        &[],
+                    ),
+                ],
+            },
+            BoardTemplate {
+                name: "Bug Triage".to_string(),
+                boards: vec![
+                    BoardTemplateBoard::new(
+                        "New",
+                        "Reported bugs that have not been looked at",
+                        None,
+                        &["Agree on severity levels"],
+                    ),
+                    BoardTemplateBoard::new("Needs Info", "Waiting on the reporter", None, &[]),
+                    BoardTemplateBoard::new("Confirmed", "Reproduced and ready to fix", None, &[]),
+                    BoardTemplateBoard::new("Fixing", "Bugs being fixed", Some(3), &[]),
+                    BoardTemplateBoard::new(
+                        "Verified",
+                        "Fixes confirmed by QA or the reporter",
+                        None,
+                        &[],
+                    ),
+                ],
+            },
+        ]
+    }
+
+    /// keeps the board layout and the open cards as seed cards, completed cards are left out
+    pub fn from_boards(name: &str, boards: &Boards) -> Self {
+        Self {
+            name: name.to_string(),
+            boards: boards
+                .get_boards()
+                .iter()
+                .map(|board| BoardTemplateBoard {
+                    name: board.name.clone(),
+                    description: board.description.clone(),
+                    wip_limit: board.wip_limit,
+                    seed_cards: board
+                        .cards
+                        .get_all_cards()
+                        .iter()
+                        .filter(|card| card.card_status != CardStatus::Complete)
+                        .map(|card| BoardTemplateCard {
+                            name: card.name.clone(),
+                            description: if card.description == FIELD_NOT_SET {
+                                String::new()
+                            } else {
+                                card.description.clone()
+                            },
+                            tags: card.tags.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// creates fresh boards and cards with new ids every time
+    pub fn to_boards(&self) -> Vec<Board> {
+        self.boards
+            .iter()
+            .map(|template_board| {
+                let mut board = Board::new(&template_board.name, &template_board.description);
+                board.wip_limit = template_board.wip_limit;
+                for seed_card in &template_board.seed_cards {
+                    board.cards.add_card(Card::new(
+                        &seed_card.name,
+                        &seed_card.description,
+                        "",
+                        CardPriority::Low,
+                        seed_card.tags.clone(),
+                        Vec::new(),
+                    ));
+                }
+                board
+            })
+            .collect()
+    }
+}
+
+/// preset values for the new card form, stored next to the board templates in the config directory
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CardTemplate {
+    pub name: String,
+    #[serde(default)]
+    pub name_prefix: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default = "default_card_template_priority")]
+    pub priority: CardPriority,
+    #[serde(default)]
+    pub checklist: Vec<String>,
+}
+
+fn default_card_template_priority() -> CardPriority {
+    CardPriority::Low
+}
+
+impl CardTemplate {
+    pub fn all_default_templates() -> Vec<CardTemplate> {
+        vec![
+            CardTemplate {
+                name: "Bug Report".to_string(),
+                name_prefix: "Bug: ".to_string(),
+                description: "Steps to reproduce:\n1. \n\nExpected behaviour:\n\nActual behaviour:\n\nEnvironment:\n".to_string(),
+                tags: vec!["bug".to_string()],
+                priority: CardPriority::High,
+                checklist: vec![
+                    "Reproduced".to_string(),
+                    "Root cause found".to_string(),
+                    "Fix tested".to_string(),
+                ],
+            },
+            CardTemplate {
+                name: "Feature Request".to_string(),
+                name_prefix: "Feature: ".to_string(),
+                description: "Problem:\n\nProposed solution:\n\nAlternatives considered:\n".to_string(),
+                tags: vec!["feature".to_string()],
+                priority: CardPriority::Medium,
+                checklist: vec![
+                    "Design agreed".to_string(),
+                    "Implemented".to_string(),
+                    "Documented".to_string(),
+                ],
+            },
+            CardTemplate {
+                name: "Chore".to_string(),
+                name_prefix: "Chore: ".to_string(),
+                description: String::new(),
+                tags: vec!["chore".to_string()],
+                priority: CardPriority::Low,
+                checklist: Vec::new(),
+            },
+        ]
+    }
+
+    /// the description skeleton followed by the checklist as markdown task items
+    pub fn description_with_checklist(&self) -> String {
+        if self.checklist.is_empty() {
+            return self.description.clone();
+        }
+        let checklist = self
+            .checklist
+            .iter()
+            .map(|item| format!("- [ ] {}", item))
+            .collect::<Vec<String>>()
+            .join("\n");
+        if self.description.trim().is_empty() {
+            format!("Checklist:\n{}", checklist)
+        } else {
+            format!(
+                "{}\n\nChecklist:\n{}",
+                self.description.trim_end(),
+                checklist
+            )
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedCard {
+    pub card: Card,
+    pub date_archived: String,
+    /// index of the card in its board when it was archived
+    pub position: usize,
+}
+
+impl ArchivedCard {
+    pub fn from_json(value: &Value) -> Result<Self, String> {
+        let card = Card::from_json(&value["card"])?;
+        let date_archived = match value["date_archived"].as_str() {
+            Some(date_archived) => date_archived.to_string(),
+            None => return Err("archived card date_archived is invalid".to_string()),
+        };
+        let position = value["position"].as_u64().unwrap_or(0) as usize;
+        Ok(Self {
+            card,
+            date_archived,
+            position,
+        })
+    }
+}
+
+/// parses timestamps stored with Utc::now().to_string(), such as date_created and date_completed
+pub fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(timestamp.trim_end_matches(" UTC"), "%Y-%m-%d %H:%M:%S%.f")
+        .ok()
+        .map(|timestamp| Utc.from_utc_datetime(&timestamp))
+}
+
+/// parses a stored due date in any supported format, date only formats are due at midnight
+pub fn parse_due_date(due_date: &str) -> Option<(NaiveDateTime, DateFormat)> {
+    let date_format = date_format_finder(due_date).ok()?;
+    if DateFormat::all_formats_with_time().contains(&date_format) {
+        NaiveDateTime::parse_from_str(due_date, date_format.to_parser_string())
+            .ok()
+            .map(|due_date| (due_date, date_format))
+    } else {
+        NaiveDate::parse_from_str(due_date, date_format.to_parser_string())
+            .ok()
+            .and_then(|due_date| due_date.and_hms_opt(0, 0, 0))
+            .map(|due_date| (due_date, date_format))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TimeEntry {
+    pub start: i64,
+    pub end: Option<i64>,
+}
+
+impl TimeEntry {
+    /// seconds of this entry that fall between range_start and range_end (unix timestamps)
+    pub fn duration_within(&self, range_start: i64, range_end: i64) -> i64 {
+        let end = self.end.unwrap_or_else(|| Utc::now().timestamp());
+        let start = self.start.max(range_start);
+        let end = end.min(range_end);
+        (end - start).max(0)
+    }
+}
+
+pub const TIME_REPORT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeReportRange {
+    Today,
+    ThisWeek,
+    #[default]
+    ThisMonth,
+    AllTime,
+    /// both days are included
+    Custom(NaiveDate, NaiveDate),
+}
+
+impl fmt::Display for TimeReportRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeReportRange::Today => write!(f, "Today"),
+            TimeReportRange::ThisWeek => write!(f, "This Week"),
+            TimeReportRange::ThisMonth => write!(f, "This Month"),
+            TimeReportRange::AllTime => write!(f, "All Time"),
+            TimeReportRange::Custom(start, end) => write!(
+                f,
+                "From {} to {}",
+                start.format(TIME_REPORT_DATE_FORMAT),
+                end.format(TIME_REPORT_DATE_FORMAT)
+            ),
+        }
+    }
+}
+
+impl TimeReportRange {
+    pub fn all() -> Vec<TimeReportRange> {
+        vec![
+            TimeReportRange::Today,
+            TimeReportRange::ThisWeek,
+            TimeReportRange::ThisMonth,
+            TimeReportRange::AllTime,
+        ]
+    }
+
+    /// a custom range is not part of all, cycling away from it starts at the first or last preset
+    pub fn next(&self) -> TimeReportRange {
+        let all = TimeReportRange::all();
+        match all.iter().position(|range| range == self) {
+            Some(index) => all[(index + 1) % all.len()],
+            None => all[0],
+        }
+    }
+
+    pub fn prv(&self) -> TimeReportRange {
+        let all = TimeReportRange::all();
+        match all.iter().position(|range| range == self) {
+            Some(index) => all[(index + all.len() - 1) % all.len()],
+            None => all[all.len() - 1],
+        }
+    }
+
+    /// parses "start | end" with both dates as YYYY-MM-DD
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let (start, end) = spec
+            .split_once('|')
+            .ok_or("Enter a start and an end date separated by |".to_string())?;
+        let parse_date = |date: &str| {
+            NaiveDate::parse_from_str(date.trim(), TIME_REPORT_DATE_FORMAT)
+                .map_err(|_| format!("Invalid date '{}', use YYYY-MM-DD", date.trim()))
+        };
+        let start = parse_date(start)?;
+        let end = parse_date(end)?;
+        if end < start {
+            return Err("The end date cannot be before the start date".to_string());
+        }
+        Ok(TimeReportRange::Custom(start, end))
+    }
+
+    pub fn to_spec(&self) -> Option<String> {
+        match self {
+            TimeReportRange::Custom(start, end) => Some(format!(
+                "{} | {}",
+                start.format(TIME_REPORT_DATE_FORMAT),
+                end.format(TIME_REPORT_DATE_FORMAT)
+            )),
+            _ => None,
+        }
+    }
+
+    /// (start, end) unix timestamps, uses local time for day boundaries
+    pub fn to_timestamps(&self) -> (i64, i64) {
+        let today = Local::now().date_naive();
+        let start_date = match self {
+            TimeReportRange::Today => Some(today),
+            TimeReportRange::ThisWeek => {
+                Some(today - Duration::days(today.weekday().num_days_from_monday() as i64))
+            }
+            TimeReportRange::ThisMonth => NaiveDate::from_ymd_opt(today.year(), today.month(), 1),
+            TimeReportRange::AllTime => None,
+            TimeReportRange::Custom(start, _) => Some(*start),
+        };
+        let end_date = match self {
+            TimeReportRange::Custom(_, end) => end.succ_opt(),
+            _ => None,
+        };
+        let to_timestamp = |date: Option<NaiveDate>| {
+            date.and_then(|date| date.and_hms_opt(0, 0, 0))
+                .and_then(|date_time| Local.from_local_datetime(&date_time).earliest())
+                .map(|date_time: DateTime<Local>| date_time.timestamp())
+        };
+        (
+            to_timestamp(start_date).unwrap_or(i64::MIN),
+            to_timestamp(end_date).unwrap_or(i64::MAX),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TimeReport {
+    pub by_board: Vec<(String, i64)>,
+    pub by_card: Vec<(String, String, i64)>,
+    pub by_tag: Vec<(String, i64)>,
+    pub total: i64,
+}
+
+impl TimeReport {
+    /// archived cards count too so auto archiving does not hide tracked time
+    pub fn new(boards: &Boards, range: TimeReportRange) -> Self {
+        let (range_start, range_end) = range.to_timestamps();
+        let mut report = TimeReport::default();
+        for board in boards.get_boards() {
+            let mut board_total = 0;
+            let all_cards = board.cards.get_all_cards().iter().chain(
+                board
+                    .archived_cards
+                    .iter()
+                    .map(|archived_card| &archived_card.card),
+            );
+            for card in all_cards {
+                let card_total: i64 = card
+                    .time_entries
+                    .iter()
+                    .map(|entry| entry.duration_within(range_start, range_end))
+                    .sum();
+                if card_total == 0 {
+                    continue;
+                }
+                board_total += card_total;
+                report
+                    .by_card
+                    .push((board.name.clone(), card.name.clone(), card_total));
+                for tag in &card.tags {
+                    let tag = tag.to_lowercase();
+                    if tag.is_empty() {
+                        continue;
+                    }
+                    if let Some(tag_total) = report.by_tag.iter_mut().find(|(t, _)| *t == tag) {
+                        tag_total.1 += card_total;
+                    } else {
+                        report.by_tag.push((tag, card_total));
+                    }
+                }
+            }
+            if board_total > 0 {
+                report.by_board.push((board.name.clone(), board_total));
+                report.total += board_total;
+            }
+        }
+        report
+            .by_tag
+            .sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        report
+    }
+}
+
+pub fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds % 3600) / 60,
+        seconds % 60
+    )
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub struct Cards {
     cards: Vec<Card>,
@@ -440,6 +2199,44 @@ impl Cards {
     pub fn reset(&mut self) {
         self.cards.clear();
     }
+    /// sum of estimates of all active cards, cards without an estimate count as 0
+    pub fn total_active_estimate(&self) -> u32 {
+        self.cards
+            .iter()
+            .filter(|card| card.card_status == CardStatus::Active)
+            .filter_map(|card| card.estimate)
+            .sum()
+    }
+    pub fn sort_by_estimate(&mut self) {
+        // largest first, cards without an estimate go to the bottom
+        self.cards
+            .sort_by_key(|card| (card.estimate.is_none(), Reverse(card.estimate)));
+    }
+    /// stable sort so cards that compare equal keep their manual order,
+    /// cards without a due date or estimate go to the bottom
+    pub fn sort_by_mode(&mut self, sort_mode: CardSortMode) {
+        match sort_mode {
+            CardSortMode::Manual => {}
+            CardSortMode::Priority => self.cards.sort_by_key(|card| match card.priority {
+                CardPriority::High => 0,
+                CardPriority::Medium => 1,
+                CardPriority::Low => 2,
+            }),
+            CardSortMode::DueDate => self.cards.sort_by_key(|card| {
+                let due_date = parse_due_date(&card.due_date).map(|(due_date, _)| due_date);
+                (due_date.is_none(), due_date)
+            }),
+            // newest first
+            CardSortMode::DateCreated => self
+                .cards
+                .sort_by_key(|card| Reverse(parse_timestamp(&card.date_created))),
+            CardSortMode::DateModified => self
+                .cards
+                .sort_by_key(|card| Reverse(parse_timestamp(&card.date_modified))),
+            CardSortMode::Name => self.cards.sort_by_key(|card| card.name.to_lowercase()),
+            CardSortMode::Estimate => self.sort_by_estimate(),
+        }
+    }
     pub fn swap(&mut self, index_1: usize, index_2: usize) {
         self.cards.swap(index_1, index_2);
     }
@@ -462,3 +2259,78 @@ impl FromIterator<Card> for Cards {
 fn get_id() -> (u64, u64) {
     Uuid::new_v4().as_u64_pair()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a board as uploaded to the cloud before comments were structured and the newer card
+    /// and board fields existed
+    const OLD_CLOUD_BOARDS: &str = r#"{"boards": [
+        {
+            "cards": {
+                "cards": [
+                    {
+                        "card_status": "Active",
+                        "comments": ["first comment", "second comment"],
+                        "date_completed": "Not Set",
+                        "date_created": "2024-01-01 10:00:00.000000 UTC",
+                        "date_modified": "2024-01-02 10:00:00.000000 UTC",
+                        "description": "Not Set",
+                        "due_date": "Not Set",
+                        "id": [1, 2],
+                        "name": "Old card",
+                        "priority": "High",
+                        "tags": ["legacy"]
+                    }
+                ]
+            },
+            "description": "Old board",
+            "id": [3, 4],
+            "name": "Board"
+        }
+    ]}"#;
+
+    #[test]
+    fn old_cloud_save_round_trips() {
+        let boards: Boards =
+            serde_json::from_str(OLD_CLOUD_BOARDS).expect("old cloud save should decode");
+        assert_eq!(boards.len(), 1);
+        let board = &boards.get_boards()[0];
+        let card = &board.cards.get_all_cards()[0];
+        assert_eq!(card.name, "Old card");
+        let comment_bodies = card
+            .comments
+            .iter()
+            .map(|comment| comment.body.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(comment_bodies, vec!["first comment", "second comment"]);
+        assert!(card
+            .comments
+            .iter()
+            .all(|comment| comment.author.is_empty()));
+        assert!(card.blocked_by.is_empty());
+        assert!(board.archived_cards.is_empty());
+        assert_eq!(board.sort_mode, CardSortMode::default());
+
+        let encoded = serde_json::to_string(&boards).unwrap();
+        let decoded: Boards = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded, boards);
+    }
+
+    #[test]
+    fn zero_day_recurrence_is_rejected() {
+        assert!(serde_json::from_str::<RecurrenceRule>(r#"{"EveryNDays": 0}"#).is_err());
+        assert_eq!(
+            serde_json::from_str::<RecurrenceRule>(r#"{"EveryNDays": 3}"#).unwrap(),
+            RecurrenceRule::EveryNDays(3)
+        );
+        // a save with a zero interval loads with the recurrence dropped instead of failing
+        let mut card = Card::default();
+        card.recurrence = Some(RecurrenceRule::EveryNDays(3));
+        let mut card_json = serde_json::to_value(&card).unwrap();
+        card_json["recurrence"] = serde_json::json!({"EveryNDays": 0});
+        let loaded_card = Card::from_json(&card_json).expect("card should still load");
+        assert_eq!(loaded_card.recurrence, None);
+    }
+}
@@ -1,24 +1,35 @@
 use self::{
+    agenda::{collect_agenda_entries, AgendaEntry},
     app_helper::{
         handle_edit_keybinding_mode, handle_general_actions, handle_mouse_action,
         handle_user_input_mode, prepare_config_for_new_app,
     },
-    kanban::{Board, Boards, Card, CardPriority},
+    kanban::{
+        detect_attachment_urls, parse_due_date, parse_timestamp, ArchivedCard, BlockedByLink,
+        Board, BoardTemplate, Boards, Card, CardAttachment, CardPriority, CardSortMode,
+        CardTemplate, SwimlaneGrouping, TimeReportRange,
+    },
+    reminders::{ReminderAlert, ReminderLog},
+    search::SearchIndex,
+    sprint::{Sprint, SprintChartMode, SprintDay},
     state::{AppStatus, Focus, KeyBindings, UiMode},
+    stats::CardStatistics,
 };
 use crate::{
     app::{actions::Action, kanban::CardStatus, state::KeyBindingEnum},
     constants::{
-        DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_TICKRATE, DEFAULT_TOAST_DURATION,
-        DEFAULT_UI_MODE, FIELD_NA, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, MAX_NO_BOARDS_PER_PAGE,
-        MAX_NO_CARDS_PER_BOARD, MAX_TICKRATE, MAX_WARNING_DUE_DATE_DAYS, MIN_NO_BOARDS_PER_PAGE,
-        MIN_NO_CARDS_PER_BOARD, MIN_TICKRATE, MIN_WARNING_DUE_DATE_DAYS,
+        DEFAULT_AUTO_ARCHIVE_DAYS, DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_REMINDER_HOURS,
+        DEFAULT_TICKRATE, DEFAULT_TOAST_DURATION, DEFAULT_UI_MODE, DEFAULT_WORKSPACE_NAME,
+        FIELD_NA, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, MAX_AUTO_ARCHIVE_DAYS, MAX_NO_BOARDS_PER_PAGE,
+        MAX_NO_CARDS_PER_BOARD, MAX_REMINDER_HOURS, MAX_TICKRATE, MAX_WARNING_DUE_DATE_DAYS,
+        MIN_NO_BOARDS_PER_PAGE, MIN_NO_CARDS_PER_BOARD, MIN_TICKRATE, MIN_WARNING_DUE_DATE_DAYS,
         MOUSE_OUT_OF_BOUNDS_COORDINATES, NO_OF_BOARDS_PER_PAGE, NO_OF_CARDS_PER_BOARD,
+        WORKSPACE_DIR_NAME,
     },
     inputs::{key::Key, mouse::Mouse},
     io::{
         data_handler::{self, get_available_local_save_files, get_default_save_directory},
-        io_handler::{refresh_visible_boards_and_cards, CloudData},
+        io_handler::{make_file_system_safe_name, refresh_visible_boards_and_cards, CloudData},
         logger::{get_logs, RUST_KANBAN_LOGGER},
         IoEvent,
     },
@@ -29,7 +40,7 @@ use crate::{
         TextColorOptions, TextModifierOptions, Theme,
     },
 };
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{Datelike, Local, Months, NaiveDate, NaiveDateTime, NaiveTime};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error};
 use ratatui::{
@@ -39,6 +50,8 @@ use ratatui::{
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    env,
     fmt::{self, Display, Formatter},
     path::PathBuf,
     str::FromStr,
@@ -49,9 +62,15 @@ use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
 pub mod actions;
+pub mod agenda;
 pub mod app_helper;
 pub mod kanban;
+pub mod query;
+pub mod reminders;
+pub mod search;
+pub mod sprint;
 pub mod state;
+pub mod stats;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum AppReturn {
@@ -61,12 +80,12 @@ pub enum AppReturn {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ActionHistory {
-    /// card, board_id
-    DeleteCard(Card, (u64, u64)),
+    /// card, board_id, removed_blocked_by_links
+    DeleteCard(Card, (u64, u64), Vec<BlockedByLink>),
     /// card, board_id
     CreateCard(Card, (u64, u64)),
-    /// board
-    DeleteBoard(Board),
+    /// board, removed_blocked_by_links
+    DeleteBoard(Board, Vec<BlockedByLink>),
     /// card, moved_from_board_id, moved_to_board_id, moved_from_index, moved_to_index
     MoveCardBetweenBoards(Card, (u64, u64), (u64, u64), usize, usize),
     /// board_id, moved_from_index, moved_to_index
@@ -74,7 +93,13 @@ pub enum ActionHistory {
     /// board
     CreateBoard(Board),
     /// old_card, new_card, board_id
-    EditCard(Card, Card, (u64, u64)),
+    EditCard(Box<Card>, Box<Card>, (u64, u64)),
+    /// old_board, new_board
+    EditBoard(Box<Board>, Box<Board>),
+    /// archived_card, board_id
+    ArchiveCard(Box<ArchivedCard>, (u64, u64)),
+    /// archived_card, board_id
+    RestoreArchivedCard(Box<ArchivedCard>, (u64, u64)),
 }
 
 #[derive(Default)]
@@ -130,6 +155,11 @@ pub struct App<'a> {
     pub action_history_manager: ActionHistoryManager,
     pub main_menu: MainMenu,
     pub widgets: Widgets,
+    pub workspace_sessions: HashMap<String, WorkspaceSession>,
+    pub workspace_boards: Vec<(String, Boards)>,
+    pub board_templates: Vec<BoardTemplate>,
+    pub card_templates: Vec<CardTemplate>,
+    pub search_index: SearchIndex,
 }
 
 impl App<'_> {
@@ -142,7 +172,7 @@ impl App<'_> {
         let all_themes = Theme::all_default_themes();
         let mut theme = Theme::default();
         let (config, config_errors, toasts) = prepare_config_for_new_app(theme.clone());
-        let default_theme = config.default_theme.clone();
+        let default_theme = config.get_theme_name();
         let theme_in_all = all_themes.iter().find(|t| t.name == default_theme);
         if let Some(theme_in_all) = theme_in_all {
             theme = theme_in_all.clone();
@@ -166,6 +196,11 @@ impl App<'_> {
             action_history_manager: ActionHistoryManager::default(),
             main_menu: MainMenu::default(),
             widgets,
+            workspace_sessions: HashMap::new(),
+            workspace_boards: Vec::new(),
+            board_templates: Vec::new(),
+            card_templates: Vec::new(),
+            search_index: SearchIndex::default(),
         };
         if !config_errors.is_empty() {
             for error in config_errors {
@@ -450,10 +485,18 @@ impl App<'_> {
         };
         self.state.app_table_states.help.select(Some(i));
     }
+    /// the change ui mode popup shares the list state but only lists the board views
+    fn default_view_list_len(&self) -> usize {
+        if self.state.popup_mode == Some(PopupMode::ChangeUIMode) {
+            UiMode::view_modes_as_string().len()
+        } else {
+            UiMode::default_view_modes_as_string().len()
+        }
+    }
     pub fn select_default_view_next(&mut self) {
         let i = match self.state.app_list_states.default_view.selected() {
             Some(i) => {
-                if i >= UiMode::view_modes_as_string().len() - 1 {
+                if i >= self.default_view_list_len() - 1 {
                     0
                 } else {
                     i + 1
@@ -467,7 +510,7 @@ impl App<'_> {
         let i = match self.state.app_list_states.default_view.selected() {
             Some(i) => {
                 if i == 0 {
-                    UiMode::view_modes_as_string().len() - 1
+                    self.default_view_list_len() - 1
                 } else {
                     i - 1
                 }
@@ -977,6 +1020,844 @@ impl App<'_> {
             .card_priority_selector
             .select(Some(i));
     }
+    /// board whose custom fields are used for the card being viewed or edited
+    pub fn get_custom_field_board(&self) -> Option<&Board> {
+        let board_id = if let Some((board_id, _)) = &self.state.card_being_edited {
+            Some(*board_id)
+        } else {
+            self.state.current_board_id
+        };
+        board_id.and_then(|board_id| self.boards.get_board_with_id(board_id))
+    }
+    /// the card shown in the card view, including unsaved edits
+    pub fn get_viewed_card(&self) -> Option<Card> {
+        if let Some((_, card)) = &self.state.card_being_edited {
+            return Some(card.clone());
+        }
+        self.state
+            .current_card_id
+            .and_then(|card_id| self.boards.get_card_with_id(card_id))
+            .cloned()
+    }
+    /// attachments of the viewed card followed by urls in its description that are not attached yet
+    pub fn get_card_attachment_entries(&self) -> Vec<CardAttachmentEntry> {
+        let card = if let Some(card) = self.get_viewed_card() {
+            card
+        } else {
+            return Vec::new();
+        };
+        let mut entries = card
+            .attachments
+            .iter()
+            .cloned()
+            .map(CardAttachmentEntry::Attachment)
+            .collect::<Vec<CardAttachmentEntry>>();
+        entries.extend(
+            detect_attachment_urls(&card.description, &card.attachments)
+                .into_iter()
+                .map(CardAttachmentEntry::DetectedUrl),
+        );
+        entries
+    }
+    pub fn card_attachment_list_next(&mut self) {
+        let entries_len = self.get_card_attachment_entries().len();
+        if entries_len > 0 {
+            let i = match self.state.app_list_states.card_attachment_list.selected() {
+                Some(i) => {
+                    if i >= entries_len - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.state
+                .app_list_states
+                .card_attachment_list
+                .select(Some(i));
+        }
+    }
+    pub fn card_attachment_list_prv(&mut self) {
+        let entries_len = self.get_card_attachment_entries().len();
+        if entries_len > 0 {
+            let i = match self.state.app_list_states.card_attachment_list.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        entries_len - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => entries_len - 1,
+            };
+            self.state
+                .app_list_states
+                .card_attachment_list
+                .select(Some(i));
+        }
+    }
+    pub fn custom_field_selector_next(&mut self) {
+        let custom_fields_len = self
+            .get_custom_field_board()
+            .map(|board| board.custom_fields.len())
+            .unwrap_or(0);
+        if custom_fields_len > 0 {
+            let i = match self.state.app_list_states.custom_field_selector.selected() {
+                Some(i) => {
+                    if i >= custom_fields_len - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.state
+                .app_list_states
+                .custom_field_selector
+                .select(Some(i));
+        }
+    }
+    pub fn custom_field_selector_prv(&mut self) {
+        let custom_fields_len = self
+            .get_custom_field_board()
+            .map(|board| board.custom_fields.len())
+            .unwrap_or(0);
+        if custom_fields_len > 0 {
+            let i = match self.state.app_list_states.custom_field_selector.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        custom_fields_len - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            self.state
+                .app_list_states
+                .custom_field_selector
+                .select(Some(i));
+        }
+    }
+    /// cards with a due date between start and end (inclusive) as (board_id, card), ordered by due time then name
+    pub fn get_cards_due_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> BTreeMap<NaiveDate, CalendarDayCards> {
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        let mut cards_by_date: BTreeMap<NaiveDate, CalendarDayCards> = BTreeMap::new();
+        for board in boards.get_boards() {
+            for card in board.cards.get_all_cards() {
+                if let Some((due_date, _)) = parse_due_date(&card.due_date) {
+                    if due_date.date() >= start && due_date.date() <= end {
+                        cards_by_date
+                            .entry(due_date.date())
+                            .or_default()
+                            .push((board.id, card.clone()));
+                    }
+                }
+            }
+        }
+        for cards in cards_by_date.values_mut() {
+            cards.sort_by_cached_key(|(_, card)| {
+                (
+                    parse_due_date(&card.due_date).map(|(due_date, _)| due_date),
+                    card.name.clone(),
+                )
+            });
+        }
+        cards_by_date
+    }
+    pub fn get_calendar_day_cards(&self, date: NaiveDate) -> CalendarDayCards {
+        self.get_cards_due_between(date, date)
+            .remove(&date)
+            .unwrap_or_default()
+    }
+    pub fn calendar_move_days(&mut self, days: i64) {
+        self.state.calendar.selected_date += chrono::Duration::days(days);
+        self.state.calendar.selected_card_index = 0;
+    }
+    pub fn calendar_card_next(&mut self) {
+        let selected_date = self.state.calendar.selected_date;
+        let day_cards_len = self.get_calendar_day_cards(selected_date).len();
+        if day_cards_len == 0 {
+            return;
+        }
+        let i = self.state.calendar.selected_card_index;
+        self.state.calendar.selected_card_index = if i >= day_cards_len - 1 { 0 } else { i + 1 };
+    }
+    pub fn calendar_card_prv(&mut self) {
+        let selected_date = self.state.calendar.selected_date;
+        let day_cards_len = self.get_calendar_day_cards(selected_date).len();
+        if day_cards_len == 0 {
+            return;
+        }
+        let i = self.state.calendar.selected_card_index;
+        self.state.calendar.selected_card_index = if i == 0 || i >= day_cards_len {
+            day_cards_len - 1
+        } else {
+            i - 1
+        };
+    }
+    /// cards with a due date grouped by board, a card starts on its start date or when it was created
+    pub fn get_timeline_entries(&self) -> Vec<TimelineEntry> {
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        let mut entries = vec![];
+        for board in boards.get_boards() {
+            let mut board_entries = board
+                .cards
+                .get_all_cards()
+                .iter()
+                .filter_map(|card| {
+                    let (due, date_format) = parse_due_date(&card.due_date)?;
+                    let start = card
+                        .start_date
+                        .as_ref()
+                        .and_then(|start_date| parse_due_date(start_date))
+                        .map(|(start, _)| start)
+                        .or_else(|| {
+                            parse_timestamp(&card.date_created).map(|date_created| {
+                                date_created.with_timezone(&Local).naive_local()
+                            })
+                        })
+                        .unwrap_or(due)
+                        .min(due);
+                    Some(TimelineEntry {
+                        board_id: board.id,
+                        card: card.clone(),
+                        start,
+                        due,
+                        date_format,
+                    })
+                })
+                .collect::<Vec<TimelineEntry>>();
+            board_entries.sort_by(|a, b| {
+                a.start
+                    .cmp(&b.start)
+                    .then_with(|| a.due.cmp(&b.due))
+                    .then_with(|| a.card.name.cmp(&b.card.name))
+            });
+            entries.extend(board_entries);
+        }
+        entries
+    }
+    pub fn timeline_next(&mut self) {
+        let entries_len = self.get_timeline_entries().len();
+        if entries_len == 0 {
+            return;
+        }
+        let i = self.state.timeline.selected_entry;
+        self.state.timeline.selected_entry = if i >= entries_len - 1 { 0 } else { i + 1 };
+        self.timeline_scroll_to_selected();
+    }
+    pub fn timeline_prv(&mut self) {
+        let entries_len = self.get_timeline_entries().len();
+        if entries_len == 0 {
+            return;
+        }
+        let i = self.state.timeline.selected_entry;
+        self.state.timeline.selected_entry = if i == 0 || i >= entries_len {
+            entries_len - 1
+        } else {
+            i - 1
+        };
+        self.timeline_scroll_to_selected();
+    }
+    /// pans the timeline so the due date of the selected card is on screen
+    pub fn timeline_scroll_to_selected(&mut self) {
+        let entries = self.get_timeline_entries();
+        let timeline = &mut self.state.timeline;
+        if let Some(entry) = entries.get(timeline.selected_entry) {
+            let due_column = timeline.zoom.column_of(timeline.origin, entry.due.date());
+            if due_column < 0 || due_column >= timeline.visible_columns {
+                timeline.origin = timeline.zoom.shift(
+                    timeline.zoom.align(entry.due.date()),
+                    -(timeline.visible_columns / 2),
+                );
+            }
+        }
+    }
+    pub fn timeline_pan(&mut self, columns: i64) {
+        let timeline = &mut self.state.timeline;
+        timeline.origin = timeline.zoom.shift(timeline.origin, columns);
+    }
+    /// one row per card in board order, sorted by the card table sort column
+    pub fn get_card_table_rows(&self) -> Vec<CardTableRow> {
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        let mut rows = boards
+            .get_boards()
+            .iter()
+            .flat_map(|board| {
+                board.cards.get_all_cards().iter().map(|card| CardTableRow {
+                    board_id: board.id,
+                    board_name: board.name.clone(),
+                    card: card.clone(),
+                })
+            })
+            .collect::<Vec<CardTableRow>>();
+        // stable sorts, rows that compare equal keep the board order
+        match self.state.card_table.sort_column {
+            CardTableColumn::Board => {}
+            CardTableColumn::Name => rows.sort_by_key(|row| row.card.name.to_lowercase()),
+            CardTableColumn::Status => rows.sort_by_key(|row| row.card.card_status.to_string()),
+            CardTableColumn::Priority => rows.sort_by_key(|row| match row.card.priority {
+                CardPriority::High => 0,
+                CardPriority::Medium => 1,
+                CardPriority::Low => 2,
+            }),
+            CardTableColumn::Tags => {
+                rows.sort_by_key(|row| row.card.tags.join(", ").to_lowercase())
+            }
+            CardTableColumn::Due => rows.sort_by_key(|row| {
+                let due_date = parse_due_date(&row.card.due_date).map(|(due_date, _)| due_date);
+                (due_date.is_none(), due_date)
+            }),
+            CardTableColumn::Modified => {
+                rows.sort_by_key(|row| parse_timestamp(&row.card.date_modified))
+            }
+        }
+        if self.state.card_table.sort_descending {
+            rows.reverse();
+        }
+        rows
+    }
+    pub fn card_table_next(&mut self) {
+        let rows_len = self.get_card_table_rows().len();
+        if rows_len == 0 {
+            return;
+        }
+        let i = match self.state.app_table_states.card_table.selected() {
+            Some(i) if i < rows_len - 1 => i + 1,
+            Some(_) => 0,
+            None => 0,
+        };
+        self.state.app_table_states.card_table.select(Some(i));
+    }
+    pub fn card_table_prv(&mut self) {
+        let rows_len = self.get_card_table_rows().len();
+        if rows_len == 0 {
+            return;
+        }
+        let i = match self.state.app_table_states.card_table.selected() {
+            Some(i) if i > 0 && i < rows_len => i - 1,
+            _ => rows_len - 1,
+        };
+        self.state.app_table_states.card_table.select(Some(i));
+    }
+    /// cards to look at today grouped into sections, from the filtered boards when a filter is on
+    pub fn get_agenda_entries(&self) -> Vec<AgendaEntry> {
+        let boards = if self.filtered_boards.is_empty() {
+            &self.boards
+        } else {
+            &self.filtered_boards
+        };
+        collect_agenda_entries(boards, Local::now())
+    }
+    pub fn agenda_next(&mut self) {
+        let entries_len = self.get_agenda_entries().len();
+        if entries_len == 0 {
+            return;
+        }
+        let i = match self.state.app_list_states.agenda_list.selected() {
+            Some(i) if i < entries_len - 1 => i + 1,
+            Some(_) => 0,
+            None => 0,
+        };
+        self.state.app_list_states.agenda_list.select(Some(i));
+    }
+    pub fn agenda_prv(&mut self) {
+        let entries_len = self.get_agenda_entries().len();
+        if entries_len == 0 {
+            return;
+        }
+        let i = match self.state.app_list_states.agenda_list.selected() {
+            Some(i) if i > 0 && i < entries_len => i - 1,
+            _ => entries_len - 1,
+        };
+        self.state.app_list_states.agenda_list.select(Some(i));
+    }
+    pub fn sprint_list_next(&mut self) {
+        let sprints_len = self.config.get_sprints().len();
+        if sprints_len == 0 {
+            return;
+        }
+        let i = match self.state.app_list_states.sprint_list.selected() {
+            Some(i) if i < sprints_len - 1 => i + 1,
+            Some(_) => 0,
+            None => 0,
+        };
+        self.state.app_list_states.sprint_list.select(Some(i));
+        self.refresh_sprint_progress();
+    }
+    pub fn sprint_list_prv(&mut self) {
+        let sprints_len = self.config.get_sprints().len();
+        if sprints_len == 0 {
+            return;
+        }
+        let i = match self.state.app_list_states.sprint_list.selected() {
+            Some(i) if i > 0 && i < sprints_len => i - 1,
+            _ => sprints_len - 1,
+        };
+        self.state.app_list_states.sprint_list.select(Some(i));
+        self.refresh_sprint_progress();
+    }
+    pub fn refresh_sprint_progress(&mut self) {
+        let selected_sprint = self
+            .state
+            .app_list_states
+            .sprint_list
+            .selected()
+            .and_then(|index| self.config.get_sprints().get(index));
+        self.state.sprints.progress = match selected_sprint {
+            Some(sprint) => sprint.daily_progress(
+                &self.boards,
+                &self.state.sprints.snapshots,
+                self.state.sprints.chart_mode,
+            ),
+            None => vec![],
+        };
+    }
+    pub fn statistics_table_next(&mut self) {
+        let rows_len = self
+            .state
+            .statistics
+            .as_ref()
+            .map(|statistics| statistics.by_board.len() + statistics.by_tag.len())
+            .unwrap_or(0);
+        if rows_len == 0 {
+            return;
+        }
+        let i = match self.state.app_table_states.statistics.selected() {
+            Some(i) if i < rows_len - 1 => i + 1,
+            Some(_) => 0,
+            None => 0,
+        };
+        self.state.app_table_states.statistics.select(Some(i));
+    }
+    pub fn statistics_table_prv(&mut self) {
+        let rows_len = self
+            .state
+            .statistics
+            .as_ref()
+            .map(|statistics| statistics.by_board.len() + statistics.by_tag.len())
+            .unwrap_or(0);
+        if rows_len == 0 {
+            return;
+        }
+        let i = match self.state.app_table_states.statistics.selected() {
+            Some(i) if i > 0 && i < rows_len => i - 1,
+            _ => rows_len - 1,
+        };
+        self.state.app_table_states.statistics.select(Some(i));
+    }
+    /// archived cards across all boards that match the archive search, as (board_id, board_name, archived_card)
+    pub fn get_archived_cards_matching_search(&self) -> Vec<((u64, u64), String, ArchivedCard)> {
+        let search_string = self.state.current_user_input.to_lowercase();
+        let mut archived_cards = vec![];
+        for board in self.boards.get_boards() {
+            for archived_card in board.archived_cards.iter().rev() {
+                let card = &archived_card.card;
+                let matches_search = search_string.is_empty()
+                    || card.name.to_lowercase().contains(&search_string)
+                    || card.description.to_lowercase().contains(&search_string)
+                    || board.name.to_lowercase().contains(&search_string)
+                    || card
+                        .tags
+                        .iter()
+                        .any(|tag| tag.to_lowercase().contains(&search_string));
+                if matches_search {
+                    archived_cards.push((board.id, board.name.clone(), archived_card.clone()));
+                }
+            }
+        }
+        archived_cards
+    }
+    /// workspaces whose name matches the search, followed by cards from every workspace that match it
+    pub fn get_workspace_switcher_entries(&self) -> Vec<WorkspaceSwitcherEntry> {
+        let search_string = self.state.current_user_input.trim().to_lowercase();
+        let mut entries: Vec<WorkspaceSwitcherEntry> = self
+            .config
+            .workspaces
+            .iter()
+            .filter(|workspace| {
+                search_string.is_empty() || workspace.name.to_lowercase().contains(&search_string)
+            })
+            .map(|workspace| WorkspaceSwitcherEntry::Workspace(workspace.name.clone()))
+            .collect();
+        if search_string.is_empty() {
+            return entries;
+        }
+        if self.config.get_workspace(&search_string).is_none() {
+            entries.push(WorkspaceSwitcherEntry::NewWorkspace(
+                self.state.current_user_input.trim().to_string(),
+            ));
+        }
+        let current_workspace_boards = (self.config.current_workspace.clone(), &self.boards);
+        for (workspace_name, boards) in std::iter::once(current_workspace_boards).chain(
+            self.workspace_boards
+                .iter()
+                .filter(|(name, _)| *name != self.config.current_workspace)
+                .map(|(name, boards)| (name.clone(), boards)),
+        ) {
+            for board in boards.get_boards() {
+                for card in board.cards.get_all_cards() {
+                    if card.name.to_lowercase().contains(&search_string)
+                        || card.description.to_lowercase().contains(&search_string)
+                        || card
+                            .tags
+                            .iter()
+                            .any(|tag| tag.to_lowercase().contains(&search_string))
+                    {
+                        entries.push(WorkspaceSwitcherEntry::Card {
+                            workspace: workspace_name.clone(),
+                            board_name: board.name.clone(),
+                            card_id: card.id,
+                            card_name: card.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        entries
+    }
+    /// registered labels and tags that are used on cards but not registered, sorted by name
+    pub fn get_label_manager_entries(&self) -> Vec<LabelManagerEntry> {
+        let mut entries: Vec<LabelManagerEntry> = self
+            .config
+            .labels
+            .iter()
+            .map(|label| LabelManagerEntry {
+                name: label.name.clone(),
+                label: Some(label.clone()),
+                usage: self.boards.tag_usage_count(&label.name),
+            })
+            .collect();
+        for board in self.boards.get_boards() {
+            let cards = board
+                .cards
+                .get_all_cards()
+                .iter()
+                .chain(board.archived_cards.iter().map(|archived| &archived.card));
+            for card in cards {
+                for tag in card.tags.iter() {
+                    if !entries
+                        .iter()
+                        .any(|entry| entry.name.eq_ignore_ascii_case(tag))
+                    {
+                        entries.push(LabelManagerEntry {
+                            name: tag.clone(),
+                            label: None,
+                            usage: self.boards.tag_usage_count(tag),
+                        });
+                    }
+                }
+            }
+        }
+        entries.sort_by_key(|entry| entry.name.to_lowercase());
+        entries
+    }
+    pub fn label_list_next(&mut self) {
+        let entries_len = self.get_label_manager_entries().len();
+        if entries_len > 0 {
+            let i = match self.state.app_list_states.label_list.selected() {
+                Some(i) => {
+                    if i >= entries_len - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.state.app_list_states.label_list.select(Some(i));
+        }
+    }
+    pub fn label_list_prv(&mut self) {
+        let entries_len = self.get_label_manager_entries().len();
+        if entries_len > 0 {
+            let i = match self.state.app_list_states.label_list.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        entries_len - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => entries_len - 1,
+            };
+            self.state.app_list_states.label_list.select(Some(i));
+        }
+    }
+    pub fn workspace_list_next(&mut self) {
+        let entries_len = self.get_workspace_switcher_entries().len();
+        if entries_len > 0 {
+            let i = match self.state.app_list_states.workspace_list.selected() {
+                Some(i) => {
+                    if i >= entries_len - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.state.app_list_states.workspace_list.select(Some(i));
+        }
+    }
+    pub fn workspace_list_prv(&mut self) {
+        let entries_len = self.get_workspace_switcher_entries().len();
+        if entries_len > 0 {
+            let i = match self.state.app_list_states.workspace_list.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        entries_len - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => entries_len - 1,
+            };
+            self.state.app_list_states.workspace_list.select(Some(i));
+        }
+    }
+    pub fn board_template_list_next(&mut self) {
+        let templates_len = self.board_templates.len();
+        if templates_len > 0 {
+            let i = match self.state.app_list_states.board_template_list.selected() {
+                Some(i) => {
+                    if i >= templates_len - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.state
+                .app_list_states
+                .board_template_list
+                .select(Some(i));
+        }
+    }
+    pub fn board_template_list_prv(&mut self) {
+        let templates_len = self.board_templates.len();
+        if templates_len > 0 {
+            let i = match self.state.app_list_states.board_template_list.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        templates_len - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => templates_len - 1,
+            };
+            self.state
+                .app_list_states
+                .board_template_list
+                .select(Some(i));
+        }
+    }
+    pub fn card_sort_mode_list_next(&mut self) {
+        let sort_modes_len = CardSortMode::all().len();
+        let i = match self.state.app_list_states.card_sort_mode_list.selected() {
+            Some(i) => {
+                if i >= sort_modes_len - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state
+            .app_list_states
+            .card_sort_mode_list
+            .select(Some(i));
+    }
+    pub fn card_sort_mode_list_prv(&mut self) {
+        let sort_modes_len = CardSortMode::all().len();
+        let i = match self.state.app_list_states.card_sort_mode_list.selected() {
+            Some(i) => {
+                if i == 0 {
+                    sort_modes_len - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => sort_modes_len - 1,
+        };
+        self.state
+            .app_list_states
+            .card_sort_mode_list
+            .select(Some(i));
+    }
+    pub fn saved_filter_list_next(&mut self) {
+        let saved_filters_len = self.config.saved_filters.len();
+        if saved_filters_len == 0 {
+            return;
+        }
+        let i = match self.state.app_list_states.saved_filter_list.selected() {
+            Some(i) => {
+                if i >= saved_filters_len - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.app_list_states.saved_filter_list.select(Some(i));
+    }
+    pub fn saved_filter_list_prv(&mut self) {
+        let saved_filters_len = self.config.saved_filters.len();
+        if saved_filters_len == 0 {
+            return;
+        }
+        let i = match self.state.app_list_states.saved_filter_list.selected() {
+            Some(i) => {
+                if i == 0 {
+                    saved_filters_len - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => saved_filters_len - 1,
+        };
+        self.state.app_list_states.saved_filter_list.select(Some(i));
+    }
+    /// the first entry of the card template list is always the blank card or no default
+    pub fn card_template_list_next(&mut self) {
+        let entries_len = self.card_templates.len() + 1;
+        let i = match self.state.app_list_states.card_template_list.selected() {
+            Some(i) => {
+                if i >= entries_len - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state
+            .app_list_states
+            .card_template_list
+            .select(Some(i));
+    }
+    pub fn card_template_list_prv(&mut self) {
+        let entries_len = self.card_templates.len() + 1;
+        let i = match self.state.app_list_states.card_template_list.selected() {
+            Some(i) => {
+                if i == 0 {
+                    entries_len - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => entries_len - 1,
+        };
+        self.state
+            .app_list_states
+            .card_template_list
+            .select(Some(i));
+    }
+    pub fn archived_card_list_next(&mut self) {
+        let archived_cards_len = self.get_archived_cards_matching_search().len();
+        if archived_cards_len > 0 {
+            let i = match self.state.app_list_states.archived_card_list.selected() {
+                Some(i) => {
+                    if i >= archived_cards_len - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.state
+                .app_list_states
+                .archived_card_list
+                .select(Some(i));
+        }
+    }
+    pub fn archived_card_list_prv(&mut self) {
+        let archived_cards_len = self.get_archived_cards_matching_search().len();
+        if archived_cards_len > 0 {
+            let i = match self.state.app_list_states.archived_card_list.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        archived_cards_len - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            self.state
+                .app_list_states
+                .archived_card_list
+                .select(Some(i));
+        }
+    }
+    fn current_card_activity_log_len(&self) -> usize {
+        self.state
+            .current_card_id
+            .and_then(|card_id| self.boards.get_card_with_id(card_id))
+            .map_or(0, |card| card.activity_log.len())
+    }
+    pub fn card_activity_list_next(&mut self) {
+        let activity_log_len = self.current_card_activity_log_len();
+        if activity_log_len > 0 {
+            let i = match self.state.app_list_states.card_activity_list.selected() {
+                Some(i) => {
+                    if i >= activity_log_len - 1 {
+                        0
+                    } else {
+                        i + 1
+                    }
+                }
+                None => 0,
+            };
+            self.state
+                .app_list_states
+                .card_activity_list
+                .select(Some(i));
+        }
+    }
+    pub fn card_activity_list_prv(&mut self) {
+        let activity_log_len = self.current_card_activity_log_len();
+        if activity_log_len > 0 {
+            let i = match self.state.app_list_states.card_activity_list.selected() {
+                Some(i) => {
+                    if i == 0 {
+                        activity_log_len - 1
+                    } else {
+                        i - 1
+                    }
+                }
+                None => 0,
+            };
+            self.state
+                .app_list_states
+                .card_activity_list
+                .select(Some(i));
+        }
+    }
     pub fn filter_by_tag_popup_next(&mut self) {
         let all_tags_len = if self.state.all_available_tags.is_some() {
             self.state.all_available_tags.clone().unwrap().len()
@@ -1062,9 +1943,10 @@ impl App<'_> {
             let history_index = self.action_history_manager.history_index - 1;
             let history = self.action_history_manager.history[history_index].clone();
             match history {
-                ActionHistory::DeleteCard(card, board_id) => {
+                ActionHistory::DeleteCard(card, board_id, removed_links) => {
                     if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
                         board.cards.add_card(card.clone());
+                        self.boards.restore_blocked_by_links(&removed_links);
                         self.action_history_manager.history_index -= 1;
                         refresh_visible_boards_and_cards(self);
                         self.send_info_toast(&format!("Undo Delete Card '{}'", card.name), None);
@@ -1154,8 +2036,9 @@ impl App<'_> {
                         self.send_error_toast(&format!("Could not undo move card '{}' as the board with id '{:?}' was not found",FIELD_NA, board_id), None);
                     }
                 }
-                ActionHistory::DeleteBoard(board) => {
+                ActionHistory::DeleteBoard(board, removed_links) => {
                     self.boards.add_board(board.clone());
+                    self.boards.restore_blocked_by_links(&removed_links);
                     refresh_visible_boards_and_cards(self);
                     self.action_history_manager.history_index -= 1;
                     self.send_info_toast(&format!("Undo Delete Board '{}'", board.name), None);
@@ -1166,12 +2049,25 @@ impl App<'_> {
                     self.action_history_manager.history_index -= 1;
                     self.send_info_toast(&format!("Undo Create Board '{}'", board.name), None);
                 }
+                ActionHistory::EditBoard(old_board, _) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(old_board.id) {
+                        *board = *old_board.clone();
+                        refresh_visible_boards_and_cards(self);
+                        self.action_history_manager.history_index -= 1;
+                        self.send_info_toast(
+                            &format!("Undo Edit Board '{}'", old_board.name),
+                            None,
+                        );
+                    } else {
+                        self.send_error_toast(&format!("Could not undo edit board '{}' as the board with id '{:?}' was not found", old_board.name, old_board.id), None);
+                    }
+                }
                 ActionHistory::EditCard(old_card, _, board_id) => {
                     let mut card_name = String::new();
                     let mut card_found = false;
                     if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
                         if let Some(card) = board.cards.get_mut_card_with_id(old_card.id) {
-                            *card = old_card.clone();
+                            *card = *old_card;
                             card_name = card.name.clone();
                             card_found = true;
                         } else {
@@ -1189,9 +2085,35 @@ impl App<'_> {
                     if card_found {
                         self.action_history_manager.history_index -= 1;
                     }
-                    if !card_name.is_empty() {
-                        self.send_info_toast(&format!("Undo Edit Card '{}'", card_name), None);
+                    if !card_name.is_empty() {
+                        self.send_info_toast(&format!("Undo Edit Card '{}'", card_name), None);
+                        refresh_visible_boards_and_cards(self);
+                    }
+                }
+                ActionHistory::ArchiveCard(archived_card, board_id) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
+                        board.restore_archived_card(archived_card.card.id);
+                        refresh_visible_boards_and_cards(self);
+                        self.action_history_manager.history_index -= 1;
+                        self.send_info_toast(
+                            &format!("Undo Archive Card '{}'", archived_card.card.name),
+                            None,
+                        );
+                    } else {
+                        self.send_error_toast(&format!("Could not undo archive card '{}' as the board with id '{:?}' was not found", archived_card.card.name, board_id), None);
+                    }
+                }
+                ActionHistory::RestoreArchivedCard(archived_card, board_id) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
+                        board.archive_card(archived_card.card.id);
                         refresh_visible_boards_and_cards(self);
+                        self.action_history_manager.history_index -= 1;
+                        self.send_info_toast(
+                            &format!("Undo Restore Card '{}'", archived_card.card.name),
+                            None,
+                        );
+                    } else {
+                        self.send_error_toast(&format!("Could not undo restore card '{}' as the board with id '{:?}' was not found", archived_card.card.name, board_id), None);
                     }
                 }
             }
@@ -1205,9 +2127,10 @@ impl App<'_> {
             let history_index = self.action_history_manager.history_index;
             let history = self.action_history_manager.history[history_index].clone();
             match history {
-                ActionHistory::DeleteCard(card, board_id) => {
+                ActionHistory::DeleteCard(card, board_id, _) => {
                     if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
                         board.cards.remove_card_with_id(card.id);
+                        self.boards.remove_blocked_by_links(card.id);
                         refresh_visible_boards_and_cards(self);
                         self.action_history_manager.history_index += 1;
                         self.send_info_toast(&format!("Redo Delete Card '{}'", card.name), None);
@@ -1298,8 +2221,11 @@ impl App<'_> {
                         self.send_error_toast(&format!("Could not redo move card '{}' as the board with id '{:?}' was not found", FIELD_NA, board_id), None);
                     }
                 }
-                ActionHistory::DeleteBoard(board) => {
+                ActionHistory::DeleteBoard(board, _) => {
                     self.boards.remove_board_with_id(board.id);
+                    for card_id in board.cards.get_all_card_ids() {
+                        self.boards.remove_blocked_by_links(card_id);
+                    }
                     refresh_visible_boards_and_cards(self);
                     self.action_history_manager.history_index += 1;
                     self.send_info_toast(&format!("Redo Delete Board '{}'", board.name), None);
@@ -1310,12 +2236,25 @@ impl App<'_> {
                     self.action_history_manager.history_index += 1;
                     self.send_info_toast(&format!("Redo Create Board '{}'", board.name), None);
                 }
+                ActionHistory::EditBoard(_, new_board) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(new_board.id) {
+                        *board = *new_board.clone();
+                        refresh_visible_boards_and_cards(self);
+                        self.action_history_manager.history_index += 1;
+                        self.send_info_toast(
+                            &format!("Redo Edit Board '{}'", new_board.name),
+                            None,
+                        );
+                    } else {
+                        self.send_error_toast(&format!("Could not redo edit board '{}' as the board with id '{:?}' was not found", new_board.name, new_board.id), None);
+                    }
+                }
                 ActionHistory::EditCard(_, new_card, board_id) => {
                     let mut card_name = String::new();
                     let mut card_found = false;
                     if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
                         if let Some(card) = board.cards.get_mut_card_with_id(new_card.id) {
-                            *card = new_card.clone();
+                            *card = *new_card;
                             card_name = card.name.clone();
                             card_found = true;
                         } else {
@@ -1338,6 +2277,32 @@ impl App<'_> {
                         refresh_visible_boards_and_cards(self);
                     }
                 }
+                ActionHistory::ArchiveCard(archived_card, board_id) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
+                        board.archive_card(archived_card.card.id);
+                        refresh_visible_boards_and_cards(self);
+                        self.action_history_manager.history_index += 1;
+                        self.send_info_toast(
+                            &format!("Redo Archive Card '{}'", archived_card.card.name),
+                            None,
+                        );
+                    } else {
+                        self.send_error_toast(&format!("Could not redo archive card '{}' as the board with id '{:?}' was not found", archived_card.card.name, board_id), None);
+                    }
+                }
+                ActionHistory::RestoreArchivedCard(archived_card, board_id) => {
+                    if let Some(board) = self.boards.get_mut_board_with_id(board_id) {
+                        board.restore_archived_card(archived_card.card.id);
+                        refresh_visible_boards_and_cards(self);
+                        self.action_history_manager.history_index += 1;
+                        self.send_info_toast(
+                            &format!("Redo Restore Card '{}'", archived_card.card.name),
+                            None,
+                        );
+                    } else {
+                        self.send_error_toast(&format!("Could not redo restore card '{}' as the board with id '{:?}' was not found", archived_card.card.name, board_id), None);
+                    }
+                }
             }
         }
     }
@@ -1376,6 +2341,7 @@ impl App<'_> {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum MainMenuItem {
     View,
+    Workspaces,
     Config,
     Help,
     LoadSaveLocal,
@@ -1387,6 +2353,7 @@ impl Display for MainMenuItem {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match *self {
             MainMenuItem::View => write!(f, "View your Boards"),
+            MainMenuItem::Workspaces => write!(f, "Switch Workspace"),
             MainMenuItem::Config => write!(f, "Configure"),
             MainMenuItem::Help => write!(f, "Help"),
             MainMenuItem::LoadSaveLocal => write!(f, "Load a Save (local)"),
@@ -1407,6 +2374,7 @@ impl Default for MainMenu {
         MainMenu {
             items: vec![
                 MainMenuItem::View,
+                MainMenuItem::Workspaces,
                 MainMenuItem::Config,
                 MainMenuItem::Help,
                 MainMenuItem::LoadSaveLocal,
@@ -1422,6 +2390,7 @@ impl MainMenu {
         if self.logged_in {
             let return_vec = vec![
                 MainMenuItem::View,
+                MainMenuItem::Workspaces,
                 MainMenuItem::Config,
                 MainMenuItem::Help,
                 MainMenuItem::LoadSaveLocal,
@@ -1433,6 +2402,7 @@ impl MainMenu {
         } else {
             let return_vec = vec![
                 MainMenuItem::View,
+                MainMenuItem::Workspaces,
                 MainMenuItem::Config,
                 MainMenuItem::Help,
                 MainMenuItem::LoadSaveLocal,
@@ -1447,20 +2417,22 @@ impl MainMenu {
         if self.logged_in {
             match index {
                 0 => MainMenuItem::View,
-                1 => MainMenuItem::Config,
-                2 => MainMenuItem::Help,
-                3 => MainMenuItem::LoadSaveLocal,
-                4 => MainMenuItem::LoadSaveCloud,
-                5 => MainMenuItem::Quit,
+                1 => MainMenuItem::Workspaces,
+                2 => MainMenuItem::Config,
+                3 => MainMenuItem::Help,
+                4 => MainMenuItem::LoadSaveLocal,
+                5 => MainMenuItem::LoadSaveCloud,
+                6 => MainMenuItem::Quit,
                 _ => MainMenuItem::Quit,
             }
         } else {
             match index {
                 0 => MainMenuItem::View,
-                1 => MainMenuItem::Config,
-                2 => MainMenuItem::Help,
-                3 => MainMenuItem::LoadSaveLocal,
-                4 => MainMenuItem::Quit,
+                1 => MainMenuItem::Workspaces,
+                2 => MainMenuItem::Config,
+                3 => MainMenuItem::Help,
+                4 => MainMenuItem::LoadSaveLocal,
+                5 => MainMenuItem::Quit,
                 _ => MainMenuItem::Quit,
             }
         }
@@ -1485,6 +2457,23 @@ pub enum PopupMode {
     ConfirmDiscardCardChanges,
     CardPrioritySelector,
     FilterByTag,
+    TimeReport,
+    EditCardCustomFields,
+    CustomFieldDefinitionPrompt,
+    CardRecurrencePrompt,
+    CardActivityLog,
+    SwimlaneGroupingPrompt,
+    SelectBoardTemplate,
+    SaveBoardTemplatePrompt,
+    SelectCardTemplate,
+    SelectDefaultCardTemplate,
+    CardAttachments,
+    SelectCardSortMode,
+    FilterByQueryPrompt,
+    SelectSavedFilter,
+    SaveFilterPrompt,
+    SprintPrompt,
+    TimeReportRangePrompt,
 }
 
 impl Display for PopupMode {
@@ -1506,6 +2495,23 @@ impl Display for PopupMode {
             PopupMode::ConfirmDiscardCardChanges => write!(f, "Confirm Discard Card Changes"),
             PopupMode::CardPrioritySelector => write!(f, "Change Card Priority"),
             PopupMode::FilterByTag => write!(f, "Filter By Tag"),
+            PopupMode::TimeReport => write!(f, "Time Report"),
+            PopupMode::EditCardCustomFields => write!(f, "Edit Card Custom Fields"),
+            PopupMode::CustomFieldDefinitionPrompt => write!(f, "Custom Field Definition Prompt"),
+            PopupMode::CardRecurrencePrompt => write!(f, "Card Recurrence Prompt"),
+            PopupMode::CardActivityLog => write!(f, "Card Activity Log"),
+            PopupMode::SwimlaneGroupingPrompt => write!(f, "Swimlane Grouping Prompt"),
+            PopupMode::SelectBoardTemplate => write!(f, "Select Board Template"),
+            PopupMode::SaveBoardTemplatePrompt => write!(f, "Save Board Template Prompt"),
+            PopupMode::SelectCardTemplate => write!(f, "Select Card Template"),
+            PopupMode::SelectDefaultCardTemplate => write!(f, "Select Default Card Template"),
+            PopupMode::CardAttachments => write!(f, "Card Attachments"),
+            PopupMode::SelectCardSortMode => write!(f, "Select Card Sort Mode"),
+            PopupMode::FilterByQueryPrompt => write!(f, "Filter By Query Prompt"),
+            PopupMode::SelectSavedFilter => write!(f, "Select Saved Filter"),
+            PopupMode::SaveFilterPrompt => write!(f, "Save Filter Prompt"),
+            PopupMode::SprintPrompt => write!(f, "Sprint Prompt"),
+            PopupMode::TimeReportRangePrompt => write!(f, "Time Report Range Prompt"),
         }
     }
 }
@@ -1519,6 +2525,12 @@ impl PopupMode {
                 Focus::CardDueDate,
                 Focus::CardPriority,
                 Focus::CardStatus,
+                Focus::CardBlockedBy,
+                Focus::CardEstimate,
+                Focus::CardCustomFields,
+                Focus::CardRecurrence,
+                Focus::CardActivityLog,
+                Focus::CardAttachments,
                 Focus::CardTags,
                 Focus::CardComments,
                 Focus::SubmitButton,
@@ -1547,6 +2559,31 @@ impl PopupMode {
             PopupMode::ConfirmDiscardCardChanges => vec![Focus::SubmitButton, Focus::ExtraFocus],
             PopupMode::CardPrioritySelector => vec![],
             PopupMode::FilterByTag => vec![Focus::FilterByTagPopup, Focus::SubmitButton],
+            PopupMode::TimeReport => vec![],
+            PopupMode::EditCardCustomFields => vec![
+                Focus::CustomFieldSelector,
+                Focus::TextInput,
+                Focus::SubmitButton,
+            ],
+            PopupMode::CustomFieldDefinitionPrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopupMode::CardRecurrencePrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopupMode::CardActivityLog => vec![],
+            PopupMode::SwimlaneGroupingPrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopupMode::SelectBoardTemplate => vec![],
+            PopupMode::SaveBoardTemplatePrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopupMode::SelectCardTemplate => vec![],
+            PopupMode::SelectDefaultCardTemplate => vec![],
+            PopupMode::SelectCardSortMode => vec![],
+            PopupMode::FilterByQueryPrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopupMode::SelectSavedFilter => vec![],
+            PopupMode::SaveFilterPrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopupMode::SprintPrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopupMode::TimeReportRangePrompt => vec![Focus::TextInput, Focus::SubmitButton],
+            PopupMode::CardAttachments => vec![
+                Focus::CardAttachmentList,
+                Focus::TextInput,
+                Focus::SubmitButton,
+            ],
         }
     }
 
@@ -1603,20 +2640,76 @@ impl PopupMode {
             PopupMode::ChangeDateFormatPopup => {
                 ui_helper::render_change_date_format_popup(rect, app);
             }
+            PopupMode::TimeReport => {
+                ui_helper::render_time_report_popup(rect, app);
+            }
+            PopupMode::EditCardCustomFields => {
+                ui_helper::render_edit_card_custom_fields_popup(rect, app);
+            }
+            PopupMode::CustomFieldDefinitionPrompt => {
+                ui_helper::render_custom_field_definition_prompt(rect, app);
+            }
+            PopupMode::CardRecurrencePrompt => {
+                ui_helper::render_card_recurrence_prompt(rect, app);
+            }
+            PopupMode::CardActivityLog => {
+                ui_helper::render_card_activity_log_popup(rect, app);
+            }
+            PopupMode::SwimlaneGroupingPrompt => {
+                ui_helper::render_swimlane_grouping_prompt(rect, app);
+            }
+            PopupMode::SelectBoardTemplate => {
+                ui_helper::render_board_template_selector(rect, app);
+            }
+            PopupMode::SaveBoardTemplatePrompt => {
+                ui_helper::render_save_board_template_prompt(rect, app);
+            }
+            PopupMode::SelectCardTemplate | PopupMode::SelectDefaultCardTemplate => {
+                ui_helper::render_card_template_selector(rect, app);
+            }
+            PopupMode::CardAttachments => {
+                ui_helper::render_card_attachments_popup(rect, app);
+            }
+            PopupMode::SelectCardSortMode => {
+                ui_helper::render_card_sort_mode_selector(rect, app);
+            }
+            PopupMode::FilterByQueryPrompt => {
+                ui_helper::render_filter_by_query_prompt(rect, app);
+            }
+            PopupMode::SelectSavedFilter => {
+                ui_helper::render_saved_filter_selector(rect, app);
+            }
+            PopupMode::SaveFilterPrompt => {
+                ui_helper::render_save_filter_prompt(rect, app);
+            }
+            PopupMode::SprintPrompt => {
+                ui_helper::render_sprint_prompt(rect, app);
+            }
+            PopupMode::TimeReportRangePrompt => {
+                ui_helper::render_time_report_range_prompt(rect, app);
+            }
         }
     }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct AppListStates {
+    pub agenda_list: ListState,
+    pub archived_card_list: ListState,
+    pub board_template_list: ListState,
+    pub card_activity_list: ListState,
+    pub card_attachment_list: ListState,
     pub card_priority_selector: ListState,
     pub card_status_selector: ListState,
+    pub card_sort_mode_list: ListState,
+    pub card_template_list: ListState,
     pub card_view_comment_list: ListState,
     pub card_view_list: ListState,
     pub card_view_tag_list: ListState,
     pub command_palette_board_search: ListState,
     pub command_palette_card_search: ListState,
     pub command_palette_command_search: ListState,
+    pub custom_field_selector: ListState,
     pub date_format_selector: ListState,
     pub default_view: ListState,
     pub edit_specific_style: (ListState, ListState, ListState),
@@ -1624,14 +2717,20 @@ pub struct AppListStates {
     pub load_save: ListState,
     pub logs: ListState,
     pub main_menu: ListState,
+    pub label_list: ListState,
+    pub saved_filter_list: ListState,
+    pub sprint_list: ListState,
     pub theme_selector: ListState,
+    pub workspace_list: ListState,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct AppTableStates {
+    pub card_table: TableState,
     pub config: TableState,
     pub edit_keybindings: TableState,
     pub help: TableState,
+    pub statistics: TableState,
     pub theme_editor: TableState,
 }
 
@@ -1649,7 +2748,7 @@ impl Default for AppFormStates {
         AppFormStates {
             login: (vec![String::new(), String::new()], false),
             new_board: vec![String::new(), String::new()],
-            new_card: vec![String::new(), String::new(), String::new()],
+            new_card: vec![String::new(), String::new(), String::new(), String::new()],
             reset_password: (
                 vec![String::new(), String::new(), String::new(), String::new()],
                 false,
@@ -1666,7 +2765,16 @@ pub struct AppState<'a> {
     pub app_list_states: AppListStates,
     pub app_status: AppStatus,
     pub app_table_states: AppTableStates,
+    pub calendar: CalendarState,
+    pub timeline: TimelineState,
+    pub card_table: CardTableState,
+    pub agenda: AgendaState,
+    pub sprints: SprintViewState,
+    pub reminders: ReminderState,
+    /// computed when the statistics dashboard opens
+    pub statistics: Option<CardStatistics>,
     pub card_being_edited: Option<((u64, u64), Card)>, // (board_id, card)
+    pub card_dependency_picker_target: Option<(u64, u64)>, // card_id whose blockers are being picked
     pub card_description_text_buffer: Option<TextBox<'a>>,
     pub card_drag_mode: bool,
     pub cloud_data: Option<Vec<CloudData>>,
@@ -1675,21 +2783,28 @@ pub struct AppState<'a> {
     pub current_card_id: Option<(u64, u64)>,
     pub current_cursor_position: Option<usize>,
     pub current_mouse_coordinates: (u16, u16),
+    pub current_swimlane: Option<String>,
     pub current_user_input: String,
     pub debug_menu_toggled: bool,
     pub default_theme_mode: bool,
     pub edited_keybinding: Option<Vec<Key>>,
     pub encryption_key_from_arguments: Option<String>,
+    pub filter_query: Option<String>,
+    pub active_saved_filter: Option<String>,
     pub filter_tags: Option<Vec<String>>,
     pub focus: Focus,
     pub hovered_board: Option<(u64, u64)>,
     pub hovered_card_dimensions: Option<(u16, u16)>,
     pub hovered_card: Option<((u64, u64), (u64, u64))>,
+    pub hovered_swimlane: Option<String>,
+    pub label_being_edited: Option<String>,
     pub last_mouse_action: Option<Mouse>,
+    pub last_scheduled_card_update: Option<Instant>,
     pub last_reset_password_link_sent_time: Option<Instant>,
     pub last_user_input: Option<String>,
     pub mouse_focus: Option<Focus>,
     pub mouse_list_index: Option<u16>,
+    pub new_card_template: Option<CardTemplate>,
     pub no_of_cards_to_show: u16,
     pub popup_mode: Option<PopupMode>,
     pub prev_focus: Option<Focus>,
@@ -1697,8 +2812,10 @@ pub struct AppState<'a> {
     pub preview_file_name: Option<String>,
     pub preview_visible_boards_and_cards: LinkedHashMap<(u64, u64), Vec<(u64, u64)>>,
     pub previous_mouse_coordinates: (u16, u16),
+    pub swimlane_grouping: Option<SwimlaneGrouping>,
     pub term_background_color: (u8, u8, u8),
     pub theme_being_edited: Theme,
+    pub time_report_range: TimeReportRange,
     pub ui_mode: UiMode,
     pub ui_render_time: Vec<u128>,
     pub user_login_data: UserLoginData,
@@ -1706,6 +2823,300 @@ pub struct AppState<'a> {
     pub path_check_state: PathCheckState,
 }
 
+/// cards due on a calendar day as (board_id, card)
+pub type CalendarDayCards = Vec<((u64, u64), Card)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CalendarLayout {
+    #[default]
+    Month,
+    Week,
+}
+
+impl CalendarLayout {
+    pub fn toggle(&self) -> Self {
+        match self {
+            CalendarLayout::Month => CalendarLayout::Week,
+            CalendarLayout::Week => CalendarLayout::Month,
+        }
+    }
+}
+
+impl Display for CalendarLayout {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CalendarLayout::Month => write!(f, "Month"),
+            CalendarLayout::Week => write!(f, "Week"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CalendarState {
+    pub layout: CalendarLayout,
+    pub selected_date: NaiveDate,
+    /// index into the cards due on the selected date
+    pub selected_card_index: usize,
+    /// the day and card under the mouse, set while rendering
+    pub mouse_target: Option<(NaiveDate, Option<usize>)>,
+}
+
+impl Default for CalendarState {
+    fn default() -> Self {
+        Self {
+            layout: CalendarLayout::default(),
+            selected_date: Local::now().date_naive(),
+            selected_card_index: 0,
+            mouse_target: None,
+        }
+    }
+}
+
+impl CalendarState {
+    /// the days shown for the selected date, whole weeks starting on monday
+    pub fn visible_days(&self) -> Vec<NaiveDate> {
+        let first_day = match self.layout {
+            CalendarLayout::Month => self.selected_date.with_day(1).unwrap_or(self.selected_date),
+            CalendarLayout::Week => self.selected_date,
+        };
+        let first_monday =
+            first_day - chrono::Duration::days(first_day.weekday().num_days_from_monday() as i64);
+        let number_of_weeks = match self.layout {
+            CalendarLayout::Month => 6,
+            CalendarLayout::Week => 1,
+        };
+        (0..number_of_weeks * 7)
+            .map(|offset| first_monday + chrono::Duration::days(offset))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TimelineZoom {
+    #[default]
+    Days,
+    Weeks,
+    Months,
+}
+
+impl TimelineZoom {
+    pub fn next(&self) -> Self {
+        match self {
+            TimelineZoom::Days => TimelineZoom::Weeks,
+            TimelineZoom::Weeks => TimelineZoom::Months,
+            TimelineZoom::Months => TimelineZoom::Days,
+        }
+    }
+    /// characters used by one column of the timeline
+    pub fn column_width(&self) -> u16 {
+        match self {
+            TimelineZoom::Days | TimelineZoom::Weeks => 3,
+            TimelineZoom::Months => 4,
+        }
+    }
+    /// the first day of the column containing date
+    pub fn align(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            TimelineZoom::Days => date,
+            TimelineZoom::Weeks => {
+                date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            TimelineZoom::Months => date.with_day(1).unwrap_or(date),
+        }
+    }
+    pub fn shift(&self, date: NaiveDate, columns: i64) -> NaiveDate {
+        self.shift_date_time(date.and_time(NaiveTime::MIN), columns)
+            .date()
+    }
+    /// moves by whole columns, keeping the time of day
+    pub fn shift_date_time(&self, date_time: NaiveDateTime, columns: i64) -> NaiveDateTime {
+        match self {
+            TimelineZoom::Days => date_time + chrono::Duration::days(columns),
+            TimelineZoom::Weeks => date_time + chrono::Duration::weeks(columns),
+            TimelineZoom::Months => {
+                let months = Months::new(columns.unsigned_abs() as u32);
+                if columns < 0 {
+                    date_time.checked_sub_months(months)
+                } else {
+                    date_time.checked_add_months(months)
+                }
+                .unwrap_or(date_time)
+            }
+        }
+    }
+    /// the column of date when the timeline starts at origin
+    pub fn column_of(&self, origin: NaiveDate, date: NaiveDate) -> i64 {
+        match self {
+            TimelineZoom::Days => (date - origin).num_days(),
+            TimelineZoom::Weeks => (self.align(date) - self.align(origin)).num_days() / 7,
+            TimelineZoom::Months => {
+                (date.year() as i64 * 12 + date.month0() as i64)
+                    - (origin.year() as i64 * 12 + origin.month0() as i64)
+            }
+        }
+    }
+}
+
+impl Display for TimelineZoom {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TimelineZoom::Days => write!(f, "Days"),
+            TimelineZoom::Weeks => write!(f, "Weeks"),
+            TimelineZoom::Months => write!(f, "Months"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimelineHandle {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    pub board_id: (u64, u64),
+    pub card: Card,
+    pub start: NaiveDateTime,
+    pub due: NaiveDateTime,
+    /// the due date format, also used when writing an explicit start date
+    pub date_format: DateFormat,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelineState {
+    pub zoom: TimelineZoom,
+    /// the first visible column, aligned to the zoom level
+    pub origin: NaiveDate,
+    pub selected_entry: usize,
+    pub scroll_offset: usize,
+    /// columns that fit on screen, set while rendering
+    pub visible_columns: i64,
+    /// the entry and bar handle under the mouse, set while rendering
+    pub mouse_target: Option<(usize, Option<TimelineHandle>)>,
+    pub mouse_column: Option<i64>,
+    /// (card_id, handle) of the bar end being dragged
+    pub dragging: Option<((u64, u64), TimelineHandle)>,
+}
+
+impl Default for TimelineState {
+    fn default() -> Self {
+        Self {
+            zoom: TimelineZoom::default(),
+            origin: Local::now().date_naive(),
+            selected_entry: 0,
+            scroll_offset: 0,
+            visible_columns: 30,
+            mouse_target: None,
+            mouse_column: None,
+            dragging: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CardTableColumn {
+    #[default]
+    Board,
+    Name,
+    Status,
+    Priority,
+    Tags,
+    Due,
+    Modified,
+}
+
+impl CardTableColumn {
+    pub fn all() -> Vec<CardTableColumn> {
+        vec![
+            CardTableColumn::Board,
+            CardTableColumn::Name,
+            CardTableColumn::Status,
+            CardTableColumn::Priority,
+            CardTableColumn::Tags,
+            CardTableColumn::Due,
+            CardTableColumn::Modified,
+        ]
+    }
+    pub fn next(&self) -> Self {
+        let all_columns = Self::all();
+        let index = all_columns
+            .iter()
+            .position(|column| column == self)
+            .unwrap_or(0);
+        all_columns[(index + 1) % all_columns.len()]
+    }
+    pub fn prv(&self) -> Self {
+        let all_columns = Self::all();
+        let index = all_columns
+            .iter()
+            .position(|column| column == self)
+            .unwrap_or(0);
+        all_columns[(index + all_columns.len() - 1) % all_columns.len()]
+    }
+}
+
+impl Display for CardTableColumn {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CardTableColumn::Board => write!(f, "Board"),
+            CardTableColumn::Name => write!(f, "Name"),
+            CardTableColumn::Status => write!(f, "Status"),
+            CardTableColumn::Priority => write!(f, "Priority"),
+            CardTableColumn::Tags => write!(f, "Tags"),
+            CardTableColumn::Due => write!(f, "Due"),
+            CardTableColumn::Modified => write!(f, "Modified"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CardTableRow {
+    pub board_id: (u64, u64),
+    pub board_name: String,
+    pub card: Card,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CardTableState {
+    pub sort_column: CardTableColumn,
+    pub sort_descending: bool,
+    /// the column that sorting and inline edits apply to
+    pub selected_column: CardTableColumn,
+    /// cards picked for bulk edits
+    pub selected_cards: BTreeSet<(u64, u64)>,
+    /// the row and header column under the mouse, set while rendering
+    pub mouse_row: Option<usize>,
+    pub mouse_header: Option<CardTableColumn>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AgendaState {
+    /// the entry under the mouse, set while rendering
+    pub mouse_entry: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SprintViewState {
+    pub chart_mode: SprintChartMode,
+    /// loaded once when the sprint view opens since reading every save is slow
+    pub snapshots: Vec<(NaiveDate, Boards)>,
+    /// daily progress of the selected sprint
+    pub progress: Vec<SprintDay>,
+    /// the sprint the prompt closes, None when the prompt creates a new sprint
+    pub closing_sprint: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReminderState {
+    /// read from disk on the first check
+    pub log: Option<ReminderLog>,
+    pub last_check: Option<Instant>,
+    /// cards of the reminders shown by the latest check, most recent last, snoozing and
+    /// dismissing outside the card view and the agenda take them one at a time
+    pub recent_cards: Vec<(u64, u64)>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PathCheckState {
     pub path_last_checked: String,
@@ -1735,7 +3146,15 @@ impl Default for AppState<'_> {
             app_list_states: AppListStates::default(),
             app_status: AppStatus::default(),
             app_table_states: AppTableStates::default(),
+            calendar: CalendarState::default(),
+            timeline: TimelineState::default(),
+            card_table: CardTableState::default(),
+            agenda: AgendaState::default(),
+            sprints: SprintViewState::default(),
+            reminders: ReminderState::default(),
+            statistics: None,
             card_being_edited: None,
+            card_dependency_picker_target: None,
             card_description_text_buffer: None,
             card_drag_mode: false,
             cloud_data: None,
@@ -1744,21 +3163,28 @@ impl Default for AppState<'_> {
             current_card_id: None,
             current_cursor_position: None,
             current_mouse_coordinates: MOUSE_OUT_OF_BOUNDS_COORDINATES, // make sure it's out of bounds when mouse mode is disabled
+            current_swimlane: None,
             current_user_input: String::new(),
             debug_menu_toggled: false,
             default_theme_mode: false,
             edited_keybinding: None,
             encryption_key_from_arguments: None,
+            filter_query: None,
+            active_saved_filter: None,
             filter_tags: None,
             focus: Focus::NoFocus,
             hovered_board: None,
             hovered_card_dimensions: None,
             hovered_card: None,
+            hovered_swimlane: None,
+            label_being_edited: None,
             last_mouse_action: None,
+            last_scheduled_card_update: None,
             last_reset_password_link_sent_time: None,
             last_user_input: None,
             mouse_focus: None,
             mouse_list_index: None,
+            new_card_template: None,
             no_of_cards_to_show: NO_OF_CARDS_PER_BOARD,
             popup_mode: None,
             prev_focus: None,
@@ -1766,8 +3192,10 @@ impl Default for AppState<'_> {
             preview_file_name: None,
             preview_visible_boards_and_cards: LinkedHashMap::new(),
             previous_mouse_coordinates: MOUSE_OUT_OF_BOUNDS_COORDINATES,
+            swimlane_grouping: None,
             term_background_color: get_term_bg_color(),
             theme_being_edited: Theme::default(),
+            time_report_range: TimeReportRange::default(),
             ui_mode: DEFAULT_UI_MODE,
             ui_render_time: Vec::new(),
             user_login_data: UserLoginData {
@@ -1789,6 +3217,17 @@ pub struct UserLoginData {
     pub user_id: Option<String>,
 }
 
+impl UserLoginData {
+    /// name recorded in card activity logs, the logged in email or the local system user
+    pub fn activity_author(&self) -> String {
+        self.email_id
+            .clone()
+            .or_else(|| env::var("USER").ok())
+            .or_else(|| env::var("USERNAME").ok())
+            .unwrap_or("Local User".to_string())
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
 pub enum DateFormat {
     DayMonthYear,
@@ -1875,24 +3314,150 @@ impl Display for DateFormat {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Workspace {
+    pub name: String,
+    pub theme_override: Option<String>,
+    #[serde(default)]
+    pub sprints: Vec<Sprint>,
+}
+
+impl Workspace {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            theme_override: None,
+            sprints: vec![],
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Label {
+    pub name: String,
+    pub color: TextColorOptions,
+    pub description: String,
+}
+
+impl Label {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            color: TextColorOptions::None,
+            description: String::new(),
+        }
+    }
+    /// parses "name | color | description", color and description are optional
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let mut parts = spec.splitn(3, '|').map(|part| part.trim());
+        let name = parts.next().unwrap_or_default();
+        if name.is_empty() {
+            return Err("Label name cannot be empty".to_string());
+        }
+        if name.contains(char::is_whitespace) || name.contains(',') {
+            return Err("Label name cannot contain spaces or commas".to_string());
+        }
+        let color = match parts.next() {
+            Some(color) if !color.is_empty() => TextColorOptions::from_name(color)
+                .ok_or_else(|| format!("'{}' is not a valid color", color))?,
+            _ => TextColorOptions::None,
+        };
+        let description = parts.next().unwrap_or_default().to_string();
+        Ok(Self {
+            name: name.to_string(),
+            color,
+            description,
+        })
+    }
+    pub fn to_spec(&self) -> String {
+        format!("{} | {} | {}", self.name, self.color, self.description)
+    }
+}
+
+/// a named card query that can be applied from the command palette or a keybinding
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SavedFilter {
+    pub name: String,
+    pub query: String,
+}
+
+impl SavedFilter {
+    pub fn new(name: &str, query: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            query: query.to_string(),
+        }
+    }
+    pub fn default_saved_filters() -> Vec<Self> {
+        vec![
+            Self::new("My overdue", "due:overdue"),
+            Self::new("High priority bugs", "priority:high tag:bug"),
+            Self::new("Stale this week", "status:stale modified:<7d"),
+        ]
+    }
+}
+
+/// filters are kept per workspace for the session so switching back restores them
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceSession {
+    pub filter_tags: Option<Vec<String>>,
+    pub filter_query: Option<String>,
+    pub active_saved_filter: Option<String>,
+    pub filtered_boards: Boards,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CardAttachmentEntry {
+    Attachment(CardAttachment),
+    DetectedUrl(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct LabelManagerEntry {
+    pub name: String,
+    pub label: Option<Label>,
+    pub usage: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkspaceSwitcherEntry {
+    Workspace(String),
+    NewWorkspace(String),
+    Card {
+        workspace: String,
+        board_name: String,
+        card_id: (u64, u64),
+        card_name: String,
+    },
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AppConfig {
     pub always_load_last_save: bool,
+    pub auto_archive_days: u16,
     pub auto_login: bool,
+    pub current_workspace: String,
     pub date_format: DateFormat,
     pub default_theme: String,
     pub default_view: UiMode,
     pub disable_animations: bool,
     pub disable_scroll_bar: bool,
     pub enable_mouse_support: bool,
+    pub enable_reminders: bool,
     pub keybindings: KeyBindings,
     pub no_of_boards_to_show: u16,
     pub no_of_cards_to_show: u16,
+    pub reminder_alert: ReminderAlert,
+    /// 0 turns off the reminder before a card is due, due today and overdue reminders still show
+    pub reminder_hours: u16,
     pub save_directory: PathBuf,
     pub save_on_exit: bool,
     pub show_line_numbers: bool,
     pub tickrate: u16,
     pub warning_delta: u16,
+    pub workspaces: Vec<Workspace>,
+    pub labels: Vec<Label>,
+    pub saved_filters: Vec<SavedFilter>,
 }
 
 impl Default for AppConfig {
@@ -1901,26 +3466,82 @@ impl Default for AppConfig {
         let default_theme = Theme::default();
         Self {
             always_load_last_save: true,
+            auto_archive_days: DEFAULT_AUTO_ARCHIVE_DAYS,
             auto_login: true,
+            current_workspace: DEFAULT_WORKSPACE_NAME.to_string(),
             date_format: DateFormat::default(),
             default_theme: default_theme.name,
             default_view,
             disable_animations: false,
             disable_scroll_bar: false,
             enable_mouse_support: true,
+            enable_reminders: true,
             keybindings: KeyBindings::default(),
             no_of_boards_to_show: NO_OF_BOARDS_PER_PAGE,
             no_of_cards_to_show: NO_OF_CARDS_PER_BOARD,
+            reminder_alert: ReminderAlert::default(),
+            reminder_hours: DEFAULT_REMINDER_HOURS,
             save_directory: get_default_save_directory(),
             save_on_exit: true,
             show_line_numbers: true,
             tickrate: DEFAULT_TICKRATE,
             warning_delta: DEFAULT_CARD_WARNING_DUE_DATE_DAYS,
+            workspaces: vec![Workspace::new(DEFAULT_WORKSPACE_NAME)],
+            labels: vec![],
+            saved_filters: SavedFilter::default_saved_filters(),
         }
     }
 }
 
 impl AppConfig {
+    pub fn get_label(&self, name: &str) -> Option<&Label> {
+        self.labels
+            .iter()
+            .find(|label| label.name.eq_ignore_ascii_case(name))
+    }
+    pub fn get_saved_filter(&self, name: &str) -> Option<&SavedFilter> {
+        self.saved_filters
+            .iter()
+            .find(|saved_filter| saved_filter.name.eq_ignore_ascii_case(name))
+    }
+    pub fn get_current_workspace_mut(&mut self) -> Option<&mut Workspace> {
+        let current_workspace = self.current_workspace.clone();
+        self.workspaces
+            .iter_mut()
+            .find(|workspace| workspace.name == current_workspace)
+    }
+    pub fn get_sprints(&self) -> &[Sprint] {
+        self.get_workspace(&self.current_workspace)
+            .map(|workspace| workspace.sprints.as_slice())
+            .unwrap_or_default()
+    }
+    /// the most recently created sprint that is not closed yet
+    pub fn get_open_sprint_index(&self) -> Option<usize> {
+        self.get_sprints().iter().rposition(|sprint| !sprint.closed)
+    }
+    pub fn get_workspace(&self, name: &str) -> Option<&Workspace> {
+        self.workspaces
+            .iter()
+            .find(|workspace| workspace.name.eq_ignore_ascii_case(name))
+    }
+    /// the default workspace keeps using the save directory itself so older saves stay where they are
+    pub fn get_workspace_save_directory(&self, workspace_name: &str) -> PathBuf {
+        if workspace_name == DEFAULT_WORKSPACE_NAME {
+            self.save_directory.clone()
+        } else {
+            self.save_directory
+                .join(WORKSPACE_DIR_NAME)
+                .join(make_file_system_safe_name(workspace_name))
+        }
+    }
+    pub fn get_save_directory(&self) -> PathBuf {
+        self.get_workspace_save_directory(&self.current_workspace)
+    }
+    pub fn get_theme_name(&self) -> String {
+        self.get_workspace(&self.current_workspace)
+            .and_then(|workspace| workspace.theme_override.clone())
+            .unwrap_or(self.default_theme.clone())
+    }
     pub fn to_view_list(&self) -> Vec<Vec<String>> {
         // Custom ordering
         let mut view_list = ConfigEnum::iter()
@@ -1943,7 +3564,11 @@ impl AppConfig {
                     ConfigEnum::NoOfBoardsToShow => (self.no_of_boards_to_show.to_string(), 12),
                     ConfigEnum::DefaultTheme => (self.default_theme.clone(), 13),
                     ConfigEnum::DateFormat => (self.date_format.to_string(), 14),
-                    ConfigEnum::Keybindings => ("".to_string(), 15),
+                    ConfigEnum::AutoArchiveDays => (self.auto_archive_days.to_string(), 15),
+                    ConfigEnum::EnableReminders => (self.enable_reminders.to_string(), 16),
+                    ConfigEnum::ReminderHours => (self.reminder_hours.to_string(), 17),
+                    ConfigEnum::ReminderAlert => (self.reminder_alert.to_string(), 18),
+                    ConfigEnum::Keybindings => ("".to_string(), 19),
                 };
                 (enum_variant.to_string(), value.to_string(), index)
             })
@@ -1959,6 +3584,7 @@ impl AppConfig {
     pub fn get_value_as_string(&self, config_enum: ConfigEnum) -> String {
         match config_enum {
             ConfigEnum::AlwaysLoadLastSave => self.always_load_last_save.to_string(),
+            ConfigEnum::AutoArchiveDays => self.auto_archive_days.to_string(),
             ConfigEnum::AutoLogin => self.auto_login.to_string(),
             ConfigEnum::DateFormat => self.date_format.to_string(),
             ConfigEnum::DefaultTheme => self.default_theme.clone(),
@@ -1966,6 +3592,7 @@ impl AppConfig {
             ConfigEnum::DisableAnimations => self.disable_animations.to_string(),
             ConfigEnum::DisableScrollBar => self.disable_scroll_bar.to_string(),
             ConfigEnum::EnableMouseSupport => self.enable_mouse_support.to_string(),
+            ConfigEnum::EnableReminders => self.enable_reminders.to_string(),
             ConfigEnum::Keybindings => {
                 // This should never be called
                 debug!("Keybindings should not be called from get_value_as_str");
@@ -1973,6 +3600,8 @@ impl AppConfig {
             }
             ConfigEnum::NoOfBoardsToShow => self.no_of_boards_to_show.to_string(),
             ConfigEnum::NoOfCardsToShow => self.no_of_cards_to_show.to_string(),
+            ConfigEnum::ReminderAlert => self.reminder_alert.to_string(),
+            ConfigEnum::ReminderHours => self.reminder_hours.to_string(),
             ConfigEnum::SaveDirectory => self.save_directory.to_string_lossy().to_string(),
             ConfigEnum::SaveOnExit => self.save_on_exit.to_string(),
             ConfigEnum::ShowLineNumbers => self.show_line_numbers.to_string(),
@@ -1988,6 +3617,9 @@ impl AppConfig {
             ConfigEnum::DisableAnimations => (!self.disable_animations).to_string(),
             ConfigEnum::DisableScrollBar => (!self.disable_scroll_bar).to_string(),
             ConfigEnum::EnableMouseSupport => (!self.enable_mouse_support).to_string(),
+            ConfigEnum::EnableReminders => (!self.enable_reminders).to_string(),
+            // not a boolean, toggling steps through the alerts
+            ConfigEnum::ReminderAlert => self.reminder_alert.next().to_string(),
             ConfigEnum::SaveOnExit => (!self.save_on_exit).to_string(),
             ConfigEnum::ShowLineNumbers => (!self.show_line_numbers).to_string(),
             _ => {
@@ -2048,6 +3680,18 @@ impl AppConfig {
 
         match key {
             KeyBindingEnum::Accept => self.keybindings.accept = value,
+            KeyBindingEnum::ApplySavedFilter1 => {
+                self.keybindings.apply_saved_filter_1 = value;
+            }
+            KeyBindingEnum::ApplySavedFilter2 => {
+                self.keybindings.apply_saved_filter_2 = value;
+            }
+            KeyBindingEnum::ApplySavedFilter3 => {
+                self.keybindings.apply_saved_filter_3 = value;
+            }
+            KeyBindingEnum::ArchiveCard => {
+                self.keybindings.archive_card = value;
+            }
             KeyBindingEnum::ChangeCardStatusToActive => {
                 self.keybindings.change_card_status_to_active = value;
             }
@@ -2060,12 +3704,18 @@ impl AppConfig {
             KeyBindingEnum::ClearAllToasts => {
                 self.keybindings.clear_all_toasts = value;
             }
+            KeyBindingEnum::CycleTimelineZoom => {
+                self.keybindings.cycle_timeline_zoom = value;
+            }
             KeyBindingEnum::DeleteBoard => {
                 self.keybindings.delete_board = value;
             }
             KeyBindingEnum::DeleteCard => {
                 self.keybindings.delete_card = value;
             }
+            KeyBindingEnum::DismissReminder => {
+                self.keybindings.dismiss_reminder = value;
+            }
             KeyBindingEnum::Down => {
                 self.keybindings.down = value;
             }
@@ -2123,12 +3773,27 @@ impl AppConfig {
             KeyBindingEnum::SaveState => {
                 self.keybindings.save_state = value;
             }
+            KeyBindingEnum::SnoozeReminder => {
+                self.keybindings.snooze_reminder = value;
+            }
+            KeyBindingEnum::SortCardTable => {
+                self.keybindings.sort_card_table = value;
+            }
             KeyBindingEnum::StopUserInput => {
                 self.keybindings.stop_user_input = value;
             }
             KeyBindingEnum::TakeUserInput => {
                 self.keybindings.take_user_input = value;
             }
+            KeyBindingEnum::ToggleCalendarLayout => {
+                self.keybindings.toggle_calendar_layout = value;
+            }
+            KeyBindingEnum::ToggleCardSelection => {
+                self.keybindings.toggle_card_selection = value;
+            }
+            KeyBindingEnum::ToggleCardTimer => {
+                self.keybindings.toggle_card_timer = value;
+            }
             KeyBindingEnum::ToggleCommandPalette => {
                 self.keybindings.toggle_command_palette = value;
             }
@@ -2330,6 +3995,30 @@ impl AppConfig {
             Some(1),
             None,
         );
+        let auto_archive_days = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::AutoArchiveDays,
+            default_config.auto_archive_days,
+            None,
+            Some(MAX_AUTO_ARCHIVE_DAYS),
+        );
+        let enable_reminders = AppConfig::get_bool_or_default(
+            &serde_json_object,
+            ConfigEnum::EnableReminders,
+            default_config.enable_reminders,
+        );
+        let reminder_hours = AppConfig::get_u16_or_default(
+            &serde_json_object,
+            ConfigEnum::ReminderHours,
+            default_config.reminder_hours,
+            None,
+            Some(MAX_REMINDER_HOURS),
+        );
+        // reminder alerts were added later, older configs will not have them
+        let reminder_alert: ReminderAlert = serde_json_object
+            .get(ConfigEnum::ReminderAlert.to_json_key())
+            .and_then(|reminder_alert| serde_json::from_value(reminder_alert.clone()).ok())
+            .unwrap_or_default();
         let tickrate = AppConfig::get_u16_or_default(
             &serde_json_object,
             ConfigEnum::Tickrate,
@@ -2379,6 +4068,37 @@ impl AppConfig {
                 default_config.date_format
             }
         };
+        // workspaces were added later, older configs will not have them
+        let mut workspaces: Vec<Workspace> = serde_json_object
+            .get("workspaces")
+            .and_then(|workspaces| serde_json::from_value(workspaces.clone()).ok())
+            .unwrap_or_default();
+        if !workspaces
+            .iter()
+            .any(|workspace| workspace.name == DEFAULT_WORKSPACE_NAME)
+        {
+            workspaces.insert(0, Workspace::new(DEFAULT_WORKSPACE_NAME));
+        }
+        // labels were added later, older configs will not have them
+        let labels: Vec<Label> = serde_json_object
+            .get("labels")
+            .and_then(|labels| serde_json::from_value(labels.clone()).ok())
+            .unwrap_or_default();
+        // saved filters were added later, older configs get the default ones
+        let saved_filters: Vec<SavedFilter> = serde_json_object
+            .get("saved_filters")
+            .and_then(|saved_filters| serde_json::from_value(saved_filters.clone()).ok())
+            .unwrap_or_else(SavedFilter::default_saved_filters);
+        let current_workspace = match serde_json_object["current_workspace"].as_str() {
+            Some(current_workspace)
+                if workspaces
+                    .iter()
+                    .any(|workspace| workspace.name == current_workspace) =>
+            {
+                current_workspace.to_string()
+            }
+            _ => default_config.current_workspace,
+        };
         Ok(Self {
             save_directory,
             default_view,
@@ -2386,6 +4106,10 @@ impl AppConfig {
             save_on_exit,
             disable_scroll_bar,
             auto_login,
+            auto_archive_days,
+            enable_reminders,
+            reminder_hours,
+            reminder_alert,
             warning_delta,
             keybindings,
             tickrate,
@@ -2396,6 +4120,10 @@ impl AppConfig {
             date_format,
             show_line_numbers,
             disable_animations,
+            current_workspace,
+            workspaces,
+            labels,
+            saved_filters,
         })
     }
 }
@@ -2403,6 +4131,7 @@ impl AppConfig {
 #[derive(PartialEq, Copy, Clone, EnumIter)]
 pub enum ConfigEnum {
     AlwaysLoadLastSave,
+    AutoArchiveDays,
     AutoLogin,
     DateFormat,
     DefaultTheme,
@@ -2410,9 +4139,12 @@ pub enum ConfigEnum {
     DisableAnimations,
     DisableScrollBar,
     EnableMouseSupport,
+    EnableReminders,
     Keybindings,
     NoOfBoardsToShow,
     NoOfCardsToShow,
+    ReminderAlert,
+    ReminderHours,
     SaveDirectory,
     SaveOnExit,
     ShowLineNumbers,
@@ -2424,6 +4156,7 @@ impl fmt::Display for ConfigEnum {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             ConfigEnum::AlwaysLoadLastSave => write!(f, "Auto Load Last Save"),
+            ConfigEnum::AutoArchiveDays => write!(f, "Auto Archive Completed Cards After Days"),
             ConfigEnum::AutoLogin => write!(f, "Auto Login"),
             ConfigEnum::DateFormat => write!(f, "Date Format"),
             ConfigEnum::DefaultTheme => write!(f, "Default Theme"),
@@ -2431,9 +4164,12 @@ impl fmt::Display for ConfigEnum {
             ConfigEnum::DisableAnimations => write!(f, "Disable Animations"),
             ConfigEnum::DisableScrollBar => write!(f, "Disable Scroll Bar"),
             ConfigEnum::EnableMouseSupport => write!(f, "Enable Mouse Support"),
+            ConfigEnum::EnableReminders => write!(f, "Enable Due Date Reminders"),
             ConfigEnum::Keybindings => write!(f, "Edit Keybindings"),
             ConfigEnum::NoOfBoardsToShow => write!(f, "Number of Boards to Show"),
             ConfigEnum::NoOfCardsToShow => write!(f, "Number of Cards to Show"),
+            ConfigEnum::ReminderAlert => write!(f, "Reminder Alert"),
+            ConfigEnum::ReminderHours => write!(f, "Hours to Remind Before Due Date"),
             ConfigEnum::SaveDirectory => write!(f, "Save Directory"),
             ConfigEnum::SaveOnExit => write!(f, "Auto Save on Exit"),
             ConfigEnum::ShowLineNumbers => write!(f, "Show Line Numbers"),
@@ -2447,6 +4183,7 @@ impl FromStr for ConfigEnum {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "Auto Archive Completed Cards After Days" => Ok(ConfigEnum::AutoArchiveDays),
             "Auto Load Last Save" => Ok(ConfigEnum::AlwaysLoadLastSave),
             "Auto Login" => Ok(ConfigEnum::AutoLogin),
             "Auto Save on Exit" => Ok(ConfigEnum::SaveOnExit),
@@ -2455,10 +4192,13 @@ impl FromStr for ConfigEnum {
             "Disable Animations" => Ok(ConfigEnum::DisableAnimations),
             "Disable Scroll Bar" => Ok(ConfigEnum::DisableScrollBar),
             "Edit Keybindings" => Ok(ConfigEnum::Keybindings),
+            "Enable Due Date Reminders" => Ok(ConfigEnum::EnableReminders),
             "Enable Mouse Support" => Ok(ConfigEnum::EnableMouseSupport),
+            "Hours to Remind Before Due Date" => Ok(ConfigEnum::ReminderHours),
             "Number of Boards to Show" => Ok(ConfigEnum::NoOfBoardsToShow),
             "Number of Cards to Show" => Ok(ConfigEnum::NoOfCardsToShow),
             "Number of Days to Warn Before Due Date" => Ok(ConfigEnum::WarningDelta),
+            "Reminder Alert" => Ok(ConfigEnum::ReminderAlert),
             "Save Directory" => Ok(ConfigEnum::SaveDirectory),
             "Select Default View" => Ok(ConfigEnum::DefaultView),
             "Show Line Numbers" => Ok(ConfigEnum::ShowLineNumbers),
@@ -2472,6 +4212,7 @@ impl ConfigEnum {
     pub fn to_json_key(&self) -> &str {
         match self {
             ConfigEnum::AlwaysLoadLastSave => "always_load_last_save",
+            ConfigEnum::AutoArchiveDays => "auto_archive_days",
             ConfigEnum::AutoLogin => "auto_login",
             ConfigEnum::DateFormat => "date_format",
             ConfigEnum::DefaultTheme => "default_theme",
@@ -2479,9 +4220,12 @@ impl ConfigEnum {
             ConfigEnum::DisableAnimations => "disable_animations",
             ConfigEnum::DisableScrollBar => "disable_scroll_bar",
             ConfigEnum::EnableMouseSupport => "enable_mouse_support",
+            ConfigEnum::EnableReminders => "enable_reminders",
             ConfigEnum::Keybindings => "keybindings",
             ConfigEnum::NoOfBoardsToShow => "no_of_boards_to_show",
             ConfigEnum::NoOfCardsToShow => "no_of_cards_to_show",
+            ConfigEnum::ReminderAlert => "reminder_alert",
+            ConfigEnum::ReminderHours => "reminder_hours",
             ConfigEnum::SaveDirectory => "save_directory",
             ConfigEnum::SaveOnExit => "save_on_exit",
             ConfigEnum::ShowLineNumbers => "show_line_numbers",
@@ -2513,6 +4257,7 @@ impl ConfigEnum {
             | ConfigEnum::DisableAnimations
             | ConfigEnum::DisableScrollBar
             | ConfigEnum::EnableMouseSupport
+            | ConfigEnum::EnableReminders
             | ConfigEnum::SaveOnExit
             | ConfigEnum::ShowLineNumbers => {
                 let check = value.parse::<bool>();
@@ -2522,8 +4267,10 @@ impl ConfigEnum {
                     Err(format!("Invalid boolean: {}", value))
                 }
             }
-            ConfigEnum::NoOfBoardsToShow
+            ConfigEnum::AutoArchiveDays
+            | ConfigEnum::NoOfBoardsToShow
             | ConfigEnum::NoOfCardsToShow
+            | ConfigEnum::ReminderHours
             | ConfigEnum::Tickrate
             | ConfigEnum::WarningDelta => {
                 let min_value = match self {
@@ -2534,6 +4281,8 @@ impl ConfigEnum {
                     _ => 0,
                 };
                 let max_value = match self {
+                    ConfigEnum::AutoArchiveDays => MAX_AUTO_ARCHIVE_DAYS,
+                    ConfigEnum::ReminderHours => MAX_REMINDER_HOURS,
                     ConfigEnum::WarningDelta => MAX_WARNING_DUE_DATE_DAYS,
                     ConfigEnum::Tickrate => MAX_TICKRATE,
                     ConfigEnum::NoOfCardsToShow => MAX_NO_CARDS_PER_BOARD,
@@ -2567,6 +4316,13 @@ impl ConfigEnum {
                     Err(format!("Invalid DateFormat: {}", value))
                 }
             }
+            ConfigEnum::ReminderAlert => {
+                if ReminderAlert::from_string(value).is_some() {
+                    Ok(())
+                } else {
+                    Err(format!("Invalid ReminderAlert: {}", value))
+                }
+            }
             ConfigEnum::Keybindings => {
                 debug!("Keybindings should not be called from validate_value");
                 // Keybindings are handled separately
@@ -2607,9 +4363,21 @@ impl ConfigEnum {
             ConfigEnum::EnableMouseSupport => {
                 config.enable_mouse_support = value.parse::<bool>().unwrap();
             }
+            ConfigEnum::EnableReminders => {
+                config.enable_reminders = value.parse::<bool>().unwrap();
+            }
+            ConfigEnum::ReminderHours => {
+                config.reminder_hours = value.parse::<u16>().unwrap();
+            }
+            ConfigEnum::ReminderAlert => {
+                config.reminder_alert = ReminderAlert::from_string(value).unwrap();
+            }
             ConfigEnum::WarningDelta => {
                 config.warning_delta = value.parse::<u16>().unwrap();
             }
+            ConfigEnum::AutoArchiveDays => {
+                config.auto_archive_days = value.parse::<u16>().unwrap();
+            }
             ConfigEnum::Tickrate => {
                 config.tickrate = value.parse::<u16>().unwrap();
             }
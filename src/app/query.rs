@@ -0,0 +1,765 @@
+use super::{
+    date_format_finder,
+    kanban::{parse_due_date, parse_timestamp, Board, Card, CardPriority, CardStatus},
+    DateFormat,
+};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use std::fmt;
+
+/// a parsed card query such as `tag:bug status:active due:<7d -tag:wontfix "login page"`,
+/// terms next to each other are AND-ed, OR binds weaker than AND and NOT or a leading - negates
+#[derive(Debug, Clone, PartialEq)]
+pub enum CardQuery {
+    And(Vec<CardQuery>),
+    Or(Vec<CardQuery>),
+    Not(Box<CardQuery>),
+    Term(QueryTerm),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueryTerm {
+    /// text without a field matches the name or the description
+    Text(String),
+    Name(String),
+    Description(String),
+    Comment(String),
+    Status(CardStatus),
+    Priority(CardPriority),
+    Tag(String),
+    Due(DateCondition),
+    Created(DateCondition),
+    Modified(DateCondition),
+    Estimate(EstimateCondition),
+    Board(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateValue {
+    /// for due dates this is the time left until the due date, for created and modified dates the age
+    Relative(Duration),
+    Absolute(NaiveDate),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateCondition {
+    NotSet,
+    Set,
+    Overdue,
+    Compare(Comparison, DateValue),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EstimateCondition {
+    NotSet,
+    Set,
+    Compare(Comparison, u32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryError {
+    pub message: String,
+    /// 1 based column in the query where the error was found
+    pub column: usize,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (column {})", self.message, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    LeftParen,
+    RightParen,
+    And,
+    Or,
+    Not,
+    Term {
+        field: Option<String>,
+        value: String,
+    },
+}
+
+pub const QUERY_FIELDS: [&str; 11] = [
+    "name",
+    "description",
+    "comment",
+    "status",
+    "priority",
+    "tag",
+    "due",
+    "created",
+    "modified",
+    "estimate",
+    "board",
+];
+
+impl CardQuery {
+    pub fn parse(query: &str) -> Result<CardQuery, QueryError> {
+        let tokens = tokenize(query)?;
+        if tokens.is_empty() {
+            return Err(QueryError {
+                message: "Query is empty".to_string(),
+                column: 1,
+            });
+        }
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+            query_length: query.chars().count(),
+        };
+        let card_query = parser.parse_or()?;
+        if let Some((token, column)) = parser.peek() {
+            let message = if *token == Token::RightParen {
+                "Unmatched closing parenthesis".to_string()
+            } else {
+                "Unexpected term".to_string()
+            };
+            return Err(QueryError {
+                message,
+                column: *column,
+            });
+        }
+        Ok(card_query)
+    }
+
+    pub fn matches(&self, card: &Card, board: &Board) -> bool {
+        match self {
+            CardQuery::And(queries) => queries.iter().all(|query| query.matches(card, board)),
+            CardQuery::Or(queries) => queries.iter().any(|query| query.matches(card, board)),
+            CardQuery::Not(query) => !query.matches(card, board),
+            CardQuery::Term(term) => term.matches(card, board),
+        }
+    }
+}
+
+impl QueryTerm {
+    fn from_field(field: Option<&str>, value: &str, column: usize) -> Result<Self, QueryError> {
+        let error = |message: String| QueryError { message, column };
+        let field = match field {
+            Some(field) => field.to_lowercase(),
+            None => return Ok(QueryTerm::Text(value.to_lowercase())),
+        };
+        if value.is_empty() {
+            return Err(error(format!("Missing value for {}", field)));
+        }
+        let lower_value = value.to_lowercase();
+        match field.as_str() {
+            "name" | "title" => Ok(QueryTerm::Name(lower_value)),
+            "description" | "desc" => Ok(QueryTerm::Description(lower_value)),
+            "comment" | "comments" => Ok(QueryTerm::Comment(lower_value)),
+            "status" => match lower_value.as_str() {
+                "active" => Ok(QueryTerm::Status(CardStatus::Active)),
+                "complete" | "completed" | "done" => Ok(QueryTerm::Status(CardStatus::Complete)),
+                "stale" => Ok(QueryTerm::Status(CardStatus::Stale)),
+                _ => Err(error(format!(
+                    "Invalid status {}, use active, complete or stale",
+                    value
+                ))),
+            },
+            "priority" | "prio" => match lower_value.as_str() {
+                "high" => Ok(QueryTerm::Priority(CardPriority::High)),
+                "medium" => Ok(QueryTerm::Priority(CardPriority::Medium)),
+                "low" => Ok(QueryTerm::Priority(CardPriority::Low)),
+                _ => Err(error(format!(
+                    "Invalid priority {}, use high, medium or low",
+                    value
+                ))),
+            },
+            "tag" | "tags" | "label" => Ok(QueryTerm::Tag(lower_value)),
+            "board" => Ok(QueryTerm::Board(lower_value)),
+            "due" => DateCondition::parse(&lower_value)
+                .map(QueryTerm::Due)
+                .map_err(error),
+            "created" => DateCondition::parse(&lower_value)
+                .map(QueryTerm::Created)
+                .map_err(error),
+            "modified" | "updated" => DateCondition::parse(&lower_value)
+                .map(QueryTerm::Modified)
+                .map_err(error),
+            "estimate" | "points" => EstimateCondition::parse(&lower_value)
+                .map(QueryTerm::Estimate)
+                .map_err(error),
+            _ => Err(error(format!(
+                "Unknown field {}, use {}",
+                field,
+                QUERY_FIELDS.join(", ")
+            ))),
+        }
+    }
+
+    fn matches(&self, card: &Card, board: &Board) -> bool {
+        match self {
+            QueryTerm::Text(text) => {
+                card.name.to_lowercase().contains(text)
+                    || card.description.to_lowercase().contains(text)
+            }
+            QueryTerm::Name(text) => card.name.to_lowercase().contains(text),
+            QueryTerm::Description(text) => card.description.to_lowercase().contains(text),
+            QueryTerm::Comment(text) => card
+                .comments
+                .iter()
+                .any(|comment| comment.body.to_lowercase().contains(text)),
+            QueryTerm::Status(status) => card.card_status == *status,
+            QueryTerm::Priority(priority) => card.priority == *priority,
+            QueryTerm::Tag(tag) => card
+                .tags
+                .iter()
+                .chain(card.custom_field_tags().iter())
+                .any(|card_tag| card_tag.to_lowercase() == *tag),
+            QueryTerm::Board(name) => board.name.to_lowercase().contains(name),
+            QueryTerm::Due(condition) => {
+                let due_date = parse_due_date(&card.due_date).map(|(due_date, _)| due_date);
+                let now = Local::now().naive_local();
+                match (condition, due_date) {
+                    (DateCondition::NotSet, due_date) => due_date.is_none(),
+                    (_, None) => false,
+                    (DateCondition::Set, Some(_)) => true,
+                    (DateCondition::Overdue, Some(due_date)) => {
+                        due_date < now && card.card_status != CardStatus::Complete
+                    }
+                    (DateCondition::Compare(comparison, value), Some(due_date)) => {
+                        compare_date(*comparison, value, due_date - now, due_date.date())
+                    }
+                }
+            }
+            QueryTerm::Created(condition) => {
+                timestamp_matches(condition, parse_timestamp(&card.date_created))
+            }
+            QueryTerm::Modified(condition) => {
+                timestamp_matches(condition, parse_timestamp(&card.date_modified))
+            }
+            QueryTerm::Estimate(condition) => match (condition, card.estimate) {
+                (EstimateCondition::NotSet, estimate) => estimate.is_none(),
+                (_, None) => false,
+                (EstimateCondition::Set, Some(_)) => true,
+                (EstimateCondition::Compare(comparison, value), Some(estimate)) => {
+                    comparison.holds(estimate, *value)
+                }
+            },
+        }
+    }
+}
+
+/// timestamps are always in the past, so relative values compare against their age
+fn timestamp_matches(condition: &DateCondition, timestamp: Option<DateTime<Utc>>) -> bool {
+    match (condition, timestamp) {
+        (DateCondition::NotSet, timestamp) => timestamp.is_none(),
+        (_, None) => false,
+        (DateCondition::Set, Some(_)) => true,
+        // a timestamp can not be in the future
+        (DateCondition::Overdue, Some(_)) => false,
+        (DateCondition::Compare(comparison, value), Some(timestamp)) => compare_date(
+            *comparison,
+            value,
+            Utc::now() - timestamp,
+            timestamp.with_timezone(&Local).date_naive(),
+        ),
+    }
+}
+
+impl Comparison {
+    /// reads an optional leading <, <=, =, >= or >, no operator means equal
+    fn split_prefix(value: &str) -> (Comparison, &str) {
+        if let Some(value) = value.strip_prefix("<=") {
+            (Comparison::LessOrEqual, value)
+        } else if let Some(value) = value.strip_prefix(">=") {
+            (Comparison::GreaterOrEqual, value)
+        } else if let Some(value) = value.strip_prefix('<') {
+            (Comparison::Less, value)
+        } else if let Some(value) = value.strip_prefix('>') {
+            (Comparison::Greater, value)
+        } else if let Some(value) = value.strip_prefix('=') {
+            (Comparison::Equal, value)
+        } else {
+            (Comparison::Equal, value)
+        }
+    }
+
+    fn holds<T: PartialOrd>(self, left: T, right: T) -> bool {
+        match self {
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Equal => left == right,
+            Comparison::GreaterOrEqual => left >= right,
+            Comparison::Greater => left > right,
+        }
+    }
+}
+
+/// relative values compare against the offset from now, absolute values against the calendar date
+fn compare_date(
+    comparison: Comparison,
+    value: &DateValue,
+    offset: Duration,
+    date: NaiveDate,
+) -> bool {
+    match value {
+        DateValue::Relative(duration) => match comparison {
+            Comparison::Less => offset < *duration,
+            Comparison::LessOrEqual => offset <= *duration,
+            // same number of whole days, "due:=0d" is due within the next day
+            Comparison::Equal => offset.num_days() == duration.num_days(),
+            Comparison::GreaterOrEqual => offset >= *duration,
+            Comparison::Greater => offset > *duration,
+        },
+        DateValue::Absolute(value) => match comparison {
+            Comparison::Less => date < *value,
+            Comparison::LessOrEqual => date <= *value,
+            Comparison::Equal => date == *value,
+            Comparison::GreaterOrEqual => date >= *value,
+            Comparison::Greater => date > *value,
+        },
+    }
+}
+
+impl DateCondition {
+    /// accepts none, set, overdue, today or an optional comparison (<, <=, =, >=, >) followed by
+    /// a relative offset like 7d, 2w, 3m, 12h or a date like 2024-05-31
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "none" => return Ok(DateCondition::NotSet),
+            "set" | "any" => return Ok(DateCondition::Set),
+            "overdue" => return Ok(DateCondition::Overdue),
+            "today" => {
+                return Ok(DateCondition::Compare(
+                    Comparison::Equal,
+                    DateValue::Absolute(Local::now().date_naive()),
+                ))
+            }
+            _ => {}
+        }
+        let (comparison, value) = Comparison::split_prefix(value);
+        if let Some(duration) = parse_relative_offset(value) {
+            return Ok(DateCondition::Compare(
+                comparison,
+                DateValue::Relative(duration),
+            ));
+        }
+        if let Some(date) = parse_query_date(value) {
+            return Ok(DateCondition::Compare(
+                comparison,
+                DateValue::Absolute(date),
+            ));
+        }
+        Err(format!(
+            "Invalid date {}, use none, set, overdue, today, an offset like <7d or a date like 2024-05-31",
+            value
+        ))
+    }
+}
+
+impl EstimateCondition {
+    /// accepts none, set or an optional comparison (<, <=, =, >=, >) followed by a whole number
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "none" => return Ok(EstimateCondition::NotSet),
+            "set" | "any" => return Ok(EstimateCondition::Set),
+            _ => {}
+        }
+        let (comparison, value) = Comparison::split_prefix(value);
+        value
+            .parse::<u32>()
+            .map(|estimate| EstimateCondition::Compare(comparison, estimate))
+            .map_err(|_| {
+                format!(
+                    "Invalid estimate {}, use none, set or a number like >=3",
+                    value
+                )
+            })
+    }
+}
+
+fn parse_relative_offset(value: &str) -> Option<Duration> {
+    let unit = value.chars().last()?;
+    let amount = value[..value.len() - unit.len_utf8()].parse::<i64>().ok()?;
+    match unit {
+        'h' => Some(Duration::hours(amount)),
+        'd' => Some(Duration::days(amount)),
+        'w' => Some(Duration::weeks(amount)),
+        'm' => Some(Duration::days(amount * 30)),
+        _ => None,
+    }
+}
+
+fn parse_query_date(value: &str) -> Option<NaiveDate> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(date);
+    }
+    let date_format = date_format_finder(value).ok()?;
+    if DateFormat::all_formats_without_time().contains(&date_format) {
+        NaiveDate::parse_from_str(value, date_format.to_parser_string()).ok()
+    } else {
+        None
+    }
+}
+
+fn tokenize(query: &str) -> Result<Vec<(Token, usize)>, QueryError> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = vec![];
+    let mut index = 0;
+    while index < chars.len() {
+        let column = index + 1;
+        match chars[index] {
+            c if c.is_whitespace() => index += 1,
+            '(' => {
+                tokens.push((Token::LeftParen, column));
+                index += 1;
+            }
+            ')' => {
+                tokens.push((Token::RightParen, column));
+                index += 1;
+            }
+            '-' if chars
+                .get(index + 1)
+                .map(|next| !next.is_whitespace())
+                .unwrap_or(false) =>
+            {
+                tokens.push((Token::Not, column));
+                index += 1;
+            }
+            '"' => {
+                let (value, next_index) = read_quoted(&chars, index)?;
+                tokens.push((Token::Term { field: None, value }, column));
+                index = next_index;
+            }
+            _ => {
+                let mut word = String::new();
+                let mut field = None;
+                while index < chars.len() {
+                    let c = chars[index];
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    if c == ':' && field.is_none() {
+                        field = Some(word.clone());
+                        word.clear();
+                        index += 1;
+                        if chars.get(index) == Some(&'"') {
+                            let (value, next_index) = read_quoted(&chars, index)?;
+                            word = value;
+                            index = next_index;
+                            break;
+                        }
+                        continue;
+                    }
+                    word.push(c);
+                    index += 1;
+                }
+                let token = match (&field, word.as_str()) {
+                    (None, "AND") => Token::And,
+                    (None, "OR") => Token::Or,
+                    (None, "NOT") => Token::Not,
+                    _ => Token::Term { field, value: word },
+                };
+                tokens.push((token, column));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// reads a quoted string starting at the opening quote, returns the text and the index after the closing quote
+fn read_quoted(chars: &[char], start: usize) -> Result<(String, usize), QueryError> {
+    let mut index = start + 1;
+    let mut value = String::new();
+    while index < chars.len() {
+        if chars[index] == '"' {
+            return Ok((value, index + 1));
+        }
+        value.push(chars[index]);
+        index += 1;
+    }
+    Err(QueryError {
+        message: "Unterminated quote".to_string(),
+        column: start + 1,
+    })
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    query_length: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.position)
+    }
+
+    fn end_column(&self) -> usize {
+        self.query_length + 1
+    }
+
+    fn parse_or(&mut self) -> Result<CardQuery, QueryError> {
+        let mut queries = vec![self.parse_and()?];
+        while let Some((Token::Or, _)) = self.peek() {
+            self.position += 1;
+            queries.push(self.parse_and()?);
+        }
+        if queries.len() == 1 {
+            Ok(queries.remove(0))
+        } else {
+            Ok(CardQuery::Or(queries))
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<CardQuery, QueryError> {
+        let mut queries = vec![self.parse_not()?];
+        loop {
+            match self.peek() {
+                Some((Token::And, _)) => {
+                    self.position += 1;
+                    queries.push(self.parse_not()?);
+                }
+                Some((Token::Or, _)) | Some((Token::RightParen, _)) | None => break,
+                Some(_) => queries.push(self.parse_not()?),
+            }
+        }
+        if queries.len() == 1 {
+            Ok(queries.remove(0))
+        } else {
+            Ok(CardQuery::And(queries))
+        }
+    }
+
+    fn parse_not(&mut self) -> Result<CardQuery, QueryError> {
+        if let Some((Token::Not, _)) = self.peek() {
+            self.position += 1;
+            return Ok(CardQuery::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_atom()
+    }
+
+    fn parse_atom(&mut self) -> Result<CardQuery, QueryError> {
+        let (token, column) = match self.peek() {
+            Some((token, column)) => (token.clone(), *column),
+            None => {
+                return Err(QueryError {
+                    message: "Query ends unexpectedly".to_string(),
+                    column: self.end_column(),
+                })
+            }
+        };
+        self.position += 1;
+        match token {
+            Token::LeftParen => {
+                let query = self.parse_or()?;
+                match self.peek() {
+                    Some((Token::RightParen, _)) => {
+                        self.position += 1;
+                        Ok(query)
+                    }
+                    _ => Err(QueryError {
+                        message: "Missing closing parenthesis".to_string(),
+                        column,
+                    }),
+                }
+            }
+            Token::Term { field, value } => {
+                QueryTerm::from_field(field.as_deref(), &value, column).map(CardQuery::Term)
+            }
+            Token::RightParen => Err(QueryError {
+                message: "Unmatched closing parenthesis".to_string(),
+                column,
+            }),
+            Token::And | Token::Or | Token::Not => Err(QueryError {
+                message: "Expected a term before the operator".to_string(),
+                column,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> CardQuery {
+        CardQuery::Term(QueryTerm::Text(value.to_string()))
+    }
+
+    fn tag(value: &str) -> CardQuery {
+        CardQuery::Term(QueryTerm::Tag(value.to_string()))
+    }
+
+    fn parse_error(query: &str) -> (String, usize) {
+        let err = CardQuery::parse(query).unwrap_err();
+        (err.message, err.column)
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        assert_eq!(
+            CardQuery::parse("a b OR c AND d").unwrap(),
+            CardQuery::Or(vec![
+                CardQuery::And(vec![text("a"), text("b")]),
+                CardQuery::And(vec![text("c"), text("d")]),
+            ])
+        );
+    }
+
+    #[test]
+    fn not_binds_tighter_than_and() {
+        assert_eq!(
+            CardQuery::parse("NOT a b OR -tag:wontfix").unwrap(),
+            CardQuery::Or(vec![
+                CardQuery::And(vec![CardQuery::Not(Box::new(text("a"))), text("b")]),
+                CardQuery::Not(Box::new(tag("wontfix"))),
+            ])
+        );
+    }
+
+    #[test]
+    fn parentheses_override_precedence() {
+        assert_eq!(
+            CardQuery::parse("(tag:bug OR tag:ui) NOT (a OR b)").unwrap(),
+            CardQuery::And(vec![
+                CardQuery::Or(vec![tag("bug"), tag("ui")]),
+                CardQuery::Not(Box::new(CardQuery::Or(vec![text("a"), text("b")]))),
+            ])
+        );
+    }
+
+    #[test]
+    fn quoted_values_keep_spaces_and_operators() {
+        assert_eq!(
+            CardQuery::parse("\"login page\" name:\"Big Bug\" \"OR\"").unwrap(),
+            CardQuery::And(vec![
+                text("login page"),
+                CardQuery::Term(QueryTerm::Name("big bug".to_string())),
+                text("or"),
+            ])
+        );
+    }
+
+    #[test]
+    fn relative_dates_are_parsed() {
+        let cases = [
+            ("due:<7d", Comparison::Less, Duration::days(7)),
+            ("due:<=2w", Comparison::LessOrEqual, Duration::weeks(2)),
+            ("due:12h", Comparison::Equal, Duration::hours(12)),
+            ("due:>=3m", Comparison::GreaterOrEqual, Duration::days(90)),
+            ("due:>-1d", Comparison::Greater, Duration::days(-1)),
+        ];
+        for (query, comparison, duration) in cases {
+            assert_eq!(
+                CardQuery::parse(query).unwrap(),
+                CardQuery::Term(QueryTerm::Due(DateCondition::Compare(
+                    comparison,
+                    DateValue::Relative(duration)
+                ))),
+                "{}",
+                query
+            );
+        }
+    }
+
+    #[test]
+    fn absolute_dates_are_parsed() {
+        let date = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+        assert_eq!(
+            CardQuery::parse("created:>=2024-05-31").unwrap(),
+            CardQuery::Term(QueryTerm::Created(DateCondition::Compare(
+                Comparison::GreaterOrEqual,
+                DateValue::Absolute(date)
+            )))
+        );
+        assert_eq!(
+            CardQuery::parse("modified:31/05/2024").unwrap(),
+            CardQuery::Term(QueryTerm::Modified(DateCondition::Compare(
+                Comparison::Equal,
+                DateValue::Absolute(date)
+            )))
+        );
+        assert_eq!(
+            CardQuery::parse("due:none").unwrap(),
+            CardQuery::Term(QueryTerm::Due(DateCondition::NotSet))
+        );
+    }
+
+    #[test]
+    fn date_conditions_match_cards() {
+        let board = Board::default();
+        let tomorrow = Local::now().date_naive() + Duration::days(1);
+        let card = Card {
+            due_date: tomorrow.format("%d/%m/%Y").to_string(),
+            ..Card::default()
+        };
+        let matches = |query: &str| CardQuery::parse(query).unwrap().matches(&card, &board);
+        assert!(matches("due:<7d"));
+        assert!(!matches("due:>7d"));
+        assert!(matches(&format!("due:{}", tomorrow.format("%Y-%m-%d"))));
+        assert!(!matches("due:overdue"));
+        assert!(!matches("due:none"));
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_column() {
+        assert_eq!(parse_error(""), ("Query is empty".to_string(), 1));
+        assert_eq!(
+            parse_error("a tag:"),
+            ("Missing value for tag".to_string(), 3)
+        );
+        assert_eq!(
+            parse_error("a name:\"open"),
+            ("Unterminated quote".to_string(), 8)
+        );
+        assert_eq!(
+            parse_error("a (b OR c"),
+            ("Missing closing parenthesis".to_string(), 3)
+        );
+        assert_eq!(
+            parse_error("a b)"),
+            ("Unmatched closing parenthesis".to_string(), 4)
+        );
+        assert_eq!(
+            parse_error("a OR"),
+            ("Query ends unexpectedly".to_string(), 5)
+        );
+        assert_eq!(
+            parse_error("OR a"),
+            ("Expected a term before the operator".to_string(), 1)
+        );
+        assert_eq!(parse_error("a due:<7x").1, 3);
+        assert_eq!(parse_error("status:open").1, 1);
+        assert_eq!(parse_error("a foo:bar").1, 3);
+        assert_eq!(parse_error("a estimate:>=x").1, 3);
+    }
+
+    #[test]
+    fn estimates_are_parsed_and_matched() {
+        assert_eq!(
+            CardQuery::parse("estimate:>=3").unwrap(),
+            CardQuery::Term(QueryTerm::Estimate(EstimateCondition::Compare(
+                Comparison::GreaterOrEqual,
+                3
+            )))
+        );
+        assert_eq!(
+            CardQuery::parse("estimate:none").unwrap(),
+            CardQuery::Term(QueryTerm::Estimate(EstimateCondition::NotSet))
+        );
+        let board = Board::default();
+        let card = Card {
+            estimate: Some(5),
+            ..Card::default()
+        };
+        let matches = |query: &str| CardQuery::parse(query).unwrap().matches(&card, &board);
+        assert!(matches("estimate:>=3"));
+        assert!(matches("estimate:5"));
+        assert!(matches("estimate:set"));
+        assert!(!matches("estimate:<5"));
+        assert!(!matches("estimate:none"));
+    }
+}
@@ -0,0 +1,260 @@
+use super::{
+    kanban::{parse_due_date, Card, CardStatus},
+    DateFormat,
+};
+use chrono::{DateTime, Duration, Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderStage {
+    DueToday,
+    DueSoon,
+    Overdue,
+}
+
+/// sent along with the toast so reminders are noticed when the terminal is in the background
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReminderAlert {
+    #[default]
+    None,
+    Bell,
+    DesktopNotification,
+}
+
+impl ReminderAlert {
+    pub fn next(&self) -> Self {
+        match self {
+            ReminderAlert::None => ReminderAlert::Bell,
+            ReminderAlert::Bell => ReminderAlert::DesktopNotification,
+            ReminderAlert::DesktopNotification => ReminderAlert::None,
+        }
+    }
+    pub fn from_string(value: &str) -> Option<Self> {
+        match value {
+            "None" => Some(ReminderAlert::None),
+            "Bell" => Some(ReminderAlert::Bell),
+            "Desktop Notification" => Some(ReminderAlert::DesktopNotification),
+            _ => None,
+        }
+    }
+    /// the terminal bell, or an OSC 9 escape that terminals like iTerm2, kitty and Windows Terminal show as a notification
+    pub fn escape_sequence(&self, message: &str) -> Option<String> {
+        match self {
+            ReminderAlert::None => None,
+            ReminderAlert::Bell => Some("\x07".to_string()),
+            ReminderAlert::DesktopNotification => {
+                let message = message.replace(|c: char| c.is_control(), " ");
+                Some(format!("\x1b]9;{}\x07", message))
+            }
+        }
+    }
+}
+
+impl Display for ReminderAlert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReminderAlert::None => write!(f, "None"),
+            ReminderAlert::Bell => write!(f, "Bell"),
+            ReminderAlert::DesktopNotification => write!(f, "Desktop Notification"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReminderLogEntry {
+    pub card_id: (u64, u64),
+    /// reminders start over when the due date changes
+    pub due_date: String,
+    /// in the order they were shown
+    pub shown_stages: Vec<ReminderStage>,
+    pub snoozed_until: Option<i64>,
+    pub dismissed: bool,
+}
+
+/// which reminders were already shown, saved so they are not repeated after a restart
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ReminderLog {
+    pub entries: Vec<ReminderLogEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reminder {
+    pub card_id: (u64, u64),
+    pub stage: ReminderStage,
+    pub message: String,
+}
+
+impl ReminderLog {
+    /// reminders to show now, they are marked as shown right away so cards past max_reminders
+    /// wait for the next check; entries of cards that were deleted or archived are dropped;
+    /// also returns whether the log changed
+    pub fn collect_due_reminders<'a>(
+        &mut self,
+        cards: impl Iterator<Item = &'a Card>,
+        remind_hours: u16,
+        max_reminders: usize,
+        now: DateTime<Local>,
+    ) -> (Vec<Reminder>, bool) {
+        let cards = cards.collect::<Vec<&Card>>();
+        let entries_before = self.entries.len();
+        self.entries
+            .retain(|entry| cards.iter().any(|card| card.id == entry.card_id));
+        let mut reminders = vec![];
+        let mut changed = self.entries.len() != entries_before;
+        for card in cards {
+            if reminders.len() >= max_reminders {
+                break;
+            }
+            let stages = if card.card_status == CardStatus::Complete {
+                vec![]
+            } else {
+                get_applicable_stages(&card.due_date, remind_hours, now.naive_local())
+            };
+            let existing_entry = self
+                .entries
+                .iter()
+                .position(|entry| entry.card_id == card.id && entry.due_date == card.due_date);
+            if stages.is_empty() {
+                // nothing to remind about, forget the card so a new due date starts fresh
+                let entries_before = self.entries.len();
+                self.entries.retain(|entry| entry.card_id != card.id);
+                changed |= self.entries.len() != entries_before;
+                continue;
+            }
+            let entry_index = match existing_entry {
+                Some(entry_index) => entry_index,
+                None => {
+                    self.entries.retain(|entry| entry.card_id != card.id);
+                    self.entries.push(ReminderLogEntry {
+                        card_id: card.id,
+                        due_date: card.due_date.clone(),
+                        shown_stages: vec![],
+                        snoozed_until: None,
+                        dismissed: false,
+                    });
+                    changed = true;
+                    self.entries.len() - 1
+                }
+            };
+            let entry = &mut self.entries[entry_index];
+            if entry.dismissed
+                || entry
+                    .snoozed_until
+                    .is_some_and(|snoozed_until| snoozed_until > now.timestamp())
+            {
+                continue;
+            }
+            if entry.snoozed_until.take().is_some() {
+                changed = true;
+            }
+            let stage = stages[0];
+            if entry.shown_stages.contains(&stage) {
+                continue;
+            }
+            // the less urgent stages that also apply are skipped, the shown stage goes last for snoozing
+            for skipped_stage in &stages[1..] {
+                if !entry.shown_stages.contains(skipped_stage) {
+                    entry.shown_stages.push(*skipped_stage);
+                }
+            }
+            entry.shown_stages.push(stage);
+            changed = true;
+            reminders.push(Reminder {
+                card_id: card.id,
+                stage,
+                message: get_reminder_message(card, stage, now.naive_local()),
+            });
+        }
+        (reminders, changed)
+    }
+
+    /// the last shown reminder comes back once the snooze runs out
+    pub fn snooze(&mut self, card_id: (u64, u64), until: i64) -> bool {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.card_id == card_id)
+        {
+            Some(entry) => {
+                entry.shown_stages.pop();
+                entry.snoozed_until = Some(until);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// no more reminders for the card until its due date changes
+    pub fn dismiss(&mut self, card_id: (u64, u64)) -> bool {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.card_id == card_id)
+        {
+            Some(entry) => {
+                entry.dismissed = true;
+                entry.snoozed_until = None;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// date only due dates are due at the end of the day
+pub fn get_due_moment(due_date: &str) -> Option<(NaiveDateTime, bool)> {
+    let (due_date, date_format) = parse_due_date(due_date)?;
+    if DateFormat::all_formats_with_time().contains(&date_format) {
+        Some((due_date, true))
+    } else {
+        Some((due_date + Duration::days(1), false))
+    }
+}
+
+/// most urgent first
+fn get_applicable_stages(
+    due_date: &str,
+    remind_hours: u16,
+    now: NaiveDateTime,
+) -> Vec<ReminderStage> {
+    let (due_moment, has_time) = match get_due_moment(due_date) {
+        Some(due_moment) => due_moment,
+        None => return vec![],
+    };
+    if now >= due_moment {
+        return vec![ReminderStage::Overdue];
+    }
+    let mut stages = vec![];
+    if has_time && remind_hours > 0 && due_moment - now <= Duration::hours(remind_hours as i64) {
+        stages.push(ReminderStage::DueSoon);
+    }
+    let due_day = if has_time {
+        due_moment.date()
+    } else {
+        due_moment.date() - Duration::days(1)
+    };
+    if due_day == now.date() {
+        stages.push(ReminderStage::DueToday);
+    }
+    stages
+}
+
+fn get_reminder_message(card: &Card, stage: ReminderStage, now: NaiveDateTime) -> String {
+    match stage {
+        ReminderStage::Overdue => format!("{} is overdue, it was due {}", card.name, card.due_date),
+        ReminderStage::DueToday => format!("{} is due today", card.name),
+        ReminderStage::DueSoon => {
+            let minutes_left = get_due_moment(&card.due_date)
+                .map(|(due_moment, _)| (due_moment - now).num_minutes())
+                .unwrap_or(0);
+            if minutes_left < 60 {
+                format!("{} is due in {} minutes", card.name, minutes_left.max(1))
+            } else if minutes_left < 120 {
+                format!("{} is due in an hour", card.name)
+            } else {
+                format!("{} is due in {} hours", card.name, minutes_left / 60)
+            }
+        }
+    }
+}
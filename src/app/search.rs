@@ -0,0 +1,365 @@
+use super::kanban::{Boards, Card};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, BTreeSet, HashMap},
+    hash::{Hash, Hasher},
+};
+
+/// characters shown before the first match in a snippet
+const SNIPPET_LEADING_CHARS: usize = 24;
+const SNIPPET_LENGTH: usize = 160;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SearchField {
+    Name,
+    Description,
+    Tag(usize),
+    Comment(usize),
+    CustomFields,
+}
+
+impl SearchField {
+    /// matches in more prominent fields rank higher
+    fn weight(&self) -> usize {
+        match self {
+            SearchField::Name => 4,
+            SearchField::Tag(_) => 3,
+            SearchField::Description => 2,
+            SearchField::Comment(_) | SearchField::CustomFields => 1,
+        }
+    }
+}
+
+impl std::fmt::Display for SearchField {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SearchField::Name => write!(f, "Name"),
+            SearchField::Description => write!(f, "Description"),
+            SearchField::Tag(_) => write!(f, "Tags"),
+            SearchField::Comment(_) => write!(f, "Comments"),
+            SearchField::CustomFields => write!(f, "Custom Fields"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub card_id: (u64, u64),
+    pub card_name: String,
+    pub field: SearchField,
+    /// the text around the first match on a single line
+    pub snippet: String,
+    /// char ranges in the snippet that matched a search term
+    pub highlights: Vec<(usize, usize)>,
+    /// row and char column of the first match in the field
+    pub match_position: (usize, usize),
+    score: usize,
+}
+
+#[derive(Debug, Clone)]
+struct IndexedCard {
+    content_hash: u64,
+    fields: Vec<(SearchField, String)>,
+    terms: BTreeSet<String>,
+}
+
+/// an inverted index over card names, descriptions, tags, comments and custom fields,
+/// syncing only re-indexes cards whose content changed since the last sync
+#[derive(Debug, Clone, Default)]
+pub struct SearchIndex {
+    terms: BTreeMap<String, BTreeSet<(u64, u64)>>,
+    cards: HashMap<(u64, u64), IndexedCard>,
+}
+
+impl SearchIndex {
+    /// brings the index up to date with the boards, returns the number of cards that were re-indexed
+    pub fn sync(&mut self, boards: &Boards) -> usize {
+        let mut seen_cards = BTreeSet::new();
+        let mut reindexed_cards = 0;
+        for board in boards.get_boards() {
+            for card in board.cards.get_all_cards() {
+                seen_cards.insert(card.id);
+                let content_hash = hash_card_content(card);
+                let up_to_date = self
+                    .cards
+                    .get(&card.id)
+                    .map(|indexed_card| indexed_card.content_hash == content_hash)
+                    .unwrap_or(false);
+                if !up_to_date {
+                    self.remove_card(card.id);
+                    self.add_card(card, content_hash);
+                    reindexed_cards += 1;
+                }
+            }
+        }
+        let removed_cards = self
+            .cards
+            .keys()
+            .filter(|card_id| !seen_cards.contains(card_id))
+            .copied()
+            .collect::<Vec<(u64, u64)>>();
+        for card_id in removed_cards {
+            self.remove_card(card_id);
+        }
+        reindexed_cards
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// every term has to match the start of a word somewhere in the card, best matches first
+    pub fn search(&self, query: &str) -> Vec<SearchResult> {
+        let query_terms = tokenize(query)
+            .into_iter()
+            .collect::<BTreeSet<String>>()
+            .into_iter()
+            .collect::<Vec<String>>();
+        if query_terms.is_empty() {
+            return vec![];
+        }
+        let mut candidates: Option<BTreeSet<(u64, u64)>> = None;
+        for query_term in &query_terms {
+            let term_cards = self
+                .terms
+                .range(query_term.clone()..)
+                .take_while(|(term, _)| term.starts_with(query_term.as_str()))
+                .flat_map(|(_, card_ids)| card_ids.iter().copied())
+                .collect::<BTreeSet<(u64, u64)>>();
+            candidates = Some(match candidates {
+                Some(candidates) => candidates.intersection(&term_cards).copied().collect(),
+                None => term_cards,
+            });
+        }
+        let mut results = candidates
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|card_id| {
+                self.cards
+                    .get(&card_id)
+                    .and_then(|indexed_card| build_result(card_id, indexed_card, &query_terms))
+            })
+            .collect::<Vec<SearchResult>>();
+        results.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.card_name.to_lowercase().cmp(&b.card_name.to_lowercase()))
+        });
+        results
+    }
+
+    fn add_card(&mut self, card: &Card, content_hash: u64) {
+        let mut fields = vec![
+            (SearchField::Name, card.name.clone()),
+            (SearchField::Description, card.description.clone()),
+        ];
+        for (index, tag) in card.tags.iter().enumerate() {
+            fields.push((SearchField::Tag(index), tag.clone()));
+        }
+        for (index, comment) in card.comments.iter().enumerate() {
+            let comment_text = if comment.author.is_empty() {
+                comment.body.clone()
+            } else {
+                format!("{}: {}", comment.author, comment.body)
+            };
+            fields.push((SearchField::Comment(index), comment_text));
+        }
+        if !card.custom_fields.is_empty() {
+            let custom_fields_text = card
+                .custom_fields
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect::<Vec<String>>()
+                .join("\n");
+            fields.push((SearchField::CustomFields, custom_fields_text));
+        }
+        let terms = fields
+            .iter()
+            .flat_map(|(_, text)| tokenize(text))
+            .collect::<BTreeSet<String>>();
+        for term in &terms {
+            self.terms.entry(term.clone()).or_default().insert(card.id);
+        }
+        self.cards.insert(
+            card.id,
+            IndexedCard {
+                content_hash,
+                fields,
+                terms,
+            },
+        );
+    }
+
+    fn remove_card(&mut self, card_id: (u64, u64)) {
+        let indexed_card = match self.cards.remove(&card_id) {
+            Some(indexed_card) => indexed_card,
+            None => return,
+        };
+        for term in indexed_card.terms {
+            if let Some(card_ids) = self.terms.get_mut(&term) {
+                card_ids.remove(&card_id);
+                if card_ids.is_empty() {
+                    self.terms.remove(&term);
+                }
+            }
+        }
+    }
+}
+
+fn hash_card_content(card: &Card) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    card.name.hash(&mut hasher);
+    card.description.hash(&mut hasher);
+    card.tags.hash(&mut hasher);
+    for comment in &card.comments {
+        comment.author.hash(&mut hasher);
+        comment.body.hash(&mut hasher);
+    }
+    card.custom_fields.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// lowercases char by char so positions line up with the original text
+fn lowercase_chars(text: &str) -> Vec<char> {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| lowercase_chars(word).into_iter().collect())
+        .collect()
+}
+
+/// char positions where a word starts with the term
+fn find_term_matches(text: &[char], term: &[char]) -> Vec<usize> {
+    if term.is_empty() || term.len() > text.len() {
+        return vec![];
+    }
+    (0..=text.len() - term.len())
+        .filter(|&position| {
+            (position == 0 || !text[position - 1].is_alphanumeric())
+                && text[position..position + term.len()] == *term
+        })
+        .collect()
+}
+
+fn build_result(
+    card_id: (u64, u64),
+    indexed_card: &IndexedCard,
+    query_terms: &[String],
+) -> Option<SearchResult> {
+    let query_terms = query_terms
+        .iter()
+        .map(|term| term.chars().collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+    // the field with the most matching terms gets the snippet, ties go to the heavier field
+    let mut best_field: Option<(usize, usize, usize)> = None;
+    let mut term_weights = vec![0; query_terms.len()];
+    for (field_index, (field, text)) in indexed_card.fields.iter().enumerate() {
+        let lowercase_text = lowercase_chars(text);
+        let mut matched_terms = 0;
+        for (term_index, term) in query_terms.iter().enumerate() {
+            if !find_term_matches(&lowercase_text, term).is_empty() {
+                matched_terms += 1;
+                term_weights[term_index] = term_weights[term_index].max(field.weight());
+            }
+        }
+        if matched_terms == 0 {
+            continue;
+        }
+        let is_better = match best_field {
+            Some((_, best_matched_terms, best_weight)) => {
+                (matched_terms, field.weight()) > (best_matched_terms, best_weight)
+            }
+            None => true,
+        };
+        if is_better {
+            best_field = Some((field_index, matched_terms, field.weight()));
+        }
+    }
+    let score = term_weights.iter().sum::<usize>();
+    let (field_index, _, _) = best_field?;
+    let (field, text) = &indexed_card.fields[field_index];
+    let lowercase_text = lowercase_chars(text);
+    let first_match = query_terms
+        .iter()
+        .flat_map(|term| find_term_matches(&lowercase_text, term))
+        .min()?;
+
+    let text_chars = text.chars().collect::<Vec<char>>();
+    let mut snippet_start = first_match.saturating_sub(SNIPPET_LEADING_CHARS);
+    // start on a word boundary when the match is not at the beginning
+    while snippet_start > 0
+        && snippet_start < first_match
+        && text_chars[snippet_start - 1].is_alphanumeric()
+    {
+        snippet_start += 1;
+    }
+    let snippet_end = (snippet_start + SNIPPET_LENGTH).min(text_chars.len());
+    let mut snippet = String::new();
+    let mut offset = 0;
+    if snippet_start > 0 {
+        snippet.push('…');
+        offset = 1;
+    }
+    snippet.extend(text_chars[snippet_start..snippet_end].iter().map(|c| {
+        if c.is_whitespace() {
+            ' '
+        } else {
+            *c
+        }
+    }));
+    if snippet_end < text_chars.len() {
+        snippet.push('…');
+    }
+    let mut highlights = query_terms
+        .iter()
+        .flat_map(|term| {
+            find_term_matches(&lowercase_text[snippet_start..snippet_end], term)
+                .into_iter()
+                .map(|position| (position + offset, position + offset + term.len()))
+                .collect::<Vec<(usize, usize)>>()
+        })
+        .collect::<Vec<(usize, usize)>>();
+    highlights.sort_unstable();
+    // overlapping terms like "log" and "login" are merged into one highlight
+    let mut merged_highlights: Vec<(usize, usize)> = vec![];
+    for (start, end) in highlights {
+        match merged_highlights.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged_highlights.push((start, end)),
+        }
+    }
+
+    let match_row = text_chars[..first_match]
+        .iter()
+        .filter(|c| **c == '\n')
+        .count();
+    let match_column = first_match
+        - text_chars[..first_match]
+            .iter()
+            .rposition(|c| *c == '\n')
+            .map(|newline| newline + 1)
+            .unwrap_or(0);
+    let card_name = indexed_card
+        .fields
+        .iter()
+        .find(|(field, _)| *field == SearchField::Name)
+        .map(|(_, name)| name.clone())
+        .unwrap_or_default();
+    Some(SearchResult {
+        card_id,
+        card_name,
+        field: field.clone(),
+        snippet,
+        highlights: merged_highlights,
+        match_position: (match_row, match_column),
+        score,
+    })
+}
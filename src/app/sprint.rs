@@ -0,0 +1,237 @@
+use super::kanban::{Boards, Card, CardStatus};
+use chrono::{Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+pub const SPRINT_DATE_FORMAT: &str = "%Y-%m-%d";
+/// two weeks, counting the start and end day
+const DEFAULT_SPRINT_DAYS: i64 = 14;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Sprint {
+    pub name: String,
+    pub start_date: String,
+    pub end_date: String,
+    /// cards added by hand or carried over from the previous sprint
+    pub card_ids: Vec<(u64, u64)>,
+    /// cards with this tag are part of the sprint too
+    pub tag: Option<String>,
+    pub closed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SprintChartMode {
+    #[default]
+    BurndownCards,
+    BurndownPoints,
+    BurnupCards,
+    BurnupPoints,
+}
+
+impl SprintChartMode {
+    pub fn next(&self) -> Self {
+        match self {
+            SprintChartMode::BurndownCards => SprintChartMode::BurndownPoints,
+            SprintChartMode::BurndownPoints => SprintChartMode::BurnupCards,
+            SprintChartMode::BurnupCards => SprintChartMode::BurnupPoints,
+            SprintChartMode::BurnupPoints => SprintChartMode::BurndownCards,
+        }
+    }
+    pub fn is_burnup(&self) -> bool {
+        matches!(
+            self,
+            SprintChartMode::BurnupCards | SprintChartMode::BurnupPoints
+        )
+    }
+    /// cards count as one, points use the estimate and unestimated cards count as zero
+    fn card_value(&self, card: &Card) -> u32 {
+        match self {
+            SprintChartMode::BurndownCards | SprintChartMode::BurnupCards => 1,
+            SprintChartMode::BurndownPoints | SprintChartMode::BurnupPoints => {
+                card.estimate.unwrap_or(0)
+            }
+        }
+    }
+}
+
+impl Display for SprintChartMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SprintChartMode::BurndownCards => write!(f, "Burndown (cards)"),
+            SprintChartMode::BurndownPoints => write!(f, "Burndown (estimate points)"),
+            SprintChartMode::BurnupCards => write!(f, "Burnup (cards)"),
+            SprintChartMode::BurnupPoints => write!(f, "Burnup (estimate points)"),
+        }
+    }
+}
+
+/// the sprint scope and how much of it was complete at the end of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SprintDay {
+    pub date: NaiveDate,
+    pub scope: u32,
+    pub completed: u32,
+}
+
+impl SprintDay {
+    pub fn remaining(&self) -> u32 {
+        self.scope.saturating_sub(self.completed)
+    }
+}
+
+impl Sprint {
+    /// parses "name | start | end | tag", the end defaults to two weeks after the start and the tag is optional
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        let parts = spec
+            .split('|')
+            .map(|part| part.trim())
+            .collect::<Vec<&str>>();
+        let name = parts.first().copied().unwrap_or_default();
+        if name.is_empty() {
+            return Err("Sprint name cannot be empty".to_string());
+        }
+        let parse_date = |date: &str| {
+            NaiveDate::parse_from_str(date, SPRINT_DATE_FORMAT)
+                .map_err(|_| format!("Invalid date {}, expected YYYY-MM-DD", date))
+        };
+        let start_date = match parts.get(1) {
+            Some(start_date) if !start_date.is_empty() => parse_date(start_date)?,
+            _ => Local::now().date_naive(),
+        };
+        let end_date = match parts.get(2) {
+            Some(end_date) if !end_date.is_empty() => parse_date(end_date)?,
+            _ => start_date + Duration::days(DEFAULT_SPRINT_DAYS - 1),
+        };
+        if end_date < start_date {
+            return Err("Sprint cannot end before it starts".to_string());
+        }
+        let tag = parts
+            .get(3)
+            .map(|tag| tag.to_string())
+            .filter(|tag| !tag.is_empty());
+        Ok(Self {
+            name: name.to_string(),
+            start_date: start_date.format(SPRINT_DATE_FORMAT).to_string(),
+            end_date: end_date.format(SPRINT_DATE_FORMAT).to_string(),
+            card_ids: vec![],
+            tag,
+            closed: false,
+        })
+    }
+
+    pub fn start(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.start_date, SPRINT_DATE_FORMAT).ok()
+    }
+
+    pub fn end(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.end_date, SPRINT_DATE_FORMAT).ok()
+    }
+
+    pub fn contains(&self, card: &Card) -> bool {
+        self.card_ids.contains(&card.id)
+            || self.tag.as_ref().is_some_and(|sprint_tag| {
+                card.tags
+                    .iter()
+                    .any(|tag| tag.eq_ignore_ascii_case(sprint_tag))
+            })
+    }
+
+    /// a spec for the sprint that follows this one with the same length, used to prefill the close prompt
+    pub fn next_spec(&self) -> String {
+        let (start, end) = match (self.start(), self.end()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return String::new(),
+        };
+        let next_start = end + Duration::days(1);
+        let next_end = next_start + (end - start);
+        let next_name = match self.name.rsplit_once(' ') {
+            Some((prefix, number)) if number.parse::<u32>().is_ok() => {
+                format!("{} {}", prefix, number.parse::<u32>().unwrap_or(0) + 1)
+            }
+            _ => format!("{} 2", self.name),
+        };
+        let mut spec = format!(
+            "{} | {} | {}",
+            next_name,
+            next_start.format(SPRINT_DATE_FORMAT),
+            next_end.format(SPRINT_DATE_FORMAT)
+        );
+        if let Some(tag) = &self.tag {
+            spec.push_str(&format!(" | {}", tag));
+        }
+        spec
+    }
+
+    /// ids of the cards in the sprint, archived cards included
+    pub fn scope_card_ids(&self, boards: &Boards) -> Vec<(u64, u64)> {
+        sprint_cards(self, boards).map(|card| card.id).collect()
+    }
+
+    pub fn unfinished_card_ids(&self, boards: &Boards) -> Vec<(u64, u64)> {
+        sprint_cards(self, boards)
+            .filter(|card| card.card_status != CardStatus::Complete)
+            .map(|card| card.id)
+            .collect()
+    }
+
+    /// one point per day from the start up to today or the end of the sprint, each day uses the
+    /// last snapshot saved on or before it, days before the first snapshot are left out
+    pub fn daily_progress(
+        &self,
+        boards: &Boards,
+        snapshots: &[(NaiveDate, Boards)],
+        chart_mode: SprintChartMode,
+    ) -> Vec<SprintDay> {
+        let (start, end) = match (self.start(), self.end()) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return vec![],
+        };
+        let today = Local::now().date_naive();
+        let last_day = end.min(today);
+        let mut days = vec![];
+        let mut date = start;
+        while date <= last_day {
+            let day_boards = if date == today {
+                Some(boards)
+            } else {
+                snapshots
+                    .iter()
+                    .filter(|(snapshot_date, _)| *snapshot_date <= date)
+                    .max_by_key(|(snapshot_date, _)| *snapshot_date)
+                    .map(|(_, snapshot_boards)| snapshot_boards)
+            };
+            if let Some(day_boards) = day_boards {
+                let mut day = SprintDay {
+                    date,
+                    scope: 0,
+                    completed: 0,
+                };
+                for card in sprint_cards(self, day_boards) {
+                    let value = chart_mode.card_value(card);
+                    day.scope += value;
+                    if card.card_status == CardStatus::Complete {
+                        day.completed += value;
+                    }
+                }
+                days.push(day);
+            }
+            date += Duration::days(1);
+        }
+        days
+    }
+}
+
+fn sprint_cards<'a>(sprint: &'a Sprint, boards: &'a Boards) -> impl Iterator<Item = &'a Card> {
+    boards
+        .get_boards()
+        .iter()
+        .flat_map(|board| {
+            board.cards.get_all_cards().iter().chain(
+                board
+                    .archived_cards
+                    .iter()
+                    .map(|archived_card| &archived_card.card),
+            )
+        })
+        .filter(move |card| sprint.contains(card))
+}
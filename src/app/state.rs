@@ -13,6 +13,15 @@ use strum_macros::EnumIter;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Copy, Default)]
 pub enum UiMode {
+    ArchiveBrowser,
+    Agenda,
+    Sprints,
+    Statistics,
+    CardTable,
+    Timeline,
+    Calendar,
+    LabelManager,
+    WorkspaceSwitcher,
     BodyHelp,
     BodyHelpLog,
     BodyLog,
@@ -49,13 +58,25 @@ pub enum AppStatus {
 #[derive(Clone, PartialEq, Debug, Copy, Default)]
 pub enum Focus {
     Body,
+    BoardTemplateSelector,
+    CardBlockedBy,
     CardComments,
+    CardCustomFields,
     CardDescription,
     CardDueDate,
+    CardEstimate,
     CardName,
     CardPriority,
+    CardRecurrence,
+    CardActivityLog,
+    CardAttachments,
+    CardAttachmentList,
+    CardSortModeSelector,
+    SavedFilterSelector,
     CardStatus,
     CardTags,
+    CardTemplate,
+    CardTemplateSelector,
     ChangeCardPriorityPopup,
     ChangeCardStatusPopup,
     ChangeDateFormatPopup,
@@ -67,6 +88,7 @@ pub enum Focus {
     ConfigHelp,
     ConfigTable,
     ConfirmPasswordField,
+    CustomFieldSelector,
     EditGeneralConfigPopup,
     EditKeybindingsTable,
     EditSpecificKeyBindingPopup,
@@ -98,12 +120,18 @@ pub enum Focus {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct KeyBindings {
     pub accept: Vec<Key>,
+    pub apply_saved_filter_1: Vec<Key>,
+    pub apply_saved_filter_2: Vec<Key>,
+    pub apply_saved_filter_3: Vec<Key>,
+    pub archive_card: Vec<Key>,
     pub change_card_status_to_active: Vec<Key>,
     pub change_card_status_to_completed: Vec<Key>,
     pub change_card_status_to_stale: Vec<Key>,
     pub clear_all_toasts: Vec<Key>,
+    pub cycle_timeline_zoom: Vec<Key>,
     pub delete_board: Vec<Key>,
     pub delete_card: Vec<Key>,
+    pub dismiss_reminder: Vec<Key>,
     pub down: Vec<Key>,
     pub go_to_main_menu: Vec<Key>,
     pub go_to_previous_ui_mode_or_cancel: Vec<Key>,
@@ -123,8 +151,13 @@ pub struct KeyBindings {
     pub reset_ui: Vec<Key>,
     pub right: Vec<Key>,
     pub save_state: Vec<Key>,
+    pub snooze_reminder: Vec<Key>,
+    pub sort_card_table: Vec<Key>,
     pub stop_user_input: Vec<Key>,
     pub take_user_input: Vec<Key>,
+    pub toggle_calendar_layout: Vec<Key>,
+    pub toggle_card_selection: Vec<Key>,
+    pub toggle_card_timer: Vec<Key>,
     pub toggle_command_palette: Vec<Key>,
     pub undo: Vec<Key>,
     pub up: Vec<Key>,
@@ -133,12 +166,18 @@ pub struct KeyBindings {
 #[derive(Serialize, Deserialize, Debug, Clone, EnumIter, PartialEq)]
 pub enum KeyBindingEnum {
     Accept,
+    ApplySavedFilter1,
+    ApplySavedFilter2,
+    ApplySavedFilter3,
+    ArchiveCard,
     ChangeCardStatusToActive,
     ChangeCardStatusToCompleted,
     ChangeCardStatusToStale,
     ClearAllToasts,
+    CycleTimelineZoom,
     DeleteBoard,
     DeleteCard,
+    DismissReminder,
     Down,
     GoToMainMenu,
     GoToPreviousUIModeorCancel,
@@ -158,8 +197,13 @@ pub enum KeyBindingEnum {
     ResetUI,
     Right,
     SaveState,
+    SnoozeReminder,
+    SortCardTable,
     StopUserInput,
     TakeUserInput,
+    ToggleCalendarLayout,
+    ToggleCardSelection,
+    ToggleCardTimer,
     ToggleCommandPalette,
     Undo,
     Up,
@@ -169,12 +213,18 @@ impl Display for KeyBindingEnum {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Self::Accept => "accept",
+            Self::ApplySavedFilter1 => "apply_saved_filter_1",
+            Self::ApplySavedFilter2 => "apply_saved_filter_2",
+            Self::ApplySavedFilter3 => "apply_saved_filter_3",
+            Self::ArchiveCard => "archive_card",
             Self::ChangeCardStatusToActive => "change_card_status_to_active",
             Self::ChangeCardStatusToCompleted => "change_card_status_to_completed",
             Self::ChangeCardStatusToStale => "change_card_status_to_stale",
             Self::ClearAllToasts => "clear_all_toasts",
+            Self::CycleTimelineZoom => "cycle_timeline_zoom",
             Self::DeleteBoard => "delete_board",
             Self::DeleteCard => "delete_card",
+            Self::DismissReminder => "dismiss_reminder",
             Self::Down => "down",
             Self::GoToMainMenu => "go_to_main_menu",
             Self::GoToPreviousUIModeorCancel => "go_to_previous_ui_mode_or_cancel",
@@ -194,8 +244,13 @@ impl Display for KeyBindingEnum {
             Self::ResetUI => "reset_ui",
             Self::Right => "right",
             Self::SaveState => "save_state",
+            Self::SnoozeReminder => "snooze_reminder",
+            Self::SortCardTable => "sort_card_table",
             Self::StopUserInput => "stop_user_input",
             Self::TakeUserInput => "take_user_input",
+            Self::ToggleCalendarLayout => "toggle_calendar_layout",
+            Self::ToggleCardSelection => "toggle_card_selection",
+            Self::ToggleCardTimer => "toggle_card_timer",
             Self::ToggleCommandPalette => "toggle_command_palette",
             Self::Undo => "undo",
             Self::Up => "up",
@@ -208,12 +263,18 @@ impl FromStr for KeyBindingEnum {
     type Err = KeyBindingEnum;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "apply_saved_filter_1" => Ok(Self::ApplySavedFilter1),
+            "apply_saved_filter_2" => Ok(Self::ApplySavedFilter2),
+            "apply_saved_filter_3" => Ok(Self::ApplySavedFilter3),
+            "archive_card" => Ok(Self::ArchiveCard),
             "change_card_status_to_active" => Ok(Self::ChangeCardStatusToActive),
             "change_card_status_to_completed" => Ok(Self::ChangeCardStatusToCompleted),
             "change_card_status_to_stale" => Ok(Self::ChangeCardStatusToStale),
             "clear_all_toasts" => Ok(Self::ClearAllToasts),
+            "cycle_timeline_zoom" => Ok(Self::CycleTimelineZoom),
             "delete_board" => Ok(Self::DeleteBoard),
             "delete_card" => Ok(Self::DeleteCard),
+            "dismiss_reminder" => Ok(Self::DismissReminder),
             "down" => Ok(Self::Down),
             "go_to_main_menu" => Ok(Self::GoToMainMenu),
             "hide_ui_element" => Ok(Self::HideUiElement),
@@ -228,8 +289,13 @@ impl FromStr for KeyBindingEnum {
             "reset_ui" => Ok(Self::ResetUI),
             "right" => Ok(Self::Right),
             "save_state" => Ok(Self::SaveState),
+            "snooze_reminder" => Ok(Self::SnoozeReminder),
+            "sort_card_table" => Ok(Self::SortCardTable),
             "stop_user_input" => Ok(Self::StopUserInput),
             "take_user_input" => Ok(Self::TakeUserInput),
+            "toggle_calendar_layout" => Ok(Self::ToggleCalendarLayout),
+            "toggle_card_selection" => Ok(Self::ToggleCardSelection),
+            "toggle_card_timer" => Ok(Self::ToggleCardTimer),
             "toggle_command_palette" => Ok(Self::ToggleCommandPalette),
             "undo" => Ok(Self::Undo),
             "up" => Ok(Self::Up),
@@ -241,6 +307,15 @@ impl FromStr for KeyBindingEnum {
 impl UiMode {
     pub fn from_string(s: &str) -> Option<UiMode> {
         match s {
+            "Archive Browser" => Some(UiMode::ArchiveBrowser),
+            "Agenda" => Some(UiMode::Agenda),
+            "Sprints" => Some(UiMode::Sprints),
+            "Statistics" => Some(UiMode::Statistics),
+            "Card Table" => Some(UiMode::CardTable),
+            "Timeline" => Some(UiMode::Timeline),
+            "Calendar" => Some(UiMode::Calendar),
+            "Label Manager" => Some(UiMode::LabelManager),
+            "Workspace Switcher" => Some(UiMode::WorkspaceSwitcher),
             "Body and Help" => Some(UiMode::BodyHelp),
             "Body, Help and Log" => Some(UiMode::BodyHelpLog),
             "Body and Log" => Some(UiMode::BodyLog),
@@ -268,6 +343,15 @@ impl UiMode {
 
     pub fn from_json_string(s: &str) -> Option<UiMode> {
         match s {
+            "ArchiveBrowser" => Some(UiMode::ArchiveBrowser),
+            "Agenda" => Some(UiMode::Agenda),
+            "Sprints" => Some(UiMode::Sprints),
+            "Statistics" => Some(UiMode::Statistics),
+            "CardTable" => Some(UiMode::CardTable),
+            "Timeline" => Some(UiMode::Timeline),
+            "Calendar" => Some(UiMode::Calendar),
+            "LabelManager" => Some(UiMode::LabelManager),
+            "WorkspaceSwitcher" => Some(UiMode::WorkspaceSwitcher),
             "BodyHelp" => Some(UiMode::BodyHelp),
             "BodyHelpLog" => Some(UiMode::BodyHelpLog),
             "BodyLog" => Some(UiMode::BodyLog),
@@ -313,6 +397,15 @@ impl UiMode {
 
     pub fn get_available_targets(&self) -> Vec<Focus> {
         match self {
+            UiMode::ArchiveBrowser => vec![Focus::TextInput, Focus::Body],
+            UiMode::Agenda => vec![Focus::Body],
+            UiMode::Sprints => vec![Focus::Body],
+            UiMode::Statistics => vec![Focus::Body],
+            UiMode::CardTable => vec![Focus::Body],
+            UiMode::Timeline => vec![Focus::Body],
+            UiMode::Calendar => vec![Focus::Body],
+            UiMode::LabelManager => vec![Focus::Body, Focus::TextInput],
+            UiMode::WorkspaceSwitcher => vec![Focus::TextInput, Focus::Body],
             UiMode::BodyHelp => vec![Focus::Body, Focus::Help],
             UiMode::BodyHelpLog => vec![Focus::Body, Focus::Help, Focus::Log],
             UiMode::BodyLog => vec![Focus::Body, Focus::Log],
@@ -337,9 +430,11 @@ impl UiMode {
                 Focus::SubmitButton,
             ],
             UiMode::NewCard => vec![
+                Focus::CardTemplate,
                 Focus::CardName,
                 Focus::CardDescription,
                 Focus::CardDueDate,
+                Focus::CardEstimate,
                 Focus::SubmitButton,
             ],
             UiMode::ResetPassword => vec![
@@ -372,6 +467,15 @@ impl UiMode {
         UiMode::view_modes().iter().map(|x| x.to_string()).collect()
     }
 
+    /// the board views plus the views that work as a start page
+    pub fn default_view_modes_as_string() -> Vec<String> {
+        UiMode::view_modes()
+            .iter()
+            .chain([UiMode::Agenda].iter())
+            .map(|x| x.to_string())
+            .collect()
+    }
+
     pub fn view_modes() -> Vec<UiMode> {
         vec![
             UiMode::Zen,
@@ -446,6 +550,15 @@ impl UiMode {
             UiMode::SignUp => ui_helper::render_signup(rect, app),
             UiMode::ResetPassword => ui_helper::render_reset_password(rect, app),
             UiMode::LoadCloudSave => ui_helper::render_load_cloud_save(rect, app),
+            UiMode::ArchiveBrowser => ui_helper::render_archive_browser(rect, app),
+            UiMode::Agenda => ui_helper::render_agenda(rect, app),
+            UiMode::Sprints => ui_helper::render_sprints(rect, app),
+            UiMode::Statistics => ui_helper::render_statistics(rect, app),
+            UiMode::CardTable => ui_helper::render_card_table(rect, app),
+            UiMode::Timeline => ui_helper::render_timeline(rect, app),
+            UiMode::Calendar => ui_helper::render_calendar(rect, app),
+            UiMode::LabelManager => ui_helper::render_label_manager(rect, app),
+            UiMode::WorkspaceSwitcher => ui_helper::render_workspace_switcher(rect, app),
         }
     }
 }
@@ -453,6 +566,15 @@ impl UiMode {
 impl fmt::Display for UiMode {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            UiMode::ArchiveBrowser => write!(f, "Archive Browser"),
+            UiMode::Agenda => write!(f, "Agenda"),
+            UiMode::Sprints => write!(f, "Sprints"),
+            UiMode::Statistics => write!(f, "Statistics"),
+            UiMode::CardTable => write!(f, "Card Table"),
+            UiMode::Timeline => write!(f, "Timeline"),
+            UiMode::Calendar => write!(f, "Calendar"),
+            UiMode::LabelManager => write!(f, "Label Manager"),
+            UiMode::WorkspaceSwitcher => write!(f, "Workspace Switcher"),
             UiMode::BodyHelp => write!(f, "Body and Help"),
             UiMode::BodyHelpLog => write!(f, "Body, Help and Log"),
             UiMode::BodyLog => write!(f, "Body and Log"),
@@ -492,13 +614,25 @@ impl Display for Focus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Self::Body => "Body",
+            Self::BoardTemplateSelector => "Board Template Selector",
+            Self::CardBlockedBy => "Card Blocked By",
+            Self::CardEstimate => "Card Estimate",
+            Self::CardCustomFields => "Card Custom Fields",
             Self::CardComments => "Card Comments",
             Self::CardDescription => "Card Description",
             Self::CardDueDate => "Card Due Date",
             Self::CardName => "New Card Name",
             Self::CardPriority => "Card Priority",
+            Self::CardRecurrence => "Card Recurrence",
+            Self::CardActivityLog => "Card Activity Log",
+            Self::CardAttachments => "Card Attachments",
+            Self::CardAttachmentList => "Card Attachment List",
+            Self::CardSortModeSelector => "Card Sort Mode Selector",
+            Self::SavedFilterSelector => "Saved Filter Selector",
             Self::CardStatus => "Card Status",
             Self::CardTags => "Card Tags",
+            Self::CardTemplate => "Card Template",
+            Self::CardTemplateSelector => "Card Template Selector",
             Self::ChangeCardPriorityPopup => "Change Card Priority Popup",
             Self::ChangeCardStatusPopup => "Change Card Status Popup",
             Self::ChangeDateFormatPopup => "Change Date Format Popup",
@@ -507,6 +641,7 @@ impl Display for Focus {
             Self::CommandPaletteBoard => "Command Palette Board",
             Self::CommandPaletteCard => "Command Palette Card",
             Self::CommandPaletteCommand => "Command Palette Command",
+            Self::CustomFieldSelector => "Custom Field Selector",
             Self::ConfigHelp => "Config Help",
             Self::ConfigTable => "Config",
             Self::ConfirmPasswordField => "Confirm Password Field",
@@ -572,12 +707,24 @@ impl FromStr for Focus {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "Body" => Ok(Self::Body),
+            "Board Template Selector" => Ok(Self::BoardTemplateSelector),
+            "Card Blocked By" => Ok(Self::CardBlockedBy),
+            "Card Estimate" => Ok(Self::CardEstimate),
+            "Card Custom Fields" => Ok(Self::CardCustomFields),
             "Card Comments" => Ok(Self::CardComments),
             "Card Description" => Ok(Self::CardDescription),
             "Card Due Date" => Ok(Self::CardDueDate),
             "Card Priority" => Ok(Self::CardPriority),
+            "Card Recurrence" => Ok(Self::CardRecurrence),
+            "Card Activity Log" => Ok(Self::CardActivityLog),
+            "Card Attachments" => Ok(Self::CardAttachments),
+            "Card Attachment List" => Ok(Self::CardAttachmentList),
+            "Card Sort Mode Selector" => Ok(Self::CardSortModeSelector),
+            "Saved Filter Selector" => Ok(Self::SavedFilterSelector),
             "Card Status" => Ok(Self::CardStatus),
             "Card Tags" => Ok(Self::CardTags),
+            "Card Template" => Ok(Self::CardTemplate),
+            "Card Template Selector" => Ok(Self::CardTemplateSelector),
             "Change Card Priority Popup" => Ok(Self::ChangeCardPriorityPopup),
             "Change Card Status Popup" => Ok(Self::ChangeCardStatusPopup),
             "Change Date Format Popup" => Ok(Self::ChangeDateFormatPopup),
@@ -586,6 +733,7 @@ impl FromStr for Focus {
             "Command Palette Board" => Ok(Self::CommandPaletteBoard),
             "Command Palette Card" => Ok(Self::CommandPaletteCard),
             "Command Palette Command" => Ok(Self::CommandPaletteCommand),
+            "Custom Field Selector" => Ok(Self::CustomFieldSelector),
             "Config Help" => Ok(Self::ConfigHelp),
             "Config" => Ok(Self::ConfigTable),
             "Confirm Password Field" => Ok(Self::ConfirmPasswordField),
@@ -625,14 +773,20 @@ impl KeyBindings {
         KeyBindingEnum::iter().map(|enum_variant| {
             let value = match enum_variant {
                 KeyBindingEnum::Accept => &self.accept,
+                KeyBindingEnum::ApplySavedFilter1 => &self.apply_saved_filter_1,
+                KeyBindingEnum::ApplySavedFilter2 => &self.apply_saved_filter_2,
+                KeyBindingEnum::ApplySavedFilter3 => &self.apply_saved_filter_3,
+                KeyBindingEnum::ArchiveCard => &self.archive_card,
                 KeyBindingEnum::ChangeCardStatusToActive => &self.change_card_status_to_active,
                 KeyBindingEnum::ChangeCardStatusToCompleted => {
                     &self.change_card_status_to_completed
                 }
                 KeyBindingEnum::ChangeCardStatusToStale => &self.change_card_status_to_stale,
                 KeyBindingEnum::ClearAllToasts => &self.clear_all_toasts,
+                KeyBindingEnum::CycleTimelineZoom => &self.cycle_timeline_zoom,
                 KeyBindingEnum::DeleteBoard => &self.delete_board,
                 KeyBindingEnum::DeleteCard => &self.delete_card,
+                KeyBindingEnum::DismissReminder => &self.dismiss_reminder,
                 KeyBindingEnum::Down => &self.down,
                 KeyBindingEnum::GoToMainMenu => &self.go_to_main_menu,
                 KeyBindingEnum::GoToPreviousUIModeorCancel => {
@@ -654,8 +808,13 @@ impl KeyBindings {
                 KeyBindingEnum::ResetUI => &self.reset_ui,
                 KeyBindingEnum::Right => &self.right,
                 KeyBindingEnum::SaveState => &self.save_state,
+                KeyBindingEnum::SnoozeReminder => &self.snooze_reminder,
+                KeyBindingEnum::SortCardTable => &self.sort_card_table,
                 KeyBindingEnum::StopUserInput => &self.stop_user_input,
                 KeyBindingEnum::TakeUserInput => &self.take_user_input,
+                KeyBindingEnum::ToggleCalendarLayout => &self.toggle_calendar_layout,
+                KeyBindingEnum::ToggleCardSelection => &self.toggle_card_selection,
+                KeyBindingEnum::ToggleCardTimer => &self.toggle_card_timer,
                 KeyBindingEnum::ToggleCommandPalette => &self.toggle_command_palette,
                 KeyBindingEnum::Undo => &self.undo,
                 KeyBindingEnum::Up => &self.up,
@@ -675,12 +834,18 @@ impl KeyBindings {
     pub fn keybinding_enum_to_action(&self, keybinding_enum: KeyBindingEnum) -> Action {
         match keybinding_enum {
             KeyBindingEnum::Accept => Action::Accept,
+            KeyBindingEnum::ApplySavedFilter1 => Action::ApplySavedFilter1,
+            KeyBindingEnum::ApplySavedFilter2 => Action::ApplySavedFilter2,
+            KeyBindingEnum::ApplySavedFilter3 => Action::ApplySavedFilter3,
+            KeyBindingEnum::ArchiveCard => Action::ArchiveCard,
             KeyBindingEnum::ChangeCardStatusToActive => Action::ChangeCardStatusToActive,
             KeyBindingEnum::ChangeCardStatusToCompleted => Action::ChangeCardStatusToCompleted,
             KeyBindingEnum::ChangeCardStatusToStale => Action::ChangeCardStatusToStale,
             KeyBindingEnum::ClearAllToasts => Action::ClearAllToasts,
+            KeyBindingEnum::CycleTimelineZoom => Action::CycleTimelineZoom,
             KeyBindingEnum::DeleteBoard => Action::DeleteBoard,
             KeyBindingEnum::DeleteCard => Action::Delete,
+            KeyBindingEnum::DismissReminder => Action::DismissReminder,
             KeyBindingEnum::Down => Action::Down,
             KeyBindingEnum::GoToMainMenu => Action::GoToMainMenu,
             KeyBindingEnum::GoToPreviousUIModeorCancel => Action::GoToPreviousUIModeorCancel,
@@ -700,8 +865,13 @@ impl KeyBindings {
             KeyBindingEnum::ResetUI => Action::ResetUI,
             KeyBindingEnum::Right => Action::Right,
             KeyBindingEnum::SaveState => Action::SaveState,
+            KeyBindingEnum::SnoozeReminder => Action::SnoozeReminder,
+            KeyBindingEnum::SortCardTable => Action::SortCardTable,
             KeyBindingEnum::StopUserInput => Action::StopUserInput,
             KeyBindingEnum::TakeUserInput => Action::TakeUserInput,
+            KeyBindingEnum::ToggleCalendarLayout => Action::ToggleCalendarLayout,
+            KeyBindingEnum::ToggleCardSelection => Action::ToggleCardSelection,
+            KeyBindingEnum::ToggleCardTimer => Action::ToggleCardTimer,
             KeyBindingEnum::ToggleCommandPalette => Action::ToggleCommandPalette,
             KeyBindingEnum::Undo => Action::Undo,
             KeyBindingEnum::Up => Action::Up,
@@ -715,6 +885,10 @@ impl KeyBindings {
         if let Ok(keybinding_enum) = keybinding_enum {
             match keybinding_enum {
                 KeyBindingEnum::Accept => self.accept = keybinding,
+                KeyBindingEnum::ApplySavedFilter1 => self.apply_saved_filter_1 = keybinding,
+                KeyBindingEnum::ApplySavedFilter2 => self.apply_saved_filter_2 = keybinding,
+                KeyBindingEnum::ApplySavedFilter3 => self.apply_saved_filter_3 = keybinding,
+                KeyBindingEnum::ArchiveCard => self.archive_card = keybinding,
                 KeyBindingEnum::ChangeCardStatusToActive => {
                     self.change_card_status_to_active = keybinding
                 }
@@ -725,8 +899,10 @@ impl KeyBindings {
                     self.change_card_status_to_stale = keybinding
                 }
                 KeyBindingEnum::ClearAllToasts => self.clear_all_toasts = keybinding,
+                KeyBindingEnum::CycleTimelineZoom => self.cycle_timeline_zoom = keybinding,
                 KeyBindingEnum::DeleteBoard => self.delete_board = keybinding,
                 KeyBindingEnum::DeleteCard => self.delete_card = keybinding,
+                KeyBindingEnum::DismissReminder => self.dismiss_reminder = keybinding,
                 KeyBindingEnum::Down => self.down = keybinding,
                 KeyBindingEnum::GoToMainMenu => self.go_to_main_menu = keybinding,
                 KeyBindingEnum::GoToPreviousUIModeorCancel => {
@@ -748,8 +924,13 @@ impl KeyBindings {
                 KeyBindingEnum::ResetUI => self.reset_ui = keybinding,
                 KeyBindingEnum::Right => self.right = keybinding,
                 KeyBindingEnum::SaveState => self.save_state = keybinding,
+                KeyBindingEnum::SnoozeReminder => self.snooze_reminder = keybinding,
+                KeyBindingEnum::SortCardTable => self.sort_card_table = keybinding,
                 KeyBindingEnum::StopUserInput => self.stop_user_input = keybinding,
                 KeyBindingEnum::TakeUserInput => self.take_user_input = keybinding,
+                KeyBindingEnum::ToggleCalendarLayout => self.toggle_calendar_layout = keybinding,
+                KeyBindingEnum::ToggleCardSelection => self.toggle_card_selection = keybinding,
+                KeyBindingEnum::ToggleCardTimer => self.toggle_card_timer = keybinding,
                 KeyBindingEnum::ToggleCommandPalette => self.toggle_command_palette = keybinding,
                 KeyBindingEnum::Undo => self.undo = keybinding,
                 KeyBindingEnum::Up => self.up = keybinding,
@@ -763,6 +944,10 @@ impl KeyBindings {
     pub fn get_keybindings(&self, keybinding_enum: KeyBindingEnum) -> Option<Vec<Key>> {
         match keybinding_enum {
             KeyBindingEnum::Accept => Some(self.accept.clone()),
+            KeyBindingEnum::ApplySavedFilter1 => Some(self.apply_saved_filter_1.clone()),
+            KeyBindingEnum::ApplySavedFilter2 => Some(self.apply_saved_filter_2.clone()),
+            KeyBindingEnum::ApplySavedFilter3 => Some(self.apply_saved_filter_3.clone()),
+            KeyBindingEnum::ArchiveCard => Some(self.archive_card.clone()),
             KeyBindingEnum::ChangeCardStatusToActive => {
                 Some(self.change_card_status_to_active.clone())
             }
@@ -773,8 +958,10 @@ impl KeyBindings {
                 Some(self.change_card_status_to_stale.clone())
             }
             KeyBindingEnum::ClearAllToasts => Some(self.clear_all_toasts.clone()),
+            KeyBindingEnum::CycleTimelineZoom => Some(self.cycle_timeline_zoom.clone()),
             KeyBindingEnum::DeleteBoard => Some(self.delete_board.clone()),
             KeyBindingEnum::DeleteCard => Some(self.delete_card.clone()),
+            KeyBindingEnum::DismissReminder => Some(self.dismiss_reminder.clone()),
             KeyBindingEnum::Down => Some(self.down.clone()),
             KeyBindingEnum::GoToMainMenu => Some(self.go_to_main_menu.clone()),
             KeyBindingEnum::GoToPreviousUIModeorCancel => {
@@ -796,8 +983,13 @@ impl KeyBindings {
             KeyBindingEnum::ResetUI => Some(self.reset_ui.clone()),
             KeyBindingEnum::Right => Some(self.right.clone()),
             KeyBindingEnum::SaveState => Some(self.save_state.clone()),
+            KeyBindingEnum::SnoozeReminder => Some(self.snooze_reminder.clone()),
+            KeyBindingEnum::SortCardTable => Some(self.sort_card_table.clone()),
             KeyBindingEnum::StopUserInput => Some(self.stop_user_input.clone()),
             KeyBindingEnum::TakeUserInput => Some(self.take_user_input.clone()),
+            KeyBindingEnum::ToggleCalendarLayout => Some(self.toggle_calendar_layout.clone()),
+            KeyBindingEnum::ToggleCardSelection => Some(self.toggle_card_selection.clone()),
+            KeyBindingEnum::ToggleCardTimer => Some(self.toggle_card_timer.clone()),
             KeyBindingEnum::ToggleCommandPalette => Some(self.toggle_command_palette.clone()),
             KeyBindingEnum::Undo => Some(self.undo.clone()),
             KeyBindingEnum::Up => Some(self.up.clone()),
@@ -809,12 +1001,18 @@ impl Default for KeyBindings {
     fn default() -> Self {
         Self {
             accept: vec![Key::Enter],
+            apply_saved_filter_1: vec![Key::Alt('1')],
+            apply_saved_filter_2: vec![Key::Alt('2')],
+            apply_saved_filter_3: vec![Key::Alt('3')],
+            archive_card: vec![Key::Char('a')],
             change_card_status_to_active: vec![Key::Char('2')],
             change_card_status_to_completed: vec![Key::Char('1')],
             change_card_status_to_stale: vec![Key::Char('3')],
             clear_all_toasts: vec![Key::Char('t')],
+            cycle_timeline_zoom: vec![Key::Char('z')],
             delete_board: vec![Key::Char('D')],
             delete_card: vec![Key::Char('d'), Key::Delete],
+            dismiss_reminder: vec![Key::Char('X')],
             down: vec![Key::Down],
             go_to_main_menu: vec![Key::Char('m')],
             go_to_previous_ui_mode_or_cancel: vec![Key::Esc],
@@ -834,8 +1032,13 @@ impl Default for KeyBindings {
             reset_ui: vec![Key::Char('r')],
             right: vec![Key::Right],
             save_state: vec![Key::Ctrl('s')],
+            snooze_reminder: vec![Key::Char('S')],
+            sort_card_table: vec![Key::Char('s')],
             stop_user_input: vec![Key::Ins],
             take_user_input: vec![Key::Char('i')],
+            toggle_calendar_layout: vec![Key::Char('w')],
+            toggle_card_selection: vec![Key::Char('x')],
+            toggle_card_timer: vec![Key::Char('T')],
             toggle_command_palette: vec![Key::Ctrl('p')],
             undo: vec![Key::Ctrl('z')],
             up: vec![Key::Up],
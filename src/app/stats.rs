@@ -0,0 +1,212 @@
+use super::kanban::{parse_timestamp, Boards, Card, CardActivityKind, CardPriority, CardStatus};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
+use std::collections::BTreeMap;
+
+/// number of weeks shown in the throughput chart, including the current one
+pub const THROUGHPUT_WEEKS: usize = 12;
+
+#[derive(Debug, Clone, Default)]
+pub struct FlowTimes {
+    pub name: String,
+    pub completed: usize,
+    /// average seconds from creation to completion
+    pub average_lead_time: Option<i64>,
+    /// average seconds from starting work to completion
+    pub average_cycle_time: Option<i64>,
+}
+
+/// card counts per status on one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowSnapshot {
+    pub date: NaiveDate,
+    pub active: usize,
+    pub stale: usize,
+    pub complete: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CardStatistics {
+    /// (monday of the week, cards completed that week), oldest first
+    pub completed_per_week: Vec<(NaiveDate, usize)>,
+    pub by_board: Vec<FlowTimes>,
+    pub by_tag: Vec<FlowTimes>,
+    pub by_priority: Vec<(CardPriority, usize)>,
+    pub by_status: Vec<(CardStatus, usize)>,
+    /// one point per snapshot day, the last point is the current state
+    pub cumulative_flow: Vec<FlowSnapshot>,
+}
+
+#[derive(Default)]
+struct FlowTimesBuilder {
+    completed: usize,
+    lead_times: Vec<i64>,
+    cycle_times: Vec<i64>,
+}
+
+impl FlowTimesBuilder {
+    fn add(&mut self, lead_time: Option<i64>, cycle_time: Option<i64>) {
+        self.completed += 1;
+        self.lead_times.extend(lead_time);
+        self.cycle_times.extend(cycle_time);
+    }
+
+    fn build(self, name: String) -> FlowTimes {
+        let average = |times: &[i64]| {
+            if times.is_empty() {
+                None
+            } else {
+                Some(times.iter().sum::<i64>() / times.len() as i64)
+            }
+        };
+        FlowTimes {
+            name,
+            completed: self.completed,
+            average_lead_time: average(&self.lead_times),
+            average_cycle_time: average(&self.cycle_times),
+        }
+    }
+}
+
+impl CardStatistics {
+    /// archived cards count too so auto archiving does not hide finished work,
+    /// snapshots are (day, boards) from older saves used for the cumulative flow
+    pub fn new(boards: &Boards, snapshots: &[(NaiveDate, Boards)]) -> Self {
+        let today = Local::now().date_naive();
+        let current_week = week_start(today);
+        let mut completed_per_week = (0..THROUGHPUT_WEEKS as i64)
+            .rev()
+            .map(|weeks_ago| (current_week - Duration::weeks(weeks_ago), 0))
+            .collect::<Vec<(NaiveDate, usize)>>();
+        let mut by_board = vec![];
+        let mut by_tag: BTreeMap<String, FlowTimesBuilder> = BTreeMap::new();
+        let mut by_priority = CardPriority::all()
+            .into_iter()
+            .map(|priority| (priority, 0))
+            .collect::<Vec<(CardPriority, usize)>>();
+        let mut by_status = CardStatus::all()
+            .into_iter()
+            .map(|status| (status, 0))
+            .collect::<Vec<(CardStatus, usize)>>();
+
+        for board in boards.get_boards() {
+            let mut board_times = FlowTimesBuilder::default();
+            for card in board.cards.get_all_cards() {
+                if let Some(count) = by_priority
+                    .iter_mut()
+                    .find(|(priority, _)| *priority == card.priority)
+                {
+                    count.1 += 1;
+                }
+                if let Some(count) = by_status
+                    .iter_mut()
+                    .find(|(status, _)| *status == card.card_status)
+                {
+                    count.1 += 1;
+                }
+            }
+            let all_cards = board.cards.get_all_cards().iter().chain(
+                board
+                    .archived_cards
+                    .iter()
+                    .map(|archived_card| &archived_card.card),
+            );
+            for card in all_cards {
+                if card.card_status != CardStatus::Complete {
+                    continue;
+                }
+                let date_completed = match parse_timestamp(&card.date_completed) {
+                    Some(date_completed) => date_completed,
+                    None => continue,
+                };
+                let completed_week = week_start(date_completed.with_timezone(&Local).date_naive());
+                if let Some(week) = completed_per_week
+                    .iter_mut()
+                    .find(|(week, _)| *week == completed_week)
+                {
+                    week.1 += 1;
+                }
+                let lead_time = parse_timestamp(&card.date_created)
+                    .map(|date_created| (date_completed - date_created).num_seconds().max(0));
+                let cycle_time = work_started(card)
+                    .map(|work_started| (date_completed - work_started).num_seconds().max(0));
+                board_times.add(lead_time, cycle_time);
+                for tag in &card.tags {
+                    let tag = tag.to_lowercase();
+                    if !tag.is_empty() {
+                        by_tag.entry(tag).or_default().add(lead_time, cycle_time);
+                    }
+                }
+            }
+            by_board.push(board_times.build(board.name.clone()));
+        }
+
+        let mut cumulative_flow = snapshots
+            .iter()
+            .filter(|(date, _)| *date < today)
+            .map(|(date, snapshot_boards)| count_statuses(*date, snapshot_boards))
+            .collect::<Vec<FlowSnapshot>>();
+        cumulative_flow.sort_by_key(|snapshot| snapshot.date);
+        cumulative_flow.dedup_by_key(|snapshot| snapshot.date);
+        cumulative_flow.push(count_statuses(today, boards));
+
+        let mut by_tag = by_tag
+            .into_iter()
+            .map(|(tag, tag_times)| tag_times.build(tag))
+            .collect::<Vec<FlowTimes>>();
+        by_tag.sort_by(|a, b| b.completed.cmp(&a.completed).then(a.name.cmp(&b.name)));
+        CardStatistics {
+            completed_per_week,
+            by_board,
+            by_tag,
+            by_priority,
+            by_status,
+            cumulative_flow,
+        }
+    }
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// work starts with the first tracked time or, without tracked time, the first move off the starting board
+fn work_started(card: &Card) -> Option<DateTime<Utc>> {
+    let first_time_entry = card
+        .time_entries
+        .iter()
+        .map(|entry| entry.start)
+        .min()
+        .and_then(|start| DateTime::from_timestamp(start, 0));
+    first_time_entry.or_else(|| {
+        card.activity_log
+            .iter()
+            .filter(|activity| matches!(activity.kind, CardActivityKind::Moved { .. }))
+            .filter_map(|activity| parse_timestamp(&activity.timestamp))
+            .min()
+    })
+}
+
+fn count_statuses(date: NaiveDate, boards: &Boards) -> FlowSnapshot {
+    let mut snapshot = FlowSnapshot {
+        date,
+        active: 0,
+        stale: 0,
+        complete: 0,
+    };
+    for board in boards.get_boards() {
+        let all_cards = board.cards.get_all_cards().iter().chain(
+            board
+                .archived_cards
+                .iter()
+                .map(|archived_card| &archived_card.card),
+        );
+        for card in all_cards {
+            match card.card_status {
+                CardStatus::Active => snapshot.active += 1,
+                CardStatus::Stale => snapshot.stale += 1,
+                CardStatus::Complete => snapshot.complete += 1,
+            }
+        }
+    }
+    snapshot
+}
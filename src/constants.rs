@@ -1,14 +1,22 @@
 use crate::app::state::UiMode;
 
+pub const AGENDA_RECENT_DAYS: i64 = 2;
+pub const AGENDA_UPCOMING_DAYS: i64 = 7;
 pub const APP_TITLE: &str = "Rust 🦀 Kanban";
+pub const BOARD_TEMPLATE_DIR_NAME: &str = "templates";
+pub const BOARD_TEMPLATE_FILE_NAME: &str = "kanban_board_template";
+pub const CARD_TEMPLATE_FILE_NAME: &str = "kanban_card_template";
 pub const CONFIG_DIR_NAME: &str = "rust_kanban";
 pub const CONFIG_FILE_NAME: &str = "config.json";
+pub const DEFAULT_AUTO_ARCHIVE_DAYS: u16 = 0; // 0 disables auto archive
 pub const DEFAULT_BOARD_TITLE_LENGTH: u16 = 20;
 pub const DEFAULT_CARD_TITLE_LENGTH: u16 = 20;
 pub const DEFAULT_CARD_WARNING_DUE_DATE_DAYS: u16 = 3;
+pub const DEFAULT_REMINDER_HOURS: u16 = 2;
 pub const DEFAULT_TICKRATE: u16 = 50;
 pub const DEFAULT_TOAST_DURATION: u64 = 5;
 pub const DEFAULT_UI_MODE: UiMode = UiMode::TitleBodyHelpLog;
+pub const DEFAULT_WORKSPACE_NAME: &str = "Default";
 pub const ENCRYPTION_KEY_FILE_NAME: &str = "kanban_encryption_key";
 pub const FIELD_NA: &str = "N/A";
 pub const FIELD_NOT_SET: &str = "Not Set";
@@ -16,8 +24,10 @@ pub const HIDDEN_PASSWORD_SYMBOL: char = '•';
 pub const IO_EVENT_WAIT_TIME: u64 = 5; // ms
 pub const LIST_SELECTED_SYMBOL: &str = ">> ";
 pub const LOGIN_FORM_DEFAULT_STATE: ([&str; 2], bool) = (["", ""], false);
+pub const MAX_AUTO_ARCHIVE_DAYS: u16 = 365;
 pub const MAX_NO_BOARDS_PER_PAGE: u16 = 5;
 pub const MAX_NO_CARDS_PER_BOARD: u16 = 4;
+pub const MAX_REMINDER_HOURS: u16 = 168;
 pub const MAX_TICKRATE: u16 = 1000;
 pub const MAX_TOASTS_TO_DISPLAY: usize = 5;
 pub const MAX_WARNING_DUE_DATE_DAYS: u16 = 30;
@@ -29,10 +39,14 @@ pub const MIN_TICKRATE: u16 = 10;
 pub const MIN_WARNING_DUE_DATE_DAYS: u16 = 1;
 pub const MOUSE_OUT_OF_BOUNDS_COORDINATES: (u16, u16) = (9999, 9999);
 pub const NEW_BOARD_FORM_DEFAULT_STATE: [&str; 2] = ["", ""];
-pub const NEW_CARD_FORM_DEFAULT_STATE: [&str; 3] = ["", "", ""];
+pub const NEW_CARD_FORM_DEFAULT_STATE: [&str; 4] = ["", "", "", ""];
 pub const NO_OF_BOARDS_PER_PAGE: u16 = 3;
 pub const NO_OF_CARDS_PER_BOARD: u16 = 2;
 pub const PATTERN_CHANGE_INTERVAL: u64 = 1000; // ms
+pub const REMINDER_CHECK_INTERVAL: u64 = 30; // seconds
+pub const REMINDER_FILE_NAME: &str = "kanban_reminders.json";
+pub const REMINDER_SNOOZE_MINUTES: i64 = 60;
+pub const REMINDER_TOAST_DURATION: u64 = 15; // seconds
 pub const RANDOM_SEARCH_TERM: &str = "iibnigivirneiivure";
 pub const REFRESH_TOKEN_FILE_NAME: &str = "kanban_token";
 pub const REFRESH_TOKEN_SEPARATOR: &str = "<<>>";
@@ -53,6 +67,7 @@ pub const THEME_DIR_NAME: &str = "themes";
 pub const THEME_FILE_NAME: &str = "kanban_theme";
 pub const TOAST_FADE_IN_TIME: u64 = 200;
 pub const TOAST_FADE_OUT_TIME: u64 = 400;
+pub const WORKSPACE_DIR_NAME: &str = "workspaces";
 
 // Cloud Stuff
 pub const MAX_PASSWORD_LENGTH: usize = 32;
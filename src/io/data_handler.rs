@@ -1,20 +1,24 @@
 use super::io_handler::{get_config_dir, make_file_system_safe_name};
 use crate::{
     app::{
-        kanban::{Board, Boards},
+        kanban::{Board, BoardTemplate, Boards, CardTemplate, TimeReportRange},
+        reminders::ReminderLog,
+        stats::CardStatistics,
         AppConfig,
     },
     constants::{
-        CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME, SAVE_FILE_REGEX,
-        THEME_DIR_NAME, THEME_FILE_NAME,
+        BOARD_TEMPLATE_DIR_NAME, BOARD_TEMPLATE_FILE_NAME, CARD_TEMPLATE_FILE_NAME,
+        CONFIG_DIR_NAME, CONFIG_FILE_NAME, REMINDER_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME,
+        SAVE_FILE_REGEX, THEME_DIR_NAME, THEME_FILE_NAME,
     },
     inputs::key::Key,
     io::io_handler::prepare_config_dir,
     ui::Theme,
 };
+use chrono::TimeZone;
 use log::{debug, error, info};
 use regex::Regex;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{cmp::Ordering, collections::HashMap, env, fs, path::PathBuf};
 
 pub fn get_config(ignore_overlapped_keybindings: bool) -> Result<AppConfig, String> {
@@ -99,6 +103,30 @@ pub fn write_config(config: &AppConfig) -> Result<(), String> {
     }
 }
 
+pub fn get_reminder_log() -> ReminderLog {
+    let reminder_file = match get_config_dir() {
+        Ok(config_dir) => config_dir.join(REMINDER_FILE_NAME),
+        Err(_) => return ReminderLog::default(),
+    };
+    match fs::read_to_string(&reminder_file) {
+        Ok(reminder_log) => serde_json::from_str(&reminder_log).unwrap_or_else(|e| {
+            error!("Could not read reminder log: {}", e);
+            ReminderLog::default()
+        }),
+        Err(_) => ReminderLog::default(),
+    }
+}
+
+pub fn save_reminder_log(reminder_log: &ReminderLog) -> Result<(), String> {
+    prepare_config_dir()?;
+    let config_dir = get_config_dir()?;
+    fs::write(
+        config_dir.join(REMINDER_FILE_NAME),
+        serde_json::to_string_pretty(reminder_log).unwrap(),
+    )
+    .map_err(|e| e.to_string())
+}
+
 pub fn reset_config() {
     let config = AppConfig::default();
     let write_config_status = write_config(&config);
@@ -111,7 +139,12 @@ pub fn reset_config() {
 }
 
 pub fn save_kanban_state_locally(boards: Vec<Board>, config: &AppConfig) -> Result<(), String> {
-    let files = fs::read_dir(&config.save_directory);
+    let save_directory = config.get_save_directory();
+    if let Err(e) = fs::create_dir_all(&save_directory) {
+        debug!("Error creating save directory: {}", e);
+        return Err("Error creating save directory".to_string());
+    }
+    let files = fs::read_dir(&save_directory);
     if files.is_err() {
         return Err("Error reading save directory".to_string());
     }
@@ -169,7 +202,7 @@ pub fn get_local_kanban_state(
     preview_mode: bool,
     config: &AppConfig,
 ) -> Result<Boards, String> {
-    let file_path = config.save_directory.join(file_name);
+    let file_path = config.get_save_directory().join(file_name);
     if !preview_mode {
         info!("Loading local save file: {:?}", file_path);
     }
@@ -206,7 +239,12 @@ pub fn get_local_kanban_state(
 }
 
 pub fn get_available_local_save_files(config: &AppConfig) -> Option<Vec<String>> {
-    let read_dir_status = fs::read_dir(&config.save_directory);
+    let save_directory = config.get_save_directory();
+    if save_directory != config.save_directory && !save_directory.exists() {
+        // a workspace without any saves yet
+        return Some(vec![]);
+    }
+    let read_dir_status = fs::read_dir(&save_directory);
     match read_dir_status {
         Ok(files) => {
             let mut savefiles = Vec::new();
@@ -272,6 +310,35 @@ pub fn get_available_local_save_files(config: &AppConfig) -> Option<Vec<String>>
     }
 }
 
+/// the last save of every day as (day, boards), oldest first
+pub fn get_local_save_snapshots(config: &AppConfig) -> Vec<(chrono::NaiveDate, Boards)> {
+    let save_files = get_available_local_save_files(config).unwrap_or_default();
+    let mut last_save_per_day: Vec<(chrono::NaiveDate, String)> = Vec::new();
+    // save files are sorted by date and version, so later versions replace earlier ones
+    for save_file in save_files {
+        let save_date = save_file
+            .split('_')
+            .nth(1)
+            .and_then(|date| chrono::NaiveDate::parse_from_str(date, "%d-%m-%Y").ok());
+        let save_date = match save_date {
+            Some(save_date) => save_date,
+            None => continue,
+        };
+        match last_save_per_day.last_mut() {
+            Some((last_date, last_file)) if *last_date == save_date => *last_file = save_file,
+            _ => last_save_per_day.push((save_date, save_file)),
+        }
+    }
+    last_save_per_day
+        .into_iter()
+        .filter_map(|(save_date, save_file)| {
+            get_local_kanban_state(save_file, true, config)
+                .ok()
+                .map(|boards| (save_date, boards))
+        })
+        .collect()
+}
+
 pub fn export_kanban_to_json(
     boards: &[Board],
     config: &AppConfig,
@@ -288,7 +355,7 @@ pub fn export_kanban_to_json(
         export_date: date,
         kanban_version: version.to_string(),
     };
-    let file_path = config.save_directory.join(file_name);
+    let file_path = config.get_save_directory().join(file_name);
     let write_status = fs::write(
         file_path.clone(),
         serde_json::to_string_pretty(&export_struct).unwrap(),
@@ -299,27 +366,178 @@ pub fn export_kanban_to_json(
     }
 }
 
+fn escape_csv_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// writes every time entry that overlaps the range as a csv row to the save directory,
+/// archived cards included
+pub fn export_time_report_to_csv(
+    boards: &Boards,
+    range: TimeReportRange,
+    config: &AppConfig,
+) -> Result<String, String> {
+    let (range_start, range_end) = range.to_timestamps();
+    let mut csv = String::from("board,card,tags,custom_fields,start,end,duration_seconds\n");
+    for board in boards.get_boards() {
+        let all_cards = board.cards.get_all_cards().iter().chain(
+            board
+                .archived_cards
+                .iter()
+                .map(|archived_card| &archived_card.card),
+        );
+        for card in all_cards {
+            for entry in &card.time_entries {
+                let duration = entry.duration_within(range_start, range_end);
+                if duration == 0 {
+                    continue;
+                }
+                let format_timestamp = |timestamp: i64| {
+                    chrono::Local
+                        .timestamp_opt(timestamp, 0)
+                        .single()
+                        .map(|date_time| date_time.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_default()
+                };
+                let end = entry.end.map(format_timestamp).unwrap_or_default();
+                let custom_fields = card
+                    .custom_fields
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", name, value))
+                    .collect::<Vec<String>>()
+                    .join(";");
+                csv.push_str(&format!(
+                    "{},{},{},{},{},{},{}\n",
+                    escape_csv_field(&board.name),
+                    escape_csv_field(&card.name),
+                    escape_csv_field(&card.tags.join(";")),
+                    escape_csv_field(&custom_fields),
+                    format_timestamp(entry.start),
+                    end,
+                    duration
+                ));
+            }
+        }
+    }
+    let file_name = format!(
+        "{}_time_report_{}_{}.csv",
+        SAVE_FILE_NAME,
+        range.to_string().to_lowercase().replace(' ', "_"),
+        chrono::Local::now().format("%d-%m-%Y_%H-%M-%S")
+    );
+    let save_directory = config.get_save_directory();
+    fs::create_dir_all(&save_directory).map_err(|e| e.to_string())?;
+    let file_path = save_directory.join(file_name);
+    match fs::write(file_path.clone(), csv) {
+        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// writes the statistics as metric,group,name,value rows to the save directory, times are in hours
+pub fn export_statistics_to_csv(
+    statistics: &CardStatistics,
+    config: &AppConfig,
+) -> Result<String, String> {
+    let mut csv = String::from("metric,group,name,value\n");
+    let hours = |seconds: Option<i64>| {
+        seconds
+            .map(|seconds| format!("{:.2}", seconds as f64 / 3600.0))
+            .unwrap_or_default()
+    };
+    for (week, completed) in &statistics.completed_per_week {
+        csv.push_str(&format!("completed,week,{},{}\n", week, completed));
+    }
+    for (group, flow_times) in statistics
+        .by_board
+        .iter()
+        .map(|flow_times| ("board", flow_times))
+        .chain(
+            statistics
+                .by_tag
+                .iter()
+                .map(|flow_times| ("tag", flow_times)),
+        )
+    {
+        let name = escape_csv_field(&flow_times.name);
+        csv.push_str(&format!(
+            "completed,{},{},{}\n",
+            group, name, flow_times.completed
+        ));
+        csv.push_str(&format!(
+            "lead_time_hours,{},{},{}\n",
+            group,
+            name,
+            hours(flow_times.average_lead_time)
+        ));
+        csv.push_str(&format!(
+            "cycle_time_hours,{},{},{}\n",
+            group,
+            name,
+            hours(flow_times.average_cycle_time)
+        ));
+    }
+    for (priority, count) in &statistics.by_priority {
+        csv.push_str(&format!("cards,priority,{},{}\n", priority, count));
+    }
+    for (status, count) in &statistics.by_status {
+        csv.push_str(&format!("cards,status,{},{}\n", status, count));
+    }
+    for snapshot in &statistics.cumulative_flow {
+        for (status, count) in [
+            ("active", snapshot.active),
+            ("stale", snapshot.stale),
+            ("complete", snapshot.complete),
+        ] {
+            csv.push_str(&format!(
+                "cumulative_flow,{},{},{}\n",
+                snapshot.date, status, count
+            ));
+        }
+    }
+    let file_name = format!(
+        "{}_statistics_{}.csv",
+        SAVE_FILE_NAME,
+        chrono::Local::now().format("%d-%m-%Y_%H-%M-%S")
+    );
+    let save_directory = config.get_save_directory();
+    fs::create_dir_all(&save_directory).map_err(|e| e.to_string())?;
+    let file_path = save_directory.join(file_name);
+    match fs::write(file_path.clone(), csv) {
+        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
 pub fn get_default_save_directory() -> PathBuf {
     let mut default_save_path = env::temp_dir();
     default_save_path.push(SAVE_DIR_NAME);
     default_save_path
 }
 
-fn get_theme_dir() -> Result<PathBuf, String> {
+fn get_config_sub_dir(sub_dir_name: &str) -> Result<PathBuf, String> {
     let home_dir = home::home_dir();
     if home_dir.is_none() {
         return Err(String::from("Error getting home directory"));
     }
-    let mut theme_dir = home_dir.unwrap();
+    let mut config_sub_dir = home_dir.unwrap();
     if cfg!(windows) {
-        theme_dir.push("AppData");
-        theme_dir.push("Roaming");
+        config_sub_dir.push("AppData");
+        config_sub_dir.push("Roaming");
     } else {
-        theme_dir.push(".config");
+        config_sub_dir.push(".config");
     }
-    theme_dir.push(CONFIG_DIR_NAME);
-    theme_dir.push(THEME_DIR_NAME);
-    Ok(theme_dir)
+    config_sub_dir.push(CONFIG_DIR_NAME);
+    config_sub_dir.push(sub_dir_name);
+    Ok(config_sub_dir)
+}
+
+fn get_theme_dir() -> Result<PathBuf, String> {
+    get_config_sub_dir(THEME_DIR_NAME)
 }
 
 pub fn get_saved_themes() -> Option<Vec<Theme>> {
@@ -377,6 +595,71 @@ pub fn save_theme(theme: Theme) -> Result<String, String> {
     Ok(theme_path.to_str().unwrap().to_string())
 }
 
+fn get_saved_templates<T: DeserializeOwned>(template_file_name: &str) -> Vec<T> {
+    let template_dir = if let Ok(template_dir) = get_config_sub_dir(BOARD_TEMPLATE_DIR_NAME) {
+        template_dir
+    } else {
+        return Vec::new();
+    };
+    let files = if let Ok(files) = fs::read_dir(&template_dir) {
+        files
+    } else {
+        return Vec::new();
+    };
+    let re = Regex::new(&format!("^{}_.*\\.json$", template_file_name)).unwrap();
+    let mut templates = Vec::new();
+    for file in files.flatten() {
+        let file_name = file.file_name().to_string_lossy().to_string();
+        if !re.is_match(&file_name) {
+            continue;
+        }
+        let parsed_template = fs::read_to_string(template_dir.join(&file_name))
+            .map_err(|e| e.to_string())
+            .and_then(|template| serde_json::from_str::<T>(&template).map_err(|e| e.to_string()));
+        match parsed_template {
+            Ok(template) => templates.push(template),
+            Err(e) => error!("Could not read template {}: {}", file_name, e),
+        }
+    }
+    templates
+}
+
+fn save_template<T: Serialize>(
+    template: &T,
+    template_file_name: &str,
+    template_name: &str,
+) -> Result<String, String> {
+    let template_dir = get_config_sub_dir(BOARD_TEMPLATE_DIR_NAME)?;
+    fs::create_dir_all(&template_dir).map_err(|e| e.to_string())?;
+    let template_path = template_dir.join(format!(
+        "{}_{}.json",
+        template_file_name,
+        make_file_system_safe_name(template_name)
+    ));
+    fs::write(
+        &template_path,
+        serde_json::to_string_pretty(template).unwrap(),
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(template_path.to_string_lossy().to_string())
+}
+
+pub fn get_saved_board_templates() -> Vec<BoardTemplate> {
+    let mut templates = get_saved_templates::<BoardTemplate>(BOARD_TEMPLATE_FILE_NAME);
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+pub fn save_board_template(template: &BoardTemplate) -> Result<String, String> {
+    save_template(template, BOARD_TEMPLATE_FILE_NAME, &template.name)
+}
+
+pub fn get_saved_card_templates() -> Vec<CardTemplate> {
+    let mut templates = get_saved_templates::<CardTemplate>(CARD_TEMPLATE_FILE_NAME);
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
 fn write_default_config() {
     let config = AppConfig::default();
     let write_config_status = write_config(&config);
@@ -1,16 +1,19 @@
 use super::{
-    data_handler::{get_available_local_save_files, get_local_kanban_state},
+    data_handler::{get_available_local_save_files, get_local_kanban_state, write_config},
     IoEvent,
 };
 use crate::{
     app::{
-        app_helper::handle_go_to_previous_ui_mode, kanban::Boards, state::UiMode, App, AppConfig,
-        UserLoginData,
+        app_helper::{handle_go_to_previous_ui_mode, jump_to_card},
+        kanban::Boards,
+        state::UiMode,
+        App, AppConfig, UserLoginData, WorkspaceSession,
     },
     constants::{
-        CONFIG_DIR_NAME, CONFIG_FILE_NAME, ENCRYPTION_KEY_FILE_NAME, MAX_PASSWORD_LENGTH,
-        MIN_PASSWORD_LENGTH, MIN_TIME_BETWEEN_SENDING_RESET_LINK, REFRESH_TOKEN_FILE_NAME,
-        REFRESH_TOKEN_SEPARATOR, SAVE_DIR_NAME, SUPABASE_ANON_KEY, SUPABASE_URL,
+        CONFIG_DIR_NAME, CONFIG_FILE_NAME, DEFAULT_WORKSPACE_NAME, ENCRYPTION_KEY_FILE_NAME,
+        MAX_PASSWORD_LENGTH, MIN_PASSWORD_LENGTH, MIN_TIME_BETWEEN_SENDING_RESET_LINK,
+        REFRESH_TOKEN_FILE_NAME, REFRESH_TOKEN_SEPARATOR, SAVE_DIR_NAME, SUPABASE_ANON_KEY,
+        SUPABASE_URL,
     },
     io::data_handler::{get_default_save_directory, get_saved_themes, save_kanban_state_locally},
     ui::TextColorOptions,
@@ -72,6 +75,9 @@ impl IoAsyncHandler<'_> {
             IoEvent::LoadSaveCloud => self.load_save_file_cloud().await,
             IoEvent::LoadCloudPreview => self.preview_cloud_save().await,
             IoEvent::DeleteCloudSave => self.delete_cloud_save().await,
+            IoEvent::SwitchWorkspace(workspace_name, card_to_select) => {
+                self.switch_workspace(workspace_name, card_to_select).await
+            }
         };
 
         let mut app = self.app.lock().await;
@@ -101,7 +107,7 @@ impl IoAsyncHandler<'_> {
         if let Some(saved_themes) = saved_themes {
             app.all_themes.extend(saved_themes);
         }
-        let default_theme = app.config.default_theme.clone();
+        let default_theme = app.config.get_theme_name();
         for theme in &app.all_themes {
             if theme.name == default_theme {
                 app.current_theme = theme.clone();
@@ -249,7 +255,7 @@ impl IoAsyncHandler<'_> {
         }
         let file_name = file_list[selected].clone();
         info!("🚀 Deleting save file: {}", file_name);
-        let path = app.config.save_directory.join(file_name);
+        let path = app.config.get_save_directory().join(file_name);
         if !Path::new(&path).exists() {
             error!("Cannot delete save file: file not found");
             app.send_error_toast("Cannot delete save file: file not found", None);
@@ -283,6 +289,85 @@ impl IoAsyncHandler<'_> {
         Ok(())
     }
 
+    async fn switch_workspace(
+        &mut self,
+        workspace_name: String,
+        card_to_select: Option<(u64, u64)>,
+    ) -> Result<()> {
+        let mut app = self.app.lock().await;
+        if workspace_name != app.config.current_workspace {
+            if save_required(&mut app) {
+                if let Err(err) =
+                    save_kanban_state_locally(app.boards.get_boards().to_vec(), &app.config)
+                {
+                    debug!("Cannot save workspace before switching: {:?}", err);
+                    app.send_error_toast(
+                        "Cannot save the current workspace, not switching workspaces",
+                        None,
+                    );
+                    return Ok(());
+                }
+            }
+            let previous_workspace = app.config.current_workspace.clone();
+            let previous_session = WorkspaceSession {
+                filter_tags: app.state.filter_tags.take(),
+                filter_query: app.state.filter_query.take(),
+                active_saved_filter: app.state.active_saved_filter.take(),
+                filtered_boards: std::mem::take(&mut app.filtered_boards),
+            };
+            app.workspace_sessions
+                .insert(previous_workspace, previous_session);
+            app.config.current_workspace = workspace_name.clone();
+            if let Err(err) = write_config(&app.config) {
+                error!("Cannot remember the current workspace: {}", err);
+                app.send_error_toast("Cannot remember the current workspace", None);
+            }
+            let boards = match get_latest_save_file(&app.config) {
+                Ok(save_file_name) => {
+                    match get_local_kanban_state(save_file_name.clone(), false, &app.config) {
+                        Ok(boards) => boards,
+                        Err(err) => {
+                            debug!("Cannot load workspace save {}: {:?}", save_file_name, err);
+                            app.send_error_toast(
+                                &format!("Cannot load workspace save {}", save_file_name),
+                                None,
+                            );
+                            Boards::default()
+                        }
+                    }
+                }
+                Err(_) => Boards::default(),
+            };
+            app.boards.set_boards(boards);
+            let session = app
+                .workspace_sessions
+                .remove(&workspace_name)
+                .unwrap_or_default();
+            app.state.filter_tags = session.filter_tags;
+            app.state.filter_query = session.filter_query;
+            app.state.active_saved_filter = session.active_saved_filter;
+            app.filtered_boards = session.filtered_boards;
+            app.action_history_manager.reset();
+            app.state.cloud_data = None;
+            app.state.current_board_id = None;
+            app.state.current_card_id = None;
+            let theme_name = app.config.get_theme_name();
+            if let Some(theme) = app.all_themes.iter().find(|t| t.name == theme_name) {
+                app.current_theme = theme.clone();
+            }
+            info!("👍 Switched to workspace {}", workspace_name);
+            app.send_info_toast(
+                &format!("👍 Switched to workspace {}", workspace_name),
+                None,
+            );
+        }
+        refresh_visible_boards_and_cards(&mut app);
+        if let Some(card_id) = card_to_select {
+            jump_to_card(&mut app, card_id);
+        }
+        Ok(())
+    }
+
     async fn refresh_visible_boards_and_cards(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
         refresh_visible_boards_and_cards(&mut app);
@@ -916,7 +1001,10 @@ impl IoAsyncHandler<'_> {
             return Ok(());
         }
         let key = key.unwrap();
-        let encrypt_result = encrypt_save(&app.boards, &key);
+        // saves from the default workspace are encrypted as before so older clients can still read them
+        let workspace = Some(app.config.current_workspace.clone())
+            .filter(|workspace| workspace != DEFAULT_WORKSPACE_NAME);
+        let encrypt_result = encrypt_save(&app.boards, workspace, &key);
         if encrypt_result.is_err() {
             error!("Error syncing local data");
             debug!(
@@ -929,6 +1017,14 @@ impl IoAsyncHandler<'_> {
         let (encrypted_board_data, nonce) = encrypt_result.unwrap();
         let auth_token = app.state.user_login_data.auth_token.clone().unwrap();
         let user_id = app.state.user_login_data.user_id.clone().unwrap();
+        let save_body = json!(
+            {
+                "user_id": user_id,
+                "board_data": encrypted_board_data,
+                "save_id": max_save_id,
+                "nonce": nonce
+            }
+        );
         drop(app);
         let client = reqwest::Client::new();
         let response = client
@@ -936,17 +1032,7 @@ impl IoAsyncHandler<'_> {
             .header("apikey", SUPABASE_ANON_KEY)
             .header("Content-Type", "application/json")
             .header("Authorization", format!("Bearer {}", auth_token))
-            .body(
-                json!(
-                    {
-                        "user_id": user_id,
-                        "board_data": encrypted_board_data,
-                        "save_id": max_save_id,
-                        "nonce": nonce
-                    }
-                )
-                .to_string(),
-            )
+            .body(save_body.to_string())
             .send()
             .await?;
 
@@ -1034,7 +1120,24 @@ impl IoAsyncHandler<'_> {
         if status == StatusCode::OK {
             let body = response.json::<Vec<CloudData>>().await;
             match body {
-                Ok(cloud_data) => {
+                Ok(mut cloud_data) => {
+                    // the workspace is stored inside the encrypted save, saves that cannot be
+                    // decrypted are listed under the default workspace
+                    let key =
+                        get_user_encryption_key(app.state.encryption_key_from_arguments.clone())
+                            .ok();
+                    for save in cloud_data.iter_mut() {
+                        save.workspace = key.as_ref().and_then(|key| {
+                            decrypt_save_payload(save.board_data.clone(), key, &save.nonce)
+                                .ok()
+                                .and_then(|payload| payload.workspace)
+                        });
+                    }
+                    let current_workspace = app.config.current_workspace.clone();
+                    cloud_data.retain(|save| {
+                        save.workspace.as_deref().unwrap_or(DEFAULT_WORKSPACE_NAME)
+                            == current_workspace
+                    });
                     app.state.cloud_data = Some(cloud_data);
                     info!("👍 Cloud data loaded");
                     app.send_info_toast("👍 Cloud data loaded", None);
@@ -1389,6 +1492,15 @@ fn get_latest_save_file(config: &AppConfig) -> Result<String, String> {
 }
 
 pub fn refresh_visible_boards_and_cards(app: &mut App) {
+    // boards with a sort mode are kept sorted, manual boards keep the order the user gave them
+    for board in app
+        .boards
+        .get_mut_boards()
+        .iter_mut()
+        .chain(app.filtered_boards.get_mut_boards().iter_mut())
+    {
+        board.cards.sort_by_mode(board.sort_mode);
+    }
     let mut visible_boards_and_cards: LinkedHashMap<(u64, u64), Vec<(u64, u64)>> =
         LinkedHashMap::new();
     let boards = if app.filtered_boards.is_empty() {
@@ -1436,6 +1548,15 @@ pub fn refresh_visible_boards_and_cards(app: &mut App) {
     }
 }
 
+/// the latest save of a workspace, used to search across workspaces without switching to them
+pub fn get_latest_workspace_boards(config: &AppConfig, workspace_name: &str) -> Boards {
+    let mut workspace_config = config.clone();
+    workspace_config.current_workspace = workspace_name.to_string();
+    get_latest_save_file(&workspace_config)
+        .and_then(|save_file_name| get_local_kanban_state(save_file_name, true, &workspace_config))
+        .unwrap_or_default()
+}
+
 pub fn make_file_system_safe_name(name: &str) -> String {
     let mut safe_name = name.to_string();
     let unsafe_chars = vec!["/", "\\", ":", "*", "?", "\"", "<", ">", "|", " "];
@@ -1474,6 +1595,19 @@ pub struct CloudData {
     pub board_data: String,
     pub nonce: String,
     pub save_id: usize,
+    /// read from the encrypted save after the list is fetched, it is not a column
+    #[serde(skip)]
+    pub workspace: Option<String>,
+}
+
+/// what is encrypted for a cloud save, keeping the workspace inside the encrypted data leaves the
+/// user_data table unchanged, older clients ignore the extra field
+#[derive(Serialize, Deserialize)]
+struct CloudSavePayload {
+    #[serde(flatten)]
+    boards: Boards,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    workspace: Option<String>,
 }
 
 enum PasswordStatus {
@@ -1509,9 +1643,17 @@ fn check_for_safe_password(check_password: &str) -> PasswordStatus {
     password_status
 }
 
-fn encrypt_save(boards: &Boards, key: &[u8]) -> Result<(String, String), String> {
+fn encrypt_save(
+    boards: &Boards,
+    workspace: Option<String>,
+    key: &[u8],
+) -> Result<(String, String), String> {
     let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
-    let boards_json = serde_json::to_string(&boards);
+    let payload = CloudSavePayload {
+        boards: boards.clone(),
+        workspace,
+    };
+    let boards_json = serde_json::to_string(&payload);
     if boards_json.is_err() {
         return Err("Error serializing boards".to_string());
     }
@@ -1535,6 +1677,14 @@ fn decrypt_save(
     key: &[u8],
     encoded_nonce: &str,
 ) -> Result<Boards, String> {
+    decrypt_save_payload(encrypted_boards, key, encoded_nonce).map(|payload| payload.boards)
+}
+
+fn decrypt_save_payload(
+    encrypted_boards: String,
+    key: &[u8],
+    encoded_nonce: &str,
+) -> Result<CloudSavePayload, String> {
     let base64_engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
     let encrypted_boards = base64_engine.decode(encrypted_boards);
     if encrypted_boards.is_err() {
@@ -20,5 +20,6 @@ pub enum IoEvent {
     SaveLocalData,
     SendResetPasswordEmail(String),
     SignUp(String, String, String),
+    SwitchWorkspace(String, Option<(u64, u64)>),
     SyncLocalData,
 }
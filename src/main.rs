@@ -7,7 +7,7 @@ use rust_kanban::{
     app::App,
     constants::APP_TITLE,
     io::{io_handler::IoAsyncHandler, logger, IoEvent},
-    util::{gen_new_key_main, print_error, reset_app_main, start_ui},
+    util::{gen_new_key_main, print_error, query_cards_main, reset_app_main, start_ui},
 };
 use std::{io::stdout, sync::Arc};
 
@@ -26,6 +26,9 @@ struct CliArgs {
     encryption_key: Option<String>,
     #[arg(short, long, default_value = "false")]
     debug_mode: bool,
+    /// print the cards of the latest save that match a query like 'tag:bug status:active due:<7d'
+    #[arg(short, long)]
+    query: Option<String>,
 }
 
 #[tokio::main]
@@ -76,6 +79,10 @@ async fn main() -> Result<()> {
         reset_app_main();
         return Ok(());
     }
+    if let Some(query) = args.query {
+        query_cards_main(&query);
+        return Ok(());
+    }
     if args.generate_new_encryption_key {
         if args.email_id.is_none() || args.password.is_none() {
             println!();
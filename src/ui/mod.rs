@@ -69,6 +69,24 @@ impl Theme {
         ]
     }
 
+    /// labels are drawn as chips with the label color as background, the text takes the theme background
+    /// so chips stay readable on light and dark themes, labels without a color use the keyboard focus style
+    pub fn label_style(&self, color: &TextColorOptions) -> Style {
+        match color.to_color() {
+            Some(label_color) => {
+                let text_color = match self.general_style.bg {
+                    Some(Color::Reset) | None => Color::Black,
+                    Some(background_color) => background_color,
+                };
+                Style::default()
+                    .fg(text_color)
+                    .bg(label_color)
+                    .add_modifier(Modifier::BOLD)
+            }
+            None => self.keyboard_focus_style.add_modifier(Modifier::REVERSED),
+        }
+    }
+
     pub fn to_rows(&self, app: &App) -> (Vec<Row>, Vec<Row>) {
         let popup_mode = app.state.popup_mode.is_some();
         let text_style = if popup_mode {
@@ -433,6 +451,38 @@ impl TextColorOptions {
         ]
         .into_iter()
     }
+    /// accepts a color name like "LightBlue", a hex value like "#ff8800" or an rgb triplet like "255, 136, 0"
+    pub fn from_name(name: &str) -> Option<TextColorOptions> {
+        let name = name.trim();
+        if let Some(hex) = name.strip_prefix('#') {
+            if hex.len() != 6 {
+                return None;
+            }
+            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+            return Some(TextColorOptions::RGB(r, g, b));
+        }
+        let rgb_values = name
+            .trim_start_matches("RGB")
+            .trim_start_matches("rgb")
+            .trim_matches(|c| c == '(' || c == ')')
+            .split(',')
+            .map(|value| value.trim().parse::<u8>())
+            .collect::<Result<Vec<u8>, _>>();
+        if let Ok(rgb_values) = rgb_values {
+            if rgb_values.len() == 3 {
+                return Some(TextColorOptions::RGB(
+                    rgb_values[0],
+                    rgb_values[1],
+                    rgb_values[2],
+                ));
+            }
+        }
+        TextColorOptions::to_iter()
+            .filter(|color| !matches!(color, TextColorOptions::RGB(_, _, _)))
+            .find(|color| color.to_string().eq_ignore_ascii_case(name))
+    }
     pub fn from(color: Color) -> TextColorOptions {
         match color {
             Color::Black => TextColorOptions::Black,
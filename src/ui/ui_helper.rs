@@ -5,11 +5,18 @@ use super::{
 };
 use crate::{
     app::{
-        app_helper::reset_card_drag_mode,
+        agenda::{AgendaEntry, AgendaSection},
+        app_helper::{get_current_filter_query, reset_card_drag_mode},
         date_format_converter, date_format_finder,
-        kanban::{Boards, Card, CardPriority, CardStatus},
+        kanban::{
+            detect_attachment_urls, format_duration, parse_timestamp, Boards, Card, CardComment,
+            CardPriority, CardSortMode, CardStatus, SwimlaneGrouping, TimeReport, TimeReportRange,
+        },
+        query::CardQuery,
+        search::{SearchField, SearchResult},
         state::{AppStatus, Focus, KeyBindingEnum, UiMode},
-        App, ConfigEnum, DateFormat, PopupMode,
+        App, CalendarLayout, CardAttachmentEntry, CardTableColumn, ConfigEnum, DateFormat,
+        PopupMode, TimelineHandle, TimelineZoom, WorkspaceSwitcherEntry,
     },
     constants::{
         APP_TITLE, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH, FIELD_NOT_SET,
@@ -24,15 +31,17 @@ use crate::{
     },
     util::calculate_cursor_position,
 };
-use chrono::{Local, NaiveDate, NaiveDateTime};
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime};
 use log::{debug, Level};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Modifier, Style},
-    text::{Line, Span},
+    symbols::Marker,
+    text::{Line, Span, Text},
     widgets::{
-        Block, BorderType, Borders, Cell, Clear, Gauge, List, ListItem, ListState, Paragraph, Row,
-        Scrollbar, ScrollbarOrientation, ScrollbarState, Table,
+        Axis, Bar, BarChart, BarGroup, Block, BorderType, Borders, Cell, Chart, Clear, Dataset,
+        Gauge, GraphType, HighlightSpacing, List, ListItem, ListState, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Table,
     },
     Frame,
 };
@@ -822,7 +831,7 @@ pub fn render_select_default_view(rect: &mut Frame, app: &mut App) {
         .constraints([Constraint::Fill(1), Constraint::Length(5)].as_ref())
         .split(render_area);
 
-    let list_items = UiMode::view_modes_as_string();
+    let list_items = UiMode::default_view_modes_as_string();
     let list_items: Vec<ListItem> = list_items
         .iter()
         .map(|s| ListItem::new(s.to_string()))
@@ -1714,6 +1723,16 @@ pub fn render_body(rect: &mut Frame, area: Rect, app: &mut App, preview_mode: bo
         rect.render_widget(filtered_paragraph, filter_chunks[0]);
     }
 
+    if !preview_mode {
+        if let Some(swimlane_grouping) = app.state.swimlane_grouping.clone() {
+            render_swimlanes(rect, chunks[0], app, &boards, &swimlane_grouping);
+            if !app.config.disable_scroll_bar {
+                render_board_position_gauge(rect, chunks[1], app, &boards, scrollbar_style);
+            }
+            return;
+        }
+    }
+
     let mut constraints = vec![];
     if boards.len() > app.config.no_of_boards_to_show.into() {
         for _i in 0..app.config.no_of_boards_to_show {
@@ -1750,7 +1769,36 @@ pub fn render_body(rect: &mut Frame, area: Rect, app: &mut App, preview_mode: bo
         } else {
             board_title
         };
-        let board_title = format!("{} ({})", board_title, board.cards.len());
+        let board_title = if let Some(wip_limit) = board.wip_limit {
+            format!(
+                "{} ({}, WIP {}/{})",
+                board_title,
+                board.cards.len(),
+                board.wip_count(),
+                wip_limit
+            )
+        } else {
+            format!("{} ({})", board_title, board.cards.len())
+        };
+        let board_title = if board
+            .cards
+            .get_all_cards()
+            .iter()
+            .any(|card| card.estimate.is_some())
+        {
+            format!(
+                "{} [{} pts]",
+                board_title,
+                board.cards.total_active_estimate()
+            )
+        } else {
+            board_title
+        };
+        let board_title = if board.sort_mode == CardSortMode::Manual {
+            board_title
+        } else {
+            format!("{} [by {}]", board_title, board.sort_mode)
+        };
         let board_title = if board_id == current_board_id {
             format!("{} {}", ">>", board_title)
         } else {
@@ -1800,6 +1848,8 @@ pub fn render_body(rect: &mut Frame, area: Rect, app: &mut App, preview_mode: bo
             app.current_theme.keyboard_focus_style
         } else if app.state.card_drag_mode {
             app.current_theme.inactive_text_style
+        } else if board.is_over_wip_limit() {
+            app.current_theme.card_due_overdue_style
         } else {
             app.current_theme.general_style
         };
@@ -1917,22 +1967,213 @@ pub fn render_body(rect: &mut Frame, area: Rect, app: &mut App, preview_mode: bo
     }
 
     if !app.config.disable_scroll_bar {
-        let current_board_index = boards.get_board_index(*current_board_id).unwrap_or(0) + 1;
-        let percentage = {
-            let temp_percent = (current_board_index as f64 / boards.len() as f64) * 100.0;
-            if temp_percent.is_nan() {
-                0
-            } else if temp_percent > 100.0 {
-                100
+        render_board_position_gauge(rect, chunks[1], app, &boards, scrollbar_style);
+    }
+}
+
+fn render_board_position_gauge(
+    rect: &mut Frame,
+    area: Rect,
+    app: &App,
+    boards: &Boards,
+    gauge_style: Style,
+) {
+    let current_board_id = app.state.current_board_id.unwrap_or((0, 0));
+    let current_board_index = boards.get_board_index(current_board_id).unwrap_or(0) + 1;
+    let percentage = {
+        let temp_percent = (current_board_index as f64 / boards.len() as f64) * 100.0;
+        if temp_percent.is_nan() {
+            0
+        } else if temp_percent > 100.0 {
+            100
+        } else {
+            temp_percent as u16
+        }
+    };
+    let line_gauge = Gauge::default()
+        .block(Block::default())
+        .gauge_style(gauge_style)
+        .percent(percentage);
+    rect.render_widget(line_gauge, area);
+}
+
+/// renders a horizontal band per swimlane, each band has a column per visible board
+fn render_swimlanes(
+    rect: &mut Frame,
+    area: Rect,
+    app: &mut App,
+    boards: &Boards,
+    swimlane_grouping: &SwimlaneGrouping,
+) {
+    let lanes = swimlane_grouping.lanes(boards);
+    let visible_board_ids: Vec<(u64, u64)> = app.visible_boards_and_cards.keys().copied().collect();
+    let current_swimlane = app
+        .state
+        .current_card_id
+        .and_then(|card_id| boards.get_card_with_id(card_id))
+        .map(|card| swimlane_grouping.lane_for(card))
+        .or(app.state.current_swimlane.clone());
+    let inactive = app.state.popup_mode.is_some() || app.state.card_drag_mode;
+    let general_style = if inactive {
+        app.current_theme.inactive_text_style
+    } else {
+        app.current_theme.general_style
+    };
+    if lanes.is_empty() || visible_board_ids.is_empty() {
+        let empty_paragraph = Paragraph::new("No cards to group into swimlanes")
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title(format!("Swimlanes by {}", swimlane_grouping))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .style(general_style);
+        rect.render_widget(empty_paragraph, area);
+        return;
+    }
+
+    let lane_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            lanes
+                .iter()
+                .map(|_| Constraint::Fill(1))
+                .collect::<Vec<Constraint>>(),
+        )
+        .split(area);
+    for (lane, lane_area) in lanes.iter().zip(lane_chunks.iter()) {
+        let lane_card_count: usize = boards
+            .get_boards()
+            .iter()
+            .map(|board| {
+                board
+                    .cards
+                    .get_all_cards()
+                    .iter()
+                    .filter(|card| swimlane_grouping.lane_for(card) == *lane)
+                    .count()
+            })
+            .sum();
+        let lane_border_style = if inactive {
+            app.current_theme.inactive_text_style
+        } else if current_swimlane.as_ref() == Some(lane) && app.state.focus == Focus::Body {
+            app.current_theme.keyboard_focus_style
+        } else {
+            app.current_theme.general_style
+        };
+        let lane_block = Block::default()
+            .title(format!(
+                "{}: {} ({})",
+                swimlane_grouping, lane, lane_card_count
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(general_style)
+            .border_style(lane_border_style);
+        let lane_inner_area = lane_block.inner(*lane_area);
+        rect.render_widget(lane_block, *lane_area);
+
+        let board_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(
+                visible_board_ids
+                    .iter()
+                    .map(|_| Constraint::Fill(1))
+                    .collect::<Vec<Constraint>>(),
+            )
+            .split(lane_inner_area);
+        for (board_id, cell_area) in visible_board_ids.iter().zip(board_chunks.iter()) {
+            let board = if let Some(board) = boards.get_board_with_id(*board_id) {
+                board
             } else {
-                temp_percent as u16
+                continue;
+            };
+            let lane_cards: Vec<&Card> = board
+                .cards
+                .get_all_cards()
+                .iter()
+                .filter(|card| swimlane_grouping.lane_for(card) == *lane)
+                .collect();
+            let mouse_over_cell =
+                check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, cell_area);
+            let mouse_in_cell = !inactive && mouse_over_cell;
+            if mouse_over_cell && (mouse_in_cell || app.state.card_drag_mode) {
+                app.state.hovered_board = Some(*board_id);
+                app.state.hovered_swimlane = Some(lane.clone());
             }
-        };
-        let line_gauge = Gauge::default()
-            .block(Block::default())
-            .gauge_style(scrollbar_style)
-            .percent(percentage);
-        rect.render_widget(line_gauge, chunks[1]);
+            let is_current_cell = app.state.current_board_id == Some(*board_id)
+                && current_swimlane.as_ref() == Some(lane);
+            let cell_border_style = if inactive {
+                app.current_theme.inactive_text_style
+            } else if mouse_in_cell
+                || (app.state.card_drag_mode
+                    && app.state.hovered_swimlane.as_ref() == Some(lane)
+                    && app.state.hovered_board == Some(*board_id))
+            {
+                app.current_theme.mouse_focus_style
+            } else if is_current_cell && app.state.focus == Focus::Body {
+                app.current_theme.keyboard_focus_style
+            } else {
+                app.current_theme.general_style
+            };
+            let cell_title = if board.name.len() > DEFAULT_BOARD_TITLE_LENGTH.into() {
+                format!("{}...", &board.name[0..DEFAULT_BOARD_TITLE_LENGTH as usize])
+            } else {
+                board.name.clone()
+            };
+            let cell_block = Block::default()
+                .title(format!("{} ({})", cell_title, lane_cards.len()))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(general_style)
+                .border_style(cell_border_style);
+            let items: Vec<ListItem> = lane_cards
+                .iter()
+                .map(|card| {
+                    let card_style = if app.boards.is_card_blocked(card) && !inactive {
+                        app.current_theme.card_due_overdue_style
+                    } else {
+                        general_style
+                    };
+                    ListItem::new(Line::from(Span::styled(card.name.clone(), card_style)))
+                })
+                .collect();
+            let selected_index = app
+                .state
+                .current_card_id
+                .and_then(|card_id| lane_cards.iter().position(|card| card.id == card_id))
+                .filter(|_| is_current_cell);
+            let mut cell_list_state = ListState::default().with_selected(selected_index);
+            let cell_list = List::new(items)
+                .block(cell_block)
+                .highlight_style(app.current_theme.list_select_style)
+                .highlight_symbol(LIST_SELECTED_SYMBOL);
+            rect.render_stateful_widget(cell_list, *cell_area, &mut cell_list_state);
+
+            if mouse_in_cell {
+                let mouse_row = app
+                    .state
+                    .current_mouse_coordinates
+                    .1
+                    .saturating_sub(cell_area.y + 1) as usize;
+                let hovered_card = lane_cards.get(cell_list_state.offset() + mouse_row);
+                if let Some(hovered_card) = hovered_card {
+                    if app.state.current_mouse_coordinates.1 > cell_area.y {
+                        app.state.mouse_focus = Some(Focus::Body);
+                        app.state.set_focus(Focus::Body);
+                        app.state.current_board_id = Some(*board_id);
+                        app.state.current_swimlane = Some(lane.clone());
+                        app.state.current_card_id = Some(hovered_card.id);
+                        if !app.state.card_drag_mode {
+                            app.state.hovered_card = Some((*board_id, hovered_card.id));
+                            app.state.hovered_card_dimensions =
+                                Some((cell_area.width, cell_area.height.clamp(3, 8)));
+                        }
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -1966,7 +2207,44 @@ fn render_a_single_card(
         card.description.clone()
     };
 
-    let mut card_extra_info = vec![Line::from("")];
+    let mut first_line_spans = if app.boards.is_card_blocked(card) {
+        if app.state.popup_mode.is_some() {
+            vec![Span::styled(
+                "[Blocked]",
+                app.current_theme.inactive_text_style,
+            )]
+        } else {
+            vec![Span::styled(
+                "[Blocked]",
+                app.current_theme.card_due_overdue_style,
+            )]
+        }
+    } else {
+        vec![]
+    };
+    // tags are shown as chips on the first line, as many as fit in the card
+    let available_tag_width = render_area.width.saturating_sub(2) as usize;
+    let mut used_tag_width = first_line_spans
+        .iter()
+        .map(|span| span.content.len() + 1)
+        .sum::<usize>();
+    for tag in card.tags.iter() {
+        let chip = format!(" {} ", tag);
+        if used_tag_width + chip.len() > available_tag_width {
+            break;
+        }
+        used_tag_width += chip.len() + 1;
+        if !first_line_spans.is_empty() {
+            first_line_spans.push(Span::raw(" "));
+        }
+        let chip_style = if app.state.popup_mode.is_some() {
+            app.current_theme.inactive_text_style
+        } else {
+            get_tag_style(app, tag)
+        };
+        first_line_spans.push(Span::styled(chip, chip_style));
+    }
+    let mut card_extra_info = vec![Line::from(first_line_spans)];
     if card.due_date == FIELD_NOT_SET {
         if app.state.popup_mode.is_some() {
             card_extra_info.push(Line::from(Span::styled(
@@ -2130,6 +2408,14 @@ fn render_a_single_card(
     frame_to_render_on.render_widget(card_extra_info, inner_card_chunks[1]);
 }
 
+/// registered labels are drawn with their color, plain tags keep the general style
+fn get_tag_style(app: &App, tag: &str) -> Style {
+    match app.config.get_label(tag) {
+        Some(label) => app.current_theme.label_style(&label.color),
+        None => app.current_theme.general_style,
+    }
+}
+
 fn centered_rect_with_percentage(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -2271,15 +2557,83 @@ pub fn draw_title<'a>(app: &mut App, render_area: Rect) -> Paragraph<'a> {
         app.state.popup_mode.is_some(),
         false,
     );
-    Paragraph::new(APP_TITLE)
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .style(title_style)
-                .borders(Borders::ALL)
-                .border_style(border_style)
-                .border_type(BorderType::Rounded),
-        )
+    let mut title = APP_TITLE.to_string();
+    if let Some(active_saved_filter) = &app.state.active_saved_filter {
+        title.push_str(&format!(" | Filter: {}", active_saved_filter));
+    }
+    if let Some(card) = app.boards.get_card_with_running_timer() {
+        title.push_str(&format!(
+            " | Tracking: {} ({})",
+            card.name,
+            format_duration(card.total_time_tracked())
+        ));
+    }
+    Paragraph::new(title).alignment(Alignment::Center).block(
+        Block::default()
+            .style(title_style)
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .border_type(BorderType::Rounded),
+    )
+}
+
+/// the card name followed by the snippet around the match, matched terms use the highlight style
+fn search_result_to_line(
+    search_result: &SearchResult,
+    max_width: usize,
+    text_style: Style,
+    highlight_style: Style,
+) -> Line<'static> {
+    let snippet_chars = search_result.snippet.chars().collect::<Vec<char>>();
+    let mut segments = vec![];
+    if search_result.field != SearchField::Name {
+        segments.push((
+            format!("{} - {}: ", search_result.card_name, search_result.field),
+            text_style,
+        ));
+    }
+    let mut position = 0;
+    for (start, end) in &search_result.highlights {
+        if *start > position {
+            segments.push((
+                snippet_chars[position..*start].iter().collect::<String>(),
+                text_style,
+            ));
+        }
+        segments.push((
+            snippet_chars[*start..*end].iter().collect::<String>(),
+            highlight_style,
+        ));
+        position = *end;
+    }
+    if position < snippet_chars.len() {
+        segments.push((
+            snippet_chars[position..].iter().collect::<String>(),
+            text_style,
+        ));
+    }
+    if search_result.field == SearchField::Name {
+        segments.push((" - Name".to_string(), text_style));
+    }
+    let mut remaining_width = max_width;
+    let mut spans = vec![];
+    for (text, style) in segments {
+        if remaining_width == 0 {
+            break;
+        }
+        let text_width = text.chars().count();
+        if text_width > remaining_width {
+            let truncated = text
+                .chars()
+                .take(remaining_width.saturating_sub(1))
+                .collect::<String>();
+            spans.push(Span::styled(format!("{}…", truncated), style));
+            break;
+        }
+        remaining_width -= text_width;
+        spans.push(Span::styled(text, style));
+    }
+    Line::from(spans)
 }
 
 pub fn check_size(rect: &Rect) -> String {
@@ -2476,10 +2830,12 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
         .direction(Direction::Vertical)
         .constraints(
             [
+                Constraint::Length(3),
                 Constraint::Length(3),
                 Constraint::Length(5),
                 Constraint::Fill(1),
                 Constraint::Length(3),
+                Constraint::Length(3),
                 Constraint::Length(4),
                 Constraint::Length(3),
             ]
@@ -2488,16 +2844,20 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
         .split(rect.size());
 
     let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let template_style =
+        get_mouse_focusable_field_style(app, Focus::CardTemplate, &chunks[1], popup_mode, false);
     let name_style =
-        get_mouse_focusable_field_style(app, Focus::CardName, &chunks[1], popup_mode, false);
+        get_mouse_focusable_field_style(app, Focus::CardName, &chunks[2], popup_mode, false);
     let description_style =
-        get_mouse_focusable_field_style(app, Focus::CardDescription, &chunks[2], popup_mode, false);
+        get_mouse_focusable_field_style(app, Focus::CardDescription, &chunks[3], popup_mode, false);
     let due_date_style =
-        get_mouse_focusable_field_style(app, Focus::CardDueDate, &chunks[3], popup_mode, false);
+        get_mouse_focusable_field_style(app, Focus::CardDueDate, &chunks[4], popup_mode, false);
+    let estimate_style =
+        get_mouse_focusable_field_style(app, Focus::CardEstimate, &chunks[5], popup_mode, false);
     let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
     let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
     let submit_style =
-        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[5], popup_mode, false);
+        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[7], popup_mode, false);
 
     let title_paragraph = Paragraph::new("Create a new Card")
         .alignment(Alignment::Center)
@@ -2509,9 +2869,37 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
         );
     rect.render_widget(title_paragraph, chunks[0]);
 
+    let card_template_text = match &app.state.new_card_template {
+        Some(card_template) => format!(
+            "{} ({} priority{})",
+            card_template.name,
+            card_template.priority,
+            if card_template.tags.is_empty() {
+                String::new()
+            } else {
+                format!(", tags: {}", card_template.tags.join(", "))
+            }
+        ),
+        None => "Blank Card".to_string(),
+    };
+    let card_template = Paragraph::new(card_template_text)
+        .alignment(Alignment::Left)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(template_style)
+                .border_type(BorderType::Rounded)
+                .title(format!(
+                    "Card Template (press {} to choose)",
+                    app.get_first_keybinding(KeyBindingEnum::Accept)
+                        .unwrap_or_default()
+                )),
+        );
+    rect.render_widget(card_template, chunks[1]);
+
     let wrapped_card_name_text = textwrap::wrap(
         &app.state.app_form_states.new_card[0],
-        (chunks[1].width - 2) as usize,
+        (chunks[2].width - 2) as usize,
     );
     let card_name_field = wrapped_card_name_text
         .iter()
@@ -2519,12 +2907,20 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
         .collect::<Vec<Line>>();
     let wrapped_card_due_date_text = textwrap::wrap(
         &app.state.app_form_states.new_card[2],
-        (chunks[3].width - 2) as usize,
+        (chunks[4].width - 2) as usize,
     );
     let card_due_date_field = wrapped_card_due_date_text
         .iter()
         .map(|x| Line::from(Span::raw(&**x)))
         .collect::<Vec<Line>>();
+    let wrapped_card_estimate_text = textwrap::wrap(
+        &app.state.app_form_states.new_card[3],
+        (chunks[5].width - 2) as usize,
+    );
+    let card_estimate_field = wrapped_card_estimate_text
+        .iter()
+        .map(|x| Line::from(Span::raw(&**x)))
+        .collect::<Vec<Line>>();
     let card_name = Paragraph::new(card_name_field)
         .alignment(Alignment::Left)
         .block(
@@ -2534,7 +2930,7 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
                 .border_type(BorderType::Rounded)
                 .title("Card Name (required)"),
         );
-    rect.render_widget(card_name, chunks[1]);
+    rect.render_widget(card_name, chunks[2]);
     let description_block = Block::default()
         .borders(Borders::ALL)
         .style(description_style)
@@ -2580,7 +2976,7 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
         app.state.card_description_text_buffer = Some(textarea.clone());
         textarea
     };
-    rect.render_widget(card_description.widget(), chunks[2]);
+    rect.render_widget(card_description.widget(), chunks[3]);
 
     let parsed_due_date = date_format_converter(
         app.state.app_form_states.new_card[2].trim(),
@@ -2599,7 +2995,7 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
         let new_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Fill(1), Constraint::Length(21)].as_ref())
-            .split(chunks[3]);
+            .split(chunks[4]);
         rect.render_widget(card_due_date, new_chunks[0]);
         let error_text = Line::from(vec![Span::raw("Invalid date format")]);
         let error_paragraph = Paragraph::new(error_text)
@@ -2610,9 +3006,39 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
                     .border_type(BorderType::Rounded)
                     .style(app.current_theme.error_text_style),
             );
-        rect.render_widget(error_paragraph, new_chunks[1]);
+        rect.render_widget(error_paragraph, new_chunks[2]);
+    } else {
+        rect.render_widget(card_due_date, chunks[4]);
+    }
+
+    let card_estimate = Paragraph::new(card_estimate_field)
+        .alignment(Alignment::Left)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(estimate_style)
+                .border_type(BorderType::Rounded)
+                .title("Card Estimate (whole number, optional)"),
+        );
+    let estimate_text = app.state.app_form_states.new_card[3].trim();
+    if !estimate_text.is_empty() && estimate_text.parse::<u32>().is_err() {
+        let new_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Fill(1), Constraint::Length(21)].as_ref())
+            .split(chunks[5]);
+        rect.render_widget(card_estimate, new_chunks[0]);
+        let error_text = Line::from(vec![Span::raw("Invalid estimate")]);
+        let error_paragraph = Paragraph::new(error_text)
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .style(app.current_theme.error_text_style),
+            );
+        rect.render_widget(error_paragraph, new_chunks[2]);
     } else {
-        rect.render_widget(card_due_date, chunks[3]);
+        rect.render_widget(card_estimate, chunks[5]);
     }
 
     let input_mode_key = app
@@ -2661,7 +3087,7 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
                 .border_style(default_style),
         )
         .wrap(ratatui::widgets::Wrap { trim: true });
-    rect.render_widget(help_paragraph, chunks[4]);
+    rect.render_widget(help_paragraph, chunks[6]);
 
     let submit_button = Paragraph::new("Submit").alignment(Alignment::Center).block(
         Block::default()
@@ -2669,7 +3095,7 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
             .style(submit_style)
             .border_type(BorderType::Rounded),
     );
-    rect.render_widget(submit_button, chunks[5]);
+    rect.render_widget(submit_button, chunks[7]);
 
     if app.state.focus == Focus::CardName && app.state.app_status == AppStatus::UserInput {
         if app.state.current_cursor_position.is_some() {
@@ -2678,11 +3104,11 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
                 app.state
                     .current_cursor_position
                     .unwrap_or_else(|| app.state.app_form_states.new_card[0].len()),
-                chunks[1],
+                chunks[2],
             );
             rect.set_cursor(x_pos, y_pos);
         } else {
-            rect.set_cursor(chunks[1].x + 1, chunks[1].y + 1);
+            rect.set_cursor(chunks[2].x + 1, chunks[2].y + 1);
         }
     } else if app.state.focus == Focus::CardDueDate && app.state.app_status == AppStatus::UserInput
     {
@@ -2692,11 +3118,25 @@ pub fn render_new_card_form(rect: &mut Frame, app: &mut App) {
                 app.state
                     .current_cursor_position
                     .unwrap_or_else(|| app.state.app_form_states.new_card[2].len()),
-                chunks[3],
+                chunks[4],
+            );
+            rect.set_cursor(x_pos, y_pos);
+        } else {
+            rect.set_cursor(chunks[4].x + 1, chunks[4].y + 1);
+        }
+    } else if app.state.focus == Focus::CardEstimate && app.state.app_status == AppStatus::UserInput
+    {
+        if app.state.current_cursor_position.is_some() {
+            let (x_pos, y_pos) = calculate_cursor_position(
+                wrapped_card_estimate_text,
+                app.state
+                    .current_cursor_position
+                    .unwrap_or_else(|| app.state.app_form_states.new_card[3].len()),
+                chunks[5],
             );
             rect.set_cursor(x_pos, y_pos);
         } else {
-            rect.set_cursor(chunks[3].x + 1, chunks[3].y + 1);
+            rect.set_cursor(chunks[5].x + 1, chunks[5].y + 1);
         }
     }
 
@@ -2914,223 +3354,2981 @@ pub fn render_load_a_save(rect: &mut Frame, app: &mut App) {
     }
 }
 
-pub fn render_toast(rect: &mut Frame, app: &mut App) {
-    let all_toasts = app.widgets.toasts.clone();
-    let mut loading_toasts = all_toasts
-        .iter()
-        .filter(|x| x.toast_type == ToastType::Loading)
-        .collect::<Vec<&ToastWidget>>();
-    let app_toasts = app.widgets.toasts.clone();
-    let toasts = if !loading_toasts.is_empty() {
-        let sorted_loading_toasts = if loading_toasts.len() > MAX_TOASTS_TO_DISPLAY - 1 {
-            loading_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-            loading_toasts
-                .iter()
-                .copied()
-                .take(MAX_TOASTS_TO_DISPLAY - 1)
-                .rev()
-                .collect::<Vec<&ToastWidget>>()
-        } else {
-            loading_toasts
-        };
-        let mut toasts = sorted_loading_toasts;
-        let mut regular_toasts = all_toasts
-            .iter()
-            .filter(|x| x.toast_type != ToastType::Loading)
-            .collect::<Vec<&ToastWidget>>();
-        regular_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-        while toasts.len() < MAX_TOASTS_TO_DISPLAY {
-            if let Some(toast) = regular_toasts.pop() {
-                toasts.push(toast);
-            } else {
-                break;
-            }
-        }
-        if toasts.len() < MAX_TOASTS_TO_DISPLAY {
-            let mut loading_toasts = all_toasts
-                .iter()
-                .filter(|x| x.toast_type == ToastType::Loading)
-                .collect::<Vec<&ToastWidget>>();
-            loading_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-            while toasts.len() < MAX_TOASTS_TO_DISPLAY {
-                if let Some(toast) = loading_toasts.pop() {
-                    if !toasts.contains(&toast) {
-                        toasts.push(toast);
-                    }
-                } else {
-                    break;
-                }
-            }
-        }
-        toasts
-    } else {
-        app_toasts
-            .iter()
-            .rev()
-            .take(MAX_TOASTS_TO_DISPLAY)
-            .rev()
-            .collect::<Vec<&ToastWidget>>()
-    };
-
-    if toasts.is_empty() {
-        return;
-    }
-    let mut total_height_rendered = 1;
-    for toast in toasts.iter() {
-        let toast_style = app
-            .current_theme
-            .general_style
-            .fg(ratatui::style::Color::Rgb(
-                toast.toast_color.0,
-                toast.toast_color.1,
-                toast.toast_color.2,
-            ));
-        let mut toast_title = toast.title.to_owned();
-        toast_title = match toast.toast_type {
-            ToastType::Loading => {
-                let spinner_frames = &SPINNER_FRAMES;
-                let frame =
-                    (toast.start_time.elapsed().as_millis() / 100) % spinner_frames.len() as u128;
-                let frame = frame as usize;
-                format!("{} {}", spinner_frames[frame], toast_title)
-            }
-            _ => toast_title,
-        };
-        let x_offset = rect.size().width - (rect.size().width / SCREEN_TO_TOAST_WIDTH_RATIO);
-        let lines = textwrap::wrap(
-            &toast.message,
-            ((rect.size().width / SCREEN_TO_TOAST_WIDTH_RATIO) - 2) as usize,
+pub fn render_archive_browser(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let main_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Fill(1)].as_ref())
+        .split(rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(6),
+            ]
+            .as_ref(),
         )
-        .iter()
-        .map(|x| Line::from(x.to_string()))
-        .collect::<Vec<Line>>();
-        let toast_height = lines.len() as u16 + 2;
-        let toast_block = Block::default()
-            .title(toast_title)
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(toast_style);
-        let toast_paragraph = Paragraph::new(lines)
-            .block(toast_block)
-            .alignment(Alignment::Left)
-            .wrap(ratatui::widgets::Wrap { trim: true })
-            .style(toast_style);
-        if toast_height + total_height_rendered > rect.size().height {
-            debug!("Toast height is greater than the height of the screen");
-            break;
-        }
-        rect.render_widget(
-            Clear,
-            Rect::new(
-                x_offset,
-                total_height_rendered,
-                rect.size().width / SCREEN_TO_TOAST_WIDTH_RATIO,
-                toast_height,
-            ),
-        );
-        rect.render_widget(
-            toast_paragraph,
-            Rect::new(
-                x_offset,
-                total_height_rendered,
-                rect.size().width / SCREEN_TO_TOAST_WIDTH_RATIO,
-                toast_height,
-            ),
-        );
-        total_height_rendered += toast_height;
-        if total_height_rendered >= rect.size().height {
-            debug!("Toast height is greater than the height of the screen");
-            break;
-        }
+        .split(main_chunks[0]);
+    let preview_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Fill(1)].as_ref())
+        .split(main_chunks[1]);
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(preview_chunks[0]);
+
+    let archived_cards = app.get_archived_cards_matching_search();
+    let selected_index = app.state.app_list_states.archived_card_list.selected();
+    if archived_cards.is_empty() {
+        app.state.app_list_states.archived_card_list.select(None);
+    } else if !matches!(selected_index, Some(index) if index < archived_cards.len()) {
+        app.state.app_list_states.archived_card_list.select(Some(0));
     }
 
-    let text_offset = 15;
-    let toast_count = app.widgets.toasts.len();
-    let toast_count_text = format!(" {} Message(s)", toast_count);
-    let toast_count_paragraph = Paragraph::new(toast_count_text)
-        .alignment(Alignment::Right)
+    let title_paragraph = Paragraph::new("Archive")
+        .alignment(Alignment::Center)
         .block(
             Block::default()
-                .borders(Borders::LEFT)
+                .borders(Borders::ALL)
                 .border_type(BorderType::Rounded),
         )
-        .style(app.current_theme.general_style);
-    let message_area = Rect::new(rect.size().width - text_offset, 0, text_offset, 1);
+        .style(default_style);
+    rect.render_widget(title_paragraph, chunks[0]);
 
-    render_blank_styled_canvas(rect, app, message_area, false);
-    rect.render_widget(toast_count_paragraph, message_area);
-}
+    let search_style =
+        get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], false, true);
+    let search_text = if app.state.current_user_input.is_empty()
+        && app.state.app_status != AppStatus::UserInput
+    {
+        Span::styled(
+            "Search by name, description, tag or board",
+            app.current_theme.inactive_text_style,
+        )
+    } else {
+        Span::styled(app.state.current_user_input.clone(), default_style)
+    };
+    let search_paragraph = Paragraph::new(search_text).block(
+        Block::default()
+            .title("Search")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(search_style),
+    );
+    rect.render_widget(search_paragraph, chunks[1]);
 
-pub fn render_view_card(rect: &mut Frame, app: &mut App) {
-    let popup_area = centered_rect_with_percentage(90, 90, rect.size());
-    render_blank_styled_canvas(rect, app, popup_area, false);
-    if app.state.current_board_id.is_none() || app.state.current_card_id.is_none() {
-        let no_board_or_card_selected = Paragraph::new("No board or card selected.")
+    let list_border_style = if app.state.focus == Focus::Body {
+        app.current_theme.keyboard_focus_style
+    } else {
+        default_style
+    };
+    if archived_cards.is_empty() {
+        let empty_message = if app.state.current_user_input.is_empty() {
+            "No archived cards"
+        } else {
+            "No archived cards match the search"
+        };
+        let empty_paragraph = Paragraph::new(empty_message)
+            .alignment(Alignment::Center)
             .block(
                 Block::default()
-                    .title("Card Info")
+                    .title("Archived Cards")
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .style(app.current_theme.error_text_style),
+                    .border_style(list_border_style),
             )
-            .alignment(Alignment::Center);
-        rect.render_widget(no_board_or_card_selected, popup_area);
-        return;
-    }
-
-    let board = app
-        .boards
-        .get_board_with_id(app.state.current_board_id.unwrap());
-    if board.is_none() {
-        let could_not_find_board = Paragraph::new("Could not find board to view card.")
+            .style(app.current_theme.error_text_style);
+        rect.render_widget(empty_paragraph, chunks[2]);
+    } else {
+        let items: Vec<ListItem> = archived_cards
+            .iter()
+            .map(|(_, board_name, archived_card)| {
+                ListItem::new(format!("{} ({})", archived_card.card.name, board_name))
+            })
+            .collect();
+        let archived_card_list = List::new(items)
             .block(
                 Block::default()
-                    .title("Card Info")
+                    .title(format!("Archived Cards ({})", archived_cards.len()))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .style(app.current_theme.error_text_style),
+                    .border_style(list_border_style),
             )
-            .alignment(Alignment::Center)
-            .wrap(ratatui::widgets::Wrap { trim: true });
-        rect.render_widget(could_not_find_board, popup_area);
-        return;
+            .highlight_style(app.current_theme.list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL)
+            .style(default_style);
+        if app.state.popup_mode.is_none()
+            && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[2])
+        {
+            app.state.mouse_focus = Some(Focus::Body);
+            app.state.set_focus(Focus::Body);
+            calculate_mouse_list_select_index(
+                app.state.current_mouse_coordinates.1,
+                &archived_cards,
+                chunks[2],
+                &mut app.state.app_list_states.archived_card_list,
+            );
+        }
+        rect.render_stateful_widget(
+            archived_card_list,
+            chunks[2],
+            &mut app.state.app_list_states.archived_card_list,
+        );
     }
 
-    let board = board.unwrap();
-    let card = board
-        .cards
-        .get_card_with_id(app.state.current_card_id.unwrap());
-    if card.is_none() {
-        let could_not_find_card = Paragraph::new("Could not find card to view.")
-            .block(
-                Block::default()
-                    .title("Card Info")
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .style(app.current_theme.error_text_style),
-            )
-            .alignment(Alignment::Center)
-            .wrap(ratatui::widgets::Wrap { trim: true });
-        rect.render_widget(could_not_find_card, popup_area);
-        return;
-    }
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let input_mode_key = app
+        .get_first_keybinding(KeyBindingEnum::TakeUserInput)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" or ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to navigate. Press ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(
+            " to restore the selected card to its board. Press ",
+            help_text_style,
+        ),
+        Span::styled(input_mode_key, help_key_style),
+        Span::styled(" to search. Press ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[3]);
 
-    let card_being_edited = app.state.get_card_being_edited();
-    let card = if let Some(card_being_edited) = card_being_edited {
-        card_being_edited.1
+    let selected_card = app
+        .state
+        .app_list_states
+        .archived_card_list
+        .selected()
+        .and_then(|index| archived_cards.get(index));
+    let preview_lines = if let Some((_, board_name, archived_card)) = selected_card {
+        let card = &archived_card.card;
+        let tags = if card.tags.is_empty() {
+            FIELD_NOT_SET.to_string()
+        } else {
+            card.tags.join(", ")
+        };
+        vec![
+            Line::from(Span::styled(
+                format!("Board: {}", board_name),
+                default_style,
+            )),
+            Line::from(Span::styled(
+                format!("Status: {}", card.card_status),
+                default_style,
+            )),
+            Line::from(Span::styled(
+                format!("Priority: {}", card.priority),
+                default_style,
+            )),
+            Line::from(Span::styled(
+                format!("Due: {}", card.due_date),
+                default_style,
+            )),
+            Line::from(Span::styled(
+                format!("Completed: {}", card.date_completed),
+                default_style,
+            )),
+            Line::from(Span::styled(
+                format!("Archived: {}", archived_card.date_archived),
+                default_style,
+            )),
+            Line::from(Span::styled(format!("Tags: {}", tags), default_style)),
+            Line::from(""),
+            Line::from(Span::styled(card.description.clone(), default_style)),
+        ]
     } else {
-        card.unwrap().to_owned()
+        vec![Line::from(Span::styled(
+            "Select an archived card to see its details",
+            help_text_style,
+        ))]
     };
+    let preview_title = selected_card
+        .map(|(_, _, archived_card)| archived_card.card.name.clone())
+        .unwrap_or("Card Details".to_string());
+    let preview_paragraph = Paragraph::new(preview_lines)
+        .block(
+            Block::default()
+                .title(preview_title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    rect.render_widget(preview_paragraph, preview_chunks[1]);
 
-    let board_name = board.name.clone();
-    let card_name = card.name.clone();
-
-    // Prepare Main Block Widget
-    let main_block_widget = {
+    let archive_info_paragraph = Paragraph::new(if app.config.auto_archive_days > 0 {
+        format!(
+            "Completed cards are archived automatically after {} days",
+            app.config.auto_archive_days
+        )
+    } else {
+        "Auto archive is disabled, set it in the config menu".to_string()
+    })
+    .alignment(Alignment::Center)
+    .block(
         Block::default()
-            .title(format!("{} >> Board({})", card_name, board_name))
             .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
+            .border_type(BorderType::Rounded),
+    )
+    .style(default_style);
+
+    if app.state.focus == Focus::TextInput && app.state.app_status == AppStatus::UserInput {
+        let current_cursor_position =
+            app.state
+                .current_cursor_position
+                .unwrap_or(app.state.current_user_input.len()) as u16;
+        let x_offset = current_cursor_position % (chunks[1].width - 2);
+        rect.set_cursor(chunks[1].x + x_offset + 1, chunks[1].y + 1);
+    }
+
+    if app.config.enable_mouse_support {
+        rect.render_widget(archive_info_paragraph, title_bar_chunks[0]);
+        render_close_button(rect, app);
+    } else {
+        rect.render_widget(archive_info_paragraph, preview_chunks[0]);
+    }
+}
+
+pub fn render_workspace_switcher(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let main_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Fill(1)].as_ref())
+        .split(rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(6),
+            ]
+            .as_ref(),
+        )
+        .split(main_chunks[0]);
+    let preview_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Fill(1)].as_ref())
+        .split(main_chunks[1]);
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(preview_chunks[0]);
+
+    let entries = app.get_workspace_switcher_entries();
+    let selected_index = app.state.app_list_states.workspace_list.selected();
+    if entries.is_empty() {
+        app.state.app_list_states.workspace_list.select(None);
+    } else if !matches!(selected_index, Some(index) if index < entries.len()) {
+        app.state.app_list_states.workspace_list.select(Some(0));
+    }
+
+    let title_paragraph = Paragraph::new(format!(
+        "Workspaces (current: {})",
+        app.config.current_workspace
+    ))
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    )
+    .style(default_style);
+    rect.render_widget(title_paragraph, chunks[0]);
+
+    let search_style =
+        get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], false, true);
+    let search_text = if app.state.current_user_input.is_empty()
+        && app.state.app_status != AppStatus::UserInput
+    {
+        Span::styled(
+            "Type a workspace name to switch to or create it, or search cards in all workspaces",
+            app.current_theme.inactive_text_style,
+        )
+    } else {
+        Span::styled(app.state.current_user_input.clone(), default_style)
+    };
+    let search_paragraph = Paragraph::new(search_text).block(
+        Block::default()
+            .title("Workspace or Search")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(search_style),
+    );
+    rect.render_widget(search_paragraph, chunks[1]);
+
+    let list_border_style = if app.state.focus == Focus::Body {
+        app.current_theme.keyboard_focus_style
+    } else {
+        default_style
+    };
+    let items: Vec<ListItem> = entries
+        .iter()
+        .map(|entry| match entry {
+            WorkspaceSwitcherEntry::Workspace(workspace_name) => {
+                if *workspace_name == app.config.current_workspace {
+                    ListItem::new(format!("{} (current)", workspace_name))
+                } else {
+                    ListItem::new(workspace_name.clone())
+                }
+            }
+            WorkspaceSwitcherEntry::NewWorkspace(workspace_name) => ListItem::new(Span::styled(
+                format!("+ Create workspace {}", workspace_name),
+                app.current_theme.card_priority_low_style,
+            )),
+            WorkspaceSwitcherEntry::Card {
+                workspace,
+                board_name,
+                card_name,
+                ..
+            } => ListItem::new(format!("  {} ({} / {})", card_name, workspace, board_name)),
+        })
+        .collect();
+    let workspace_list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Workspaces and Cards ({})", entries.len()))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(list_border_style),
+        )
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL)
+        .style(default_style);
+    if app.state.popup_mode.is_none()
+        && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[2])
+    {
+        app.state.mouse_focus = Some(Focus::Body);
+        app.state.set_focus(Focus::Body);
+        calculate_mouse_list_select_index(
+            app.state.current_mouse_coordinates.1,
+            &entries,
+            chunks[2],
+            &mut app.state.app_list_states.workspace_list,
+        );
+    }
+    rect.render_stateful_widget(
+        workspace_list,
+        chunks[2],
+        &mut app.state.app_list_states.workspace_list,
+    );
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let input_mode_key = app
+        .get_first_keybinding(KeyBindingEnum::TakeUserInput)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" or ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to navigate. Press ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(
+            " to switch to the selected workspace or card. Press ",
+            help_text_style,
+        ),
+        Span::styled(input_mode_key, help_key_style),
+        Span::styled(" to type. Press ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[3]);
+
+    let selected_entry = app
+        .state
+        .app_list_states
+        .workspace_list
+        .selected()
+        .and_then(|index| entries.get(index));
+    let workspace_boards = |workspace_name: &str| -> Option<&Boards> {
+        if workspace_name == app.config.current_workspace {
+            Some(&app.boards)
+        } else {
+            app.workspace_boards
+                .iter()
+                .find(|(name, _)| name == workspace_name)
+                .map(|(_, boards)| boards)
+        }
+    };
+    let (preview_title, preview_lines) = match selected_entry {
+        Some(WorkspaceSwitcherEntry::Workspace(workspace_name)) => {
+            let (board_count, card_count) = workspace_boards(workspace_name)
+                .map(|boards| {
+                    (
+                        boards.len(),
+                        boards
+                            .get_boards()
+                            .iter()
+                            .map(|board| board.cards.len())
+                            .sum::<usize>(),
+                    )
+                })
+                .unwrap_or((0, 0));
+            let theme = app
+                .config
+                .get_workspace(workspace_name)
+                .and_then(|workspace| workspace.theme_override.clone())
+                .map(|theme| format!("{} (workspace override)", theme))
+                .unwrap_or(format!("{} (default)", app.config.default_theme));
+            (
+                workspace_name.clone(),
+                vec![
+                    Line::from(Span::styled(
+                        format!("Boards: {}", board_count),
+                        default_style,
+                    )),
+                    Line::from(Span::styled(
+                        format!("Cards: {}", card_count),
+                        default_style,
+                    )),
+                    Line::from(Span::styled(format!("Theme: {}", theme), default_style)),
+                    Line::from(Span::styled(
+                        format!(
+                            "Saves: {}",
+                            app.config
+                                .get_workspace_save_directory(workspace_name)
+                                .to_string_lossy()
+                        ),
+                        default_style,
+                    )),
+                ],
+            )
+        }
+        Some(WorkspaceSwitcherEntry::NewWorkspace(workspace_name)) => (
+            workspace_name.clone(),
+            vec![Line::from(Span::styled(
+                format!(
+                    "Workspace {} does not exist yet, it will be created empty and saved separately from your other workspaces",
+                    workspace_name
+                ),
+                default_style,
+            ))],
+        ),
+        Some(WorkspaceSwitcherEntry::Card {
+            workspace,
+            board_name,
+            card_id,
+            card_name,
+        }) => {
+            let card = workspace_boards(workspace).and_then(|boards| boards.get_card_with_id(*card_id));
+            let mut lines = vec![
+                Line::from(Span::styled(
+                    format!("Workspace: {}", workspace),
+                    default_style,
+                )),
+                Line::from(Span::styled(format!("Board: {}", board_name), default_style)),
+            ];
+            if let Some(card) = card {
+                lines.push(Line::from(Span::styled(
+                    format!("Status: {}", card.card_status),
+                    default_style,
+                )));
+                lines.push(Line::from(Span::styled(
+                    format!("Due: {}", card.due_date),
+                    default_style,
+                )));
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(
+                    card.description.clone(),
+                    default_style,
+                )));
+            }
+            (card_name.clone(), lines)
+        }
+        None => (
+            "Details".to_string(),
+            vec![Line::from(Span::styled(
+                "Select a workspace or card to see its details",
+                help_text_style,
+            ))],
+        ),
+    };
+    let preview_paragraph = Paragraph::new(preview_lines)
+        .block(
+            Block::default()
+                .title(preview_title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    rect.render_widget(preview_paragraph, preview_chunks[1]);
+
+    let workspace_info_paragraph = Paragraph::new(format!(
+        "{} workspaces, each with its own saves",
+        app.config.workspaces.len()
+    ))
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    )
+    .style(default_style);
+
+    if app.state.focus == Focus::TextInput && app.state.app_status == AppStatus::UserInput {
+        let current_cursor_position =
+            app.state
+                .current_cursor_position
+                .unwrap_or(app.state.current_user_input.len()) as u16;
+        let x_offset = current_cursor_position % (chunks[1].width - 2);
+        rect.set_cursor(chunks[1].x + x_offset + 1, chunks[1].y + 1);
+    }
+
+    if app.config.enable_mouse_support {
+        rect.render_widget(workspace_info_paragraph, title_bar_chunks[0]);
+        render_close_button(rect, app);
+    } else {
+        rect.render_widget(workspace_info_paragraph, preview_chunks[0]);
+    }
+}
+
+pub fn render_label_manager(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let main_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Fill(1)].as_ref())
+        .split(rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(6),
+            ]
+            .as_ref(),
+        )
+        .split(main_chunks[0]);
+    let preview_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Fill(1)].as_ref())
+        .split(main_chunks[1]);
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(preview_chunks[0]);
+
+    let entries = app.get_label_manager_entries();
+    let selected_index = app.state.app_list_states.label_list.selected();
+    if entries.is_empty() {
+        app.state.app_list_states.label_list.select(None);
+    } else if !matches!(selected_index, Some(index) if index < entries.len()) {
+        app.state.app_list_states.label_list.select(Some(0));
+    }
+
+    let title_paragraph = Paragraph::new("Label Manager")
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style);
+    rect.render_widget(title_paragraph, chunks[0]);
+
+    let input_style =
+        get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], false, true);
+    let input_text = if app.state.current_user_input.is_empty()
+        && app.state.app_status != AppStatus::UserInput
+    {
+        Span::styled(
+            "name | color | description",
+            app.current_theme.inactive_text_style,
+        )
+    } else {
+        Span::styled(app.state.current_user_input.clone(), default_style)
+    };
+    let input_title = match &app.state.label_being_edited {
+        Some(label_name) => format!("Edit Label {}", label_name),
+        None => "New Label".to_string(),
+    };
+    let input_paragraph = Paragraph::new(input_text).block(
+        Block::default()
+            .title(input_title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(input_style),
+    );
+    rect.render_widget(input_paragraph, chunks[1]);
+
+    let list_border_style = if app.state.focus == Focus::Body {
+        app.current_theme.keyboard_focus_style
+    } else {
+        default_style
+    };
+    let items: Vec<ListItem> = entries
+        .iter()
+        .map(|entry| {
+            let chip_style = if app.state.popup_mode.is_some() {
+                app.current_theme.inactive_text_style
+            } else {
+                get_tag_style(app, &entry.name)
+            };
+            let usage_text = if entry.label.is_some() {
+                format!(" {} cards", entry.usage)
+            } else {
+                format!(" {} cards, not registered", entry.usage)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!(" {} ", entry.name), chip_style),
+                Span::styled(usage_text, default_style),
+            ]))
+        })
+        .collect();
+    let label_list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Labels and Tags ({})", entries.len()))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(list_border_style),
+        )
+        .highlight_symbol(LIST_SELECTED_SYMBOL)
+        .style(default_style);
+    if app.state.popup_mode.is_none()
+        && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[2])
+    {
+        app.state.mouse_focus = Some(Focus::Body);
+        app.state.set_focus(Focus::Body);
+        calculate_mouse_list_select_index(
+            app.state.current_mouse_coordinates.1,
+            &entries,
+            chunks[2],
+            &mut app.state.app_list_states.label_list,
+        );
+    }
+    rect.render_stateful_widget(
+        label_list,
+        chunks[2],
+        &mut app.state.app_list_states.label_list,
+    );
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let input_mode_key = app
+        .get_first_keybinding(KeyBindingEnum::TakeUserInput)
+        .unwrap_or("".to_string());
+    let delete_key = app
+        .get_first_keybinding(KeyBindingEnum::DeleteCard)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" or ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to navigate. Press ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(
+            " to edit the selected label, renaming it onto another label merges them. Press ",
+            help_text_style,
+        ),
+        Span::styled(input_mode_key, help_key_style),
+        Span::styled(" to add a label. Press ", help_text_style),
+        Span::styled(delete_key, help_key_style),
+        Span::styled(" to delete an unused label. Press ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[3]);
+
+    let selected_entry = app
+        .state
+        .app_list_states
+        .label_list
+        .selected()
+        .and_then(|index| entries.get(index));
+    let mut preview_lines = match selected_entry {
+        Some(entry) => {
+            let chip_style = if app.state.popup_mode.is_some() {
+                app.current_theme.inactive_text_style
+            } else {
+                get_tag_style(app, &entry.name)
+            };
+            let mut lines = vec![
+                Line::from(Span::styled(format!(" {} ", entry.name), chip_style)),
+                Line::from(""),
+                Line::from(Span::styled(
+                    format!("Used on: {} cards", entry.usage),
+                    default_style,
+                )),
+            ];
+            match &entry.label {
+                Some(label) => {
+                    lines.push(Line::from(Span::styled(
+                        format!("Color: {}", label.color),
+                        default_style,
+                    )));
+                    if !label.description.is_empty() {
+                        lines.push(Line::from(Span::styled(
+                            format!("Description: {}", label.description),
+                            default_style,
+                        )));
+                    }
+                }
+                None => lines.push(Line::from(Span::styled(
+                    "This tag is not registered yet, edit it to give it a color",
+                    default_style,
+                ))),
+            }
+            lines
+        }
+        None => vec![Line::from(Span::styled(
+            "No labels yet, add one or tag some cards",
+            help_text_style,
+        ))],
+    };
+    preview_lines.push(Line::from(""));
+    preview_lines.push(Line::from(Span::styled(
+        format!(
+            "Colors: {}, #rrggbb or r, g, b. None follows the theme",
+            TextColorOptions::to_iter()
+                .filter(|color| !matches!(
+                    color,
+                    TextColorOptions::RGB(_, _, _) | TextColorOptions::None
+                ))
+                .map(|color| color.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        help_text_style,
+    )));
+    let preview_paragraph = Paragraph::new(preview_lines)
+        .block(
+            Block::default()
+                .title("Details")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    rect.render_widget(preview_paragraph, preview_chunks[1]);
+
+    let label_info_paragraph =
+        Paragraph::new(format!("{} registered labels", app.config.labels.len()))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .style(default_style);
+
+    if app.state.focus == Focus::TextInput && app.state.app_status == AppStatus::UserInput {
+        let current_cursor_position =
+            app.state
+                .current_cursor_position
+                .unwrap_or(app.state.current_user_input.len()) as u16;
+        let x_offset = current_cursor_position % (chunks[1].width - 2);
+        rect.set_cursor(chunks[1].x + x_offset + 1, chunks[1].y + 1);
+    }
+
+    if app.config.enable_mouse_support {
+        rect.render_widget(label_info_paragraph, title_bar_chunks[0]);
+        render_close_button(rect, app);
+    } else {
+        rect.render_widget(label_info_paragraph, preview_chunks[0]);
+    }
+}
+
+pub fn render_calendar(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let inactive_text_style =
+        check_for_popup_and_get_style(app, app.current_theme.inactive_text_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(3),
+                Constraint::Length(4),
+            ]
+            .as_ref(),
+        )
+        .split(rect.size());
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(chunks[0]);
+
+    let layout = app.state.calendar.layout;
+    let selected_date = app.state.calendar.selected_date;
+    let today = Local::now().date_naive();
+    let visible_days = app.state.calendar.visible_days();
+    let (first_visible_day, last_visible_day) = match (visible_days.first(), visible_days.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return,
+    };
+    let cards_by_date = app.get_cards_due_between(first_visible_day, last_visible_day);
+    let selected_day_cards = cards_by_date
+        .get(&selected_date)
+        .cloned()
+        .unwrap_or_default();
+    if app.state.calendar.selected_card_index >= selected_day_cards.len() {
+        app.state.calendar.selected_card_index = selected_day_cards.len().saturating_sub(1);
+    }
+    let selected_card_index = app.state.calendar.selected_card_index;
+    app.state.calendar.mouse_target = None;
+
+    let title = format!("Calendar - {} ({})", selected_date.format("%B %Y"), layout);
+    let title_paragraph = Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style);
+    if app.config.enable_mouse_support {
+        rect.render_widget(title_paragraph, title_bar_chunks[0]);
+    } else {
+        rect.render_widget(title_paragraph, chunks[0]);
+    }
+
+    let grid_chunks = match layout {
+        CalendarLayout::Month => {
+            let grid_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Fill(1)].as_ref())
+                .split(chunks[1]);
+            let weekday_chunks = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Ratio(1, 7); 7].as_ref())
+                .split(grid_chunks[0]);
+            for (weekday_chunk, day) in weekday_chunks.iter().zip(visible_days.iter()) {
+                let weekday_paragraph = Paragraph::new(day.format("%a").to_string())
+                    .alignment(Alignment::Center)
+                    .style(help_text_style);
+                rect.render_widget(weekday_paragraph, *weekday_chunk);
+            }
+            grid_chunks[1]
+        }
+        CalendarLayout::Week => chunks[1],
+    };
+    let number_of_weeks = (visible_days.len() / 7) as u32;
+    let week_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![
+            Constraint::Ratio(1, number_of_weeks);
+            number_of_weeks as usize
+        ])
+        .split(grid_chunks);
+
+    for (week_index, week_chunk) in week_chunks.iter().enumerate() {
+        let day_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, 7); 7].as_ref())
+            .split(*week_chunk);
+        for (day_index, day_chunk) in day_chunks.iter().enumerate() {
+            let day = visible_days[week_index * 7 + day_index];
+            let is_selected_day = day == selected_date;
+            let is_outside_month = layout == CalendarLayout::Month
+                && day.format("%Y-%m").to_string() != selected_date.format("%Y-%m").to_string();
+            let day_title = match layout {
+                CalendarLayout::Month => day.format("%e").to_string().trim().to_string(),
+                CalendarLayout::Week => day.format("%a %e %b").to_string(),
+            };
+            let day_title_style = if day == today {
+                check_for_popup_and_get_style(app, app.current_theme.keyboard_focus_style)
+                    .add_modifier(Modifier::BOLD)
+            } else if is_outside_month {
+                inactive_text_style
+            } else {
+                default_style
+            };
+            let border_style = if is_selected_day && app.state.popup_mode.is_none() {
+                app.current_theme.keyboard_focus_style
+            } else if is_outside_month {
+                inactive_text_style
+            } else {
+                default_style
+            };
+            let day_block = Block::default()
+                .title(Span::styled(day_title, day_title_style))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(border_style);
+            let inner_area = day_block.inner(*day_chunk);
+            let day_cards = cards_by_date.get(&day).cloned().unwrap_or_default();
+
+            // keep the selected card in view, the last line shows how many cards did not fit
+            let available_lines = inner_area.height as usize;
+            let mut first_shown_card = 0;
+            let mut shown_cards = day_cards.len();
+            if day_cards.len() > available_lines {
+                shown_cards = available_lines.saturating_sub(1);
+                if is_selected_day && selected_card_index >= shown_cards {
+                    first_shown_card = selected_card_index + 1 - shown_cards;
+                }
+            }
+            let mut card_lines = vec![];
+            for (card_index, (_, card)) in day_cards
+                .iter()
+                .enumerate()
+                .skip(first_shown_card)
+                .take(shown_cards)
+            {
+                let days_left = (day - today).num_days();
+                let card_style = if app.state.popup_mode.is_some() {
+                    inactive_text_style
+                } else if is_selected_day && card_index == selected_card_index {
+                    app.current_theme.list_select_style
+                } else if card.card_status == CardStatus::Complete {
+                    app.current_theme.card_status_completed_style
+                } else if days_left < 0 {
+                    app.current_theme.card_due_overdue_style
+                } else if days_left <= app.config.warning_delta.into() {
+                    app.current_theme.card_due_warning_style
+                } else {
+                    app.current_theme.card_due_default_style
+                };
+                let card_name = card
+                    .name
+                    .chars()
+                    .take(inner_area.width as usize)
+                    .collect::<String>();
+                card_lines.push(Line::from(Span::styled(card_name, card_style)));
+
+                let line_area = Rect::new(
+                    inner_area.x,
+                    inner_area.y + (card_index - first_shown_card) as u16,
+                    inner_area.width,
+                    1,
+                );
+                if app.state.popup_mode.is_none()
+                    && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &line_area)
+                {
+                    app.state.calendar.mouse_target = Some((day, Some(card_index)));
+                }
+            }
+            let hidden_cards = day_cards.len() - shown_cards;
+            if hidden_cards > 0 {
+                card_lines.push(Line::from(Span::styled(
+                    format!("+{} more", hidden_cards),
+                    help_text_style,
+                )));
+            }
+            if app.state.popup_mode.is_none()
+                && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, day_chunk)
+            {
+                app.state.mouse_focus = Some(Focus::Body);
+                app.state.set_focus(Focus::Body);
+                if app.state.calendar.mouse_target.is_none() {
+                    app.state.calendar.mouse_target = Some((day, None));
+                }
+            }
+            let day_paragraph = Paragraph::new(card_lines)
+                .block(day_block)
+                .style(default_style);
+            rect.render_widget(day_paragraph, *day_chunk);
+        }
+    }
+
+    let details_line = match selected_day_cards.get(selected_card_index) {
+        Some((board_id, card)) => {
+            let board_name = app
+                .boards
+                .get_board_with_id(*board_id)
+                .map(|board| board.name.clone())
+                .unwrap_or_default();
+            Line::from(vec![
+                Span::styled(
+                    card.name.clone(),
+                    default_style.add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        " in {} | Due: {} | {} of {} due on this day",
+                        board_name,
+                        card.due_date,
+                        selected_card_index + 1,
+                        selected_day_cards.len()
+                    ),
+                    default_style,
+                ),
+            ])
+        }
+        None => Line::from(Span::styled(
+            format!("Nothing due on {}", selected_date.format("%A %e %B %Y")),
+            help_text_style,
+        )),
+    };
+    let details_paragraph = Paragraph::new(details_line).block(
+        Block::default()
+            .title("Selected")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(default_style),
+    );
+    rect.render_widget(details_paragraph, chunks[2]);
+
+    let left_key = app
+        .get_first_keybinding(KeyBindingEnum::Left)
+        .unwrap_or("".to_string());
+    let right_key = app
+        .get_first_keybinding(KeyBindingEnum::Right)
+        .unwrap_or("".to_string());
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let next_focus_key = app
+        .get_first_keybinding(KeyBindingEnum::NextFocus)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let move_card_left_key = app
+        .get_first_keybinding(KeyBindingEnum::MoveCardLeft)
+        .unwrap_or("".to_string());
+    let move_card_right_key = app
+        .get_first_keybinding(KeyBindingEnum::MoveCardRight)
+        .unwrap_or("".to_string());
+    let toggle_layout_key = app
+        .get_first_keybinding(KeyBindingEnum::ToggleCalendarLayout)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(left_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(right_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to change the day and ", help_text_style),
+        Span::styled(next_focus_key, help_key_style),
+        Span::styled(" to pick a card. Press ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(" to open it, ", help_text_style),
+        Span::styled(move_card_left_key, help_key_style),
+        Span::styled(" or ", help_text_style),
+        Span::styled(move_card_right_key, help_key_style),
+        Span::styled(
+            " (and move card up/down for a week) to reschedule it. Press ",
+            help_text_style,
+        ),
+        Span::styled(toggle_layout_key, help_key_style),
+        Span::styled(" to switch between month and week. Press ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[3]);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_timeline(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let inactive_text_style =
+        check_for_popup_and_get_style(app, app.current_theme.inactive_text_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let today_style = check_for_popup_and_get_style(app, app.current_theme.keyboard_focus_style)
+        .add_modifier(Modifier::BOLD);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(3),
+                Constraint::Length(4),
+            ]
+            .as_ref(),
+        )
+        .split(rect.size());
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(chunks[0]);
+
+    let zoom = app.state.timeline.zoom;
+    let title_paragraph = Paragraph::new(format!("Timeline ({})", zoom))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style);
+    if app.config.enable_mouse_support {
+        rect.render_widget(title_paragraph, title_bar_chunks[0]);
+    } else {
+        rect.render_widget(title_paragraph, chunks[0]);
+    }
+
+    let chart_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(if app.state.popup_mode.is_none() {
+            app.current_theme.keyboard_focus_style
+        } else {
+            default_style
+        });
+    let chart_area = chart_block.inner(chunks[1]);
+    rect.render_widget(chart_block, chunks[1]);
+    let label_width = (chart_area.width / 4).min(28);
+    let bars_x = chart_area.x + label_width + 1;
+    let column_width = zoom.column_width();
+    let visible_columns = (chart_area.width.saturating_sub(label_width + 1) / column_width) as i64;
+    app.state.timeline.visible_columns = visible_columns.max(1);
+    let origin = app.state.timeline.origin;
+    let today = Local::now().date_naive();
+    let today_column = zoom.column_of(origin, today);
+
+    let entries = app.get_timeline_entries();
+    if app.state.timeline.selected_entry >= entries.len() {
+        app.state.timeline.selected_entry = entries.len().saturating_sub(1);
+    }
+    let selected_entry = app.state.timeline.selected_entry;
+    app.state.timeline.mouse_target = None;
+    app.state.timeline.mouse_column = None;
+    let mouse_coordinates = app.state.current_mouse_coordinates;
+    if app.state.popup_mode.is_none() && check_if_mouse_is_in_area(&mouse_coordinates, &chart_area)
+    {
+        app.state.mouse_focus = Some(Focus::Body);
+        app.state.set_focus(Focus::Body);
+        if mouse_coordinates.0 >= bars_x {
+            app.state.timeline.mouse_column =
+                Some(((mouse_coordinates.0 - bars_x) / column_width) as i64);
+        }
+    }
+
+    // two header lines, the upper one names the month (or year) where it starts
+    let mut period_labels = vec![' '; (visible_columns * column_width as i64) as usize];
+    let mut column_labels = vec![];
+    for column in 0..visible_columns {
+        let column_date = zoom.shift(origin, column);
+        let previous_column_date = zoom.shift(origin, column - 1);
+        let (period_changed, period_label) = match zoom {
+            TimelineZoom::Days | TimelineZoom::Weeks => (
+                column_date.format("%Y-%m").to_string()
+                    != previous_column_date.format("%Y-%m").to_string(),
+                column_date.format("%b %Y").to_string(),
+            ),
+            TimelineZoom::Months => (
+                column_date.year() != previous_column_date.year(),
+                column_date.format("%Y").to_string(),
+            ),
+        };
+        if column == 0 || period_changed {
+            let label_start = (column * column_width as i64) as usize;
+            for (offset, label_char) in period_label.chars().enumerate() {
+                if let Some(label_cell) = period_labels.get_mut(label_start + offset) {
+                    *label_cell = label_char;
+                }
+            }
+        }
+        let column_label = match zoom {
+            TimelineZoom::Days | TimelineZoom::Weeks => column_date.format("%d").to_string(),
+            TimelineZoom::Months => column_date.format("%b").to_string(),
+        };
+        let column_style = if column == today_column {
+            today_style
+        } else {
+            help_text_style
+        };
+        column_labels.push(Span::styled(
+            format!("{:<width$}", column_label, width = column_width as usize),
+            column_style,
+        ));
+    }
+    let header_lines = vec![
+        Line::from(vec![
+            Span::styled(" ".repeat(label_width as usize + 1), default_style),
+            Span::styled(period_labels.iter().collect::<String>(), default_style),
+        ]),
+        Line::from(
+            std::iter::once(Span::styled(
+                format!("{:<width$}", "Card", width = label_width as usize + 1),
+                help_text_style,
+            ))
+            .chain(column_labels)
+            .collect::<Vec<Span>>(),
+        ),
+    ];
+    let header_area = Rect::new(
+        chart_area.x,
+        chart_area.y,
+        chart_area.width,
+        2.min(chart_area.height),
+    );
+    rect.render_widget(Paragraph::new(header_lines), header_area);
+
+    // (entry_index, is_board_name) a board name row goes above the cards of each board
+    let mut display_rows: Vec<(usize, bool)> = vec![];
+    for (entry_index, entry) in entries.iter().enumerate() {
+        if entry_index == 0 || entries[entry_index - 1].board_id != entry.board_id {
+            display_rows.push((entry_index, true));
+        }
+        display_rows.push((entry_index, false));
+    }
+    let body_height = chart_area.height.saturating_sub(2) as usize;
+    if let Some(selected_row) = display_rows
+        .iter()
+        .position(|(entry_index, is_board_name)| *entry_index == selected_entry && !is_board_name)
+    {
+        let timeline = &mut app.state.timeline;
+        if selected_row < timeline.scroll_offset {
+            // show the board name when the first card of a board is selected
+            timeline.scroll_offset = selected_row.saturating_sub(1);
+        } else if body_height > 0 && selected_row >= timeline.scroll_offset + body_height {
+            timeline.scroll_offset = selected_row + 1 - body_height;
+        }
+    }
+    let scroll_offset = app.state.timeline.scroll_offset;
+    let dragging = app.state.timeline.dragging;
+    let mouse_column = app.state.timeline.mouse_column;
+
+    if entries.is_empty() && body_height > 0 {
+        let empty_paragraph = Paragraph::new(Span::styled(
+            "No cards with a due date to show",
+            help_text_style,
+        ))
+        .alignment(Alignment::Center);
+        rect.render_widget(
+            empty_paragraph,
+            Rect::new(chart_area.x, chart_area.y + 2, chart_area.width, 1),
+        );
+    }
+    for (row_offset, (entry_index, is_board_name)) in display_rows
+        .iter()
+        .skip(scroll_offset)
+        .take(body_height)
+        .enumerate()
+    {
+        let row_area = Rect::new(
+            chart_area.x,
+            chart_area.y + 2 + row_offset as u16,
+            chart_area.width,
+            1,
+        );
+        let mut row_spans = vec![];
+        let mut bar_columns = None;
+        let mut bar_style = default_style;
+        if *is_board_name {
+            let board_name = app
+                .boards
+                .get_board_with_id(entries[*entry_index].board_id)
+                .map(|board| board.name.clone())
+                .unwrap_or_default();
+            row_spans.push(Span::styled(
+                format!(
+                    "{:<width$}",
+                    board_name
+                        .chars()
+                        .take(label_width as usize)
+                        .collect::<String>(),
+                    width = label_width as usize + 1
+                ),
+                default_style.add_modifier(Modifier::BOLD),
+            ));
+        } else {
+            let entry = &entries[*entry_index];
+            let mut start_column = zoom.column_of(origin, entry.start.date());
+            let mut due_column = zoom.column_of(origin, entry.due.date());
+            // preview the bar end being dragged at the mouse column
+            if let (Some((card_id, handle)), Some(mouse_column)) = (dragging, mouse_column) {
+                if card_id == entry.card.id {
+                    match handle {
+                        TimelineHandle::Start => start_column = mouse_column.min(due_column),
+                        TimelineHandle::End => due_column = mouse_column,
+                    }
+                }
+            }
+            bar_columns = Some((start_column, due_column.max(start_column)));
+            let days_left = (entry.due.date() - today).num_days();
+            bar_style = if app.state.popup_mode.is_some() {
+                inactive_text_style
+            } else if entry.card.card_status == CardStatus::Complete {
+                app.current_theme.card_status_completed_style
+            } else if days_left < 0 {
+                app.current_theme.card_due_overdue_style
+            } else if days_left <= app.config.warning_delta.into() {
+                app.current_theme.card_due_warning_style
+            } else {
+                app.current_theme.card_due_default_style
+            };
+            let label_style = if *entry_index == selected_entry && app.state.popup_mode.is_none() {
+                app.current_theme.list_select_style
+            } else {
+                default_style
+            };
+            row_spans.push(Span::styled(
+                format!(
+                    "{:<width$}",
+                    entry
+                        .card
+                        .name
+                        .chars()
+                        .take(label_width as usize)
+                        .collect::<String>(),
+                    width = label_width as usize
+                ),
+                label_style,
+            ));
+            row_spans.push(Span::styled(" ", default_style));
+
+            if app.state.popup_mode.is_none()
+                && check_if_mouse_is_in_area(&mouse_coordinates, &row_area)
+            {
+                let handle = match mouse_column {
+                    Some(mouse_column) if mouse_column == due_column => Some(TimelineHandle::End),
+                    Some(mouse_column) if mouse_column == start_column => {
+                        Some(TimelineHandle::Start)
+                    }
+                    _ => None,
+                };
+                app.state.timeline.mouse_target = Some((*entry_index, handle));
+            }
+        }
+        for column in 0..visible_columns {
+            let width = column_width as usize;
+            let cell = match bar_columns {
+                Some((start_column, due_column))
+                    if column >= start_column && column <= due_column =>
+                {
+                    let left = if column == start_column {
+                        "┣"
+                    } else if column == 0 {
+                        "◀"
+                    } else {
+                        "━"
+                    };
+                    let right = if column == due_column {
+                        "┫"
+                    } else if column == visible_columns - 1 {
+                        "▶"
+                    } else {
+                        "━"
+                    };
+                    Span::styled(
+                        format!("{}{}{}", left, "━".repeat(width - 2), right),
+                        bar_style,
+                    )
+                }
+                _ if column == today_column => {
+                    Span::styled(format!("{:<width$}", "│", width = width), today_style)
+                }
+                _ => Span::styled(" ".repeat(width), default_style),
+            };
+            row_spans.push(cell);
+        }
+        rect.render_widget(Paragraph::new(Line::from(row_spans)), row_area);
+    }
+
+    let details_line = match entries.get(selected_entry) {
+        Some(entry) => {
+            let board_name = app
+                .boards
+                .get_board_with_id(entry.board_id)
+                .map(|board| board.name.clone())
+                .unwrap_or_default();
+            let start_source = if entry.card.start_date.is_some() {
+                "Start"
+            } else {
+                "Created"
+            };
+            Line::from(vec![
+                Span::styled(
+                    entry.card.name.clone(),
+                    default_style.add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        " in {} | {}: {} | Due: {} | {} days",
+                        board_name,
+                        start_source,
+                        entry.start.format(entry.date_format.to_parser_string()),
+                        entry.card.due_date,
+                        (entry.due.date() - entry.start.date()).num_days() + 1
+                    ),
+                    default_style,
+                ),
+            ])
+        }
+        None => Line::from(Span::styled(
+            "Give cards a due date to see them on the timeline",
+            help_text_style,
+        )),
+    };
+    let details_paragraph = Paragraph::new(details_line).block(
+        Block::default()
+            .title("Selected")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(default_style),
+    );
+    rect.render_widget(details_paragraph, chunks[2]);
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let left_key = app
+        .get_first_keybinding(KeyBindingEnum::Left)
+        .unwrap_or("".to_string());
+    let right_key = app
+        .get_first_keybinding(KeyBindingEnum::Right)
+        .unwrap_or("".to_string());
+    let move_card_left_key = app
+        .get_first_keybinding(KeyBindingEnum::MoveCardLeft)
+        .unwrap_or("".to_string());
+    let move_card_right_key = app
+        .get_first_keybinding(KeyBindingEnum::MoveCardRight)
+        .unwrap_or("".to_string());
+    let zoom_key = app
+        .get_first_keybinding(KeyBindingEnum::CycleTimelineZoom)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" or ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to pick a card and ", help_text_style),
+        Span::styled(left_key, help_key_style),
+        Span::styled(" or ", help_text_style),
+        Span::styled(right_key, help_key_style),
+        Span::styled(" to scroll. Press ", help_text_style),
+        Span::styled(move_card_left_key, help_key_style),
+        Span::styled(" or ", help_text_style),
+        Span::styled(move_card_right_key, help_key_style),
+        Span::styled(
+            " to move the due date, or drag either end of a bar. Press ",
+            help_text_style,
+        ),
+        Span::styled(zoom_key, help_key_style),
+        Span::styled(" to zoom, ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(" to open the card and ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[3]);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_card_table(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(4),
+            ]
+            .as_ref(),
+        )
+        .split(rect.size());
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(chunks[0]);
+
+    let rows = app.get_card_table_rows();
+    match app.state.app_table_states.card_table.selected() {
+        _ if rows.is_empty() => app.state.app_table_states.card_table.select(None),
+        Some(selected_row) if selected_row < rows.len() => {}
+        _ => app.state.app_table_states.card_table.select(Some(0)),
+    }
+    let card_table = &app.state.card_table;
+    let mut title = format!("Card Table - {} cards", rows.len());
+    if !card_table.selected_cards.is_empty() {
+        title.push_str(&format!(", {} selected", card_table.selected_cards.len()));
+    }
+    if let Some(filter_query) = get_current_filter_query(app) {
+        title.push_str(&format!(" | Filter: {}", filter_query));
+    }
+    let title_paragraph = Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style);
+    if app.config.enable_mouse_support {
+        rect.render_widget(title_paragraph, title_bar_chunks[0]);
+    } else {
+        rect.render_widget(title_paragraph, chunks[0]);
+    }
+
+    let columns = CardTableColumn::all();
+    let widths = columns
+        .iter()
+        .map(|column| match column {
+            CardTableColumn::Board => Constraint::Length(16),
+            CardTableColumn::Name => Constraint::Fill(1),
+            CardTableColumn::Status => Constraint::Length(9),
+            CardTableColumn::Priority => Constraint::Length(8),
+            CardTableColumn::Tags => Constraint::Length(20),
+            CardTableColumn::Due => Constraint::Length(19),
+            CardTableColumn::Modified => Constraint::Length(16),
+        })
+        .collect::<Vec<Constraint>>();
+    let header_cells = columns
+        .iter()
+        .map(|column| {
+            let sort_marker = if *column != card_table.sort_column {
+                ""
+            } else if card_table.sort_descending {
+                " ▼"
+            } else {
+                " ▲"
+            };
+            let header_style = if *column == card_table.selected_column {
+                check_for_popup_and_get_style(app, app.current_theme.keyboard_focus_style)
+            } else {
+                default_style
+            }
+            .add_modifier(Modifier::BOLD);
+            Cell::from(format!("{}{}", column, sort_marker)).style(header_style)
+        })
+        .collect::<Vec<Cell>>();
+    let table_rows = rows
+        .iter()
+        .map(|row| {
+            let card = &row.card;
+            let is_selected_card = card_table.selected_cards.contains(&card.id);
+            let status_style = match card.card_status {
+                CardStatus::Active => app.current_theme.card_status_active_style,
+                CardStatus::Complete => app.current_theme.card_status_completed_style,
+                CardStatus::Stale => app.current_theme.card_status_stale_style,
+            };
+            let priority_style = match card.priority {
+                CardPriority::High => app.current_theme.card_priority_high_style,
+                CardPriority::Medium => app.current_theme.card_priority_medium_style,
+                CardPriority::Low => app.current_theme.card_priority_low_style,
+            };
+            let modified = parse_timestamp(&card.date_modified)
+                .map(|date_modified| {
+                    date_modified
+                        .with_timezone(&Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            let name = if is_selected_card {
+                format!("✓ {}", card.name)
+            } else {
+                card.name.clone()
+            };
+            let cells = columns
+                .iter()
+                .map(|column| match column {
+                    CardTableColumn::Board => Cell::from(row.board_name.clone()),
+                    CardTableColumn::Name => Cell::from(name.clone()),
+                    CardTableColumn::Status => Cell::from(Span::styled(
+                        card.card_status.to_string(),
+                        check_for_popup_and_get_style(app, status_style),
+                    )),
+                    CardTableColumn::Priority => Cell::from(Span::styled(
+                        card.priority.to_string(),
+                        check_for_popup_and_get_style(app, priority_style),
+                    )),
+                    CardTableColumn::Tags => Cell::from(card.tags.join(", ")),
+                    CardTableColumn::Due => Cell::from(card.due_date.clone()),
+                    CardTableColumn::Modified => Cell::from(modified.clone()),
+                })
+                .collect::<Vec<Cell>>();
+            let row_style = if is_selected_card {
+                check_for_popup_and_get_style(app, app.current_theme.keyboard_focus_style)
+            } else {
+                default_style
+            };
+            Row::new(cells).style(row_style)
+        })
+        .collect::<Vec<Row>>();
+    let table_border_style = if app.state.popup_mode.is_none() {
+        app.current_theme.keyboard_focus_style
+    } else {
+        default_style
+    };
+    let highlight_symbol = ">> ";
+    let table = Table::new(table_rows, widths.clone())
+        .header(Row::new(header_cells))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(table_border_style),
+        )
+        .style(default_style)
+        .highlight_style(check_for_popup_and_get_style(
+            app,
+            app.current_theme.list_select_style,
+        ))
+        .highlight_symbol(highlight_symbol)
+        .highlight_spacing(HighlightSpacing::Always);
+    rect.render_stateful_widget(table, chunks[1], &mut app.state.app_table_states.card_table);
+
+    // work out which header cell or row is under the mouse the same way the table lays itself out
+    app.state.card_table.mouse_row = None;
+    app.state.card_table.mouse_header = None;
+    let table_area = chunks[1].inner(&Margin {
+        vertical: 1,
+        horizontal: 1,
+    });
+    let mouse_coordinates = app.state.current_mouse_coordinates;
+    if app.state.popup_mode.is_none() && check_if_mouse_is_in_area(&mouse_coordinates, &table_area)
+    {
+        app.state.mouse_focus = Some(Focus::Body);
+        app.state.set_focus(Focus::Body);
+        if mouse_coordinates.1 == table_area.y {
+            let highlight_width = highlight_symbol.chars().count() as u16;
+            let column_areas = Layout::horizontal(widths).spacing(1).split(Rect::new(
+                table_area.x + highlight_width,
+                table_area.y,
+                table_area.width.saturating_sub(highlight_width),
+                1,
+            ));
+            app.state.card_table.mouse_header = column_areas
+                .iter()
+                .position(|column_area| check_if_mouse_is_in_area(&mouse_coordinates, column_area))
+                .map(|column_index| columns[column_index]);
+        } else {
+            let mouse_row = app.state.app_table_states.card_table.offset()
+                + (mouse_coordinates.1 - table_area.y - 1) as usize;
+            if mouse_row < rows.len() {
+                app.state.card_table.mouse_row = Some(mouse_row);
+            }
+        }
+    }
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let left_key = app
+        .get_first_keybinding(KeyBindingEnum::Left)
+        .unwrap_or("".to_string());
+    let right_key = app
+        .get_first_keybinding(KeyBindingEnum::Right)
+        .unwrap_or("".to_string());
+    let sort_key = app
+        .get_first_keybinding(KeyBindingEnum::SortCardTable)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let select_key = app
+        .get_first_keybinding(KeyBindingEnum::ToggleCardSelection)
+        .unwrap_or("".to_string());
+    let move_card_down_key = app
+        .get_first_keybinding(KeyBindingEnum::MoveCardDown)
+        .unwrap_or("".to_string());
+    let command_palette_key = app
+        .get_first_keybinding(KeyBindingEnum::ToggleCommandPalette)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to move between cards and ", help_text_style),
+        Span::styled(left_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(right_key, help_key_style),
+        Span::styled(" between columns. Press ", help_text_style),
+        Span::styled(sort_key, help_key_style),
+        Span::styled(" to sort by the column and ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(
+            " to change the status or priority in place, or open the card. Press ",
+            help_text_style,
+        ),
+        Span::styled(select_key, help_key_style),
+        Span::styled(" or ", help_text_style),
+        Span::styled(move_card_down_key, help_key_style),
+        Span::styled(" to select cards for bulk edits, ", help_text_style),
+        Span::styled(command_palette_key, help_key_style),
+        Span::styled(" for filters and ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to clear the selection or go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[2]);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_statistics(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let inactive_text_style =
+        check_for_popup_and_get_style(app, app.current_theme.inactive_text_style);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(rect.size());
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(chunks[0]);
+    let top_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Fill(2),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ]
+            .as_ref(),
+        )
+        .split(chunks[1]);
+    let bottom_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+        .split(chunks[2]);
+
+    let statistics = app.state.statistics.clone().unwrap_or_default();
+    let completed_total = statistics
+        .completed_per_week
+        .iter()
+        .map(|(_, completed)| completed)
+        .sum::<usize>();
+    let title_paragraph = Paragraph::new(format!(
+        "Statistics - {} cards completed in the last {} weeks",
+        completed_total,
+        statistics.completed_per_week.len()
+    ))
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    )
+    .style(default_style);
+    if app.config.enable_mouse_support {
+        rect.render_widget(title_paragraph, title_bar_chunks[0]);
+    } else {
+        rect.render_widget(title_paragraph, chunks[0]);
+    }
+
+    let throughput_bars = statistics
+        .completed_per_week
+        .iter()
+        .map(|(week, completed)| {
+            Bar::default()
+                .value(*completed as u64)
+                .label(Line::from(week.format("%m/%d").to_string()))
+        })
+        .collect::<Vec<Bar>>();
+    let week_count = statistics.completed_per_week.len().max(1) as u16;
+    let throughput_bar_width = (top_chunks[0].width.saturating_sub(2) / week_count)
+        .saturating_sub(1)
+        .max(1);
+    let throughput_chart = BarChart::default()
+        .block(
+            Block::default()
+                .title("Completed per week")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .data(BarGroup::default().bars(&throughput_bars))
+        .bar_width(throughput_bar_width)
+        .bar_gap(1)
+        .bar_style(check_for_popup_and_get_style(
+            app,
+            app.current_theme.card_status_completed_style,
+        ))
+        .value_style(check_for_popup_and_get_style(
+            app,
+            app.current_theme.list_select_style,
+        ))
+        .style(default_style);
+    rect.render_widget(throughput_chart, top_chunks[0]);
+
+    let priority_bars = statistics
+        .by_priority
+        .iter()
+        .map(|(priority, count)| {
+            let priority_style = match priority {
+                CardPriority::High => app.current_theme.card_priority_high_style,
+                CardPriority::Medium => app.current_theme.card_priority_medium_style,
+                CardPriority::Low => app.current_theme.card_priority_low_style,
+            };
+            Bar::default()
+                .value(*count as u64)
+                .label(Line::from(priority.to_string()))
+                .style(check_for_popup_and_get_style(app, priority_style))
+        })
+        .collect::<Vec<Bar>>();
+    let priority_chart = BarChart::default()
+        .block(
+            Block::default()
+                .title("By priority")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .direction(Direction::Horizontal)
+        .data(BarGroup::default().bars(&priority_bars))
+        .bar_gap(1)
+        .style(default_style);
+    rect.render_widget(priority_chart, top_chunks[1]);
+
+    let status_bars = statistics
+        .by_status
+        .iter()
+        .map(|(status, count)| {
+            let status_style = match status {
+                CardStatus::Active => app.current_theme.card_status_active_style,
+                CardStatus::Complete => app.current_theme.card_status_completed_style,
+                CardStatus::Stale => app.current_theme.card_status_stale_style,
+            };
+            Bar::default()
+                .value(*count as u64)
+                .label(Line::from(status.to_string()))
+                .style(check_for_popup_and_get_style(app, status_style))
+        })
+        .collect::<Vec<Bar>>();
+    let status_chart = BarChart::default()
+        .block(
+            Block::default()
+                .title("By status")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .direction(Direction::Horizontal)
+        .data(BarGroup::default().bars(&status_bars))
+        .bar_gap(1)
+        .style(default_style);
+    rect.render_widget(status_chart, top_chunks[2]);
+
+    // the bands are stacked so each line is the running total up to that status
+    let first_date = statistics
+        .cumulative_flow
+        .first()
+        .map(|snapshot| snapshot.date)
+        .unwrap_or_else(|| Local::now().date_naive());
+    let last_date = statistics
+        .cumulative_flow
+        .last()
+        .map(|snapshot| snapshot.date)
+        .unwrap_or(first_date);
+    let days_from_first = |date: NaiveDate| (date - first_date).num_days() as f64;
+    let complete_points = statistics
+        .cumulative_flow
+        .iter()
+        .map(|snapshot| (days_from_first(snapshot.date), snapshot.complete as f64))
+        .collect::<Vec<(f64, f64)>>();
+    let stale_points = statistics
+        .cumulative_flow
+        .iter()
+        .map(|snapshot| {
+            (
+                days_from_first(snapshot.date),
+                (snapshot.complete + snapshot.stale) as f64,
+            )
+        })
+        .collect::<Vec<(f64, f64)>>();
+    let active_points = statistics
+        .cumulative_flow
+        .iter()
+        .map(|snapshot| {
+            (
+                days_from_first(snapshot.date),
+                (snapshot.complete + snapshot.stale + snapshot.active) as f64,
+            )
+        })
+        .collect::<Vec<(f64, f64)>>();
+    let graph_type = if statistics.cumulative_flow.len() > 1 {
+        GraphType::Line
+    } else {
+        GraphType::Scatter
+    };
+    let max_cards = active_points
+        .iter()
+        .map(|(_, cards)| *cards)
+        .fold(1.0, f64::max);
+    let total_days = days_from_first(last_date).max(1.0);
+    let datasets = vec![
+        Dataset::default()
+            .name("Active")
+            .marker(Marker::Braille)
+            .graph_type(graph_type)
+            .style(check_for_popup_and_get_style(
+                app,
+                app.current_theme.card_status_active_style,
+            ))
+            .data(&active_points),
+        Dataset::default()
+            .name("Stale")
+            .marker(Marker::Braille)
+            .graph_type(graph_type)
+            .style(check_for_popup_and_get_style(
+                app,
+                app.current_theme.card_status_stale_style,
+            ))
+            .data(&stale_points),
+        Dataset::default()
+            .name("Complete")
+            .marker(Marker::Braille)
+            .graph_type(graph_type)
+            .style(check_for_popup_and_get_style(
+                app,
+                app.current_theme.card_status_completed_style,
+            ))
+            .data(&complete_points),
+    ];
+    let cumulative_flow_title = if statistics.cumulative_flow.len() > 1 {
+        "Cumulative flow".to_string()
+    } else {
+        "Cumulative flow (no older saves found, showing today only)".to_string()
+    };
+    let cumulative_flow_chart = Chart::new(datasets)
+        .block(
+            Block::default()
+                .title(cumulative_flow_title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .x_axis(
+            Axis::default()
+                .bounds([0.0, total_days])
+                .labels(vec![
+                    Span::styled(
+                        first_date.format("%Y-%m-%d").to_string(),
+                        inactive_text_style,
+                    ),
+                    Span::styled(
+                        last_date.format("%Y-%m-%d").to_string(),
+                        inactive_text_style,
+                    ),
+                ])
+                .style(default_style),
+        )
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, max_cards])
+                .labels(vec![
+                    Span::styled("0", inactive_text_style),
+                    Span::styled(format!("{}", max_cards as usize), inactive_text_style),
+                ])
+                .style(default_style),
+        )
+        .style(default_style);
+    rect.render_widget(cumulative_flow_chart, bottom_chunks[0]);
+
+    let flow_time_rows = statistics
+        .by_board
+        .iter()
+        .map(|flow_times| ("Board", flow_times))
+        .chain(
+            statistics
+                .by_tag
+                .iter()
+                .map(|flow_times| ("Tag", flow_times)),
+        )
+        .map(|(group, flow_times)| {
+            Row::new(vec![
+                Cell::from(Span::styled(group, inactive_text_style)),
+                Cell::from(flow_times.name.clone()),
+                Cell::from(flow_times.completed.to_string()),
+                Cell::from(
+                    flow_times
+                        .average_lead_time
+                        .map(format_flow_time)
+                        .unwrap_or(FIELD_NOT_SET.to_string()),
+                ),
+                Cell::from(
+                    flow_times
+                        .average_cycle_time
+                        .map(format_flow_time)
+                        .unwrap_or(FIELD_NOT_SET.to_string()),
+                ),
+            ])
+        })
+        .collect::<Vec<Row>>();
+    let flow_time_header = Row::new(vec!["", "Name", "Done", "Lead time", "Cycle time"])
+        .style(default_style.add_modifier(Modifier::BOLD));
+    let flow_time_table = Table::new(
+        flow_time_rows,
+        [
+            Constraint::Length(5),
+            Constraint::Fill(1),
+            Constraint::Length(5),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ],
+    )
+    .header(flow_time_header)
+    .block(
+        Block::default()
+            .title("Average lead and cycle time")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    )
+    .style(default_style)
+    .highlight_style(check_for_popup_and_get_style(
+        app,
+        app.current_theme.list_select_style,
+    ))
+    .highlight_symbol(LIST_SELECTED_SYMBOL)
+    .highlight_spacing(HighlightSpacing::Always);
+    rect.render_stateful_widget(
+        flow_time_table,
+        bottom_chunks[1],
+        &mut app.state.app_table_states.statistics,
+    );
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to scroll the flow times, ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(" to export the statistics as CSV and ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[3]);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+/// short form for the statistics table, e.g. 3.2h or 1.5d
+fn format_flow_time(seconds: i64) -> String {
+    let hours = seconds as f64 / 3600.0;
+    if hours < 1.0 {
+        format!("{}m", seconds / 60)
+    } else if hours < 24.0 {
+        format!("{:.1}h", hours)
+    } else {
+        format!("{:.1}d", hours / 24.0)
+    }
+}
+
+pub fn render_sprints(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let inactive_text_style =
+        check_for_popup_and_get_style(app, app.current_theme.inactive_text_style);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(4),
+            ]
+            .as_ref(),
+        )
+        .split(rect.size());
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(chunks[0]);
+    let body_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(40), Constraint::Fill(1)].as_ref())
+        .split(chunks[1]);
+
+    let title_paragraph = Paragraph::new(format!(
+        "Sprints - {} in workspace {}",
+        app.config.get_sprints().len(),
+        app.config.current_workspace
+    ))
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    )
+    .style(default_style);
+    if app.config.enable_mouse_support {
+        rect.render_widget(title_paragraph, title_bar_chunks[0]);
+    } else {
+        rect.render_widget(title_paragraph, chunks[0]);
+    }
+
+    let sprint_items = app
+        .config
+        .get_sprints()
+        .iter()
+        .map(|sprint| {
+            let (marker, style) = if sprint.closed {
+                ("✓", inactive_text_style)
+            } else {
+                ("●", default_style)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} {} ", marker, sprint.name), style),
+                Span::styled(
+                    format!("{} → {}", sprint.start_date, sprint.end_date),
+                    inactive_text_style,
+                ),
+            ]))
+        })
+        .collect::<Vec<ListItem>>();
+    let list_border_style = if app.state.popup_mode.is_none() {
+        app.current_theme.keyboard_focus_style
+    } else {
+        default_style
+    };
+    let sprint_list = List::new(sprint_items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(list_border_style),
+        )
+        .style(default_style)
+        .highlight_style(check_for_popup_and_get_style(
+            app,
+            app.current_theme.list_select_style,
+        ))
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+    rect.render_stateful_widget(
+        sprint_list,
+        body_chunks[0],
+        &mut app.state.app_list_states.sprint_list,
+    );
+
+    let selected_sprint = app
+        .state
+        .app_list_states
+        .sprint_list
+        .selected()
+        .and_then(|index| app.config.get_sprints().get(index))
+        .cloned();
+    let chart_mode = app.state.sprints.chart_mode;
+    let progress = &app.state.sprints.progress;
+    let chart_block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded);
+    match (
+        &selected_sprint,
+        selected_sprint
+            .as_ref()
+            .and_then(|sprint| sprint.start().zip(sprint.end())),
+    ) {
+        (Some(sprint), Some((start, end))) if !progress.is_empty() => {
+            let days_from_start = |date: NaiveDate| (date - start).num_days() as f64;
+            let total_days = days_from_start(end).max(1.0);
+            let first_scope = progress.first().map(|day| day.scope).unwrap_or(0) as f64;
+            let last_day = progress.last().cloned();
+            let max_scope = progress
+                .iter()
+                .map(|day| day.scope)
+                .max()
+                .unwrap_or(0)
+                .max(1) as f64;
+            let (actual_points, scope_points, ideal_points) = if chart_mode.is_burnup() {
+                (
+                    progress
+                        .iter()
+                        .map(|day| (days_from_start(day.date), day.completed as f64))
+                        .collect::<Vec<(f64, f64)>>(),
+                    progress
+                        .iter()
+                        .map(|day| (days_from_start(day.date), day.scope as f64))
+                        .collect::<Vec<(f64, f64)>>(),
+                    vec![
+                        (0.0, 0.0),
+                        (
+                            total_days,
+                            last_day.as_ref().map(|day| day.scope).unwrap_or(0) as f64,
+                        ),
+                    ],
+                )
+            } else {
+                (
+                    progress
+                        .iter()
+                        .map(|day| (days_from_start(day.date), day.remaining() as f64))
+                        .collect::<Vec<(f64, f64)>>(),
+                    vec![],
+                    vec![(0.0, first_scope), (total_days, 0.0)],
+                )
+            };
+            let actual_graph_type = if progress.len() > 1 {
+                GraphType::Line
+            } else {
+                GraphType::Scatter
+            };
+            let actual_name = if chart_mode.is_burnup() {
+                "Completed"
+            } else {
+                "Remaining"
+            };
+            let mut datasets = vec![
+                Dataset::default()
+                    .name("Ideal")
+                    .marker(Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(inactive_text_style)
+                    .data(&ideal_points),
+                Dataset::default()
+                    .name(actual_name)
+                    .marker(Marker::Braille)
+                    .graph_type(actual_graph_type)
+                    .style(check_for_popup_and_get_style(
+                        app,
+                        app.current_theme.card_status_completed_style,
+                    ))
+                    .data(&actual_points),
+            ];
+            if !scope_points.is_empty() {
+                datasets.push(
+                    Dataset::default()
+                        .name("Scope")
+                        .marker(Marker::Braille)
+                        .graph_type(actual_graph_type)
+                        .style(check_for_popup_and_get_style(
+                            app,
+                            app.current_theme.card_status_active_style,
+                        ))
+                        .data(&scope_points),
+                );
+            }
+            let summary = last_day
+                .map(|day| {
+                    format!(
+                        ", {} of {} remaining on {}",
+                        day.remaining(),
+                        day.scope,
+                        day.date.format("%Y-%m-%d")
+                    )
+                })
+                .unwrap_or_default();
+            let sprint_chart = Chart::new(datasets)
+                .block(chart_block.title(format!("{} - {}{}", sprint.name, chart_mode, summary)))
+                .x_axis(
+                    Axis::default()
+                        .bounds([0.0, total_days])
+                        .labels(vec![
+                            Span::styled(start.format("%Y-%m-%d").to_string(), inactive_text_style),
+                            Span::styled(end.format("%Y-%m-%d").to_string(), inactive_text_style),
+                        ])
+                        .style(default_style),
+                )
+                .y_axis(
+                    Axis::default()
+                        .bounds([0.0, max_scope])
+                        .labels(vec![
+                            Span::styled("0", inactive_text_style),
+                            Span::styled(format!("{}", max_scope as u32), inactive_text_style),
+                        ])
+                        .style(default_style),
+                )
+                .style(default_style);
+            rect.render_widget(sprint_chart, body_chunks[1]);
+        }
+        (Some(sprint), _) => {
+            let message = Paragraph::new(format!(
+                "No saves found between {} and {} yet, the chart fills in as the boards are saved each day",
+                sprint.start_date, sprint.end_date
+            ))
+            .alignment(Alignment::Center)
+            .block(chart_block.title(format!("{} - {}", sprint.name, chart_mode)))
+            .style(inactive_text_style)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+            rect.render_widget(message, body_chunks[1]);
+        }
+        (None, _) => {
+            let message = Paragraph::new(
+                "No sprints yet, create one with Create Sprint from the command palette",
+            )
+            .alignment(Alignment::Center)
+            .block(chart_block)
+            .style(inactive_text_style)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+            rect.render_widget(message, body_chunks[1]);
+        }
+    }
+    if app.state.popup_mode.is_none()
+        && check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &body_chunks[1])
+    {
+        app.state.mouse_focus = Some(Focus::Body);
+        app.state.set_focus(Focus::Body);
+    }
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let command_palette_key = app
+        .get_first_keybinding(KeyBindingEnum::ToggleCommandPalette)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to pick a sprint and ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(
+            " to switch between burndown and burnup in cards or estimate points. Press ",
+            help_text_style,
+        ),
+        Span::styled(command_palette_key, help_key_style),
+        Span::styled(" to create or close sprints and ", help_text_style),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[2]);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_agenda(rect: &mut Frame, app: &mut App) {
+    let default_style = check_for_popup_and_get_style(app, app.current_theme.general_style);
+    let help_key_style = check_for_popup_and_get_style(app, app.current_theme.help_key_style);
+    let help_text_style = check_for_popup_and_get_style(app, app.current_theme.help_text_style);
+    let inactive_text_style =
+        check_for_popup_and_get_style(app, app.current_theme.inactive_text_style);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(4),
+            ]
+            .as_ref(),
+        )
+        .split(rect.size());
+    let title_bar_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(chunks[0]);
+
+    let entries = app.get_agenda_entries();
+    match app.state.app_list_states.agenda_list.selected() {
+        _ if entries.is_empty() => app.state.app_list_states.agenda_list.select(None),
+        Some(selected_entry) if selected_entry < entries.len() => {}
+        _ => app.state.app_list_states.agenda_list.select(Some(0)),
+    }
+    let mut title = format!(
+        "Agenda - {} - {} cards",
+        Local::now().format("%A, %d %B %Y"),
+        entries.len()
+    );
+    if let Some(filter_query) = get_current_filter_query(app) {
+        title.push_str(&format!(" | Filter: {}", filter_query));
+    }
+    let title_paragraph = Paragraph::new(title)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style);
+    if app.config.enable_mouse_support {
+        rect.render_widget(title_paragraph, title_bar_chunks[0]);
+    } else {
+        rect.render_widget(title_paragraph, chunks[0]);
+    }
+
+    // section headers are list items too, so keep track of which entry each item shows
+    let mut list_items = vec![];
+    let mut item_entries: Vec<Option<usize>> = vec![];
+    for section in AgendaSection::all() {
+        let section_entries = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.section == section)
+            .collect::<Vec<(usize, &AgendaEntry)>>();
+        if section_entries.is_empty() {
+            continue;
+        }
+        let section_style = match section {
+            AgendaSection::Overdue => app.current_theme.card_due_overdue_style,
+            AgendaSection::DueToday | AgendaSection::DueThisWeek => {
+                app.current_theme.card_due_warning_style
+            }
+            AgendaSection::HighPriority => app.current_theme.card_priority_high_style,
+            AgendaSection::RecentlyModified => app.current_theme.general_style,
+        };
+        list_items.push(ListItem::new(Line::from(Span::styled(
+            format!("{} ({})", section, section_entries.len()),
+            check_for_popup_and_get_style(app, section_style).add_modifier(Modifier::BOLD),
+        ))));
+        item_entries.push(None);
+        for (entry_index, entry) in section_entries {
+            let card = &entry.card;
+            let mut spans = vec![
+                Span::styled(format!("  {}", card.name), default_style),
+                Span::styled(format!("  {}", entry.board_name), inactive_text_style),
+            ];
+            if card.due_date != FIELD_NOT_SET && !card.due_date.is_empty() {
+                let due_style = match section {
+                    AgendaSection::Overdue => app.current_theme.card_due_overdue_style,
+                    AgendaSection::DueToday => app.current_theme.card_due_warning_style,
+                    _ => app.current_theme.card_due_default_style,
+                };
+                spans.push(Span::styled(
+                    format!("  due {}", card.due_date),
+                    check_for_popup_and_get_style(app, due_style),
+                ));
+            }
+            if card.priority == CardPriority::High {
+                spans.push(Span::styled(
+                    format!("  {} priority", card.priority),
+                    check_for_popup_and_get_style(app, app.current_theme.card_priority_high_style),
+                ));
+            }
+            if section == AgendaSection::RecentlyModified {
+                let status_style = match card.card_status {
+                    CardStatus::Active => app.current_theme.card_status_active_style,
+                    CardStatus::Complete => app.current_theme.card_status_completed_style,
+                    CardStatus::Stale => app.current_theme.card_status_stale_style,
+                };
+                spans.push(Span::styled(
+                    format!("  {}", card.card_status),
+                    check_for_popup_and_get_style(app, status_style),
+                ));
+                if let Some(date_modified) = parse_timestamp(&card.date_modified) {
+                    spans.push(Span::styled(
+                        format!(
+                            "  modified {}",
+                            date_modified.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+                        ),
+                        inactive_text_style,
+                    ));
+                }
+            }
+            list_items.push(ListItem::new(Line::from(spans)));
+            item_entries.push(Some(entry_index));
+        }
+    }
+
+    let list_border_style = if app.state.popup_mode.is_none() {
+        app.current_theme.keyboard_focus_style
+    } else {
+        default_style
+    };
+    if list_items.is_empty() {
+        let empty_paragraph =
+            Paragraph::new("Nothing overdue, due this week, high priority or recently modified")
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(list_border_style),
+                )
+                .style(inactive_text_style);
+        rect.render_widget(empty_paragraph, chunks[1]);
+    } else {
+        let selected_item =
+            app.state
+                .app_list_states
+                .agenda_list
+                .selected()
+                .and_then(|selected_entry| {
+                    item_entries
+                        .iter()
+                        .position(|item_entry| *item_entry == Some(selected_entry))
+                });
+        let mut list_state = ListState::default().with_selected(selected_item);
+        let agenda_list = List::new(list_items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(list_border_style),
+            )
+            .style(default_style)
+            .highlight_style(check_for_popup_and_get_style(
+                app,
+                app.current_theme.list_select_style,
+            ))
+            .highlight_symbol(LIST_SELECTED_SYMBOL)
+            .highlight_spacing(HighlightSpacing::Always);
+        rect.render_stateful_widget(agenda_list, chunks[1], &mut list_state);
+
+        app.state.agenda.mouse_entry = None;
+        let list_area = chunks[1].inner(&Margin {
+            vertical: 1,
+            horizontal: 1,
+        });
+        let mouse_coordinates = app.state.current_mouse_coordinates;
+        if app.state.popup_mode.is_none()
+            && check_if_mouse_is_in_area(&mouse_coordinates, &list_area)
+        {
+            app.state.mouse_focus = Some(Focus::Body);
+            app.state.set_focus(Focus::Body);
+            let mouse_item = list_state.offset() + (mouse_coordinates.1 - list_area.y) as usize;
+            app.state.agenda.mouse_entry = item_entries.get(mouse_item).copied().flatten();
+        }
+    }
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let complete_key = app
+        .get_first_keybinding(KeyBindingEnum::ChangeCardStatusToCompleted)
+        .unwrap_or("".to_string());
+    let left_key = app
+        .get_first_keybinding(KeyBindingEnum::Left)
+        .unwrap_or("".to_string());
+    let right_key = app
+        .get_first_keybinding(KeyBindingEnum::Right)
+        .unwrap_or("".to_string());
+    let move_card_left_key = app
+        .get_first_keybinding(KeyBindingEnum::MoveCardLeft)
+        .unwrap_or("".to_string());
+    let move_card_right_key = app
+        .get_first_keybinding(KeyBindingEnum::MoveCardRight)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_text = Line::from(vec![
+        Span::styled("Use ", help_text_style),
+        Span::styled(up_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(down_key, help_key_style),
+        Span::styled(" to move between cards and ", help_text_style),
+        Span::styled(accept_key, help_key_style),
+        Span::styled(" to open one. Press ", help_text_style),
+        Span::styled(complete_key, help_key_style),
+        Span::styled(" to complete the card, ", help_text_style),
+        Span::styled(right_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(left_key, help_key_style),
+        Span::styled(
+            " to snooze the due date a day or bring it forward, ",
+            help_text_style,
+        ),
+        Span::styled(move_card_left_key, help_key_style),
+        Span::styled(" ", help_text_style),
+        Span::styled(move_card_right_key, help_key_style),
+        Span::styled(
+            " to move the card to the previous or next board and ",
+            help_text_style,
+        ),
+        Span::styled(cancel_key, help_key_style),
+        Span::styled(" to go back", help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[2]);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_toast(rect: &mut Frame, app: &mut App) {
+    let all_toasts = app.widgets.toasts.clone();
+    let mut loading_toasts = all_toasts
+        .iter()
+        .filter(|x| x.toast_type == ToastType::Loading)
+        .collect::<Vec<&ToastWidget>>();
+    let app_toasts = app.widgets.toasts.clone();
+    let toasts = if !loading_toasts.is_empty() {
+        let sorted_loading_toasts = if loading_toasts.len() > MAX_TOASTS_TO_DISPLAY - 1 {
+            loading_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+            loading_toasts
+                .iter()
+                .copied()
+                .take(MAX_TOASTS_TO_DISPLAY - 1)
+                .rev()
+                .collect::<Vec<&ToastWidget>>()
+        } else {
+            loading_toasts
+        };
+        let mut toasts = sorted_loading_toasts;
+        let mut regular_toasts = all_toasts
+            .iter()
+            .filter(|x| x.toast_type != ToastType::Loading)
+            .collect::<Vec<&ToastWidget>>();
+        regular_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+        while toasts.len() < MAX_TOASTS_TO_DISPLAY {
+            if let Some(toast) = regular_toasts.pop() {
+                toasts.push(toast);
+            } else {
+                break;
+            }
+        }
+        if toasts.len() < MAX_TOASTS_TO_DISPLAY {
+            let mut loading_toasts = all_toasts
+                .iter()
+                .filter(|x| x.toast_type == ToastType::Loading)
+                .collect::<Vec<&ToastWidget>>();
+            loading_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+            while toasts.len() < MAX_TOASTS_TO_DISPLAY {
+                if let Some(toast) = loading_toasts.pop() {
+                    if !toasts.contains(&toast) {
+                        toasts.push(toast);
+                    }
+                } else {
+                    break;
+                }
+            }
+        }
+        toasts
+    } else {
+        app_toasts
+            .iter()
+            .rev()
+            .take(MAX_TOASTS_TO_DISPLAY)
+            .rev()
+            .collect::<Vec<&ToastWidget>>()
+    };
+
+    if toasts.is_empty() {
+        return;
+    }
+    let mut total_height_rendered = 1;
+    for toast in toasts.iter() {
+        let toast_style = app
+            .current_theme
+            .general_style
+            .fg(ratatui::style::Color::Rgb(
+                toast.toast_color.0,
+                toast.toast_color.1,
+                toast.toast_color.2,
+            ));
+        let mut toast_title = toast.title.to_owned();
+        toast_title = match toast.toast_type {
+            ToastType::Loading => {
+                let spinner_frames = &SPINNER_FRAMES;
+                let frame =
+                    (toast.start_time.elapsed().as_millis() / 100) % spinner_frames.len() as u128;
+                let frame = frame as usize;
+                format!("{} {}", spinner_frames[frame], toast_title)
+            }
+            _ => toast_title,
+        };
+        let x_offset = rect.size().width - (rect.size().width / SCREEN_TO_TOAST_WIDTH_RATIO);
+        let lines = textwrap::wrap(
+            &toast.message,
+            ((rect.size().width / SCREEN_TO_TOAST_WIDTH_RATIO) - 2) as usize,
+        )
+        .iter()
+        .map(|x| Line::from(x.to_string()))
+        .collect::<Vec<Line>>();
+        let toast_height = lines.len() as u16 + 2;
+        let toast_block = Block::default()
+            .title(toast_title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(toast_style);
+        let toast_paragraph = Paragraph::new(lines)
+            .block(toast_block)
+            .alignment(Alignment::Left)
+            .wrap(ratatui::widgets::Wrap { trim: true })
+            .style(toast_style);
+        if toast_height + total_height_rendered > rect.size().height {
+            debug!("Toast height is greater than the height of the screen");
+            break;
+        }
+        rect.render_widget(
+            Clear,
+            Rect::new(
+                x_offset,
+                total_height_rendered,
+                rect.size().width / SCREEN_TO_TOAST_WIDTH_RATIO,
+                toast_height,
+            ),
+        );
+        rect.render_widget(
+            toast_paragraph,
+            Rect::new(
+                x_offset,
+                total_height_rendered,
+                rect.size().width / SCREEN_TO_TOAST_WIDTH_RATIO,
+                toast_height,
+            ),
+        );
+        total_height_rendered += toast_height;
+        if total_height_rendered >= rect.size().height {
+            debug!("Toast height is greater than the height of the screen");
+            break;
+        }
+    }
+
+    let text_offset = 15;
+    let toast_count = app.widgets.toasts.len();
+    let toast_count_text = format!(" {} Message(s)", toast_count);
+    let toast_count_paragraph = Paragraph::new(toast_count_text)
+        .alignment(Alignment::Right)
+        .block(
+            Block::default()
+                .borders(Borders::LEFT)
+                .border_type(BorderType::Rounded),
+        )
+        .style(app.current_theme.general_style);
+    let message_area = Rect::new(rect.size().width - text_offset, 0, text_offset, 1);
+
+    render_blank_styled_canvas(rect, app, message_area, false);
+    rect.render_widget(toast_count_paragraph, message_area);
+}
+
+pub fn render_view_card(rect: &mut Frame, app: &mut App) {
+    let popup_area = centered_rect_with_percentage(90, 90, rect.size());
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    if app.state.current_board_id.is_none() || app.state.current_card_id.is_none() {
+        let no_board_or_card_selected = Paragraph::new("No board or card selected.")
+            .block(
+                Block::default()
+                    .title("Card Info")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .style(app.current_theme.error_text_style),
+            )
+            .alignment(Alignment::Center);
+        rect.render_widget(no_board_or_card_selected, popup_area);
+        return;
+    }
+
+    let board = app
+        .boards
+        .get_board_with_id(app.state.current_board_id.unwrap());
+    if board.is_none() {
+        let could_not_find_board = Paragraph::new("Could not find board to view card.")
+            .block(
+                Block::default()
+                    .title("Card Info")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .style(app.current_theme.error_text_style),
+            )
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        rect.render_widget(could_not_find_board, popup_area);
+        return;
+    }
+
+    let board = board.unwrap();
+    let card = board
+        .cards
+        .get_card_with_id(app.state.current_card_id.unwrap());
+    if card.is_none() {
+        let could_not_find_card = Paragraph::new("Could not find card to view.")
+            .block(
+                Block::default()
+                    .title("Card Info")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .style(app.current_theme.error_text_style),
+            )
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        rect.render_widget(could_not_find_card, popup_area);
+        return;
+    }
+
+    let card_being_edited = app.state.get_card_being_edited();
+    let card = if let Some(card_being_edited) = card_being_edited {
+        card_being_edited.1
+    } else {
+        card.unwrap().to_owned()
+    };
+
+    let board_name = board.name.clone();
+    let card_name = card.name.clone();
+
+    // Prepare Main Block Widget
+    let main_block_widget = {
+        Block::default()
+            .title(format!("{} >> Board({})", card_name, board_name))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
             .border_style(app.current_theme.general_style)
     };
 
@@ -3296,18 +6494,142 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
         } else if card.priority == CardPriority::Low {
             Span::styled(card_priority, app.current_theme.card_priority_low_style)
         } else {
-            Span::styled(card_priority, app.current_theme.general_style)
+            Span::styled(card_priority, app.current_theme.general_style)
+        };
+        let card_status_styled = if app.state.focus == Focus::CardStatus {
+            Span::styled(card_status, app.current_theme.list_select_style)
+        } else if card.card_status == CardStatus::Complete {
+            Span::styled(card_status, app.current_theme.card_status_completed_style)
+        } else if card.card_status == CardStatus::Active {
+            Span::styled(card_status, app.current_theme.card_status_active_style)
+        } else if card.card_status == CardStatus::Stale {
+            Span::styled(card_status, app.current_theme.card_status_stale_style)
+        } else {
+            Span::styled(card_status, app.current_theme.general_style)
+        };
+        let card_blocked_by = {
+            let blocker_names = card
+                .blocked_by
+                .iter()
+                .filter_map(|blocker_id| app.boards.get_card_with_id(*blocker_id))
+                .map(|blocker| {
+                    if blocker.card_status == CardStatus::Complete {
+                        format!("{} (Done)", blocker.name)
+                    } else {
+                        blocker.name.clone()
+                    }
+                })
+                .collect::<Vec<String>>();
+            if blocker_names.is_empty() {
+                format!("Blocked By: {}", FIELD_NOT_SET)
+            } else if app.boards.is_card_blocked(&card) {
+                format!("Blocked By: {} [Blocked]", blocker_names.join(", "))
+            } else {
+                format!("Blocked By: {}", blocker_names.join(", "))
+            }
+        };
+        let card_blocked_by_styled = if app.state.focus == Focus::CardBlockedBy {
+            Span::styled(card_blocked_by, app.current_theme.list_select_style)
+        } else if app.boards.is_card_blocked(&card) {
+            Span::styled(card_blocked_by, app.current_theme.card_due_overdue_style)
+        } else {
+            Span::styled(card_blocked_by, app.current_theme.general_style)
+        };
+        let card_estimate = if let Some(estimate) = card.estimate {
+            format!("Estimate: {}", estimate)
+        } else {
+            format!("Estimate: {}", FIELD_NOT_SET)
+        };
+        let card_estimate_styled = if app.state.focus == Focus::CardEstimate {
+            Span::styled(card_estimate, app.current_theme.list_select_style)
+        } else {
+            Span::styled(card_estimate, app.current_theme.general_style)
+        };
+        let card_custom_fields = if card.custom_fields.is_empty() {
+            format!("Custom Fields: {}", FIELD_NOT_SET)
+        } else {
+            format!(
+                "Custom Fields: {}",
+                card.custom_fields
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        };
+        let card_custom_fields_styled = if app.state.focus == Focus::CardCustomFields {
+            Span::styled(card_custom_fields, app.current_theme.list_select_style)
+        } else {
+            Span::styled(card_custom_fields, app.current_theme.general_style)
+        };
+        let card_recurrence = if let Some(recurrence) = &card.recurrence {
+            format!("Repeats: {}", recurrence)
+        } else {
+            format!("Repeats: {}", FIELD_NOT_SET)
+        };
+        let card_recurrence_styled = if app.state.focus == Focus::CardRecurrence {
+            Span::styled(card_recurrence, app.current_theme.list_select_style)
+        } else {
+            Span::styled(card_recurrence, app.current_theme.general_style)
+        };
+        let card_activity = if let Some(last_activity) = card.activity_log.last() {
+            format!(
+                "Activity: {} entries, last: {}",
+                card.activity_log.len(),
+                last_activity.kind
+            )
+        } else {
+            "Activity: No activity recorded".to_string()
+        };
+        let card_activity_styled = if app.state.focus == Focus::CardActivityLog {
+            Span::styled(card_activity, app.current_theme.list_select_style)
+        } else {
+            Span::styled(card_activity, app.current_theme.general_style)
+        };
+        let missing_attachment_count = card
+            .attachments
+            .iter()
+            .filter(|attachment| attachment.is_missing_file())
+            .count();
+        let detected_url_count = detect_attachment_urls(&card.description, &card.attachments).len();
+        let mut card_attachments = if card.attachments.is_empty() {
+            format!("Attachments: {}", FIELD_NOT_SET)
+        } else {
+            format!(
+                "Attachments: {}",
+                card.attachments
+                    .iter()
+                    .map(|attachment| attachment.title.clone())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
         };
-        let card_status_styled = if app.state.focus == Focus::CardStatus {
-            Span::styled(card_status, app.current_theme.list_select_style)
-        } else if card.card_status == CardStatus::Complete {
-            Span::styled(card_status, app.current_theme.card_status_completed_style)
-        } else if card.card_status == CardStatus::Active {
-            Span::styled(card_status, app.current_theme.card_status_active_style)
-        } else if card.card_status == CardStatus::Stale {
-            Span::styled(card_status, app.current_theme.card_status_stale_style)
+        if missing_attachment_count > 0 {
+            card_attachments.push_str(&format!(" [{} missing]", missing_attachment_count));
+        }
+        if detected_url_count > 0 {
+            card_attachments.push_str(&format!(
+                " ({} links found in description)",
+                detected_url_count
+            ));
+        }
+        let card_attachments_styled = if app.state.focus == Focus::CardAttachments {
+            Span::styled(card_attachments, app.current_theme.list_select_style)
+        } else if missing_attachment_count > 0 {
+            Span::styled(card_attachments, app.current_theme.card_due_warning_style)
         } else {
-            Span::styled(card_status, app.current_theme.general_style)
+            Span::styled(card_attachments, app.current_theme.general_style)
+        };
+        let card_time_tracked = if card.running_time_entry().is_some() {
+            format!(
+                "Time Tracked: {} (Timer Running)",
+                format_duration(card.total_time_tracked())
+            )
+        } else {
+            format!(
+                "Time Tracked: {}",
+                format_duration(card.total_time_tracked())
+            )
         };
         let card_extra_info_items = vec![
             ListItem::new(vec![Line::from(card_date_created)]),
@@ -3316,6 +6638,13 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
             ListItem::new(vec![Line::from(card_date_completed)]),
             ListItem::new(vec![Line::from(card_priority_styled)]),
             ListItem::new(vec![Line::from(card_status_styled)]),
+            ListItem::new(vec![Line::from(card_blocked_by_styled)]),
+            ListItem::new(vec![Line::from(card_estimate_styled)]),
+            ListItem::new(vec![Line::from(card_custom_fields_styled)]),
+            ListItem::new(vec![Line::from(card_recurrence_styled)]),
+            ListItem::new(vec![Line::from(card_time_tracked)]),
+            ListItem::new(vec![Line::from(card_activity_styled)]),
+            ListItem::new(vec![Line::from(card_attachments_styled)]),
         ];
         let card_extra_info_items_len = card_extra_info_items.len();
         let card_extra_info = List::new(card_extra_info_items).block(
@@ -3342,7 +6671,7 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
                 for (index, tag) in card.tags.iter().enumerate() {
                     tags.push(Span::styled(
                         format!("{}) {} ", index + 1, tag),
-                        app.current_theme.general_style,
+                        get_tag_style(app, tag),
                     ));
                 }
             } else {
@@ -3361,7 +6690,7 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
                     } else {
                         tags.push(Span::styled(
                             format!("{}) {} ", index + 1, tag),
-                            app.current_theme.general_style,
+                            get_tag_style(app, tag),
                         ));
                     }
                 }
@@ -3372,7 +6701,7 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
             for (index, tag) in card.tags.iter().enumerate() {
                 tags.push(Span::styled(
                     format!("{}) {} ", index + 1, tag),
-                    app.current_theme.general_style,
+                    get_tag_style(app, tag),
                 ));
             }
             tags
@@ -3408,78 +6737,29 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
 
     // Process Card Comments
     let card_comment_lines = {
-        let card_comments = if app.state.focus == Focus::CardComments {
-            let mut comments = vec![];
-            if app
-                .state
-                .app_list_states
-                .card_view_comment_list
-                .selected()
-                .is_none()
-            {
-                for (index, comment) in card.comments.iter().enumerate() {
-                    comments.push(Span::styled(
-                        format!("{}) {} ", index + 1, comment),
-                        app.current_theme.general_style,
-                    ));
-                }
-            } else {
-                let selected_comment = app
-                    .state
-                    .app_list_states
-                    .card_view_comment_list
-                    .selected()
-                    .unwrap();
-                for (index, comment) in card.comments.iter().enumerate() {
-                    if index == selected_comment {
-                        comments.push(Span::styled(
-                            format!("{}) {} ", index + 1, comment),
-                            app.current_theme.keyboard_focus_style,
-                        ));
-                    } else {
-                        comments.push(Span::styled(
-                            format!("{}) {} ", index + 1, comment),
-                            app.current_theme.general_style,
-                        ));
-                    }
-                }
-            }
-            comments
+        let selected_comment = if app.state.focus == Focus::CardComments {
+            app.state.app_list_states.card_view_comment_list.selected()
         } else {
-            let mut comments = vec![];
-            for (index, comment) in card.comments.iter().enumerate() {
-                comments.push(Span::styled(
-                    format!("{}) {} ", index + 1, comment),
-                    app.current_theme.general_style,
-                ));
-            }
-            comments
+            None
         };
-        let mut card_comment_lines = vec![];
-        let mut collector = String::new();
-        let mut collector_start = 0;
-        let mut collector_end = 0;
-        for (i, comment) in card.comments.iter().enumerate() {
-            let comment_string = format!("{}) {} ", i + 1, comment);
-            if (collector.len() + comment_string.len()) < (popup_area.width - 2) as usize {
-                collector.push_str(&comment_string);
-                collector_end = i + 1;
-            } else {
-                card_comment_lines.push(Line::from(
-                    card_comments[collector_start..collector_end].to_vec(),
-                ));
-                collector = String::new();
-                collector.push_str(&comment_string);
-                collector_start = i;
-                collector_end = i + 1;
-            }
-        }
-        if !collector.is_empty() {
-            card_comment_lines.push(Line::from(
-                card_comments[collector_start..collector_end].to_vec(),
-            ));
-        }
-        card_comment_lines
+        card.comments
+            .iter()
+            .enumerate()
+            .map(|(index, comment)| {
+                let comment_style = if selected_comment == Some(index) {
+                    app.current_theme.keyboard_focus_style
+                } else {
+                    app.current_theme.general_style
+                };
+                Line::from(vec![
+                    Span::styled(
+                        comment_prefix(index, comment),
+                        app.current_theme.inactive_text_style,
+                    ),
+                    Span::styled(comment.body.clone(), comment_style),
+                ])
+            })
+            .collect::<Vec<Line>>()
     };
 
     // Determine chunk sizes
@@ -3490,7 +6770,7 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
         let max_height: u16 = popup_area.height - border_height;
         let submit_button_height: u16 = 3;
         let card_name_box_height: u16 = 3;
-        let card_extra_info_height: u16 = 8;
+        let card_extra_info_height: u16 = 15;
         let mut available_height: u16 = if app.state.card_being_edited.is_some() {
             max_height - card_name_box_height - card_extra_info_height - submit_button_height
         } else {
@@ -3641,6 +6921,66 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
                     app.state.app_list_states.card_view_tag_list.select(None);
                     app.state.current_cursor_position = None;
                 }
+                6 => {
+                    app.state.set_focus(Focus::CardBlockedBy);
+                    app.state.mouse_focus = Some(Focus::CardBlockedBy);
+                    app.state
+                        .app_list_states
+                        .card_view_comment_list
+                        .select(None);
+                    app.state.app_list_states.card_view_tag_list.select(None);
+                    app.state.current_cursor_position = None;
+                }
+                7 => {
+                    app.state.set_focus(Focus::CardEstimate);
+                    app.state.mouse_focus = Some(Focus::CardEstimate);
+                    app.state
+                        .app_list_states
+                        .card_view_comment_list
+                        .select(None);
+                    app.state.app_list_states.card_view_tag_list.select(None);
+                    app.state.current_cursor_position = None;
+                }
+                8 => {
+                    app.state.set_focus(Focus::CardCustomFields);
+                    app.state.mouse_focus = Some(Focus::CardCustomFields);
+                    app.state
+                        .app_list_states
+                        .card_view_comment_list
+                        .select(None);
+                    app.state.app_list_states.card_view_tag_list.select(None);
+                    app.state.current_cursor_position = None;
+                }
+                9 => {
+                    app.state.set_focus(Focus::CardRecurrence);
+                    app.state.mouse_focus = Some(Focus::CardRecurrence);
+                    app.state
+                        .app_list_states
+                        .card_view_comment_list
+                        .select(None);
+                    app.state.app_list_states.card_view_tag_list.select(None);
+                    app.state.current_cursor_position = None;
+                }
+                11 => {
+                    app.state.set_focus(Focus::CardActivityLog);
+                    app.state.mouse_focus = Some(Focus::CardActivityLog);
+                    app.state
+                        .app_list_states
+                        .card_view_comment_list
+                        .select(None);
+                    app.state.app_list_states.card_view_tag_list.select(None);
+                    app.state.current_cursor_position = None;
+                }
+                12 => {
+                    app.state.set_focus(Focus::CardAttachments);
+                    app.state.mouse_focus = Some(Focus::CardAttachments);
+                    app.state
+                        .app_list_states
+                        .card_view_comment_list
+                        .select(None);
+                    app.state.app_list_states.card_view_tag_list.select(None);
+                    app.state.current_cursor_position = None;
+                }
                 _ => {
                     app.state.set_focus(Focus::NoFocus);
                     app.state.mouse_focus = None;
@@ -3800,37 +7140,15 @@ pub fn render_view_card(rect: &mut Frame, app: &mut App) {
                         .card_view_comment_list
                         .selected()
                         .unwrap();
-                    let mut counter = 0;
-                    let mut y_index = 0;
-                    let mut length_before_selected_comment = 0;
-                    let mut prv_spans_length = 0;
-                    let comment_offset = 3;
-                    for line in card_comment_lines.iter() {
-                        for _ in line.spans.iter() {
-                            if counter == selected_index {
-                                break;
-                            } else {
-                                let element = line.spans.get(counter - prv_spans_length);
-                                if let Some(element) = element {
-                                    length_before_selected_comment += element.content.len();
-                                }
-                                counter += 1;
-                            }
-                        }
-                        if counter == selected_index {
-                            break;
-                        }
-                        y_index += 1;
-                        prv_spans_length += line.spans.iter().len();
-                        length_before_selected_comment = 0;
-                    }
-                    let digits_in_counter = (counter + 1).to_string().len();
+                    let prefix_length = card
+                        .comments
+                        .get(selected_index)
+                        .map_or(0, |comment| comment_prefix(selected_index, comment).len());
                     let x_pos = card_chunks[4].left()
-                        + length_before_selected_comment as u16
-                        + app.state.current_cursor_position.unwrap_or(0) as u16
-                        + comment_offset
-                        + digits_in_counter as u16;
-                    let y_pos = card_chunks[4].top() + y_index as u16 + 1;
+                        + 1
+                        + prefix_length as u16
+                        + app.state.current_cursor_position.unwrap_or(0) as u16;
+                    let y_pos = card_chunks[4].top() + selected_index as u16 + 1;
                     rect.set_cursor(x_pos, y_pos);
                 }
             }
@@ -4043,19 +7361,21 @@ pub fn render_command_palette(rect: &mut Frame, app: &mut App) {
             .card_search_results
             .as_ref()
             .unwrap();
-        let mut list_items = vec![];
-        for (item, _) in raw_search_results {
-            let item = if item.len() > (horizontal_chunks[1].width - 2) as usize {
-                format!("{}...", &item[0..(horizontal_chunks[1].width - 5) as usize])
-            } else {
-                item.to_string()
-            };
-            list_items.push(ListItem::new(Line::from(Span::styled(
-                item.to_string(),
-                card_search_text_style,
-            ))));
-        }
-        list_items
+        let search_highlight_style = app
+            .current_theme
+            .keyboard_focus_style
+            .add_modifier(Modifier::BOLD);
+        raw_search_results
+            .iter()
+            .map(|search_result| {
+                ListItem::new(search_result_to_line(
+                    search_result,
+                    (horizontal_chunks[1].width - 2) as usize,
+                    card_search_text_style,
+                    search_highlight_style,
+                ))
+            })
+            .collect::<Vec<ListItem>>()
     } else {
         vec![]
     };
@@ -4242,9 +7562,14 @@ pub fn render_command_palette(rect: &mut Frame, app: &mut App) {
     let y_cursor_position = search_box_chunk.y + y_offset + 1;
     rect.set_cursor(x_cursor_position, y_cursor_position);
 
+    let search_box_title = if app.state.card_dependency_picker_target.is_some() {
+        "Select a card to toggle as a blocker"
+    } else {
+        "Command Palette"
+    };
     let search_box = Paragraph::new(search_box_text).block(
         Block::default()
-            .title("Command Palette")
+            .title(search_box_title)
             .borders(Borders::ALL)
             .style(app.current_theme.general_style)
             .border_type(BorderType::Rounded),
@@ -4979,58 +8304,242 @@ pub fn render_filter_by_tag_popup(rect: &mut Frame, app: &mut App) {
                 "Confirm filter"
             }
         } else {
-            "Confirm filter"
+            "Confirm filter"
+        };
+
+        let submit_button = Paragraph::new(submit_btn_text)
+            .block(
+                Block::default()
+                    .title("Submit")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .style(app.current_theme.general_style)
+                    .border_style(submit_style),
+            )
+            .alignment(Alignment::Center);
+
+        let current_index = app
+            .state
+            .app_list_states
+            .filter_by_tag_list
+            .selected()
+            .unwrap_or(0);
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(SCROLLBAR_BEGIN_SYMBOL)
+            .style(scrollbar_style)
+            .end_symbol(SCROLLBAR_END_SYMBOL)
+            .track_symbol(SCROLLBAR_TRACK_SYMBOL)
+            .track_style(app.current_theme.inactive_text_style);
+        let mut scrollbar_state = ScrollbarState::new(all_tags.len()).position(current_index);
+        let scrollbar_area = chunks[0].inner(&Margin {
+            vertical: 1,
+            horizontal: 0,
+        });
+
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0]) {
+            app.state.mouse_focus = Some(Focus::FilterByTagPopup);
+            app.state.set_focus(Focus::FilterByTagPopup);
+        }
+        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[2]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.state.set_focus(Focus::SubmitButton);
+        }
+
+        render_blank_styled_canvas(rect, app, popup_area, false);
+        rect.render_stateful_widget(
+            tags,
+            chunks[0],
+            &mut app.state.app_list_states.filter_by_tag_list,
+        );
+        rect.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
+        rect.render_widget(help, chunks[1]);
+        rect.render_widget(submit_button, chunks[2]);
+    }
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_time_report_popup(rect: &mut Frame, app: &mut App) {
+    let popup_area = centered_rect_with_percentage(80, 80, rect.size());
+    let report = TimeReport::new(&app.boards, app.state.time_report_range);
+    let submit_style = if app.state.mouse_focus == Some(Focus::SubmitButton) {
+        app.current_theme.mouse_focus_style
+    } else {
+        app.current_theme.general_style
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(4),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(popup_area);
+    let report_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+            ]
+            .as_ref(),
+        )
+        .split(chunks[1]);
+
+    let mut ranges = TimeReportRange::all();
+    if let TimeReportRange::Custom(..) = app.state.time_report_range {
+        ranges.push(app.state.time_report_range);
+    }
+    let range_spans = Line::from(
+        ranges
+            .iter()
+            .map(|range| {
+                if *range == app.state.time_report_range {
+                    Span::styled(
+                        format!(" [{}] ", range),
+                        app.current_theme.list_select_style,
+                    )
+                } else {
+                    Span::styled(format!(" {} ", range), app.current_theme.general_style)
+                }
+            })
+            .collect::<Vec<Span>>(),
+    );
+    let range_paragraph = Paragraph::new(range_spans)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Time Report - Total {}",
+                    format_duration(report.total)
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(app.current_theme.general_style),
+        );
+
+    let make_list = |title: &str, items: Vec<String>| {
+        let items = if items.is_empty() {
+            vec![ListItem::new(Line::from("No time tracked"))]
+        } else {
+            items
+                .into_iter()
+                .map(|item| ListItem::new(Line::from(item)))
+                .collect()
         };
+        List::new(items).block(
+            Block::default()
+                .title(title.to_string())
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(app.current_theme.general_style),
+        )
+    };
+    let by_board = make_list(
+        "By Board",
+        report
+            .by_board
+            .iter()
+            .map(|(board, seconds)| format!("{} - {}", format_duration(*seconds), board))
+            .collect(),
+    );
+    let by_card = make_list(
+        "By Card",
+        report
+            .by_card
+            .iter()
+            .map(|(board, card, seconds)| {
+                format!("{} - {} ({})", format_duration(*seconds), card, board)
+            })
+            .collect(),
+    );
+    let by_tag = make_list(
+        "By Tag",
+        report
+            .by_tag
+            .iter()
+            .map(|(tag, seconds)| format!("{} - {}", format_duration(*seconds), tag))
+            .collect(),
+    );
 
-        let submit_button = Paragraph::new(submit_btn_text)
-            .block(
-                Block::default()
-                    .title("Submit")
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .style(app.current_theme.general_style)
-                    .border_style(submit_style),
-            )
-            .alignment(Alignment::Center);
-
-        let current_index = app
-            .state
-            .app_list_states
-            .filter_by_tag_list
-            .selected()
-            .unwrap_or(0);
-        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-            .begin_symbol(SCROLLBAR_BEGIN_SYMBOL)
-            .style(scrollbar_style)
-            .end_symbol(SCROLLBAR_END_SYMBOL)
-            .track_symbol(SCROLLBAR_TRACK_SYMBOL)
-            .track_style(app.current_theme.inactive_text_style);
-        let mut scrollbar_state = ScrollbarState::new(all_tags.len()).position(current_index);
-        let scrollbar_area = chunks[0].inner(&Margin {
-            vertical: 1,
-            horizontal: 0,
-        });
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let user_input_key = app
+        .get_first_keybinding(KeyBindingEnum::TakeUserInput)
+        .unwrap_or("".to_string());
+    let help_spans = Line::from(vec![
+        Span::styled("Use ", app.current_theme.help_text_style),
+        Span::styled(up_key, app.current_theme.help_key_style),
+        Span::styled(" and ", app.current_theme.help_text_style),
+        Span::styled(down_key, app.current_theme.help_key_style),
+        Span::styled(
+            " or scroll with the mouse to change the date range. Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(user_input_key, app.current_theme.help_key_style),
+        Span::styled(
+            " to enter a start and end date. Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(accept_key, app.current_theme.help_key_style),
+        Span::styled(
+            " to export the report to CSV. Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(cancel_key, app.current_theme.help_key_style),
+        Span::styled(" to close", app.current_theme.help_text_style),
+    ]);
+    let help = Paragraph::new(help_spans)
+        .block(
+            Block::default()
+                .title("Help")
+                .borders(Borders::ALL)
+                .style(app.current_theme.general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
 
-        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0]) {
-            app.state.mouse_focus = Some(Focus::FilterByTagPopup);
-            app.state.set_focus(Focus::FilterByTagPopup);
-        }
-        if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[2]) {
-            app.state.mouse_focus = Some(Focus::SubmitButton);
-            app.state.set_focus(Focus::SubmitButton);
-        }
+    let export_button = Paragraph::new("Export to CSV")
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(app.current_theme.general_style)
+                .border_style(submit_style),
+        )
+        .alignment(Alignment::Center);
 
-        render_blank_styled_canvas(rect, app, popup_area, false);
-        rect.render_stateful_widget(
-            tags,
-            chunks[0],
-            &mut app.state.app_list_states.filter_by_tag_list,
-        );
-        rect.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
-        rect.render_widget(help, chunks[1]);
-        rect.render_widget(submit_button, chunks[2]);
+    if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[3]) {
+        app.state.mouse_focus = Some(Focus::SubmitButton);
     }
 
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_widget(range_paragraph, chunks[0]);
+    rect.render_widget(by_board, report_chunks[0]);
+    rect.render_widget(by_card, report_chunks[1]);
+    rect.render_widget(by_tag, report_chunks[2]);
+    rect.render_widget(help, chunks[2]);
+    rect.render_widget(export_button, chunks[3]);
+
     if app.config.enable_mouse_support {
         render_close_button(rect, app);
     }
@@ -5577,237 +9086,861 @@ pub fn render_edit_specific_style_popup(rect: &mut Frame, app: &mut App) {
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title("Background")
-                .border_style(bg_list_border_style),
+                .title("Background")
+                .border_style(bg_list_border_style),
+        )
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+    let modifier_list_items: Vec<ListItem> = TextModifierOptions::to_iter()
+        .map(|modifier| {
+            let modifier_style = Style {
+                add_modifier: modifier.to_modifier(),
+                ..Style::default()
+            };
+            ListItem::new(vec![Line::from(vec![
+                Span::styled("Sample Text", modifier_style),
+                Span::styled(format!(" - {}", modifier), app.current_theme.general_style),
+            ])])
+        })
+        .collect();
+    let modifier_list = List::new(modifier_list_items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .title("Modifier")
+                .border_style(modifiers_list_border_style),
+        )
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+    let theme_style_being_edited_index = app.state.app_table_states.theme_editor.selected();
+    let theme_style_being_edited = if let Some(index) = theme_style_being_edited_index {
+        let theme_style_being_edited = app.state.theme_being_edited.to_vec_str();
+        if index < theme_style_being_edited.len() {
+            theme_style_being_edited[index]
+        } else {
+            debug!("Index is out of bounds for theme_style_being_edited");
+            "None"
+        }
+    } else {
+        "None"
+    };
+    let border_block = Block::default()
+        .title(format!("Editing Style: {}", theme_style_being_edited))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.current_theme.general_style);
+
+    let submit_button = Paragraph::new("Confirm Changes")
+        .style(submit_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(submit_button_style),
+        )
+        .alignment(Alignment::Center);
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let next_focus_key = app
+        .get_first_keybinding(KeyBindingEnum::NextFocus)
+        .unwrap_or("".to_string());
+    let prv_focus_key = app
+        .get_first_keybinding(KeyBindingEnum::PrvFocus)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+
+    let help_spans = vec![
+        Span::styled("Use ", app.current_theme.help_text_style),
+        Span::styled(up_key, app.current_theme.help_key_style),
+        Span::styled(" and ", app.current_theme.help_text_style),
+        Span::styled(down_key, app.current_theme.help_key_style),
+        Span::styled(
+            " or scroll with the mouse",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(
+            " to select a Color/Modifier, Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(accept_key, app.current_theme.help_key_style),
+        Span::styled(" or ", app.current_theme.help_text_style),
+        Span::styled("<Mouse Left Click>", app.current_theme.help_key_style),
+        Span::styled(
+            " to edit (for custom RBG), Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(next_focus_key, app.current_theme.help_key_style),
+        Span::styled(" or ", app.current_theme.help_text_style),
+        Span::styled(prv_focus_key, app.current_theme.help_key_style),
+        Span::styled(" to change focus.", app.current_theme.help_text_style),
+    ];
+
+    let help_text = Paragraph::new(Line::from(help_spans))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(app.current_theme.help_text_style),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        fg_list,
+        chunks[0],
+        &mut app.state.app_list_states.edit_specific_style.0,
+    );
+    rect.render_stateful_widget(
+        bg_list,
+        chunks[1],
+        &mut app.state.app_list_states.edit_specific_style.1,
+    );
+    rect.render_stateful_widget(
+        modifier_list,
+        chunks[2],
+        &mut app.state.app_list_states.edit_specific_style.2,
+    );
+    rect.render_widget(help_text, main_chunks[1]);
+    rect.render_widget(submit_button, main_chunks[2]);
+    rect.render_widget(border_block, popup_area);
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app)
+    }
+}
+
+pub fn render_save_theme_prompt(rect: &mut Frame, app: &mut App) {
+    let popup_area = centered_rect_with_length(40, 10, rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+        .margin(2)
+        .split(popup_area);
+
+    let save_theme_button_style =
+        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[0], false, false);
+    let dont_save_theme_button_style =
+        get_mouse_focusable_field_style(app, Focus::ExtraFocus, &chunks[1], false, false);
+    let save_theme_button = Paragraph::new("Save Theme to File")
+        .style(save_theme_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(save_theme_button_style),
         )
-        .highlight_style(app.current_theme.list_select_style)
-        .highlight_symbol(LIST_SELECTED_SYMBOL);
-    let modifier_list_items: Vec<ListItem> = TextModifierOptions::to_iter()
-        .map(|modifier| {
-            let modifier_style = Style {
-                add_modifier: modifier.to_modifier(),
-                ..Style::default()
-            };
-            ListItem::new(vec![Line::from(vec![
-                Span::styled("Sample Text", modifier_style),
-                Span::styled(format!(" - {}", modifier), app.current_theme.general_style),
-            ])])
-        })
-        .collect();
-    let modifier_list = List::new(modifier_list_items)
+        .alignment(Alignment::Center);
+    let dont_save_theme_button = Paragraph::new("Don't Save Theme to File")
+        .style(dont_save_theme_button_style)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .title("Modifier")
-                .border_style(modifiers_list_border_style),
+                .border_style(dont_save_theme_button_style),
         )
-        .highlight_style(app.current_theme.list_select_style)
-        .highlight_symbol(LIST_SELECTED_SYMBOL);
-    let theme_style_being_edited_index = app.state.app_table_states.theme_editor.selected();
-    let theme_style_being_edited = if let Some(index) = theme_style_being_edited_index {
-        let theme_style_being_edited = app.state.theme_being_edited.to_vec_str();
-        if index < theme_style_being_edited.len() {
-            theme_style_being_edited[index]
-        } else {
-            debug!("Index is out of bounds for theme_style_being_edited");
-            "None"
-        }
-    } else {
-        "None"
-    };
+        .alignment(Alignment::Center);
     let border_block = Block::default()
-        .title(format!("Editing Style: {}", theme_style_being_edited))
+        .title("Save Theme?")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(app.current_theme.general_style);
 
-    let submit_button = Paragraph::new("Confirm Changes")
-        .style(submit_button_style)
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(save_theme_button, chunks[0]);
+    rect.render_widget(dont_save_theme_button, chunks[1]);
+    rect.render_widget(border_block, popup_area);
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app)
+    }
+}
+
+pub fn render_confirm_discard_card_changes(rect: &mut Frame, app: &mut App) {
+    let popup_area = centered_rect_with_length(30, 7, rect.size());
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
+        .margin(2)
+        .split(popup_area);
+
+    let save_card_button_style =
+        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[0], false, false);
+    let dont_save_card_button_style =
+        get_mouse_focusable_field_style(app, Focus::ExtraFocus, &chunks[1], false, false);
+    let save_theme_button = Paragraph::new("Yes")
+        .style(save_card_button_style)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(submit_button_style),
+                .border_style(save_card_button_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center);
+    let dont_save_theme_button = Paragraph::new("No")
+        .style(dont_save_card_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(dont_save_card_button_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title("Save Changes to Card?")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.current_theme.general_style);
+
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(save_theme_button, chunks[0]);
+    rect.render_widget(dont_save_theme_button, chunks[1]);
+    rect.render_widget(border_block, popup_area);
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app)
+    }
+}
+
+pub fn render_custom_rgb_color_prompt(rect: &mut Frame, app: &mut App) {
+    let popup_area = centered_rect_with_length(60, 18, rect.size());
+    let prompt_text = "Enter a custom RGB color in the format: r,g,b (0-254)";
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(1),
+                Constraint::Fill(1),
+                Constraint::Length(3),
+                Constraint::Length(5),
+            ]
+            .as_ref(),
+        )
+        .margin(1)
+        .split(popup_area);
+    let border_block = Block::default()
+        .title("Custom RGB Color")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.current_theme.general_style);
+
+    let text_input_style =
+        get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], false, true);
+    let submit_button_style =
+        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[2], false, false);
+    let prompt_text = Paragraph::new(prompt_text)
+        .style(app.current_theme.general_style)
+        .block(Block::default())
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+    let text_input = Paragraph::new(app.state.current_user_input.clone())
+        .style(app.current_theme.general_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(text_input_style)
+                .border_type(BorderType::Rounded),
+        );
+
+    let input_mode_key = app
+        .get_first_keybinding(KeyBindingEnum::TakeUserInput)
+        .unwrap_or("".to_string());
+    let next_focus_key = app
+        .get_first_keybinding(KeyBindingEnum::NextFocus)
+        .unwrap_or("".to_string());
+    let prv_focus_key = app
+        .get_first_keybinding(KeyBindingEnum::PrvFocus)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let stop_editing_key = app
+        .get_first_keybinding(KeyBindingEnum::StopUserInput)
+        .unwrap_or("".to_string());
+
+    let help_spans = vec![
+        Span::styled("Press ", app.current_theme.help_text_style),
+        Span::styled(input_mode_key, app.current_theme.help_key_style),
+        Span::styled(
+            " to enter input mode. Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(stop_editing_key, app.current_theme.help_key_style),
+        Span::styled(" to stop editing. Use ", app.current_theme.help_text_style),
+        Span::styled(next_focus_key, app.current_theme.help_key_style),
+        Span::styled(" or ", app.current_theme.help_text_style),
+        Span::styled(prv_focus_key, app.current_theme.help_key_style),
+        Span::styled(
+            " to change focus. Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(accept_key, app.current_theme.help_key_style),
+        Span::styled(" to submit.", app.current_theme.help_text_style),
+    ];
+    let help_text = Paragraph::new(Line::from(help_spans))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.current_theme.general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    let submit_button = Paragraph::new("Submit")
+        .style(app.current_theme.general_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(submit_button_style)
+                .border_type(BorderType::Rounded),
         )
         .alignment(Alignment::Center);
 
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(prompt_text, chunks[0]);
+    rect.render_widget(text_input, chunks[1]);
+    rect.render_widget(submit_button, chunks[2]);
+    rect.render_widget(help_text, chunks[3]);
+    rect.render_widget(border_block, popup_area);
+
+    if app.state.app_status == AppStatus::UserInput {
+        let current_cursor_position = if app.state.current_cursor_position.is_some() {
+            app.state.current_cursor_position.unwrap() as u16
+        } else {
+            app.state.current_user_input.len() as u16
+        };
+        let x_offset = current_cursor_position % (chunks[1].width - 2);
+        let y_offset = current_cursor_position / (chunks[1].width - 2);
+        let x_cursor_position = chunks[1].x + x_offset + 1;
+        let y_cursor_position = chunks[1].y + y_offset + 1;
+        rect.set_cursor(x_cursor_position, y_cursor_position);
+    }
+}
+
+pub fn render_custom_field_definition_prompt(rect: &mut Frame, app: &mut App) {
+    let existing_fields = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+        .map(|board| {
+            board
+                .custom_fields
+                .iter()
+                .map(|custom_field| format!("{} ({})", custom_field.name, custom_field.field_type))
+                .collect::<Vec<String>>()
+        })
+        .unwrap_or_default();
+    let prompt_text = if existing_fields.is_empty() {
+        "Enter a custom field as name:type where type is text, number, date, url or select:option1,option2".to_string()
+    } else {
+        format!(
+            "Enter a custom field as name:type where type is text, number, date, url or select:option1,option2. Enter -name to remove a field. Current fields: {}",
+            existing_fields.join(", ")
+        )
+    };
+    render_text_input_prompt(rect, app, "Add Custom Field", prompt_text);
+}
+
+/// "1) author (date, edited): " shown before each comment in the card view
+fn comment_prefix(index: usize, comment: &CardComment) -> String {
+    let author = if comment.author.is_empty() {
+        "Unknown"
+    } else {
+        &comment.author
+    };
+    let date_created = parse_timestamp(&comment.date_created)
+        .map(|date_created| {
+            date_created
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or(comment.date_created.clone());
+    if comment.date_edited.is_some() {
+        format!("{}) {} ({}, edited): ", index + 1, author, date_created)
+    } else {
+        format!("{}) {} ({}): ", index + 1, author, date_created)
+    }
+}
+
+pub fn render_card_activity_log_popup(rect: &mut Frame, app: &mut App) {
+    let popup_area = centered_rect_with_percentage(80, 80, rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Fill(1), Constraint::Length(3)].as_ref())
+        .split(popup_area);
+    let card = app
+        .state
+        .current_card_id
+        .and_then(|card_id| app.boards.get_card_with_id(card_id))
+        .cloned();
+    let (title, activity_log) = if let Some(card) = card {
+        (
+            format!("Activity Timeline - {}", card.name),
+            card.activity_log,
+        )
+    } else {
+        ("Activity Timeline".to_string(), vec![])
+    };
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    let timeline_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.current_theme.general_style);
+    if activity_log.is_empty() {
+        let no_activity = Paragraph::new("No activity has been recorded for this card yet")
+            .alignment(Alignment::Center)
+            .block(timeline_block)
+            .style(app.current_theme.inactive_text_style);
+        rect.render_widget(no_activity, chunks[0]);
+    } else {
+        // newest first, the list state indexes into this order
+        let items: Vec<ListItem> = activity_log
+            .iter()
+            .rev()
+            .map(|activity| {
+                let timestamp = parse_timestamp(&activity.timestamp)
+                    .map(|timestamp| {
+                        timestamp
+                            .with_timezone(&Local)
+                            .format("%Y-%m-%d %H:%M")
+                            .to_string()
+                    })
+                    .unwrap_or(activity.timestamp.clone());
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{} ", timestamp),
+                        app.current_theme.inactive_text_style,
+                    ),
+                    Span::styled(
+                        format!("{}: ", activity.author),
+                        app.current_theme.help_key_style,
+                    ),
+                    Span::styled(activity.kind.to_string(), app.current_theme.general_style),
+                ]))
+            })
+            .collect();
+        let timeline = List::new(items)
+            .block(timeline_block)
+            .highlight_style(app.current_theme.list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL);
+        rect.render_stateful_widget(
+            timeline,
+            chunks[0],
+            &mut app.state.app_list_states.card_activity_list,
+        );
+    }
+
     let up_key = app
         .get_first_keybinding(KeyBindingEnum::Up)
         .unwrap_or("".to_string());
     let down_key = app
         .get_first_keybinding(KeyBindingEnum::Down)
         .unwrap_or("".to_string());
-    let next_focus_key = app
-        .get_first_keybinding(KeyBindingEnum::NextFocus)
-        .unwrap_or("".to_string());
-    let prv_focus_key = app
-        .get_first_keybinding(KeyBindingEnum::PrvFocus)
-        .unwrap_or("".to_string());
-    let accept_key = app
-        .get_first_keybinding(KeyBindingEnum::Accept)
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
         .unwrap_or("".to_string());
-
-    let help_spans = vec![
+    let help_spans = Line::from(vec![
         Span::styled("Use ", app.current_theme.help_text_style),
         Span::styled(up_key, app.current_theme.help_key_style),
-        Span::styled(" and ", app.current_theme.help_text_style),
-        Span::styled(down_key, app.current_theme.help_key_style),
-        Span::styled(
-            " or scroll with the mouse",
-            app.current_theme.help_text_style,
-        ),
-        Span::styled(
-            " to select a Color/Modifier, Press ",
-            app.current_theme.help_text_style,
-        ),
-        Span::styled(accept_key, app.current_theme.help_key_style),
         Span::styled(" or ", app.current_theme.help_text_style),
-        Span::styled("<Mouse Left Click>", app.current_theme.help_key_style),
+        Span::styled(down_key, app.current_theme.help_key_style),
         Span::styled(
-            " to edit (for custom RBG), Press ",
+            " to scroll the timeline. Press ",
             app.current_theme.help_text_style,
         ),
-        Span::styled(next_focus_key, app.current_theme.help_key_style),
-        Span::styled(" or ", app.current_theme.help_text_style),
-        Span::styled(prv_focus_key, app.current_theme.help_key_style),
-        Span::styled(" to change focus.", app.current_theme.help_text_style),
-    ];
-
-    let help_text = Paragraph::new(Line::from(help_spans))
+        Span::styled(cancel_key, app.current_theme.help_key_style),
+        Span::styled(" to go back to the card", app.current_theme.help_text_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_spans)
+        .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(app.current_theme.help_text_style),
+                .border_style(app.current_theme.general_style),
         )
-        .alignment(Alignment::Center)
         .wrap(ratatui::widgets::Wrap { trim: true });
+    rect.render_widget(help_paragraph, chunks[1]);
 
-    render_blank_styled_canvas(rect, app, popup_area, false);
-    rect.render_stateful_widget(
-        fg_list,
-        chunks[0],
-        &mut app.state.app_list_states.edit_specific_style.0,
-    );
-    rect.render_stateful_widget(
-        bg_list,
-        chunks[1],
-        &mut app.state.app_list_states.edit_specific_style.1,
-    );
-    rect.render_stateful_widget(
-        modifier_list,
-        chunks[2],
-        &mut app.state.app_list_states.edit_specific_style.2,
-    );
-    rect.render_widget(help_text, main_chunks[1]);
-    rect.render_widget(submit_button, main_chunks[2]);
-    rect.render_widget(border_block, popup_area);
     if app.config.enable_mouse_support {
-        render_close_button(rect, app)
+        render_close_button(rect, app);
     }
 }
 
-pub fn render_save_theme_prompt(rect: &mut Frame, app: &mut App) {
-    let popup_area = centered_rect_with_length(40, 10, rect.size());
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
-        .margin(2)
-        .split(popup_area);
+pub fn render_swimlane_grouping_prompt(rect: &mut Frame, app: &mut App) {
+    let prompt_text = "Enter how cards are split into swimlanes: priority, tag:<prefix> (for example tag:epic:), field:<custom field name> or assignee for the Assignee custom field. Leave empty to turn swimlanes off".to_string();
+    render_text_input_prompt(rect, app, "Swimlane Grouping", prompt_text);
+}
 
-    let save_theme_button_style =
-        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[0], false, false);
-    let dont_save_theme_button_style =
-        get_mouse_focusable_field_style(app, Focus::ExtraFocus, &chunks[1], false, false);
-    let save_theme_button = Paragraph::new("Save Theme to File")
-        .style(save_theme_button_style)
+pub fn render_filter_by_query_prompt(rect: &mut Frame, app: &mut App) {
+    let mut prompt_lines = vec![Line::from(
+        "Filter cards with name:, description:, comment:, status:, priority:, tag:, due:, created:, modified:, estimate: and board:, for example tag:bug status:active due:<7d estimate:>=3 -tag:wontfix \"login page\". Combine terms with AND, OR, NOT and parentheses",
+    )];
+    let filter_query = app.state.current_user_input.trim();
+    if filter_query.is_empty() {
+        prompt_lines.push(Line::from(Span::styled(
+            "Leave empty to clear the query filter",
+            app.current_theme.help_text_style,
+        )));
+    } else {
+        match CardQuery::parse(filter_query) {
+            Ok(card_query) => {
+                let matching_cards = app
+                    .boards
+                    .get_boards()
+                    .iter()
+                    .map(|board| {
+                        board
+                            .cards
+                            .get_all_cards()
+                            .iter()
+                            .filter(|card| card_query.matches(card, board))
+                            .count()
+                    })
+                    .sum::<usize>();
+                prompt_lines.push(Line::from(Span::styled(
+                    format!("{} cards match", matching_cards),
+                    app.current_theme.help_text_style,
+                )));
+            }
+            Err(err) => prompt_lines.push(Line::from(Span::styled(
+                err.to_string(),
+                app.current_theme.error_text_style,
+            ))),
+        }
+    }
+    render_text_input_prompt(rect, app, "Filter by Query", prompt_lines);
+}
+
+pub fn render_save_board_template_prompt(rect: &mut Frame, app: &mut App) {
+    let prompt_text = "Enter a name for the template. The current boards, their descriptions and WIP limits are saved along with every card that is not completed as a seed card".to_string();
+    render_text_input_prompt(rect, app, "Save Boards as Template", prompt_text);
+}
+
+pub fn render_board_template_selector(rect: &mut Frame, app: &mut App) {
+    let template_items = app
+        .board_templates
+        .iter()
+        .map(|template| {
+            let board_names = template
+                .boards
+                .iter()
+                .map(|board| match board.wip_limit {
+                    Some(wip_limit) => format!("{} (WIP {})", board.name, wip_limit),
+                    None => board.name.clone(),
+                })
+                .collect::<Vec<String>>()
+                .join(" -> ");
+            let seed_card_count = template
+                .boards
+                .iter()
+                .map(|board| board.seed_cards.len())
+                .sum::<usize>();
+            ListItem::new(vec![Line::from(vec![
+                Span::styled(
+                    template.name.clone(),
+                    app.current_theme.keyboard_focus_style,
+                ),
+                Span::raw(format!(
+                    ": {} ({} seed cards)",
+                    board_names, seed_card_count
+                )),
+            ])])
+        })
+        .collect::<Vec<ListItem>>();
+    let popup_area = centered_rect_with_percentage(70, 60, rect.size());
+    if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+        app.state.mouse_focus = Some(Focus::BoardTemplateSelector);
+        app.state.set_focus(Focus::BoardTemplateSelector);
+        calculate_mouse_list_select_index(
+            app.state.current_mouse_coordinates.1,
+            &template_items,
+            popup_area,
+            &mut app.state.app_list_states.board_template_list,
+        );
+    }
+    let templates = List::new(template_items)
         .block(
             Block::default()
+                .title("Create Boards from Template")
+                .style(app.current_theme.general_style)
                 .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(save_theme_button_style),
+                .border_type(BorderType::Rounded),
         )
-        .alignment(Alignment::Center);
-    let dont_save_theme_button = Paragraph::new("Don't Save Theme to File")
-        .style(dont_save_theme_button_style)
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        templates,
+        popup_area,
+        &mut app.state.app_list_states.board_template_list,
+    );
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_card_template_selector(rect: &mut Frame, app: &mut App) {
+    let selecting_default = app.state.popup_mode == Some(PopupMode::SelectDefaultCardTemplate);
+    let board_default_template = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+        .and_then(|board| board.default_card_template.clone());
+    let first_entry = if selecting_default {
+        "No Default"
+    } else {
+        "Blank Card"
+    };
+    let mut template_items = vec![ListItem::new(vec![Line::from(first_entry)])];
+    template_items.extend(app.card_templates.iter().map(|template| {
+        let mut spans = vec![Span::styled(
+            template.name.clone(),
+            app.current_theme.keyboard_focus_style,
+        )];
+        if board_default_template.as_ref() == Some(&template.name) {
+            spans.push(Span::raw(" (board default)"));
+        }
+        spans.push(Span::raw(format!(
+            ": {} priority, {} checklist items",
+            template.priority,
+            template.checklist.len()
+        )));
+        if !template.tags.is_empty() {
+            spans.push(Span::raw(format!(", tags: {}", template.tags.join(", "))));
+        }
+        ListItem::new(vec![Line::from(spans)])
+    }));
+    let title = if selecting_default {
+        "Default Card Template for this Board"
+    } else {
+        "Create Card from Template"
+    };
+    let percent_height =
+        (((template_items.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+    let popup_area = centered_rect_with_percentage(60, percent_height, rect.size());
+    if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+        app.state.mouse_focus = Some(Focus::CardTemplateSelector);
+        app.state.set_focus(Focus::CardTemplateSelector);
+        calculate_mouse_list_select_index(
+            app.state.current_mouse_coordinates.1,
+            &template_items,
+            popup_area,
+            &mut app.state.app_list_states.card_template_list,
+        );
+    }
+    let templates = List::new(template_items)
         .block(
             Block::default()
+                .title(title)
+                .style(app.current_theme.general_style)
                 .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(dont_save_theme_button_style),
+                .border_type(BorderType::Rounded),
         )
-        .alignment(Alignment::Center);
-    let border_block = Block::default()
-        .title("Save Theme?")
-        .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(app.current_theme.general_style);
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
 
-    render_blank_styled_canvas(rect, app, popup_area, true);
-    rect.render_widget(save_theme_button, chunks[0]);
-    rect.render_widget(dont_save_theme_button, chunks[1]);
-    rect.render_widget(border_block, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        templates,
+        popup_area,
+        &mut app.state.app_list_states.card_template_list,
+    );
     if app.config.enable_mouse_support {
-        render_close_button(rect, app)
+        render_close_button(rect, app);
     }
 }
 
-pub fn render_confirm_discard_card_changes(rect: &mut Frame, app: &mut App) {
-    let popup_area = centered_rect_with_length(30, 7, rect.size());
-
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Fill(1), Constraint::Fill(1)].as_ref())
-        .margin(2)
-        .split(popup_area);
-
-    let save_card_button_style =
-        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[0], false, false);
-    let dont_save_card_button_style =
-        get_mouse_focusable_field_style(app, Focus::ExtraFocus, &chunks[1], false, false);
-    let save_theme_button = Paragraph::new("Yes")
-        .style(save_card_button_style)
+pub fn render_card_sort_mode_selector(rect: &mut Frame, app: &mut App) {
+    let board_sort_mode = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.get_board_with_id(board_id))
+        .map(|board| board.sort_mode);
+    let sort_mode_items: Vec<ListItem> = CardSortMode::all()
+        .iter()
+        .map(|sort_mode| {
+            if Some(*sort_mode) == board_sort_mode {
+                ListItem::new(format!("{} (current)", sort_mode))
+            } else {
+                ListItem::new(sort_mode.to_string())
+            }
+        })
+        .collect();
+    let percent_height =
+        (((sort_mode_items.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+    let popup_area = centered_rect_with_percentage(40, percent_height, rect.size());
+    if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+        app.state.mouse_focus = Some(Focus::CardSortModeSelector);
+        app.state.set_focus(Focus::CardSortModeSelector);
+        calculate_mouse_list_select_index(
+            app.state.current_mouse_coordinates.1,
+            &sort_mode_items,
+            popup_area,
+            &mut app.state.app_list_states.card_sort_mode_list,
+        );
+    }
+    let sort_modes = List::new(sort_mode_items)
         .block(
             Block::default()
+                .title("Sort Cards in Board")
+                .style(app.current_theme.general_style)
                 .borders(Borders::ALL)
-                .border_style(save_card_button_style)
                 .border_type(BorderType::Rounded),
         )
-        .alignment(Alignment::Center);
-    let dont_save_theme_button = Paragraph::new("No")
-        .style(dont_save_card_button_style)
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        sort_modes,
+        popup_area,
+        &mut app.state.app_list_states.card_sort_mode_list,
+    );
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_saved_filter_selector(rect: &mut Frame, app: &mut App) {
+    let slot_keybindings = [
+        &app.config.keybindings.apply_saved_filter_1,
+        &app.config.keybindings.apply_saved_filter_2,
+        &app.config.keybindings.apply_saved_filter_3,
+    ];
+    let saved_filter_items = app
+        .config
+        .saved_filters
+        .iter()
+        .enumerate()
+        .map(|(index, saved_filter)| {
+            let mut spans = vec![Span::styled(
+                saved_filter.name.clone(),
+                app.current_theme.keyboard_focus_style,
+            )];
+            if let Some(key) = slot_keybindings
+                .get(index)
+                .and_then(|keybinding| keybinding.first())
+            {
+                spans.push(Span::raw(format!(" {}", key)));
+            }
+            if app.state.active_saved_filter.as_ref() == Some(&saved_filter.name) {
+                spans.push(Span::raw(" (active)"));
+            }
+            spans.push(Span::raw(format!(": {}", saved_filter.query)));
+            ListItem::new(Line::from(spans))
+        })
+        .collect::<Vec<ListItem>>();
+    let percent_height =
+        (((saved_filter_items.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+    let popup_area = centered_rect_with_percentage(60, percent_height, rect.size());
+    if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &popup_area) {
+        app.state.mouse_focus = Some(Focus::SavedFilterSelector);
+        app.state.set_focus(Focus::SavedFilterSelector);
+        calculate_mouse_list_select_index(
+            app.state.current_mouse_coordinates.1,
+            &saved_filter_items,
+            popup_area,
+            &mut app.state.app_list_states.saved_filter_list,
+        );
+    }
+    let delete_key = app
+        .config
+        .keybindings
+        .delete_card
+        .first()
+        .map(|key| key.to_string())
+        .unwrap_or_default();
+    let saved_filters = List::new(saved_filter_items)
         .block(
             Block::default()
+                .title(format!("Saved Filters ({} to delete)", delete_key))
+                .style(app.current_theme.general_style)
                 .borders(Borders::ALL)
-                .border_style(dont_save_card_button_style)
                 .border_type(BorderType::Rounded),
         )
-        .alignment(Alignment::Center);
-    let border_block = Block::default()
-        .title("Save Changes to Card?")
-        .borders(Borders::ALL)
-        .border_type(BorderType::Rounded)
-        .border_style(app.current_theme.general_style);
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
 
-    render_blank_styled_canvas(rect, app, popup_area, true);
-    rect.render_widget(save_theme_button, chunks[0]);
-    rect.render_widget(dont_save_theme_button, chunks[1]);
-    rect.render_widget(border_block, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        saved_filters,
+        popup_area,
+        &mut app.state.app_list_states.saved_filter_list,
+    );
     if app.config.enable_mouse_support {
-        render_close_button(rect, app)
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_save_filter_prompt(rect: &mut Frame, app: &mut App) {
+    let filter_query = get_current_filter_query(app).unwrap_or_default();
+    let prompt_text = format!(
+        "Enter a name for the current filter, using the name of an existing saved filter replaces it. The filter is saved as: {}",
+        filter_query
+    );
+    render_text_input_prompt(rect, app, "Save Current Filter", prompt_text);
+}
+
+pub fn render_time_report_range_prompt(rect: &mut Frame, app: &mut App) {
+    let prompt_text =
+        "Enter the report range as start | end with dates as YYYY-MM-DD, both days are included"
+            .to_string();
+    render_text_input_prompt(rect, app, "Time Report Range", prompt_text);
+}
+
+pub fn render_sprint_prompt(rect: &mut Frame, app: &mut App) {
+    let closing_sprint = app
+        .state
+        .sprints
+        .closing_sprint
+        .and_then(|index| app.config.get_sprints().get(index))
+        .cloned();
+    match closing_sprint {
+        Some(sprint) => {
+            let unfinished_cards = sprint.unfinished_card_ids(&app.boards).len();
+            let prompt_text = format!(
+                "Closing sprint {} with {} unfinished cards. Enter the next sprint as name | start | end | tag to carry them over, or leave it empty to close without carrying anything over",
+                sprint.name, unfinished_cards
+            );
+            render_text_input_prompt(rect, app, "Close Sprint", prompt_text);
+        }
+        None => {
+            let prompt_text = "Enter the sprint as name | start | end | tag with dates as YYYY-MM-DD. The end defaults to two weeks after the start, cards with the tag are in the sprint and others can be added with Add Card to Sprint".to_string();
+            render_text_input_prompt(rect, app, "Create Sprint", prompt_text);
+        }
     }
 }
 
-pub fn render_custom_rgb_color_prompt(rect: &mut Frame, app: &mut App) {
-    let popup_area = centered_rect_with_length(60, 18, rect.size());
-    let prompt_text = "Enter a custom RGB color in the format: r,g,b (0-254)";
+pub fn render_card_recurrence_prompt(rect: &mut Frame, app: &mut App) {
+    let prompt_text = "Enter how often this card repeats: daily, weekly, weekly:mon,thu, monthly or every:N for every N days. Leave empty to stop repeating. Completing the card or reaching its due date creates the next occurrence".to_string();
+    render_text_input_prompt(rect, app, "Card Recurrence", prompt_text);
+}
 
+fn render_text_input_prompt<'a>(
+    rect: &mut Frame,
+    app: &mut App,
+    title: &str,
+    prompt_text: impl Into<Text<'a>>,
+) {
+    let popup_area = centered_rect_with_length(70, 20, rect.size());
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
             [
-                Constraint::Length(1),
+                Constraint::Length(4),
                 Constraint::Fill(1),
                 Constraint::Length(3),
                 Constraint::Length(5),
@@ -5817,7 +9950,7 @@ pub fn render_custom_rgb_color_prompt(rect: &mut Frame, app: &mut App) {
         .margin(1)
         .split(popup_area);
     let border_block = Block::default()
-        .title("Custom RGB Color")
+        .title(title)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .border_style(app.current_theme.general_style);
@@ -5895,7 +10028,7 @@ pub fn render_custom_rgb_color_prompt(rect: &mut Frame, app: &mut App) {
         )
         .alignment(Alignment::Center);
 
-    render_blank_styled_canvas(rect, app, popup_area, true);
+    render_blank_styled_canvas(rect, app, popup_area, false);
     rect.render_widget(prompt_text, chunks[0]);
     rect.render_widget(text_input, chunks[1]);
     rect.render_widget(submit_button, chunks[2]);
@@ -5903,17 +10036,339 @@ pub fn render_custom_rgb_color_prompt(rect: &mut Frame, app: &mut App) {
     rect.render_widget(border_block, popup_area);
 
     if app.state.app_status == AppStatus::UserInput {
-        let current_cursor_position = if app.state.current_cursor_position.is_some() {
-            app.state.current_cursor_position.unwrap() as u16
-        } else {
-            app.state.current_user_input.len() as u16
-        };
+        let current_cursor_position =
+            app.state
+                .current_cursor_position
+                .unwrap_or(app.state.current_user_input.len()) as u16;
         let x_offset = current_cursor_position % (chunks[1].width - 2);
         let y_offset = current_cursor_position / (chunks[1].width - 2);
         let x_cursor_position = chunks[1].x + x_offset + 1;
         let y_cursor_position = chunks[1].y + y_offset + 1;
         rect.set_cursor(x_cursor_position, y_cursor_position);
     }
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_edit_card_custom_fields_popup(rect: &mut Frame, app: &mut App) {
+    let popup_area = centered_rect_with_percentage(70, 70, rect.size());
+    let custom_fields = app
+        .get_custom_field_board()
+        .map(|board| board.custom_fields.clone())
+        .unwrap_or_default();
+    let card_custom_fields = if let Some((_, card)) = &app.state.card_being_edited {
+        card.custom_fields.clone()
+    } else {
+        app.state
+            .current_card_id
+            .and_then(|card_id| app.boards.get_card_with_id(card_id))
+            .map(|card| card.custom_fields.clone())
+            .unwrap_or_default()
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Fill(1),
+                Constraint::Length(3),
+                Constraint::Length(4),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(popup_area);
+
+    let list_style = if app.state.focus == Focus::CustomFieldSelector {
+        app.current_theme.keyboard_focus_style
+    } else {
+        app.current_theme.general_style
+    };
+    let text_input_style =
+        get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], false, true);
+    let submit_style =
+        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[3], false, false);
+
+    let field_items = custom_fields
+        .iter()
+        .map(|custom_field| {
+            let value = card_custom_fields
+                .get(&custom_field.name)
+                .cloned()
+                .unwrap_or(FIELD_NOT_SET.to_string());
+            ListItem::new(Line::from(format!(
+                "{} ({}): {}",
+                custom_field.name, custom_field.field_type, value
+            )))
+        })
+        .collect::<Vec<ListItem>>();
+    let field_list = List::new(field_items)
+        .block(
+            Block::default()
+                .title("Custom Fields")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(app.current_theme.general_style)
+                .border_style(list_style),
+        )
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    let text_input = Paragraph::new(app.state.current_user_input.clone())
+        .style(app.current_theme.general_style)
+        .block(
+            Block::default()
+                .title("Value")
+                .borders(Borders::ALL)
+                .border_style(text_input_style)
+                .border_type(BorderType::Rounded),
+        );
+
+    let up_key = app
+        .get_first_keybinding(KeyBindingEnum::Up)
+        .unwrap_or("".to_string());
+    let down_key = app
+        .get_first_keybinding(KeyBindingEnum::Down)
+        .unwrap_or("".to_string());
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_spans = Line::from(vec![
+        Span::styled("Use ", app.current_theme.help_text_style),
+        Span::styled(up_key, app.current_theme.help_key_style),
+        Span::styled(" and ", app.current_theme.help_text_style),
+        Span::styled(down_key, app.current_theme.help_key_style),
+        Span::styled(
+            " to select a field. Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(accept_key.clone(), app.current_theme.help_key_style),
+        Span::styled(
+            " to edit its value, then ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(accept_key, app.current_theme.help_key_style),
+        Span::styled(
+            " again to save it (an empty value clears the field). Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(cancel_key, app.current_theme.help_key_style),
+        Span::styled(" to go back to the card", app.current_theme.help_text_style),
+    ]);
+    let help = Paragraph::new(help_spans)
+        .block(
+            Block::default()
+                .title("Help")
+                .borders(Borders::ALL)
+                .style(app.current_theme.general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    let submit_button = Paragraph::new("Save Value")
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(app.current_theme.general_style)
+                .border_style(submit_style),
+        )
+        .alignment(Alignment::Center);
+
+    if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0]) {
+        app.state.mouse_focus = Some(Focus::CustomFieldSelector);
+        app.state.set_focus(Focus::CustomFieldSelector);
+        let top_of_list = chunks[0].y + 1;
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && ((mouse_y - top_of_list) as usize) < custom_fields.len() {
+            app.state
+                .app_list_states
+                .custom_field_selector
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        field_list,
+        chunks[0],
+        &mut app.state.app_list_states.custom_field_selector,
+    );
+    rect.render_widget(text_input, chunks[1]);
+    rect.render_widget(help, chunks[2]);
+    rect.render_widget(submit_button, chunks[3]);
+
+    if app.state.focus == Focus::TextInput && app.state.app_status == AppStatus::UserInput {
+        let current_cursor_position =
+            app.state
+                .current_cursor_position
+                .unwrap_or(app.state.current_user_input.len()) as u16;
+        let x_offset = current_cursor_position % (chunks[1].width - 2);
+        rect.set_cursor(chunks[1].x + x_offset + 1, chunks[1].y + 1);
+    }
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_card_attachments_popup(rect: &mut Frame, app: &mut App) {
+    let popup_area = centered_rect_with_percentage(70, 70, rect.size());
+    let attachment_entries = app.get_card_attachment_entries();
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Fill(1),
+                Constraint::Length(3),
+                Constraint::Length(4),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .split(popup_area);
+
+    let list_style = if app.state.focus == Focus::CardAttachmentList {
+        app.current_theme.keyboard_focus_style
+    } else {
+        app.current_theme.general_style
+    };
+    let text_input_style =
+        get_mouse_focusable_field_style(app, Focus::TextInput, &chunks[1], false, true);
+    let submit_style =
+        get_mouse_focusable_field_style(app, Focus::SubmitButton, &chunks[3], false, false);
+
+    let mut attachment_number = 0;
+    let attachment_items = attachment_entries
+        .iter()
+        .map(|entry| match entry {
+            CardAttachmentEntry::Attachment(attachment) => {
+                attachment_number += 1;
+                let kind = if attachment.is_url() { "Link" } else { "File" };
+                if attachment.is_missing_file() {
+                    ListItem::new(Line::from(vec![
+                        Span::raw(format!("{}) [{}] {} ", attachment_number, kind, attachment)),
+                        Span::styled("[missing]", app.current_theme.card_due_overdue_style),
+                    ]))
+                } else {
+                    ListItem::new(Line::from(format!(
+                        "{}) [{}] {}",
+                        attachment_number, kind, attachment
+                    )))
+                }
+            }
+            CardAttachmentEntry::DetectedUrl(url) => ListItem::new(Line::from(Span::styled(
+                format!("+ {} (found in description)", url),
+                app.current_theme.inactive_text_style,
+            ))),
+        })
+        .collect::<Vec<ListItem>>();
+    let attachment_list = List::new(attachment_items)
+        .block(
+            Block::default()
+                .title("Attachments")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(app.current_theme.general_style)
+                .border_style(list_style),
+        )
+        .highlight_style(app.current_theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    let text_input = Paragraph::new(app.state.current_user_input.clone())
+        .style(app.current_theme.general_style)
+        .block(
+            Block::default()
+                .title("Add Attachment (<title> | <url or file path>, or -<number> to remove)")
+                .borders(Borders::ALL)
+                .border_style(text_input_style)
+                .border_type(BorderType::Rounded),
+        );
+
+    let accept_key = app
+        .get_first_keybinding(KeyBindingEnum::Accept)
+        .unwrap_or("".to_string());
+    let cancel_key = app
+        .get_first_keybinding(KeyBindingEnum::GoToPreviousUIModeorCancel)
+        .unwrap_or("".to_string());
+    let help_spans = Line::from(vec![
+        Span::styled("Press ", app.current_theme.help_text_style),
+        Span::styled(accept_key.clone(), app.current_theme.help_key_style),
+        Span::styled(
+            " on an attachment to open it, or on a link found in the description to attach it. Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(accept_key, app.current_theme.help_key_style),
+        Span::styled(
+            " in the input to add or remove an attachment. Press ",
+            app.current_theme.help_text_style,
+        ),
+        Span::styled(cancel_key, app.current_theme.help_key_style),
+        Span::styled(" to go back to the card", app.current_theme.help_text_style),
+    ]);
+    let help = Paragraph::new(help_spans)
+        .block(
+            Block::default()
+                .title("Help")
+                .borders(Borders::ALL)
+                .style(app.current_theme.general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    let submit_button = Paragraph::new("Add Attachment")
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(app.current_theme.general_style)
+                .border_style(submit_style),
+        )
+        .alignment(Alignment::Center);
+
+    if check_if_mouse_is_in_area(&app.state.current_mouse_coordinates, &chunks[0]) {
+        app.state.mouse_focus = Some(Focus::CardAttachmentList);
+        app.state.set_focus(Focus::CardAttachmentList);
+        let top_of_list = chunks[0].y + 1;
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && ((mouse_y - top_of_list) as usize) < attachment_entries.len() {
+            app.state
+                .app_list_states
+                .card_attachment_list
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        attachment_list,
+        chunks[0],
+        &mut app.state.app_list_states.card_attachment_list,
+    );
+    rect.render_widget(text_input, chunks[1]);
+    rect.render_widget(help, chunks[2]);
+    rect.render_widget(submit_button, chunks[3]);
+
+    if app.state.focus == Focus::TextInput && app.state.app_status == AppStatus::UserInput {
+        let current_cursor_position =
+            app.state
+                .current_cursor_position
+                .unwrap_or(app.state.current_user_input.len()) as u16;
+        let x_offset = current_cursor_position % (chunks[1].width - 2);
+        rect.set_cursor(chunks[1].x + x_offset + 1, chunks[1].y + 1);
+    }
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
 }
 
 pub fn render_blank_styled_canvas(
@@ -1,8 +1,19 @@
 use super::{TextColorOptions, Theme};
 use crate::{
     app::{
-        app_helper::reset_preview_boards,
+        app_helper::{
+            check_due_reminders, filter_unestimated_cards, handle_toggle_card_timer, open_agenda,
+            open_archive_browser, open_board_template_selector, open_calendar,
+            open_card_sort_mode_selector, open_card_table, open_card_template_selector,
+            open_close_sprint_prompt, open_create_sprint_prompt,
+            open_default_card_template_selector, open_label_manager,
+            open_save_board_template_prompt, open_save_filter_prompt, open_saved_filter_selector,
+            open_sprints, open_statistics, open_timeline, open_workspace_switcher,
+            prepare_new_card_form, reset_preview_boards, sort_current_board_by_estimate,
+            toggle_current_card_in_sprint, toggle_workspace_theme_override,
+        },
         handle_exit,
+        search::SearchResult,
         state::{AppStatus, Focus, UiMode},
         App, AppReturn, PopupMode,
     },
@@ -166,6 +177,7 @@ impl WidgetManager<'_> {
 
     pub async fn update(&mut self) {
         let mut app = self.app.lock().await;
+        check_due_reminders(&mut app);
         ToastWidget::update(&mut app);
         CommandPaletteWidget::update(&mut app);
         CloseButtonWidget::update(&mut app);
@@ -230,7 +242,7 @@ pub struct CommandPaletteWidget {
     pub already_in_user_input_mode: bool,
     pub available_commands: Vec<CommandPaletteActions>,
     pub board_search_results: Option<Vec<(String, (u64, u64))>>,
-    pub card_search_results: Option<Vec<(String, (u64, u64))>>,
+    pub card_search_results: Option<Vec<SearchResult>>,
     pub command_palette_actions_corpus: Corpus,
     pub command_search_results: Option<Vec<CommandPaletteActions>>,
     pub last_focus: Option<Focus>,
@@ -331,6 +343,7 @@ impl CommandPaletteWidget {
                                 return AppReturn::Continue;
                             }
                             app.state.popup_mode = None;
+                            prepare_new_card_form(app);
                             app.state.prev_ui_mode = Some(app.state.ui_mode);
                             app.state.ui_mode = UiMode::NewCard;
                             app.state.set_focus(Focus::CardName);
@@ -414,6 +427,8 @@ impl CommandPaletteWidget {
                         } else {
                             app.send_info_toast("All Filters Cleared", None);
                         }
+                        app.state.filter_query = None;
+                        app.state.active_saved_filter = None;
                         app.state.filter_tags = None;
                         app.state.all_available_tags = None;
                         app.state.app_list_states.filter_by_tag_list.select(None);
@@ -424,6 +439,198 @@ impl CommandPaletteWidget {
                     CommandPaletteActions::ChangeDateFormat => {
                         app.state.popup_mode = Some(PopupMode::ChangeDateFormatPopup);
                     }
+                    CommandPaletteActions::FilterUnestimatedCards => {
+                        app.state.popup_mode = None;
+                        filter_unestimated_cards(app);
+                    }
+                    CommandPaletteActions::SortBoardByEstimate => {
+                        app.state.popup_mode = None;
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.send_error_toast("Cannot sort cards in this view", None);
+                        } else {
+                            sort_current_board_by_estimate(app);
+                        }
+                    }
+                    CommandPaletteActions::AddCustomField => {
+                        if app.state.current_board_id.is_none() {
+                            app.state.popup_mode = None;
+                            app.send_error_toast(
+                                "No board selected to add a custom field to",
+                                None,
+                            );
+                        } else {
+                            app.state.popup_mode = Some(PopupMode::CustomFieldDefinitionPrompt);
+                            app.state.set_focus(Focus::TextInput);
+                        }
+                    }
+                    CommandPaletteActions::OpenArchive => {
+                        app.state.popup_mode = None;
+                        open_archive_browser(app);
+                    }
+                    CommandPaletteActions::SetSwimlaneGrouping => {
+                        app.state.current_user_input = app
+                            .state
+                            .swimlane_grouping
+                            .as_ref()
+                            .map(|grouping| grouping.to_spec())
+                            .unwrap_or_default();
+                        app.state.current_cursor_position =
+                            Some(app.state.current_user_input.len());
+                        app.state.popup_mode = Some(PopupMode::SwimlaneGroupingPrompt);
+                        app.state.set_focus(Focus::TextInput);
+                        app.state.app_status = AppStatus::UserInput;
+                        // skip the cleanup below so the current grouping stays in the input
+                        app.widgets.command_palette.already_in_user_input_mode = false;
+                        app.widgets.command_palette.last_focus = None;
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::SwitchWorkspace => {
+                        app.state.popup_mode = None;
+                        open_workspace_switcher(app);
+                    }
+                    CommandPaletteActions::ToggleWorkspaceTheme => {
+                        app.state.popup_mode = None;
+                        toggle_workspace_theme_override(app);
+                    }
+                    CommandPaletteActions::CreateFromTemplate => {
+                        app.state.popup_mode = None;
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.send_error_toast("Cannot create boards in this view", None);
+                        } else {
+                            open_board_template_selector(app);
+                        }
+                    }
+                    CommandPaletteActions::SaveBoardsAsTemplate => {
+                        app.state.popup_mode = None;
+                        open_save_board_template_prompt(app);
+                    }
+                    CommandPaletteActions::NewCardFromTemplate => {
+                        app.state.popup_mode = None;
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.send_error_toast("Cannot create a new card in this view", None);
+                        } else if app.state.current_board_id.is_none() {
+                            app.send_error_toast("No board Selected / Available", None);
+                        } else {
+                            prepare_new_card_form(app);
+                            app.set_ui_mode(UiMode::NewCard);
+                            open_card_template_selector(app);
+                        }
+                    }
+                    CommandPaletteActions::SetDefaultCardTemplate => {
+                        app.state.popup_mode = None;
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.send_error_toast("Cannot change board settings in this view", None);
+                        } else {
+                            open_default_card_template_selector(app);
+                        }
+                    }
+                    CommandPaletteActions::OpenLabelManager => {
+                        app.state.popup_mode = None;
+                        open_label_manager(app);
+                    }
+                    CommandPaletteActions::SetBoardSortMode => {
+                        app.state.popup_mode = None;
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.send_error_toast("Cannot change board settings in this view", None);
+                        } else {
+                            open_card_sort_mode_selector(app);
+                        }
+                    }
+                    CommandPaletteActions::FilterByQuery => {
+                        app.state.current_user_input =
+                            app.state.filter_query.clone().unwrap_or_default();
+                        app.state.current_cursor_position =
+                            Some(app.state.current_user_input.len());
+                        app.state.popup_mode = Some(PopupMode::FilterByQueryPrompt);
+                        app.state.set_focus(Focus::TextInput);
+                        app.state.app_status = AppStatus::UserInput;
+                        // skip the cleanup below so the current query stays in the input
+                        app.widgets.command_palette.already_in_user_input_mode = false;
+                        app.widgets.command_palette.last_focus = None;
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::ApplySavedFilter => {
+                        app.state.popup_mode = None;
+                        if !UiMode::view_modes().contains(&app.state.ui_mode)
+                            && app.state.ui_mode != UiMode::CardTable
+                        {
+                            app.send_error_toast("Cannot apply filters in this view", None);
+                        } else {
+                            open_saved_filter_selector(app);
+                        }
+                    }
+                    CommandPaletteActions::SaveCurrentFilter => {
+                        app.state.popup_mode = None;
+                        if open_save_filter_prompt(app) {
+                            // skip the cleanup below so the current filter name stays in the input
+                            app.widgets.command_palette.already_in_user_input_mode = false;
+                            app.widgets.command_palette.last_focus = None;
+                            return AppReturn::Continue;
+                        }
+                    }
+                    CommandPaletteActions::OpenCalendar => {
+                        app.state.popup_mode = None;
+                        open_calendar(app);
+                    }
+                    CommandPaletteActions::OpenTimeline => {
+                        app.state.popup_mode = None;
+                        open_timeline(app);
+                    }
+                    CommandPaletteActions::OpenCardTable => {
+                        app.state.popup_mode = None;
+                        open_card_table(app);
+                    }
+                    CommandPaletteActions::OpenStatistics => {
+                        app.state.popup_mode = None;
+                        open_statistics(app);
+                    }
+                    CommandPaletteActions::OpenSprints => {
+                        app.state.popup_mode = None;
+                        open_sprints(app);
+                    }
+                    CommandPaletteActions::CreateSprint => {
+                        app.state.popup_mode = None;
+                        open_create_sprint_prompt(app);
+                        // skip the cleanup below so the suggested sprint stays in the input
+                        app.widgets.command_palette.already_in_user_input_mode = false;
+                        app.widgets.command_palette.last_focus = None;
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::CloseSprint => {
+                        app.state.popup_mode = None;
+                        if open_close_sprint_prompt(app) {
+                            // skip the cleanup below so the suggested next sprint stays in the input
+                            app.widgets.command_palette.already_in_user_input_mode = false;
+                            app.widgets.command_palette.last_focus = None;
+                            return AppReturn::Continue;
+                        }
+                    }
+                    CommandPaletteActions::AddCardToSprint => {
+                        app.state.popup_mode = None;
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.send_error_toast(
+                                "Cannot add a card to a sprint in this view",
+                                None,
+                            );
+                        } else {
+                            toggle_current_card_in_sprint(app);
+                        }
+                    }
+                    CommandPaletteActions::OpenAgenda => {
+                        app.state.popup_mode = None;
+                        open_agenda(app);
+                    }
+                    CommandPaletteActions::TimeReport => {
+                        app.state.popup_mode = Some(PopupMode::TimeReport);
+                    }
+                    CommandPaletteActions::ToggleCardTimer => {
+                        app.state.popup_mode = None;
+                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                            app.send_error_toast("Cannot track time on a card in this view", None);
+                        } else {
+                            handle_toggle_card_timer(app);
+                        }
+                    }
                     CommandPaletteActions::NoCommandsFound => {
                         app.state.popup_mode = None;
                         app.state.app_status = AppStatus::Initialized;
@@ -551,40 +758,12 @@ impl CommandPaletteWidget {
                 command_search_results = vec![CommandPaletteActions::NoCommandsFound]
             }
 
-            let mut card_search_results: Vec<(String, (u64, u64))> = vec![];
+            let mut card_search_results = vec![];
             if !current_search_string.is_empty() {
-                for board in app.boards.get_boards() {
-                    for card in board.cards.get_all_cards() {
-                        let search_helper =
-                            if card.name.to_lowercase().contains(&current_search_string) {
-                                format!("{} - Matched in Name", card.name)
-                            } else if card
-                                .description
-                                .to_lowercase()
-                                .contains(&current_search_string)
-                            {
-                                format!("{} - Matched in Description", card.name)
-                            } else if card
-                                .tags
-                                .iter()
-                                .any(|tag| tag.to_lowercase().contains(&current_search_string))
-                            {
-                                format!("{} - Matched in Tags", card.name)
-                            } else if card.comments.iter().any(|comment| {
-                                comment.to_lowercase().contains(&current_search_string)
-                            }) {
-                                format!("{} - Matched in Comments", card.name)
-                            } else {
-                                String::new()
-                            };
-                        if !search_helper.is_empty() {
-                            card_search_results.push((search_helper, card.id));
-                        }
-                    }
-                }
+                card_search_results = app.search_index.search(&current_search_string);
             }
             if !card_search_results.is_empty() {
-                app.widgets.command_palette.card_search_results = Some(card_search_results.clone());
+                app.widgets.command_palette.card_search_results = Some(card_search_results);
             }
 
             let mut board_search_results: Vec<(String, (u64, u64))> = vec![];
@@ -641,6 +820,7 @@ impl CommandPaletteWidget {
                     }
                     tags.push(tag.clone());
                 }
+                tags.extend(card.custom_field_tags());
             }
         }
         tags = tags.iter().map(|tag| tag.to_lowercase()).collect();
@@ -665,6 +845,7 @@ impl CommandPaletteWidget {
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum CommandPaletteActions {
+    AddCustomField,
     ChangeCurrentCardStatus,
     ChangeDateFormat,
     ChangeTheme,
@@ -674,11 +855,13 @@ pub enum CommandPaletteActions {
     CreateATheme,
     DebugMenu,
     FilterByTag,
+    FilterUnestimatedCards,
     HelpMenu,
     LoadASaveCloud,
     LoadASaveLocal,
     Login,
     Logout,
+    OpenArchive,
     MainMenu,
     NewBoard,
     NewCard,
@@ -688,12 +871,37 @@ pub enum CommandPaletteActions {
     ResetUI,
     SaveKanbanState,
     SignUp,
+    SortBoardByEstimate,
     SyncLocalData,
+    SetSwimlaneGrouping,
+    SwitchWorkspace,
+    ToggleWorkspaceTheme,
+    CreateFromTemplate,
+    SaveBoardsAsTemplate,
+    NewCardFromTemplate,
+    SetDefaultCardTemplate,
+    OpenLabelManager,
+    SetBoardSortMode,
+    FilterByQuery,
+    ApplySavedFilter,
+    SaveCurrentFilter,
+    OpenCalendar,
+    OpenTimeline,
+    OpenCardTable,
+    OpenStatistics,
+    OpenSprints,
+    CreateSprint,
+    CloseSprint,
+    AddCardToSprint,
+    OpenAgenda,
+    TimeReport,
+    ToggleCardTimer,
 }
 
 impl Display for CommandPaletteActions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::AddCustomField => write!(f, "Add Custom Field"),
             Self::ChangeCurrentCardStatus => write!(f, "Change Current Card Status"),
             Self::ChangeDateFormat => write!(f, "Change Date Format"),
             Self::ChangeTheme => write!(f, "Change Theme"),
@@ -702,6 +910,7 @@ impl Display for CommandPaletteActions {
             Self::CreateATheme => write!(f, "Create a Theme"),
             Self::DebugMenu => write!(f, "Toggle Debug Panel"),
             Self::FilterByTag => write!(f, "Filter by Tag"),
+            Self::FilterUnestimatedCards => write!(f, "Filter Unestimated Cards"),
             Self::LoadASaveCloud => write!(f, "Load a Save (Cloud)"),
             Self::LoadASaveLocal => write!(f, "Load a Save (Local)"),
             Self::Login => write!(f, "Login"),
@@ -717,7 +926,32 @@ impl Display for CommandPaletteActions {
             Self::ResetUI => write!(f, "Reset UI"),
             Self::SaveKanbanState => write!(f, "Save Kanban State"),
             Self::SignUp => write!(f, "Sign Up"),
+            Self::SortBoardByEstimate => write!(f, "Sort Board by Estimate"),
             Self::SyncLocalData => write!(f, "Sync Local Data"),
+            Self::TimeReport => write!(f, "Time Report"),
+            Self::ToggleCardTimer => write!(f, "Toggle Card Timer"),
+            Self::OpenArchive => write!(f, "Open Archive"),
+            Self::OpenAgenda => write!(f, "Open Agenda"),
+            Self::AddCardToSprint => write!(f, "Add Card to Sprint"),
+            Self::CloseSprint => write!(f, "Close Sprint"),
+            Self::CreateSprint => write!(f, "Create Sprint"),
+            Self::OpenSprints => write!(f, "Open Sprints"),
+            Self::OpenStatistics => write!(f, "Open Statistics"),
+            Self::OpenCardTable => write!(f, "Open Card Table"),
+            Self::OpenTimeline => write!(f, "Open Timeline"),
+            Self::OpenCalendar => write!(f, "Open Calendar"),
+            Self::SaveCurrentFilter => write!(f, "Save Current Filter"),
+            Self::ApplySavedFilter => write!(f, "Apply Saved Filter"),
+            Self::FilterByQuery => write!(f, "Filter by Query"),
+            Self::SetBoardSortMode => write!(f, "Set Board Sort Mode"),
+            Self::OpenLabelManager => write!(f, "Open Label Manager"),
+            Self::SetDefaultCardTemplate => write!(f, "Set Default Card Template"),
+            Self::NewCardFromTemplate => write!(f, "New Card from Template"),
+            Self::SaveBoardsAsTemplate => write!(f, "Save Boards as Template"),
+            Self::CreateFromTemplate => write!(f, "Create Boards from Template"),
+            Self::ToggleWorkspaceTheme => write!(f, "Toggle Workspace Theme Override"),
+            Self::SwitchWorkspace => write!(f, "Switch Workspace"),
+            Self::SetSwimlaneGrouping => write!(f, "Set Swimlane Grouping"),
         }
     }
 }
@@ -725,6 +959,7 @@ impl Display for CommandPaletteActions {
 impl CommandPaletteActions {
     pub fn all(debug_mode: bool) -> Vec<Self> {
         let all = vec![
+            Self::AddCustomField,
             Self::ChangeCurrentCardStatus,
             Self::ChangeDateFormat,
             Self::ChangeTheme,
@@ -733,11 +968,13 @@ impl CommandPaletteActions {
             Self::ConfigMenu,
             Self::CreateATheme,
             Self::FilterByTag,
+            Self::FilterUnestimatedCards,
             Self::HelpMenu,
             Self::LoadASaveCloud,
             Self::LoadASaveLocal,
             Self::Login,
             Self::Logout,
+            Self::OpenArchive,
             Self::MainMenu,
             Self::NewBoard,
             Self::NewCard,
@@ -746,7 +983,31 @@ impl CommandPaletteActions {
             Self::ResetUI,
             Self::SaveKanbanState,
             Self::SignUp,
+            Self::SortBoardByEstimate,
             Self::SyncLocalData,
+            Self::SetSwimlaneGrouping,
+            Self::SwitchWorkspace,
+            Self::ToggleWorkspaceTheme,
+            Self::CreateFromTemplate,
+            Self::SaveBoardsAsTemplate,
+            Self::NewCardFromTemplate,
+            Self::SetDefaultCardTemplate,
+            Self::OpenLabelManager,
+            Self::SetBoardSortMode,
+            Self::FilterByQuery,
+            Self::ApplySavedFilter,
+            Self::SaveCurrentFilter,
+            Self::OpenCalendar,
+            Self::OpenTimeline,
+            Self::OpenCardTable,
+            Self::OpenStatistics,
+            Self::OpenSprints,
+            Self::CreateSprint,
+            Self::CloseSprint,
+            Self::AddCardToSprint,
+            Self::OpenAgenda,
+            Self::TimeReport,
+            Self::ToggleCardTimer,
         ];
 
         if cfg!(debug_assertions) || debug_mode {
@@ -783,6 +1044,33 @@ impl CommandPaletteActions {
                 "logout" => Some(Self::Logout),
                 "sync local data" => Some(Self::SyncLocalData),
                 "load a save (cloud)" => Some(Self::LoadASaveCloud),
+                "time report" => Some(Self::TimeReport),
+                "filter unestimated cards" => Some(Self::FilterUnestimatedCards),
+                "sort board by estimate" => Some(Self::SortBoardByEstimate),
+                "toggle card timer" => Some(Self::ToggleCardTimer),
+                "add custom field" => Some(Self::AddCustomField),
+                "open archive" => Some(Self::OpenArchive),
+                "set swimlane grouping" => Some(Self::SetSwimlaneGrouping),
+                "switch workspace" => Some(Self::SwitchWorkspace),
+                "toggle workspace theme override" => Some(Self::ToggleWorkspaceTheme),
+                "create boards from template" => Some(Self::CreateFromTemplate),
+                "save boards as template" => Some(Self::SaveBoardsAsTemplate),
+                "new card from template" => Some(Self::NewCardFromTemplate),
+                "set default card template" => Some(Self::SetDefaultCardTemplate),
+                "open label manager" => Some(Self::OpenLabelManager),
+                "set board sort mode" => Some(Self::SetBoardSortMode),
+                "filter by query" => Some(Self::FilterByQuery),
+                "apply saved filter" => Some(Self::ApplySavedFilter),
+                "save current filter" => Some(Self::SaveCurrentFilter),
+                "open calendar" => Some(Self::OpenCalendar),
+                "open timeline" => Some(Self::OpenTimeline),
+                "open card table" => Some(Self::OpenCardTable),
+                "open statistics" => Some(Self::OpenStatistics),
+                "open sprints" => Some(Self::OpenSprints),
+                "create sprint" => Some(Self::CreateSprint),
+                "close sprint" => Some(Self::CloseSprint),
+                "add card to sprint" => Some(Self::AddCardToSprint),
+                "open agenda" => Some(Self::OpenAgenda),
                 "quit" => Some(Self::Quit),
                 _ => None,
             }
@@ -810,6 +1098,33 @@ impl CommandPaletteActions {
                 "Logout" => Some(Self::Logout),
                 "Sync Local Data" => Some(Self::SyncLocalData),
                 "Load a Save (Cloud)" => Some(Self::LoadASaveCloud),
+                "Time Report" => Some(Self::TimeReport),
+                "Filter Unestimated Cards" => Some(Self::FilterUnestimatedCards),
+                "Sort Board by Estimate" => Some(Self::SortBoardByEstimate),
+                "Toggle Card Timer" => Some(Self::ToggleCardTimer),
+                "Add Custom Field" => Some(Self::AddCustomField),
+                "Open Archive" => Some(Self::OpenArchive),
+                "Set Swimlane Grouping" => Some(Self::SetSwimlaneGrouping),
+                "Switch Workspace" => Some(Self::SwitchWorkspace),
+                "Toggle Workspace Theme Override" => Some(Self::ToggleWorkspaceTheme),
+                "Create Boards from Template" => Some(Self::CreateFromTemplate),
+                "Save Boards as Template" => Some(Self::SaveBoardsAsTemplate),
+                "New Card from Template" => Some(Self::NewCardFromTemplate),
+                "Set Default Card Template" => Some(Self::SetDefaultCardTemplate),
+                "Open Label Manager" => Some(Self::OpenLabelManager),
+                "Set Board Sort Mode" => Some(Self::SetBoardSortMode),
+                "Filter by Query" => Some(Self::FilterByQuery),
+                "Apply Saved Filter" => Some(Self::ApplySavedFilter),
+                "Save Current Filter" => Some(Self::SaveCurrentFilter),
+                "Open Calendar" => Some(Self::OpenCalendar),
+                "Open Timeline" => Some(Self::OpenTimeline),
+                "Open Card Table" => Some(Self::OpenCardTable),
+                "Open Statistics" => Some(Self::OpenStatistics),
+                "Open Sprints" => Some(Self::OpenSprints),
+                "Create Sprint" => Some(Self::CreateSprint),
+                "Close Sprint" => Some(Self::CloseSprint),
+                "Add Card to Sprint" => Some(Self::AddCardToSprint),
+                "Open Agenda" => Some(Self::OpenAgenda),
                 "Quit" => Some(Self::Quit),
                 _ => None,
             }
@@ -1,13 +1,13 @@
 use crate::{
-    app::{App, AppReturn},
+    app::{app_helper::handle_scheduled_card_updates, query::CardQuery, App, AppReturn},
     constants::ENCRYPTION_KEY_FILE_NAME,
     inputs::{events::Events, InputEvent},
     io::{
-        data_handler::reset_config,
+        data_handler::{get_config, reset_config},
         io_handler::{
             delete_a_save_from_database, generate_new_encryption_key,
-            get_all_save_ids_and_creation_dates_for_user, get_config_dir, login_for_user,
-            save_user_encryption_key,
+            get_all_save_ids_and_creation_dates_for_user, get_config_dir,
+            get_latest_workspace_boards, login_for_user, save_user_encryption_key,
         },
         IoEvent,
     },
@@ -16,7 +16,13 @@ use crate::{
 use crossterm::{event::EnableMouseCapture, execute};
 use eyre::Result;
 use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
-use std::{borrow::Cow, io::stdout, sync::Arc, time::Duration};
+use std::{
+    borrow::Cow,
+    io::stdout,
+    process::{Command, Stdio},
+    sync::Arc,
+    time::Duration,
+};
 use tokio::time::Instant;
 
 pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
@@ -65,6 +71,7 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App<'_>>>) -> Result<()> {
                 if app.state.previous_mouse_coordinates != app.state.current_mouse_coordinates {
                     app.state.previous_mouse_coordinates = app.state.current_mouse_coordinates;
                 }
+                handle_scheduled_card_updates(&mut app);
                 AppReturn::Continue
             }
         };
@@ -249,6 +256,42 @@ pub fn reset_app_main() {
     print_info("👍 Config reset");
 }
 
+/// prints the cards of the latest save that match the query, the same query engine filters cards in the app
+pub fn query_cards_main(query: &str) {
+    let card_query = match CardQuery::parse(query) {
+        Ok(card_query) => card_query,
+        Err(err) => {
+            print_error(&format!("Invalid query: {}", err));
+            return;
+        }
+    };
+    let config = match get_config(false) {
+        Ok(config) => config,
+        Err(err) => {
+            print_error(&format!("Could not load config: {}", err));
+            return;
+        }
+    };
+    let boards = get_latest_workspace_boards(&config, &config.current_workspace);
+    if boards.is_empty() {
+        print_warn("No saved boards found");
+        return;
+    }
+    let mut matching_cards = 0;
+    for board in boards.get_boards() {
+        for card in board.cards.get_all_cards() {
+            if card_query.matches(card, board) {
+                matching_cards += 1;
+                println!(
+                    "{} / {} [{}, {} priority, due {}]",
+                    board.name, card.name, card.card_status, card.priority, card.due_date
+                );
+            }
+        }
+    }
+    print_info(&format!("{} cards match", matching_cards));
+}
+
 pub fn print_error(error: &str) {
     bunt::println!("{$red}[ERROR]{/$} - {}", error);
 }
@@ -267,6 +310,31 @@ pub fn print_warn(warn: &str) {
     bunt::println!("{$yellow}[WARN]{/$}  - {}", warn);
 }
 
+/// hands a url or file path to the platform opener without waiting for it, the target is passed
+/// as a single argument and never goes through a shell, so characters like & in urls are safe
+pub fn open_with_system_opener(target: &str) -> Result<(), String> {
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else {
+        Command::new("xdg-open")
+    };
+    command
+        .arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|mut child| {
+            // reap the opener once it exits so it does not linger as a zombie process
+            std::thread::spawn(move || child.wait());
+        })
+        .map_err(|e| format!("Could not open {}: {}", target, e))
+}
+
 pub fn spaces(size: u8) -> &'static str {
     const SPACES: &str = "                                                                                                                                                                                                                                                                ";
     &SPACES[..size as usize]